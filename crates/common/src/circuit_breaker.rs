@@ -0,0 +1,254 @@
+use std::error::Error as StdError;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Returned instead of making a request while the breaker is open.
+#[derive(Debug, Clone)]
+pub struct CircuitOpenError {
+    pub retry_in: Duration,
+}
+
+impl std::fmt::Display for CircuitOpenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Polymarket API circuit open (retry in {}s)",
+            self.retry_in.as_secs()
+        )
+    }
+}
+
+impl StdError for CircuitOpenError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,
+    Open,
+    HalfOpen,
+}
+
+impl CircuitState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Closed => "closed",
+            Self::Open => "open",
+            Self::HalfOpen => "half_open",
+        }
+    }
+
+    /// Value for the `evaluator_polymarket_circuit_state` gauge (0=closed, 1=open, 2=half-open).
+    pub fn as_gauge(self) -> f64 {
+        match self {
+            Self::Closed => 0.0,
+            Self::Open => 1.0,
+            Self::HalfOpen => 2.0,
+        }
+    }
+}
+
+/// Consecutive-failure circuit breaker for upstream API calls.
+///
+/// After `failure_threshold` consecutive upstream failures the breaker opens and calls fail fast
+/// with `CircuitOpenError` for `cooldown`. Then a single probe call is let through (half-open):
+/// success closes the breaker, failure re-opens it for another cooldown. A probe whose outcome
+/// is never recorded (e.g. the caller was cancelled) is abandoned after one cooldown.
+/// A `failure_threshold` of 0 disables the breaker.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// When the current half-open probe was let through (None = no probe in flight).
+    probe_started_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold,
+            cooldown,
+            state: Mutex::new(BreakerState {
+                consecutive_failures: 0,
+                opened_at: None,
+                probe_started_at: None,
+            }),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(0, Duration::ZERO)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    pub fn state(&self) -> CircuitState {
+        let state = self.lock();
+        match state.opened_at {
+            None => CircuitState::Closed,
+            Some(at) if at.elapsed() >= self.cooldown => CircuitState::HalfOpen,
+            Some(_) => CircuitState::Open,
+        }
+    }
+
+    /// Check whether a call may proceed. While open, fails fast; once the cooldown has elapsed,
+    /// lets exactly one probe through until its outcome is recorded or it has been in flight
+    /// for longer than the cooldown.
+    pub fn before_call(&self) -> Result<(), CircuitOpenError> {
+        if self.failure_threshold == 0 {
+            return Ok(());
+        }
+        let mut state = self.lock();
+        let Some(opened_at) = state.opened_at else {
+            return Ok(());
+        };
+        let elapsed = opened_at.elapsed();
+        if elapsed < self.cooldown {
+            return Err(CircuitOpenError {
+                retry_in: self.cooldown - elapsed,
+            });
+        }
+        if let Some(probe_elapsed) = state.probe_started_at.map(|at| at.elapsed()) {
+            if probe_elapsed < self.cooldown {
+                return Err(CircuitOpenError {
+                    retry_in: self.cooldown - probe_elapsed,
+                });
+            }
+            tracing::warn!("Polymarket API circuit probe never completed; letting another through");
+        }
+        state.probe_started_at = Some(Instant::now());
+        drop(state);
+        metrics::gauge!("evaluator_polymarket_circuit_state")
+            .set(CircuitState::HalfOpen.as_gauge());
+        Ok(())
+    }
+
+    pub fn record_success(&self) {
+        if self.failure_threshold == 0 {
+            return;
+        }
+        let mut state = self.lock();
+        let was_open = state.opened_at.is_some();
+        state.consecutive_failures = 0;
+        state.opened_at = None;
+        state.probe_started_at = None;
+        drop(state);
+        if was_open {
+            tracing::info!("Polymarket API circuit closed (probe succeeded)");
+            metrics::gauge!("evaluator_polymarket_circuit_state")
+                .set(CircuitState::Closed.as_gauge());
+        }
+    }
+
+    pub fn record_failure(&self) {
+        if self.failure_threshold == 0 {
+            return;
+        }
+        let mut state = self.lock();
+        state.consecutive_failures = state.consecutive_failures.saturating_add(1);
+        let probe_failed = state.probe_started_at.take().is_some();
+        let should_open = probe_failed || state.consecutive_failures >= self.failure_threshold;
+        if !should_open {
+            return;
+        }
+        let newly_opened = state.opened_at.is_none();
+        state.opened_at = Some(Instant::now());
+        let failures = state.consecutive_failures;
+        drop(state);
+        if newly_opened || probe_failed {
+            tracing::warn!(
+                consecutive_failures = failures,
+                cooldown_secs = self.cooldown.as_secs(),
+                "Polymarket API circuit opened"
+            );
+        }
+        metrics::gauge!("evaluator_polymarket_circuit_state").set(CircuitState::Open.as_gauge());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_breaker_opens_after_consecutive_failures() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.before_call().is_ok());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.before_call().is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker_success_resets_failure_count() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_breaker_half_open_allows_single_probe() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(20));
+        breaker.record_failure();
+        assert!(breaker.before_call().is_err());
+        std::thread::sleep(Duration::from_millis(30));
+        assert_eq!(breaker.state(), CircuitState::HalfOpen);
+        assert!(breaker.before_call().is_ok(), "first probe allowed");
+        assert!(
+            breaker.before_call().is_err(),
+            "second caller waits for probe"
+        );
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(breaker.before_call().is_ok());
+    }
+
+    #[test]
+    fn test_circuit_breaker_failed_probe_reopens() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(20));
+        breaker.record_failure();
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(breaker.before_call().is_ok());
+        breaker.record_failure();
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(breaker.before_call().is_err());
+    }
+
+    #[test]
+    fn test_circuit_breaker_abandoned_probe_expires_after_cooldown() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(20));
+        breaker.record_failure();
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(breaker.before_call().is_ok(), "probe allowed");
+        // The probe's outcome is never recorded.
+        assert!(breaker.before_call().is_err());
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(
+            breaker.before_call().is_ok(),
+            "stale probe no longer blocks the breaker"
+        );
+        breaker.record_success();
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuit_breaker_disabled_never_opens() {
+        let breaker = CircuitBreaker::disabled();
+        for _ in 0..100 {
+            breaker.record_failure();
+        }
+        assert!(breaker.before_call().is_ok());
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use serde::Deserialize;
+use std::collections::HashMap;
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -20,17 +21,51 @@ pub struct Config {
     pub web: Option<Web>,
     #[serde(default)]
     pub events: Events,
+    #[serde(default)]
+    pub ranking: Ranking,
+    #[serde(default)]
+    pub trader_promotion: TraderPromotion,
+    #[serde(default)]
+    pub wallet_profiles: WalletProfiles,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct General {
     pub mode: String,
+    /// `EnvFilter` directives: a level ("info") or per-module overrides
+    /// ("info,evaluator::ingestion=debug"). `RUST_LOG` takes precedence when set.
     pub log_level: String,
+    /// Startup is treated as broken when this many critical bootstrap jobs (event scoring,
+    /// wallet / leaderboard discovery) fail; logged loudly and sent as JobFailed (0 = off).
+    #[serde(default = "default_bootstrap_critical_failure_threshold")]
+    pub bootstrap_critical_failure_threshold: usize,
+    /// Exit non-zero instead of running on when bootstrap is broken (see threshold above).
+    #[serde(default)]
+    pub bootstrap_exit_on_failure: bool,
+}
+
+fn default_bootstrap_critical_failure_threshold() -> usize {
+    3
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct Database {
     pub path: String,
+    /// Run a PASSIVE checkpoint as soon as the `-wal` file exceeds this size, independent of
+    /// the 5-minute timer (0 = timer only).
+    #[serde(default = "default_wal_checkpoint_max_bytes")]
+    pub wal_checkpoint_max_bytes: u64,
+    /// How often the `-wal` file size is checked against `wal_checkpoint_max_bytes`.
+    #[serde(default = "default_wal_size_check_interval_secs")]
+    pub wal_size_check_interval_secs: u64,
+}
+
+fn default_wal_checkpoint_max_bytes() -> u64 {
+    64 * 1024 * 1024
+}
+
+fn default_wal_size_check_interval_secs() -> u64 {
+    15
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -79,16 +114,52 @@ pub struct WalletDiscovery {
     #[serde(default = "default_markets_per_discovery_run")]
     pub markets_per_discovery_run: usize,
     pub refresh_interval_secs: u64,
-    /// Number of pages of 200 trades to fetch per market (offset 0, 200, 400, ...). Cap at 15 (API offset ~3000).
+    /// Number of pages of 200 trades to fetch per market (offset 0, 200, 400, ...). Clamped to 15 (API offset ~3000).
     #[serde(default = "default_trades_pages_per_market")]
     pub trades_pages_per_market: u32,
     /// "continuous" = run discovery in loop (rate limit only); "scheduled" = use refresh_interval_secs.
     #[serde(default = "default_wallet_discovery_mode")]
     pub wallet_discovery_mode: String,
+    /// Stop a discovery run after inserting this many new wallets (0 = unlimited).
+    #[serde(default = "default_max_new_wallets_per_run")]
+    pub max_new_wallets_per_run: u64,
+    /// Holders snapshot: flag wallets whose holding moved by at least this % since the previous snapshot.
+    #[serde(default = "default_holder_flow_min_change_pct")]
+    pub holder_flow_min_change_pct: f64,
+    /// Holders snapshot: only the top N markets by `market_scores.rank` are snapshotted.
+    #[serde(default = "default_holders_snapshot_top_markets")]
+    pub holders_snapshot_top_markets: u32,
+    /// Holders snapshot: skip markets whose latest score is older than this many days.
+    #[serde(default = "default_holders_snapshot_max_score_age_days")]
+    pub holders_snapshot_max_score_age_days: u32,
+    /// Market discovery skips wallets with a terminal exclusion newer than this many days
+    /// (0 = never skip). Leaderboard discovery is a strong signal and ignores the cooldown.
+    #[serde(default = "default_exclusion_cooldown_days")]
+    pub exclusion_cooldown_days: u32,
     #[serde(default)]
     pub leaderboard: WalletDiscoveryLeaderboard,
 }
 
+fn default_holder_flow_min_change_pct() -> f64 {
+    50.0
+}
+
+fn default_holders_snapshot_top_markets() -> u32 {
+    20
+}
+
+fn default_holders_snapshot_max_score_age_days() -> u32 {
+    2
+}
+
+fn default_exclusion_cooldown_days() -> u32 {
+    30
+}
+
+fn default_max_new_wallets_per_run() -> u64 {
+    5000
+}
+
 fn default_trades_pages_per_market() -> u32 {
     15
 }
@@ -155,6 +226,10 @@ fn default_stage1_recent_profit_window_days() -> u32 {
     30
 }
 
+fn default_stage1_max_wash_trade_score() -> f64 {
+    0.5
+}
+
 fn default_ingestion_parallel_tasks() -> usize {
     4
 }
@@ -172,6 +247,66 @@ pub struct Ingestion {
     pub backoff_base_ms: u64,
     #[serde(default = "default_ingestion_parallel_tasks")]
     pub parallel_tasks: usize,
+    /// Wallets/markets in flight across all ingestion jobs combined. `parallel_tasks` is per
+    /// job; this bounds the sum when trades, activity, positions and holders overlap.
+    #[serde(default = "default_ingestion_max_in_flight")]
+    pub max_in_flight: usize,
+    /// Token-bucket request rate shared across all API callers. When unset, derived from
+    /// `rate_limit_delay_ms` (e.g. 200ms -> 5 req/s).
+    #[serde(default)]
+    pub requests_per_second: Option<f64>,
+    /// Requests allowed back-to-back before the rate limit kicks in.
+    #[serde(default = "default_burst_size")]
+    pub burst_size: u32,
+    /// Consecutive upstream failures before the Polymarket circuit opens (0 = disabled).
+    #[serde(default = "default_circuit_breaker_failures")]
+    pub circuit_breaker_failures: u32,
+    /// How long the circuit stays open before a probe request is allowed.
+    #[serde(default = "default_circuit_breaker_cooldown_secs")]
+    pub circuit_breaker_cooldown_secs: u64,
+    /// Consecutive wallet-specific failures (4xx, malformed data) before trades ingestion
+    /// deactivates the wallet with an `INGESTION_FAILED` exclusion (0 = never).
+    #[serde(default = "default_max_wallet_ingestion_failures")]
+    pub max_wallet_ingestion_failures: u32,
+    /// Wall-clock cap on one Polymarket call including all retries and backoff sleeps
+    /// (0 = bounded by `max_retries` only).
+    #[serde(default = "default_retry_budget_ms")]
+    pub retry_budget_ms: u64,
+}
+
+fn default_burst_size() -> u32 {
+    1
+}
+
+fn default_circuit_breaker_failures() -> u32 {
+    5
+}
+
+fn default_circuit_breaker_cooldown_secs() -> u64 {
+    60
+}
+
+fn default_max_wallet_ingestion_failures() -> u32 {
+    10
+}
+
+fn default_retry_budget_ms() -> u64 {
+    30_000
+}
+
+fn default_ingestion_max_in_flight() -> usize {
+    8
+}
+
+impl Ingestion {
+    /// Effective token-bucket rate; 0 means unlimited.
+    pub fn effective_requests_per_second(&self) -> f64 {
+        match self.requests_per_second {
+            Some(rps) => rps,
+            None if self.rate_limit_delay_ms == 0 => 0.0,
+            None => 1000.0 / self.rate_limit_delay_ms as f64,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -188,6 +323,20 @@ pub struct PaperTrading {
     pub slippage_default_cents: f64,
     pub mirror_use_proportional_sizing: bool,
     pub mirror_default_their_bankroll_usd: f64,
+    /// How often open paper trades are checked against market resolution.
+    #[serde(default = "default_settlement_interval_secs")]
+    pub settlement_interval_secs: u64,
+    /// Markets per Gamma request when checking resolution (limits API calls).
+    #[serde(default = "default_settlement_batch_size")]
+    pub settlement_batch_size: usize,
+}
+
+fn default_settlement_interval_secs() -> u64 {
+    3600
+}
+
+fn default_settlement_batch_size() -> usize {
+    50
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -199,6 +348,20 @@ pub struct WalletScoring {
     pub market_skill_weight: f64,
     pub timing_skill_weight: f64,
     pub behavior_quality_weight: f64,
+    /// Weight of the recency-weighted edge sub-score (decayed ROI).
+    #[serde(default = "default_recency_edge_weight")]
+    pub recency_edge_weight: f64,
+    /// Age at which a closed trade counts half as much toward the recency-weighted edge.
+    #[serde(default = "default_recency_half_life_days")]
+    pub recency_half_life_days: f64,
+}
+
+fn default_recency_edge_weight() -> f64 {
+    0.15
+}
+
+fn default_recency_half_life_days() -> f64 {
+    14.0
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -210,6 +373,13 @@ pub struct Observability {
 pub struct Polymarket {
     pub data_api_url: String,
     pub gamma_api_url: String,
+    /// TTL for cached Gamma market metadata responses (0 disables the cache).
+    #[serde(default = "default_cache_ttl_secs")]
+    pub cache_ttl_secs: u64,
+}
+
+fn default_cache_ttl_secs() -> u64 {
+    300
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -217,9 +387,112 @@ pub struct Web {
     pub port: u16,
     pub host: String,
     pub auth_password: Option<String>,
+    /// Bearer token for machine clients (`Authorization: Bearer <token>`), accepted in place of
+    /// the login cookie when auth_password is set.
+    #[serde(default)]
+    pub api_token: Option<String>,
     /// Base URL of the trader microservice (e.g. "http://aws-trader:8081").
     /// When set, the dashboard proxies /trader/** routes and shows "Follow" buttons.
     pub trader_api_url: Option<String>,
+    /// Read-only SQLite queries allowed to run at once; extra requests wait for a permit.
+    #[serde(default = "default_web_max_concurrent_db_queries")]
+    pub max_concurrent_db_queries: usize,
+    /// Per-query deadline (after a permit is acquired) before the request fails with 503.
+    #[serde(default = "default_web_db_timeout_ms")]
+    pub db_timeout_ms: u64,
+    /// How long Polymarket display names fetched from Gamma are reused (0 = always re-fetch).
+    #[serde(default = "default_web_display_name_cache_ttl_secs")]
+    pub display_name_cache_ttl_secs: u64,
+    /// How often `GET /ws` re-reads the dashboard state and pushes changes to connected clients.
+    #[serde(default = "default_web_dashboard_push_interval_secs")]
+    pub dashboard_push_interval_secs: u64,
+    /// Page size for the wallet trades/positions/activity JSON endpoints when `limit` is omitted.
+    #[serde(default = "default_web_default_page_size")]
+    pub default_page_size: u32,
+    /// Largest `limit` those endpoints accept; bigger requests are clamped.
+    #[serde(default = "default_web_max_page_size")]
+    pub max_page_size: u32,
+    /// Send `Strict-Transport-Security`; turn off for plain-HTTP internal deployments.
+    #[serde(default = "default_web_hsts_enabled")]
+    pub hsts_enabled: bool,
+    /// Extra `script-src` sources appended to the Content-Security-Policy (e.g. a CDN mirror).
+    #[serde(default)]
+    pub csp_extra_script_src: Vec<String>,
+    /// Extra `connect-src` sources appended to the Content-Security-Policy.
+    #[serde(default)]
+    pub csp_extra_connect_src: Vec<String>,
+    /// Net shares a (market, outcome) position must exceed to count as active; at or below
+    /// it the position is treated as closed dust.
+    #[serde(default = "default_web_active_position_min_shares")]
+    pub active_position_min_shares: f64,
+    /// Reuse HTTP/1.1 connections across requests (hyper default).
+    #[serde(default = "default_web_http1_keep_alive")]
+    pub http1_keep_alive: bool,
+    /// Streams one HTTP/2 connection may have open at once (hyper default 200).
+    #[serde(default = "default_web_http2_max_concurrent_streams")]
+    pub http2_max_concurrent_streams: u32,
+    /// Interval between HTTP/2 keep-alive PINGs on idle connections (0 = no pings).
+    #[serde(default)]
+    pub http2_keep_alive_interval_secs: u64,
+    /// How long to wait for a PING ack before closing the connection.
+    #[serde(default = "default_web_http2_keep_alive_timeout_secs")]
+    pub http2_keep_alive_timeout_secs: u64,
+    /// Upper bounds (ms) of the histogram buckets for `evaluator_*` latency metrics.
+    #[serde(default = "default_web_metrics_histogram_buckets_ms")]
+    pub metrics_histogram_buckets_ms: Vec<f64>,
+}
+
+/// Histogram buckets (ms) used when `web.metrics_histogram_buckets_ms` is not set.
+pub const DEFAULT_METRICS_HISTOGRAM_BUCKETS_MS: [f64; 13] = [
+    1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0, 10000.0,
+];
+
+fn default_web_max_concurrent_db_queries() -> usize {
+    8
+}
+
+fn default_web_db_timeout_ms() -> u64 {
+    5000
+}
+
+fn default_web_display_name_cache_ttl_secs() -> u64 {
+    3600
+}
+
+fn default_web_dashboard_push_interval_secs() -> u64 {
+    10
+}
+
+fn default_web_default_page_size() -> u32 {
+    20
+}
+
+fn default_web_max_page_size() -> u32 {
+    100
+}
+
+fn default_web_hsts_enabled() -> bool {
+    true
+}
+
+fn default_web_active_position_min_shares() -> f64 {
+    0.5
+}
+
+fn default_web_http1_keep_alive() -> bool {
+    true
+}
+
+fn default_web_http2_max_concurrent_streams() -> u32 {
+    200
+}
+
+fn default_web_http2_keep_alive_timeout_secs() -> u64 {
+    20
+}
+
+fn default_web_metrics_histogram_buckets_ms() -> Vec<f64> {
+    DEFAULT_METRICS_HISTOGRAM_BUCKETS_MS.to_vec()
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -242,6 +515,10 @@ pub struct Personas {
     /// Window (days) for the Stage 1.5 recent profitability check.
     #[serde(default = "default_stage1_recent_profit_window_days")]
     pub stage1_recent_profit_window_days: u32,
+    /// Stage 1 gate: Maximum `wash_trade_score` (share of volume in quick same-market BUY/SELL
+    /// round trips). Wallets above this are excluded as volume manufacturers.
+    #[serde(default = "default_stage1_max_wash_trade_score")]
+    pub stage1_max_wash_trade_score: f64,
     /// Enable parallel classification (default: true)
     #[serde(default = "default_parallel_enabled")]
     pub parallel_enabled: bool,
@@ -292,6 +569,61 @@ pub struct Personas {
     /// Stage 2 ROI gate: minimum ROI (win rate + PnL combo) for followable personas. 0 = disabled.
     #[serde(default)]
     pub stage2_min_roi: f64,
+    /// Whether a persona (keyed by `wallet_personas.persona`, e.g. "INFORMED_SPECIALIST") counts
+    /// toward the suitable/follow-worthy funnel. Personas not listed count (default true); set an
+    /// experimental persona to false to keep it out of funnel totals while it is still classified.
+    #[serde(default)]
+    pub funnel_visibility: HashMap<String, bool>,
+    /// Trade-history window (days) used for classification features.
+    #[serde(default = "default_classification_window_days")]
+    pub classification_window_days: u32,
+    /// Wallets need at least this many days since their first trade before classification runs.
+    #[serde(default = "default_min_tracking_days")]
+    pub min_tracking_days: u32,
+    /// Minimum confidence (win rate) per followable persona, keyed like `funnel_visibility`.
+    /// A wallet below the cutoff is not assigned that persona. Unlisted personas have no cutoff.
+    #[serde(default)]
+    pub min_confidence: HashMap<String, f64>,
+    /// Drift score (largest per-feature change, 0..1) at which a classified wallet is re-evaluated.
+    #[serde(default = "default_drift_threshold")]
+    pub drift_threshold: f64,
+    /// How often classified wallets are checked for drift.
+    #[serde(default = "default_drift_check_interval_secs")]
+    pub drift_check_interval_secs: u64,
+    /// Optional TOML file of declarative Stage 2 persona rules (see `config/personas.example.toml`).
+    /// When set it replaces the built-in persona detectors; validated at evaluator startup.
+    #[serde(default)]
+    pub rules_file: Option<String>,
+}
+
+fn default_classification_window_days() -> u32 {
+    180
+}
+
+fn default_min_tracking_days() -> u32 {
+    7
+}
+
+fn default_drift_threshold() -> f64 {
+    0.5
+}
+
+fn default_drift_check_interval_secs() -> u64 {
+    86400
+}
+
+impl Personas {
+    /// Personas explicitly hidden from the suitable funnel counts (sorted for stable SQL).
+    pub fn funnel_hidden_personas(&self) -> Vec<String> {
+        let mut hidden: Vec<String> = self
+            .funnel_visibility
+            .iter()
+            .filter(|(_, visible)| !**visible)
+            .map(|(persona, _)| persona.clone())
+            .collect();
+        hidden.sort();
+        hidden
+    }
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -312,6 +644,12 @@ pub struct WalletRules {
     pub min_paper_profit_per_trade: f64,
     pub max_paper_drawdown: f64,
     pub max_paper_slippage_bps: f64,
+    /// Minimum win rate (0-1) over closed round trips in the last 7 days to leave paper trading.
+    #[serde(default = "default_paper_min_win_rate_7d")]
+    pub paper_min_win_rate_7d: f64,
+    /// Closed round trips in the last 7 days needed before the win rate counts.
+    #[serde(default = "default_paper_min_closed_trades_7d")]
+    pub paper_min_closed_trades_7d: u32,
     // Live
     pub live_breakers_enabled: bool,
     pub live_max_drawdown: f64,
@@ -344,10 +682,14 @@ pub struct Events {
     #[serde(default)]
     pub log_to_db: bool,
 
-    /// Capacity for broadcast channels (pipeline and operational events)
+    /// Capacity for the pipeline-event broadcast channel
     #[serde(default = "default_bus_capacity")]
     pub bus_capacity: usize,
 
+    /// Capacity for the operational-event broadcast channel (sized separately from pipeline)
+    #[serde(default = "default_operational_bus_capacity")]
+    pub operational_bus_capacity: usize,
+
     /// Phase 3: Enable event-driven wallet discovery (MarketsScored → discovery)
     #[serde(default)]
     pub enable_discovery_event_trigger: bool,
@@ -363,6 +705,18 @@ pub struct Events {
     /// Batching window for classification trigger (seconds)
     #[serde(default = "default_classification_batch_window")]
     pub classification_batch_window_secs: u64,
+
+    /// Enable event-driven wallet scoring (TradesIngested → batched → scoring of those wallets)
+    #[serde(default)]
+    pub enable_scoring_event_trigger: bool,
+
+    /// Batching window for the wallet-scoring trigger (seconds)
+    #[serde(default = "default_scoring_batch_window")]
+    pub scoring_batch_window_secs: u64,
+
+    /// Pipeline events kept for subscribers that attach late (0 = replay disabled)
+    #[serde(default)]
+    pub replay_buffer_size: usize,
 }
 
 impl Default for Events {
@@ -371,26 +725,160 @@ impl Default for Events {
             enabled: false,
             log_to_db: false,
             bus_capacity: default_bus_capacity(),
+            operational_bus_capacity: default_operational_bus_capacity(),
             enable_discovery_event_trigger: false,
             enable_classification_event_trigger: false,
             enable_fast_path_trigger: false,
             classification_batch_window_secs: default_classification_batch_window(),
+            enable_scoring_event_trigger: false,
+            scoring_batch_window_secs: default_scoring_batch_window(),
+            replay_buffer_size: 0,
+        }
+    }
+}
+
+/// Paper-ROI bar a wallet must clear on the latest score date to count as "worth following"
+/// (rankings table and funnel counts share it).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Ranking {
+    /// Minimum 7-day paper ROI, percent (strictly greater than).
+    #[serde(default = "default_min_roi_7d_pct")]
+    pub min_roi_7d_pct: f64,
+    /// Minimum 30-day paper ROI, percent (strictly greater than).
+    #[serde(default = "default_min_roi_30d_pct")]
+    pub min_roi_30d_pct: f64,
+}
+
+impl Default for Ranking {
+    fn default() -> Self {
+        Self {
+            min_roi_7d_pct: default_min_roi_7d_pct(),
+            min_roi_30d_pct: default_min_roi_30d_pct(),
+        }
+    }
+}
+
+/// Opt-in job that registers follow-worthy wallets with the trader microservice
+/// (`POST /api/wallets`). Each wallet is promoted once; see the `trader_promotions` table.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TraderPromotion {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Trader base URL (e.g. "http://aws-trader:8081"); required when enabled.
+    #[serde(default)]
+    pub trader_base_url: Option<String>,
+    /// Sent as `Authorization: Bearer <key>` (the trader's `api_key`).
+    #[serde(default)]
+    pub trader_api_key: Option<String>,
+    /// Only the top N follow-worthy wallets by WScore are promoted.
+    #[serde(default = "default_trader_promotion_top_n")]
+    pub top_n: usize,
+    #[serde(default = "default_trader_promotion_interval_secs")]
+    pub interval_secs: u64,
+}
+
+impl Default for TraderPromotion {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            trader_base_url: None,
+            trader_api_key: None,
+            top_n: default_trader_promotion_top_n(),
+            interval_secs: default_trader_promotion_interval_secs(),
         }
     }
 }
 
+/// Background resolution of Gamma profile names for followed / ranked wallets into the
+/// `wallet_profiles` table (read by the web's journey and scorecard pages).
+#[derive(Debug, Clone, Deserialize)]
+pub struct WalletProfiles {
+    #[serde(default = "default_wallet_profiles_interval_secs")]
+    pub interval_secs: u64,
+    /// Profiles older than this are fetched again (names change, new profiles get set).
+    #[serde(default = "default_wallet_profiles_refresh_after_secs")]
+    pub refresh_after_secs: u64,
+    /// Max Gamma lookups per run; missing profiles go first, then the stalest.
+    #[serde(default = "default_wallet_profiles_batch_size")]
+    pub batch_size: usize,
+}
+
+impl Default for WalletProfiles {
+    fn default() -> Self {
+        Self {
+            interval_secs: default_wallet_profiles_interval_secs(),
+            refresh_after_secs: default_wallet_profiles_refresh_after_secs(),
+            batch_size: default_wallet_profiles_batch_size(),
+        }
+    }
+}
+
+fn default_wallet_profiles_interval_secs() -> u64 {
+    3600
+}
+
+fn default_wallet_profiles_refresh_after_secs() -> u64 {
+    86400
+}
+
+fn default_wallet_profiles_batch_size() -> usize {
+    50
+}
+
+fn default_trader_promotion_top_n() -> usize {
+    10
+}
+
+fn default_trader_promotion_interval_secs() -> u64 {
+    3600
+}
+
+fn default_min_roi_7d_pct() -> f64 {
+    5.0
+}
+
+fn default_min_roi_30d_pct() -> f64 {
+    10.0
+}
+
+fn default_paper_min_win_rate_7d() -> f64 {
+    0.55
+}
+
+fn default_paper_min_closed_trades_7d() -> u32 {
+    10
+}
+
 fn default_bus_capacity() -> usize {
     1000
 }
 
+fn default_operational_bus_capacity() -> usize {
+    1000
+}
+
 fn default_classification_batch_window() -> u64 {
     300 // 5 minutes
 }
 
+fn default_scoring_batch_window() -> u64 {
+    600 // 10 minutes
+}
+
 impl Config {
+    /// Load `config/default.toml` and reject semantically invalid settings (see `validate`).
     pub fn load() -> Result<Self> {
         let content = std::fs::read_to_string("config/default.toml")?;
-        Self::from_toml_str(&content)
+        let config = Self::from_toml_str(&content)?;
+        let problems = config.validate();
+        if !problems.is_empty() {
+            anyhow::bail!(
+                "invalid config/default.toml ({} problem(s)):\n  - {}",
+                problems.len(),
+                problems.join("\n  - ")
+            );
+        }
+        Ok(config)
     }
 
     pub fn from_toml_str(s: &str) -> Result<Self> {
@@ -448,6 +936,38 @@ mod tests {
         assert!(config.personas.accumulator_min_roi > 0.0);
     }
 
+    #[test]
+    fn test_effective_requests_per_second_falls_back_to_delay() {
+        let mut config =
+            Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
+        config.ingestion.requests_per_second = None;
+        config.ingestion.rate_limit_delay_ms = 200;
+        assert!((config.ingestion.effective_requests_per_second() - 5.0).abs() < 1e-9);
+        config.ingestion.rate_limit_delay_ms = 0;
+        assert!(config.ingestion.effective_requests_per_second().abs() < 1e-9);
+        config.ingestion.requests_per_second = Some(12.0);
+        assert!((config.ingestion.effective_requests_per_second() - 12.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_funnel_hidden_personas_lists_only_disabled_entries() {
+        let mut config =
+            Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
+        assert!(config.personas.funnel_hidden_personas().is_empty());
+        config
+            .personas
+            .funnel_visibility
+            .insert("FADE_MATERIAL".to_string(), false);
+        config
+            .personas
+            .funnel_visibility
+            .insert("INFORMED_SPECIALIST".to_string(), true);
+        assert_eq!(
+            config.personas.funnel_hidden_personas(),
+            vec!["FADE_MATERIAL".to_string()]
+        );
+    }
+
     #[test]
     fn test_wallet_rules_config_loads() {
         let config = Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
@@ -982,6 +1502,7 @@ size_change_multiplier = 10.0
             "event logging to DB should be enabled in default.toml"
         );
         assert_eq!(events.bus_capacity, 1000);
+        assert_eq!(events.operational_bus_capacity, 1000);
     }
 
     #[test]
@@ -1001,5 +1522,10 @@ size_change_multiplier = 10.0
             "fast path trigger should be enabled in default.toml"
         );
         assert_eq!(events.classification_batch_window_secs, 300);
+        assert!(
+            !events.enable_scoring_event_trigger,
+            "scoring event trigger should be opt-in"
+        );
+        assert_eq!(events.scoring_batch_window_secs, 600);
     }
 }
@@ -0,0 +1,46 @@
+use super::require_nonzero;
+use crate::config::Config;
+
+impl Config {
+    /// `[events]`.
+    pub(super) fn validate_events(&self, problems: &mut Vec<String>) {
+        require_nonzero(problems, "events.bus_capacity", self.events.bus_capacity);
+        require_nonzero(
+            problems,
+            "events.operational_bus_capacity",
+            self.events.operational_bus_capacity,
+        );
+        if self.events.enable_classification_event_trigger {
+            require_nonzero(
+                problems,
+                "events.classification_batch_window_secs",
+                self.events.classification_batch_window_secs,
+            );
+        }
+        if self.events.enable_scoring_event_trigger {
+            require_nonzero(
+                problems,
+                "events.scoring_batch_window_secs",
+                self.events.scoring_batch_window_secs,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::default_config;
+
+    #[test]
+    fn test_validate_scoring_window_only_checked_when_trigger_enabled() {
+        let mut cfg = default_config();
+        cfg.events.scoring_batch_window_secs = 0;
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+
+        cfg.events.enable_scoring_event_trigger = true;
+        assert_eq!(
+            cfg.validate(),
+            vec!["events.scoring_batch_window_secs must be > 0".to_string()]
+        );
+    }
+}
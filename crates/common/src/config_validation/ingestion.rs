@@ -0,0 +1,38 @@
+use super::require_nonzero;
+use crate::config::Config;
+
+impl Config {
+    /// `[ingestion]`.
+    pub(super) fn validate_ingestion(&self, problems: &mut Vec<String>) {
+        let ing = &self.ingestion;
+        require_nonzero(
+            problems,
+            "ingestion.trades_poll_interval_secs",
+            ing.trades_poll_interval_secs,
+        );
+        require_nonzero(
+            problems,
+            "ingestion.activity_poll_interval_secs",
+            ing.activity_poll_interval_secs,
+        );
+        require_nonzero(
+            problems,
+            "ingestion.positions_poll_interval_secs",
+            ing.positions_poll_interval_secs,
+        );
+        require_nonzero(
+            problems,
+            "ingestion.holders_poll_interval_secs",
+            ing.holders_poll_interval_secs,
+        );
+        require_nonzero(problems, "ingestion.parallel_tasks", ing.parallel_tasks);
+        require_nonzero(problems, "ingestion.max_in_flight", ing.max_in_flight);
+        if let Some(rps) = ing.requests_per_second {
+            if !rps.is_finite() || rps < 0.0 {
+                problems.push(format!(
+                    "ingestion.requests_per_second must be >= 0 (got {rps})"
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,299 @@
+mod events;
+mod ingestion;
+mod personas;
+mod wallet_rules;
+mod web;
+
+use crate::config::Config;
+
+/// Push a problem when `value` is zero (intervals, capacities, batch sizes).
+fn require_nonzero<T: PartialEq + Default + Copy>(
+    problems: &mut Vec<String>,
+    field: &str,
+    value: T,
+) {
+    if value == T::default() {
+        problems.push(format!("{field} must be > 0"));
+    }
+}
+
+/// Push a problem when `value` is not a finite number > 0.
+fn require_positive(problems: &mut Vec<String>, field: &str, value: f64) {
+    if !(value.is_finite() && value > 0.0) {
+        problems.push(format!("{field} must be > 0 (got {value})"));
+    }
+}
+
+/// Push a problem when `value` is not a finite percentage in (0, 100].
+fn require_pct(problems: &mut Vec<String>, field: &str, value: f64) {
+    if !(value.is_finite() && value > 0.0 && value <= 100.0) {
+        problems.push(format!("{field} must be in (0, 100] (got {value})"));
+    }
+}
+
+impl Config {
+    /// Semantic checks that TOML deserialization can't express.
+    ///
+    /// Returns every problem found (empty = valid) so startup can report them all at once
+    /// instead of failing on the first. `Config::load` calls this and refuses to start on errors.
+    pub fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        self.validate_general(&mut problems);
+        self.validate_market_scoring(&mut problems);
+        self.validate_wallet_discovery(&mut problems);
+        self.validate_ingestion(&mut problems);
+        self.validate_paper_trading(&mut problems);
+        self.validate_scoring(&mut problems);
+        self.validate_wallet_rules(&mut problems);
+        self.validate_trader_promotion(&mut problems);
+        self.validate_personas(&mut problems);
+        self.validate_events(&mut problems);
+        if let Some(web) = &self.web {
+            web::validate_web(web, &mut problems);
+        }
+
+        problems
+    }
+
+    /// Log level and database settings.
+    fn validate_general(&self, problems: &mut Vec<String>) {
+        if let Err(e) = tracing_subscriber::EnvFilter::try_new(&self.general.log_level) {
+            problems.push(format!(
+                "general.log_level is not a valid filter directive ({:?}): {e}",
+                self.general.log_level
+            ));
+        }
+
+        require_nonzero(
+            problems,
+            "database.wal_size_check_interval_secs",
+            self.database.wal_size_check_interval_secs,
+        );
+    }
+
+    /// `[market_scoring]`.
+    fn validate_market_scoring(&self, problems: &mut Vec<String>) {
+        let ms = &self.market_scoring;
+        require_nonzero(problems, "market_scoring.top_n_events", ms.top_n_events);
+        require_nonzero(
+            problems,
+            "market_scoring.refresh_interval_secs",
+            ms.refresh_interval_secs,
+        );
+        if ms.min_days_to_expiry > ms.max_days_to_expiry {
+            problems.push(format!(
+                "market_scoring.min_days_to_expiry ({}) must be <= max_days_to_expiry ({})",
+                ms.min_days_to_expiry, ms.max_days_to_expiry
+            ));
+        }
+    }
+
+    /// `[wallet_discovery]`.
+    fn validate_wallet_discovery(&self, problems: &mut Vec<String>) {
+        let wd = &self.wallet_discovery;
+        require_nonzero(
+            problems,
+            "wallet_discovery.refresh_interval_secs",
+            wd.refresh_interval_secs,
+        );
+        let mode = wd.wallet_discovery_mode.to_ascii_lowercase();
+        if mode != "continuous" && mode != "scheduled" {
+            problems.push(format!(
+                "wallet_discovery.wallet_discovery_mode must be \"continuous\" or \"scheduled\" (got {:?})",
+                wd.wallet_discovery_mode
+            ));
+        }
+
+        require_positive(
+            problems,
+            "wallet_discovery.holder_flow_min_change_pct",
+            wd.holder_flow_min_change_pct,
+        );
+        require_nonzero(
+            problems,
+            "wallet_discovery.holders_snapshot_top_markets",
+            wd.holders_snapshot_top_markets,
+        );
+        require_nonzero(
+            problems,
+            "wallet_discovery.holders_snapshot_max_score_age_days",
+            wd.holders_snapshot_max_score_age_days,
+        );
+    }
+
+    /// `[paper_trading]` and the `[risk]` bankroll.
+    fn validate_paper_trading(&self, problems: &mut Vec<String>) {
+        let pt = &self.paper_trading;
+        require_positive(problems, "paper_trading.bankroll_usd", pt.bankroll_usd);
+        require_pct(
+            problems,
+            "paper_trading.max_total_exposure_pct",
+            pt.max_total_exposure_pct,
+        );
+        require_pct(
+            problems,
+            "paper_trading.max_daily_loss_pct",
+            pt.max_daily_loss_pct,
+        );
+        require_positive(
+            problems,
+            "paper_trading.per_trade_size_usd",
+            pt.per_trade_size_usd,
+        );
+        require_nonzero(
+            problems,
+            "paper_trading.settlement_interval_secs",
+            pt.settlement_interval_secs,
+        );
+        require_nonzero(
+            problems,
+            "paper_trading.settlement_batch_size",
+            pt.settlement_batch_size,
+        );
+
+        require_positive(
+            problems,
+            "risk.paper_bankroll_usdc",
+            self.risk.paper_bankroll_usdc,
+        );
+    }
+
+    /// `[wallet_scoring]` and `[ranking]`.
+    fn validate_scoring(&self, problems: &mut Vec<String>) {
+        if self.wallet_scoring.windows_days.is_empty() {
+            problems.push("wallet_scoring.windows_days must not be empty".to_string());
+        }
+        if self.wallet_scoring.windows_days.contains(&0) {
+            problems.push("wallet_scoring.windows_days must not contain 0".to_string());
+        }
+        require_positive(
+            problems,
+            "wallet_scoring.recency_half_life_days",
+            self.wallet_scoring.recency_half_life_days,
+        );
+
+        for (field, value) in [
+            ("ranking.min_roi_7d_pct", self.ranking.min_roi_7d_pct),
+            ("ranking.min_roi_30d_pct", self.ranking.min_roi_30d_pct),
+        ] {
+            if !value.is_finite() {
+                problems.push(format!("{field} must be a finite number (got {value})"));
+            }
+        }
+    }
+
+    /// `[trader_promotion]` and `[wallet_profiles]`.
+    fn validate_trader_promotion(&self, problems: &mut Vec<String>) {
+        let tp = &self.trader_promotion;
+        if tp.enabled {
+            if tp
+                .trader_base_url
+                .as_deref()
+                .filter(|u| !u.trim().is_empty())
+                .is_none()
+            {
+                problems
+                    .push("trader_promotion.trader_base_url is required when enabled".to_string());
+            }
+            require_nonzero(problems, "trader_promotion.top_n", tp.top_n);
+            require_nonzero(problems, "trader_promotion.interval_secs", tp.interval_secs);
+        }
+
+        require_nonzero(
+            problems,
+            "wallet_profiles.interval_secs",
+            self.wallet_profiles.interval_secs,
+        );
+        require_nonzero(
+            problems,
+            "wallet_profiles.batch_size",
+            self.wallet_profiles.batch_size,
+        );
+    }
+}
+
+#[cfg(test)]
+fn default_config() -> Config {
+    Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_default_config_is_valid() {
+        assert_eq!(default_config().validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem_at_once() {
+        let mut cfg = default_config();
+        cfg.market_scoring.refresh_interval_secs = 0;
+        cfg.paper_trading.max_total_exposure_pct = 0.0;
+        cfg.events.bus_capacity = 0;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 3, "{problems:?}");
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("market_scoring.refresh_interval_secs")));
+        assert!(problems
+            .iter()
+            .any(|p| p.contains("paper_trading.max_total_exposure_pct")));
+        assert!(problems.iter().any(|p| p.contains("events.bus_capacity")));
+    }
+
+    #[test]
+    fn test_validate_rejects_inverted_expiry_window() {
+        let mut cfg = default_config();
+        cfg.market_scoring.min_days_to_expiry = 100;
+        cfg.market_scoring.max_days_to_expiry = 10;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("min_days_to_expiry"));
+    }
+
+    #[test]
+    fn test_validate_log_level_accepts_per_module_directives() {
+        let mut cfg = default_config();
+        cfg.general.log_level = "info,evaluator::ingestion=debug".to_string();
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+
+        cfg.general.log_level = "info,evaluator::ingestion=loud".to_string();
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].starts_with("general.log_level"));
+    }
+
+    #[test]
+    fn test_validate_trader_promotion_requires_base_url_when_enabled() {
+        let mut cfg = default_config();
+        cfg.trader_promotion.enabled = true;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("trader_promotion.trader_base_url"));
+
+        cfg.trader_promotion.trader_base_url = Some("http://trader:8081".to_string());
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_wallet_profiles_batch_size() {
+        let mut cfg = default_config();
+        cfg.wallet_profiles.batch_size = 0;
+        let problems = cfg.validate();
+        assert_eq!(
+            problems,
+            vec!["wallet_profiles.batch_size must be > 0".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_discovery_mode_and_nan() {
+        let mut cfg = default_config();
+        cfg.wallet_discovery.wallet_discovery_mode = "sometimes".to_string();
+        cfg.paper_trading.bankroll_usd = f64::NAN;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+    }
+}
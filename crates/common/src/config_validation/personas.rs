@@ -0,0 +1,53 @@
+use super::require_nonzero;
+use crate::config::Config;
+
+impl Config {
+    /// `[personas]`.
+    pub(super) fn validate_personas(&self, problems: &mut Vec<String>) {
+        if self.personas.parallel_enabled {
+            require_nonzero(
+                problems,
+                "personas.parallel_tasks",
+                self.personas.parallel_tasks,
+            );
+        }
+
+        let wash = self.personas.stage1_max_wash_trade_score;
+        if !(wash.is_finite() && wash > 0.0 && wash <= 1.0) {
+            problems.push(format!(
+                "personas.stage1_max_wash_trade_score must be in (0, 1] (got {wash})"
+            ));
+        }
+
+        let drift = self.personas.drift_threshold;
+        if !(drift.is_finite() && drift > 0.0 && drift <= 1.0) {
+            problems.push(format!(
+                "personas.drift_threshold must be in (0, 1] (got {drift})"
+            ));
+        }
+        require_nonzero(
+            problems,
+            "personas.drift_check_interval_secs",
+            self.personas.drift_check_interval_secs,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::default_config;
+
+    #[test]
+    fn test_validate_rejects_out_of_range_wash_trade_score() {
+        let mut cfg = default_config();
+        cfg.personas.stage1_max_wash_trade_score = 1.0;
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+
+        for bad in [0.0, 1.5, f64::NAN] {
+            cfg.personas.stage1_max_wash_trade_score = bad;
+            let problems = cfg.validate();
+            assert_eq!(problems.len(), 1, "{problems:?}");
+            assert!(problems[0].starts_with("personas.stage1_max_wash_trade_score"));
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use super::require_nonzero;
+use crate::config::Config;
+
+impl Config {
+    /// `[wallet_rules]` rolling 7-day win-rate gate for PAPER_TRADING → APPROVED.
+    pub(super) fn validate_wallet_rules(&self, problems: &mut Vec<String>) {
+        let win_rate = self.wallet_rules.paper_min_win_rate_7d;
+        if !(win_rate.is_finite() && (0.0..=1.0).contains(&win_rate)) {
+            problems.push(format!(
+                "wallet_rules.paper_min_win_rate_7d must be in [0, 1] (got {win_rate})"
+            ));
+        }
+        require_nonzero(
+            problems,
+            "wallet_rules.paper_min_closed_trades_7d",
+            self.wallet_rules.paper_min_closed_trades_7d,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::default_config;
+
+    #[test]
+    fn test_validate_rejects_out_of_range_paper_win_rate_gate() {
+        let mut cfg = default_config();
+        cfg.wallet_rules.paper_min_win_rate_7d = 1.5;
+        cfg.wallet_rules.paper_min_closed_trades_7d = 0;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(problems[0].contains("wallet_rules.paper_min_win_rate_7d"));
+        assert!(problems[1].contains("wallet_rules.paper_min_closed_trades_7d"));
+    }
+}
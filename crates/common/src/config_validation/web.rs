@@ -0,0 +1,150 @@
+use super::require_nonzero;
+use crate::config::Web;
+
+/// `[web]` (only checked when the section is present).
+pub(super) fn validate_web(web: &Web, problems: &mut Vec<String>) {
+    require_nonzero(
+        problems,
+        "web.max_concurrent_db_queries",
+        web.max_concurrent_db_queries,
+    );
+    require_nonzero(problems, "web.db_timeout_ms", web.db_timeout_ms);
+    require_nonzero(
+        problems,
+        "web.dashboard_push_interval_secs",
+        web.dashboard_push_interval_secs,
+    );
+    require_nonzero(problems, "web.default_page_size", web.default_page_size);
+    if web.default_page_size > web.max_page_size {
+        problems.push(format!(
+            "web.default_page_size ({}) must be <= max_page_size ({})",
+            web.default_page_size, web.max_page_size
+        ));
+    }
+    require_nonzero(
+        problems,
+        "web.http2_max_concurrent_streams",
+        web.http2_max_concurrent_streams,
+    );
+    require_nonzero(
+        problems,
+        "web.http2_keep_alive_timeout_secs",
+        web.http2_keep_alive_timeout_secs,
+    );
+    // An empty token would let a bare `Authorization: Bearer ` header authenticate.
+    if web
+        .api_token
+        .as_deref()
+        .is_some_and(|t| t.trim().is_empty())
+    {
+        problems.push("web.api_token must not be empty (omit it to disable bearer auth)".into());
+    }
+    let buckets = &web.metrics_histogram_buckets_ms;
+    if buckets.is_empty()
+        || buckets.iter().any(|b| !b.is_finite() || *b <= 0.0)
+        || buckets.windows(2).any(|w| w[0] >= w[1])
+    {
+        problems.push(format!(
+                "web.metrics_histogram_buckets_ms must be a non-empty, strictly increasing list of positive numbers (got {buckets:?})"
+            ));
+    }
+    if !(web.active_position_min_shares.is_finite() && web.active_position_min_shares >= 0.0) {
+        problems.push(format!(
+            "web.active_position_min_shares must be >= 0 (got {})",
+            web.active_position_min_shares
+        ));
+    }
+    for (field, sources) in [
+        ("web.csp_extra_script_src", &web.csp_extra_script_src),
+        ("web.csp_extra_connect_src", &web.csp_extra_connect_src),
+    ] {
+        for source in sources {
+            // One CSP source per entry: whitespace or ';' would inject extra directives.
+            if source.is_empty()
+                || source
+                    .chars()
+                    .any(|c| c.is_whitespace() || c.is_control() || c == ';' || c == ',')
+            {
+                problems.push(format!(
+                    "{field} entries must be single CSP sources (got {source:?})"
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::default_config;
+
+    #[test]
+    fn test_validate_rejects_default_page_size_above_max() {
+        let mut cfg = default_config();
+        let web = cfg.web.as_mut().unwrap();
+        web.default_page_size = 200;
+        web.max_page_size = 100;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].contains("default_page_size"));
+    }
+
+    #[test]
+    fn test_validate_rejects_negative_active_position_min_shares() {
+        let mut cfg = default_config();
+        let web = cfg.web.as_mut().unwrap();
+        web.active_position_min_shares = 0.0;
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+
+        cfg.web.as_mut().unwrap().active_position_min_shares = -1.0;
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].starts_with("web.active_position_min_shares"));
+    }
+
+    #[test]
+    fn test_validate_rejects_csp_source_with_extra_directive() {
+        let mut cfg = default_config();
+        let web = cfg.web.as_mut().unwrap();
+        web.csp_extra_script_src = vec!["https://cdn.example.com".to_string()];
+        web.csp_extra_connect_src = vec!["https://a.example.com; script-src *".to_string()];
+        let problems = cfg.validate();
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        assert!(problems[0].starts_with("web.csp_extra_connect_src"));
+    }
+
+    #[test]
+    fn test_validate_rejects_blank_api_token() {
+        for token in ["", "   "] {
+            let mut cfg = default_config();
+            cfg.web.as_mut().unwrap().api_token = Some(token.to_string());
+            let problems = cfg.validate();
+            assert_eq!(problems.len(), 1, "{token:?}: {problems:?}");
+            assert!(problems[0].starts_with("web.api_token"));
+        }
+
+        let mut cfg = default_config();
+        cfg.web.as_mut().unwrap().api_token = Some("machine-token".to_string());
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_metrics_histogram_buckets() {
+        for buckets in [
+            vec![],
+            vec![5.0, 5.0],
+            vec![10.0, 2.0],
+            vec![0.0, 1.0],
+            vec![f64::NAN],
+        ] {
+            let mut cfg = default_config();
+            cfg.web.as_mut().unwrap().metrics_histogram_buckets_ms = buckets.clone();
+            let problems = cfg.validate();
+            assert_eq!(problems.len(), 1, "{buckets:?}: {problems:?}");
+            assert!(problems[0].contains("web.metrics_histogram_buckets_ms"));
+        }
+
+        let mut cfg = default_config();
+        cfg.web.as_mut().unwrap().metrics_histogram_buckets_ms = vec![0.5, 1.0, 250.0];
+        assert_eq!(cfg.validate(), Vec::<String>::new());
+    }
+}
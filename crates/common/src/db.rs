@@ -7,6 +7,37 @@ pub struct Database {
     pub conn: Connection,
 }
 
+/// Schema version stamped into `PRAGMA user_version` by `Database::run_migrations`.
+/// Bump it whenever a migration changes tables or columns the web reader queries.
+pub const SCHEMA_VERSION: i64 = 2;
+
+/// Why a reader refused a DB whose `user_version` isn't `SCHEMA_VERSION`.
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum SchemaVersionError {
+    #[error("evaluator database schema not initialized; run the evaluator once to migrate it")]
+    NotInitialized,
+    #[error(
+        "evaluator database schema version mismatch: database is at {found}, this build expects {expected}; run the matching evaluator to migrate it"
+    )]
+    VersionMismatch { found: i64, expected: i64 },
+}
+
+/// Compare `PRAGMA user_version` with `SCHEMA_VERSION`. A mismatch is returned as a
+/// `SchemaVersionError` inside the `anyhow::Error`, so callers can `downcast_ref` it apart
+/// from plain SQLite failures.
+pub fn check_schema_version(conn: &Connection) -> Result<()> {
+    let found: i64 = conn.pragma_query_value(None, "user_version", |row| row.get(0))?;
+    match found {
+        SCHEMA_VERSION => Ok(()),
+        0 => Err(SchemaVersionError::NotInitialized.into()),
+        found => Err(SchemaVersionError::VersionMismatch {
+            found,
+            expected: SCHEMA_VERSION,
+        }
+        .into()),
+    }
+}
+
 /// Async database wrapper around `tokio_rusqlite::Connection`.
 ///
 /// Runs all SQLite operations on a dedicated background thread via
@@ -119,6 +150,17 @@ impl AsyncDb {
         )
     }
 
+    /// Close the background connection after every operation already queued has run.
+    ///
+    /// Used at shutdown once worker loops have stopped; calls through other clones made
+    /// afterwards fail with "database connection closed".
+    pub async fn close(self) -> Result<()> {
+        self.conn
+            .close()
+            .await
+            .map_err(|e| anyhow::anyhow!("database close error: {e}"))
+    }
+
     /// Like [`Self::call`], but records Prometheus metrics for DB latency and errors.
     ///
     /// Records three histograms:
@@ -205,6 +247,11 @@ impl Database {
         migrate_wallet_features_domain_columns(&self.conn).map_err(anyhow::Error::from)?;
         migrate_wallet_features_ag_columns(&self.conn).map_err(anyhow::Error::from)?;
         migrate_wallet_features_pnl_columns(&self.conn).map_err(anyhow::Error::from)?;
+        migrate_wallet_scores_recency_edge_column(&self.conn).map_err(anyhow::Error::from)?;
+        migrate_job_status_schedule_columns(&self.conn).map_err(anyhow::Error::from)?;
+        migrate_wallet_personas_superseded_column(&self.conn).map_err(anyhow::Error::from)?;
+        self.conn
+            .pragma_update(None, "user_version", SCHEMA_VERSION)?;
         Ok(())
     }
 }
@@ -248,10 +295,66 @@ fn migrate_markets_is_crypto_15m(conn: &Connection) -> std::result::Result<(), r
     Ok(())
 }
 
+/// Add recency_edge_score to wallet_scores_daily if missing (existing DBs; NULL for old rows).
+fn migrate_wallet_scores_recency_edge_column(
+    conn: &Connection,
+) -> std::result::Result<(), rusqlite::Error> {
+    let has: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('wallet_scores_daily') WHERE name='recency_edge_score'",
+        [],
+        |row| row.get(0),
+    )?;
+    if has == 0 {
+        conn.execute(
+            "ALTER TABLE wallet_scores_daily ADD COLUMN recency_edge_score REAL",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// Add superseded_at to wallet_personas if missing (existing DBs; NULL keeps old rows current).
+fn migrate_wallet_personas_superseded_column(
+    conn: &Connection,
+) -> std::result::Result<(), rusqlite::Error> {
+    let has: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM pragma_table_info('wallet_personas') WHERE name='superseded_at'",
+        [],
+        |row| row.get(0),
+    )?;
+    if has == 0 {
+        conn.execute(
+            "ALTER TABLE wallet_personas ADD COLUMN superseded_at TEXT",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+/// Add scheduler interval / next-run columns to job_status if missing (existing DBs).
+fn migrate_job_status_schedule_columns(
+    conn: &Connection,
+) -> std::result::Result<(), rusqlite::Error> {
+    for (name, ty) in [("interval_secs", "INTEGER"), ("next_run_at", "TEXT")] {
+        let has: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM pragma_table_info('job_status') WHERE name=?1",
+            rusqlite::params![name],
+            |row| row.get(0),
+        )?;
+        if has == 0 {
+            conn.execute(
+                &format!("ALTER TABLE job_status ADD COLUMN {name} {ty}"),
+                [],
+            )?;
+        }
+    }
+    Ok(())
+}
+
 fn migrate_wallet_features_ag_columns(
     conn: &Connection,
 ) -> std::result::Result<(), rusqlite::Error> {
-    let required: [(&str, &str); 16] = [
+    let required: [(&str, &str); 17] = [
         ("trades_per_day", "REAL NOT NULL DEFAULT 0.0"),
         ("avg_trade_size_usdc", "REAL NOT NULL DEFAULT 0.0"),
         ("size_cv", "REAL NOT NULL DEFAULT 0.0"),
@@ -268,6 +371,7 @@ fn migrate_wallet_features_ag_columns(
         ("resolved_wins", "INTEGER NOT NULL DEFAULT 0"),
         ("resolved_losses", "INTEGER NOT NULL DEFAULT 0"),
         ("realized_pnl", "REAL NOT NULL DEFAULT 0.0"),
+        ("wash_trade_score", "REAL NOT NULL DEFAULT 0.0"),
     ];
     for (name, ty) in required {
         let has: i64 = conn.query_row(
@@ -519,6 +623,7 @@ CREATE TABLE IF NOT EXISTS wallet_features_daily (
     top_domain TEXT,               -- dominant domain (wallet's lane)
     top_domain_ratio REAL NOT NULL DEFAULT 0.0,
     profitable_markets INTEGER NOT NULL DEFAULT 0,
+    wash_trade_score REAL NOT NULL DEFAULT 0.0,  -- balanced quick round-trip share (see wallet_features)
     UNIQUE(proxy_wallet, feature_date, window_days)
 );
 
@@ -566,6 +671,7 @@ CREATE TABLE IF NOT EXISTS wallet_scores_daily (
     market_skill_score REAL,
     timing_skill_score REAL,
     behavior_quality_score REAL,
+    recency_edge_score REAL,          -- edge from exponentially time-decayed ROI
     paper_roi_pct REAL,
     paper_hit_rate REAL,
     paper_max_drawdown_pct REAL,
@@ -581,6 +687,7 @@ CREATE TABLE IF NOT EXISTS wallet_personas (
     confidence REAL NOT NULL,          -- 0.0 to 1.0
     feature_values_json TEXT,          -- JSON: trade_count, win_rate, unique_markets, etc.
     classified_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%d %H:%M:%f', 'now')),
+    superseded_at TEXT,                -- set when persona drift retires the row; NULL = current
     UNIQUE(proxy_wallet, classified_at)
 );
 
@@ -594,6 +701,13 @@ CREATE TABLE IF NOT EXISTS wallet_exclusions (
     UNIQUE(proxy_wallet, reason)
 );
 
+CREATE TABLE IF NOT EXISTS wallet_ingestion_failures (
+    proxy_wallet TEXT PRIMARY KEY,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_failed_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
 CREATE TABLE IF NOT EXISTS wallet_persona_traits (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
     proxy_wallet TEXT NOT NULL,
@@ -628,7 +742,9 @@ CREATE TABLE IF NOT EXISTS job_status (
     duration_ms INTEGER,
     last_error TEXT,
     metadata TEXT,                     -- JSON with progress info
-    updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    interval_secs INTEGER,             -- scheduler interval (NULL = event-driven / not scheduled)
+    next_run_at TEXT                   -- scheduler's next tick, UTC "YYYY-MM-DD HH:MM:SS"
 );
 
 CREATE TABLE IF NOT EXISTS event_log (
@@ -700,6 +816,32 @@ CREATE TABLE IF NOT EXISTS failed_events (
     UNIQUE(event_type, event_data)
 );
 
+-- Wallets registered with the trader microservice by the trader_promotion job (one row per wallet).
+CREATE TABLE IF NOT EXISTS trader_promotions (
+    proxy_wallet TEXT PRIMARY KEY,
+    follow_mode TEXT NOT NULL,
+    wscore REAL,
+    promoted_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- Polymarket (Gamma) profile names resolved by the wallet_profiles job; the web reads these
+-- instead of calling Gamma per request. display_name is NULL when the wallet has no profile.
+CREATE TABLE IF NOT EXISTS wallet_profiles (
+    proxy_wallet TEXT PRIMARY KEY,
+    display_name TEXT,
+    fetched_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+-- Operator annotations added from the web scorecard ("manual review pending", ...). The only
+-- table the web service writes to.
+CREATE TABLE IF NOT EXISTS wallet_notes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    proxy_wallet TEXT NOT NULL,
+    note TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+CREATE INDEX IF NOT EXISTS idx_wallet_notes_wallet ON wallet_notes(proxy_wallet, created_at);
+
 CREATE INDEX IF NOT EXISTS idx_failed_events_status ON failed_events(status);
 CREATE INDEX IF NOT EXISTS idx_failed_events_failed_at ON failed_events(failed_at);
 
@@ -742,10 +884,14 @@ mod tests {
         assert!(tables.contains(&"wallet_personas".to_string()));
         assert!(tables.contains(&"wallet_exclusions".to_string()));
         assert!(tables.contains(&"wallet_persona_traits".to_string()));
+        assert!(tables.contains(&"wallet_ingestion_failures".to_string()));
         assert!(tables.contains(&"wallet_rules_state".to_string()));
         assert!(tables.contains(&"wallet_rules_events".to_string()));
         assert!(tables.contains(&"event_log".to_string()));
         assert!(tables.contains(&"failed_events".to_string()));
+        assert!(tables.contains(&"trader_promotions".to_string()));
+        assert!(tables.contains(&"wallet_profiles".to_string()));
+        assert!(tables.contains(&"wallet_notes".to_string()));
     }
 
     #[test]
@@ -755,6 +901,31 @@ mod tests {
         db.run_migrations().unwrap(); // second call must not fail
     }
 
+    #[test]
+    fn test_check_schema_version_reports_uninitialized_and_mismatch() {
+        let db = Database::open(":memory:").unwrap();
+        let err = check_schema_version(&db.conn).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SchemaVersionError>(),
+            Some(&SchemaVersionError::NotInitialized)
+        );
+
+        db.run_migrations().unwrap();
+        check_schema_version(&db.conn).unwrap();
+
+        db.conn
+            .pragma_update(None, "user_version", SCHEMA_VERSION + 1)
+            .unwrap();
+        let err = check_schema_version(&db.conn).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<SchemaVersionError>(),
+            Some(&SchemaVersionError::VersionMismatch {
+                found: SCHEMA_VERSION + 1,
+                expected: SCHEMA_VERSION,
+            })
+        );
+    }
+
     #[test]
     fn test_migrations_create_expected_indexes() {
         let db = Database::open(":memory:").unwrap();
@@ -814,6 +985,7 @@ mod tests {
             "burstiness_top_1h_ratio",
             "top_domain",
             "top_domain_ratio",
+            "wash_trade_score",
         ] {
             assert!(
                 cols.contains(&col.to_string()),
@@ -980,6 +1152,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_recency_edge_migration_adds_column_to_existing_table() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE wallet_scores_daily (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                proxy_wallet TEXT NOT NULL,
+                score_date TEXT NOT NULL,
+                window_days INTEGER NOT NULL,
+                wscore REAL NOT NULL
+            );",
+        )
+        .unwrap();
+
+        migrate_wallet_scores_recency_edge_column(&conn).unwrap();
+        migrate_wallet_scores_recency_edge_column(&conn).unwrap();
+
+        let has: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('wallet_scores_daily') WHERE name='recency_edge_score'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(has, 1);
+    }
+
+    #[test]
+    fn test_wallet_personas_superseded_migration_adds_column_to_existing_table() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE wallet_personas (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                proxy_wallet TEXT NOT NULL,
+                persona TEXT NOT NULL,
+                confidence REAL NOT NULL,
+                classified_at TEXT NOT NULL
+            );",
+        )
+        .unwrap();
+
+        migrate_wallet_personas_superseded_column(&conn).unwrap();
+        migrate_wallet_personas_superseded_column(&conn).unwrap();
+
+        let has: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('wallet_personas') WHERE name='superseded_at'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(has, 1);
+    }
+
+    #[test]
+    fn test_job_status_schedule_columns_migration_is_idempotent() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE job_status (
+                job_name TEXT PRIMARY KEY,
+                status TEXT NOT NULL,
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );",
+        )
+        .unwrap();
+
+        migrate_job_status_schedule_columns(&conn).unwrap();
+        migrate_job_status_schedule_columns(&conn).unwrap();
+
+        let n: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM pragma_table_info('job_status')
+                 WHERE name IN ('interval_secs', 'next_run_at')",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(n, 2);
+    }
+
     #[test]
     fn test_pnl_migration_copies_realized_to_cashflow() {
         let db = Database::open(":memory:").unwrap();
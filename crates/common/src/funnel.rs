@@ -33,7 +33,12 @@ pub fn funnel_stage_infos(cfg: &Config) -> [String; 6] {
         cfg.wallet_discovery.min_total_trades
     );
 
-    let tracked = "Wallets with is_active=1; ingestion runs only for tracked wallets.".to_string();
+    let tracked = format!(
+        "Wallets with is_active=1; ingestion runs only for tracked wallets. Persona Stage 1 then requires stage1_min_wallet_age_days={} stage1_max_inactive_days={} stage1_min_total_trades={}.",
+        cfg.personas.stage1_min_wallet_age_days,
+        cfg.personas.stage1_max_inactive_days,
+        cfg.personas.stage1_min_total_trades
+    );
 
     let paper = format!(
         "Mirrored paper trades when checks pass: position_size_usdc={} slippage_pct={} paper_bankroll_usdc={} max_exposure_per_market_pct={} max_exposure_per_wallet_pct={} max_daily_trades={} portfolio_stop_drawdown_pct={}.",
@@ -54,14 +59,41 @@ pub fn funnel_stage_infos(cfg: &Config) -> [String; 6] {
         .collect::<Vec<_>>()
         .join(",");
     let ranked = format!(
-        "Wallet scores from paper PnL: windows_days=[{}] min_trades_for_score={}; ranked=wallets with a score row today.",
+        "Wallet scores from paper PnL: windows_days=[{}] min_trades_for_score={}; ranked=wallets with a score row today. Follow-worthy: min_roi_7d_pct>{} min_roi_30d_pct>{}.",
         windows,
-        cfg.wallet_scoring.min_trades_for_score
+        cfg.wallet_scoring.min_trades_for_score,
+        fmt_f64(cfg.ranking.min_roi_7d_pct),
+        fmt_f64(cfg.ranking.min_roi_30d_pct)
     );
 
     [markets, scored, wallets, tracked, paper, ranked]
 }
 
+// Follow-worthy is a best-effort approximation based on available data:
+// Promotion rules in docs/EVALUATION_STRATEGY.md §3.3 use ROI + hit rate + drawdown, but
+// hit rate/drawdown aren't fully computed yet. For visibility in UI/Grafana, we use ROI-only
+// thresholds on the latest score date. Every follow-worthy query is built from these two
+// fragments so the rankings table, the funnel counts and trader promotion can't disagree.
+// `ws7` is the latest 7-day score row and `ws30` the matching 30-day row.
+const FOLLOW_WORTHY_FROM: &str = "
+    FROM wallet_scores_daily ws7
+    JOIN wallet_scores_daily ws30
+      ON ws30.proxy_wallet = ws7.proxy_wallet
+     AND ws30.score_date = ws7.score_date
+     AND ws30.window_days = 30";
+
+/// Binds `:min_roi_7d_pct` and `:min_roi_30d_pct`.
+const FOLLOW_WORTHY_WHERE: &str = "
+    ws7.score_date = (SELECT MAX(score_date) FROM wallet_scores_daily)
+    AND ws7.window_days = 7
+    AND COALESCE(ws7.paper_roi_pct, 0) > :min_roi_7d_pct
+    AND COALESCE(ws30.paper_roi_pct, 0) > :min_roi_30d_pct";
+
+/// Build a follow-worthy query: `SELECT {select} FROM <ws7+ws30> {joins} WHERE <thresholds> {tail}`.
+pub fn follow_worthy_sql(select: &str, joins: &str, tail: &str) -> String {
+    format!("SELECT {select} {FOLLOW_WORTHY_FROM} {joins} WHERE {FOLLOW_WORTHY_WHERE} {tail}")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,7 +123,22 @@ mod tests {
         assert!(infos[4].contains("max_daily_trades="));
         assert!(infos[4].contains("portfolio_stop_drawdown_pct="));
 
+        assert!(infos[3].contains("stage1_min_wallet_age_days="));
+
         assert!(infos[5].contains("windows_days=["));
         assert!(infos[5].contains("min_trades_for_score="));
     }
+
+    #[test]
+    fn test_funnel_stage_infos_reflect_configured_thresholds() {
+        let mut cfg = Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
+        cfg.personas.stage1_min_wallet_age_days = 77;
+        cfg.ranking.min_roi_7d_pct = 3.5;
+        cfg.ranking.min_roi_30d_pct = 12.0;
+        let infos = funnel_stage_infos(&cfg);
+
+        assert!(infos[3].contains("stage1_min_wallet_age_days=77"));
+        assert!(infos[5].contains("min_roi_7d_pct>3.5"));
+        assert!(infos[5].contains("min_roi_30d_pct>12"));
+    }
 }
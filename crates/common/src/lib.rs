@@ -1,6 +1,9 @@
+pub mod circuit_breaker;
 pub mod config;
+mod config_validation;
 pub mod db;
 pub mod funnel;
 pub mod observability;
 pub mod polymarket;
+pub mod rate_limit;
 pub mod types;
@@ -37,7 +37,8 @@ where
 
 /// Build a `tracing` dispatcher configured for:
 /// - JSON logs to stdout
-/// - EnvFilter that respects `RUST_LOG` (takes precedence) and falls back to `default_level`
+/// - EnvFilter that respects `RUST_LOG` (takes precedence) and falls back to `default_level`,
+///   a directive string such as `info,evaluator::ingestion=debug` (validated at config load)
 /// - `tracing_error_events` counter for ERROR events
 /// - Optional OpenTelemetry OTLP trace export when `OTEL_EXPORTER_OTLP_ENDPOINT` is set
 pub fn build_dispatch(
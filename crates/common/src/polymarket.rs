@@ -1,10 +1,15 @@
+use crate::circuit_breaker::{CircuitBreaker, CircuitOpenError};
+use crate::rate_limit::TokenBucket;
 use crate::types::{
     ApiActivity, ApiHolderResponse, ApiLeaderboardEntry, ApiPosition, ApiTrade, GammaMarket,
 };
 use anyhow::Result;
 use reqwest::{Client, StatusCode, Url};
+use std::collections::HashMap;
 use std::error::Error as StdError;
-use std::time::Duration;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone)]
 pub struct HttpStatusError {
@@ -26,9 +31,11 @@ pub enum ApiErrorKind {
     Timeout,
     Upstream5xx,
     BadRequest,
+    NotFound,
     PaginationOffsetCap,
     Decode,
     Connect,
+    CircuitOpen,
     Other,
 }
 
@@ -39,9 +46,11 @@ impl ApiErrorKind {
             Self::Timeout => "timeout",
             Self::Upstream5xx => "upstream_5xx",
             Self::BadRequest => "bad_request",
+            Self::NotFound => "not_found",
             Self::PaginationOffsetCap => "pagination_offset_cap",
             Self::Decode => "decode",
             Self::Connect => "connect",
+            Self::CircuitOpen => "circuit_open",
             Self::Other => "other",
         }
     }
@@ -51,6 +60,9 @@ impl ApiErrorKind {
 ///
 /// IMPORTANT: keep the returned `kind` set small to avoid Prometheus cardinality blowups.
 pub fn classify_anyhow_api_error(err: &anyhow::Error) -> ApiErrorKind {
+    if err.downcast_ref::<CircuitOpenError>().is_some() {
+        return ApiErrorKind::CircuitOpen;
+    }
     for cause in err.chain() {
         if let Some(h) = cause.downcast_ref::<HttpStatusError>() {
             if h.status == StatusCode::TOO_MANY_REQUESTS {
@@ -68,6 +80,9 @@ pub fn classify_anyhow_api_error(err: &anyhow::Error) -> ApiErrorKind {
                 }
                 return ApiErrorKind::BadRequest;
             }
+            if h.status == StatusCode::NOT_FOUND {
+                return ApiErrorKind::NotFound;
+            }
         }
 
         if let Some(r) = cause.downcast_ref::<reqwest::Error>() {
@@ -117,15 +132,28 @@ pub struct GammaFilter {
     pub end_date_max: Option<String>,
     /// false = only open markets.
     pub closed: Option<bool>,
+    /// Only these markets (repeated `condition_ids` query param). Empty = no filter.
+    pub condition_ids: Vec<String>,
 }
 
+/// Cached successful response bodies keyed by full request URL (endpoint + query params).
+type ResponseCache = Arc<Mutex<HashMap<String, (Instant, Vec<u8>)>>>;
+
 pub struct PolymarketClient {
     data_api_url: String,
     gamma_api_url: String,
     client: Client,
-    rate_limit_delay: Duration,
+    /// Shared request budget across all tasks using this client (see `with_rate_limit`).
+    limiter: TokenBucket,
     max_retries: u32,
     backoff_base: Duration,
+    /// Wall-clock cap on one call including retries (see `with_retry_budget`).
+    retry_budget: Duration,
+    /// TTL for cached Gamma market metadata. Zero disables the cache.
+    cache_ttl: Duration,
+    cache: ResponseCache,
+    /// Fails fast after repeated upstream failures (see `with_circuit_breaker`).
+    breaker: CircuitBreaker,
 }
 
 impl PolymarketClient {
@@ -165,9 +193,91 @@ impl PolymarketClient {
             data_api_url: data_api_url.trim_end_matches('/').to_string(),
             gamma_api_url: gamma_api_url.trim_end_matches('/').to_string(),
             client,
-            rate_limit_delay,
+            limiter: TokenBucket::from_delay(rate_limit_delay),
             max_retries,
             backoff_base,
+            retry_budget: Duration::ZERO,
+            cache_ttl: Duration::ZERO,
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            breaker: CircuitBreaker::disabled(),
+        }
+    }
+
+    /// Replace the fixed inter-request delay with a token bucket of `requests_per_second`
+    /// allowing bursts of `burst` requests (`requests_per_second <= 0` disables limiting).
+    /// Retry/backoff on 429 still applies on top of this.
+    #[must_use]
+    pub fn with_rate_limit(mut self, requests_per_second: f64, burst: u32) -> Self {
+        self.limiter = TokenBucket::new(requests_per_second, burst);
+        self
+    }
+
+    /// Cache successful Gamma `/markets` responses for `ttl` (zero disables caching).
+    ///
+    /// Market metadata (slug, event slug, title) rarely changes, so repeated lookups within
+    /// the TTL are served from memory. Data API responses (trades, activity, positions) are
+    /// time-sensitive and never cached. Error responses are never cached.
+    #[must_use]
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Open the circuit after `failure_threshold` consecutive upstream failures (429, 5xx,
+    /// timeouts, connect errors once retries are exhausted). While open, requests fail
+    /// immediately with `CircuitOpenError` for `cooldown`; then one probe request decides
+    /// whether to close again. `failure_threshold == 0` disables the breaker.
+    #[must_use]
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.breaker = CircuitBreaker::new(failure_threshold, cooldown);
+        self
+    }
+
+    /// Stop retrying once a call (all attempts plus backoff sleeps) would exceed `budget`,
+    /// so one flaky endpoint can't hold a task for minutes. Zero means `max_retries` is the
+    /// only bound.
+    #[must_use]
+    pub fn with_retry_budget(mut self, budget: Duration) -> Self {
+        self.retry_budget = budget;
+        self
+    }
+
+    /// Client configured from `[polymarket]` and `[ingestion]` (timeouts, rate limit, cache,
+    /// circuit breaker), as used by the evaluator service and its CLI.
+    pub fn from_config(cfg: &crate::config::Config) -> Self {
+        Self::new_with_settings(
+            &cfg.polymarket.data_api_url,
+            &cfg.polymarket.gamma_api_url,
+            Duration::from_secs(15),
+            Duration::from_millis(cfg.ingestion.rate_limit_delay_ms),
+            cfg.ingestion.max_retries,
+            Duration::from_millis(cfg.ingestion.backoff_base_ms),
+        )
+        .with_rate_limit(
+            cfg.ingestion.effective_requests_per_second(),
+            cfg.ingestion.burst_size,
+        )
+        .with_cache_ttl(Duration::from_secs(cfg.polymarket.cache_ttl_secs))
+        .with_retry_budget(Duration::from_millis(cfg.ingestion.retry_budget_ms))
+        .with_circuit_breaker(
+            cfg.ingestion.circuit_breaker_failures,
+            Duration::from_secs(cfg.ingestion.circuit_breaker_cooldown_secs),
+        )
+    }
+
+    /// Feed the final outcome of a request (after retries) into the circuit breaker.
+    /// Client-side errors (400, 404, decode) mean upstream is reachable and count as success.
+    fn record_breaker_outcome<T>(&self, res: &Result<T>) {
+        match res {
+            Ok(_) => self.breaker.record_success(),
+            Err(e) => match classify_anyhow_api_error(e) {
+                ApiErrorKind::RateLimited
+                | ApiErrorKind::Timeout
+                | ApiErrorKind::Upstream5xx
+                | ApiErrorKind::Connect => self.breaker.record_failure(),
+                ApiErrorKind::CircuitOpen => {}
+                _ => self.breaker.record_success(),
+            },
         }
     }
 
@@ -225,6 +335,42 @@ impl PolymarketClient {
         Ok((serde_json::from_slice(&body)?, body))
     }
 
+    /// `get_bytes_with_retry` behind the TTL cache (see `with_cache_ttl`).
+    async fn get_bytes_cached(&self, url: Url) -> Result<Vec<u8>> {
+        if self.cache_ttl.is_zero() {
+            return self.get_bytes_with_retry(url).await;
+        }
+        let key = url.to_string();
+        let hit = {
+            let cache = self
+                .cache
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            cache
+                .get(&key)
+                .filter(|(stored_at, _)| stored_at.elapsed() < self.cache_ttl)
+                .map(|(_, body)| body.clone())
+        };
+        if let Some(body) = hit {
+            metrics::counter!("evaluator_polymarket_cache_total", "result" => "hit").increment(1);
+            return Ok(body);
+        }
+        metrics::counter!("evaluator_polymarket_cache_total", "result" => "miss").increment(1);
+
+        let body = self.get_bytes_with_retry(url).await?;
+        {
+            let mut cache = self
+                .cache
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let ttl = self.cache_ttl;
+            // Evict expired entries on insert so the cache stays bounded by distinct live keys.
+            cache.retain(|_, (stored_at, _)| stored_at.elapsed() < ttl);
+            cache.insert(key, (Instant::now(), body.clone()));
+        }
+        Ok(body)
+    }
+
     #[allow(dead_code)]
     pub async fn fetch_holders(
         &self,
@@ -315,6 +461,25 @@ impl PolymarketClient {
         Ok((serde_json::from_slice(&body)?, body))
     }
 
+    /// Gamma `public-profile` display name for `address`; `Ok(None)` when the wallet has no
+    /// profile (404) or neither name nor pseudonym is set.
+    pub async fn fetch_profile_name(&self, address: &str) -> Result<Option<String>> {
+        let mut url = Url::parse(&format!("{}/public-profile", self.gamma_api_url))?;
+        url.query_pairs_mut().append_pair("address", address);
+        let body = match self.get_bytes_with_retry(url).await {
+            Ok(body) => body,
+            Err(e)
+                if e.downcast_ref::<HttpStatusError>()
+                    .is_some_and(|h| h.status == StatusCode::NOT_FOUND) =>
+            {
+                return Ok(None);
+            }
+            Err(e) => return Err(e),
+        };
+        let profile: serde_json::Value = serde_json::from_slice(&body)?;
+        Ok(profile_display_name(&profile))
+    }
+
     #[allow(dead_code)]
     pub async fn fetch_leaderboard(
         &self,
@@ -369,20 +534,44 @@ impl PolymarketClient {
             if let Some(closed) = filter.closed {
                 qp.append_pair("closed", &closed.to_string());
             }
+            for id in &filter.condition_ids {
+                qp.append_pair("condition_ids", id);
+            }
         }
-        let body = self.get_bytes_with_retry(url).await?;
+        let body = self.get_bytes_cached(url).await?;
         Ok((serde_json::from_slice(&body)?, body))
     }
 
+    /// Backoff before retry number `attempt` (1-based), or `None` when retries are exhausted
+    /// or sleeping would overrun the retry budget. Counts the retry when one is granted.
+    fn retry_delay(&self, attempt: u32, started: Instant, url: &Url) -> Option<Duration> {
+        if attempt > self.max_retries {
+            return None;
+        }
+        let delay = full_jitter_backoff(self.backoff_base, attempt, jitter_fraction());
+        if !self.retry_budget.is_zero() && started.elapsed() + delay >= self.retry_budget {
+            return None;
+        }
+        metrics::counter!("evaluator_polymarket_retries_total", "endpoint" => endpoint_label(url))
+            .increment(1);
+        Some(delay)
+    }
+
     async fn get_text_with_retry<U: IntoUrlLike>(&self, url: U) -> Result<String> {
         let url = url.into_url()?;
+        self.breaker.before_call()?;
+        let res = self.get_text_attempts(url).await;
+        self.record_breaker_outcome(&res);
+        res
+    }
+
+    async fn get_text_attempts(&self, url: Url) -> Result<String> {
+        let started = Instant::now();
         let mut attempt: u32 = 0;
 
         loop {
             attempt += 1;
-            if !self.rate_limit_delay.is_zero() {
-                tokio::time::sleep(self.rate_limit_delay).await;
-            }
+            self.limiter.acquire().await;
 
             let req = self.client.get(url.clone());
             match req.send().await {
@@ -393,21 +582,17 @@ impl PolymarketClient {
                     }
 
                     // Retry on transient statuses.
-                    if attempt <= self.max_retries
-                        && (status == StatusCode::TOO_MANY_REQUESTS
-                            || status.is_server_error()
-                            || status == StatusCode::REQUEST_TIMEOUT)
-                    {
-                        let backoff = self.backoff_base.mul_f64(2_f64.powi((attempt - 1) as i32));
-                        tokio::time::sleep(backoff).await;
-                        continue;
+                    if is_retryable_status(status) {
+                        if let Some(backoff) = self.retry_delay(attempt, started, &url) {
+                            tokio::time::sleep(backoff).await;
+                            continue;
+                        }
                     }
 
                     return Err(anyhow::Error::new(HttpStatusError { status, url }));
                 }
                 Err(e) => {
-                    if attempt <= self.max_retries {
-                        let backoff = self.backoff_base.mul_f64(2_f64.powi((attempt - 1) as i32));
+                    if let Some(backoff) = self.retry_delay(attempt, started, &url) {
                         tokio::time::sleep(backoff).await;
                         continue;
                     }
@@ -419,13 +604,19 @@ impl PolymarketClient {
 
     async fn get_bytes_with_retry<U: IntoUrlLike>(&self, url: U) -> Result<Vec<u8>> {
         let url = url.into_url()?;
+        self.breaker.before_call()?;
+        let res = self.get_bytes_attempts(url).await;
+        self.record_breaker_outcome(&res);
+        res
+    }
+
+    async fn get_bytes_attempts(&self, url: Url) -> Result<Vec<u8>> {
+        let started = Instant::now();
         let mut attempt: u32 = 0;
 
         loop {
             attempt += 1;
-            if !self.rate_limit_delay.is_zero() {
-                tokio::time::sleep(self.rate_limit_delay).await;
-            }
+            self.limiter.acquire().await;
 
             let req = self.client.get(url.clone());
             match req.send().await {
@@ -436,21 +627,17 @@ impl PolymarketClient {
                         return Ok(b.to_vec());
                     }
 
-                    if attempt <= self.max_retries
-                        && (status == StatusCode::TOO_MANY_REQUESTS
-                            || status.is_server_error()
-                            || status == StatusCode::REQUEST_TIMEOUT)
-                    {
-                        let backoff = self.backoff_base.mul_f64(2_f64.powi((attempt - 1) as i32));
-                        tokio::time::sleep(backoff).await;
-                        continue;
+                    if is_retryable_status(status) {
+                        if let Some(backoff) = self.retry_delay(attempt, started, &url) {
+                            tokio::time::sleep(backoff).await;
+                            continue;
+                        }
                     }
 
                     return Err(anyhow::Error::new(HttpStatusError { status, url }));
                 }
                 Err(e) => {
-                    if attempt <= self.max_retries {
-                        let backoff = self.backoff_base.mul_f64(2_f64.powi((attempt - 1) as i32));
+                    if let Some(backoff) = self.retry_delay(attempt, started, &url) {
                         tokio::time::sleep(backoff).await;
                         continue;
                     }
@@ -461,6 +648,43 @@ impl PolymarketClient {
     }
 }
 
+fn is_retryable_status(status: StatusCode) -> bool {
+    status == StatusCode::TOO_MANY_REQUESTS
+        || status.is_server_error()
+        || status == StatusCode::REQUEST_TIMEOUT
+}
+
+/// Full-jitter exponential backoff: `unit` (in [0, 1)) of `base * 2^(attempt - 1)`.
+/// Spreading sleeps over the whole window keeps parallel tasks that failed together from
+/// retrying in lockstep.
+fn full_jitter_backoff(base: Duration, attempt: u32, unit: f64) -> Duration {
+    let exp = attempt.saturating_sub(1).min(16);
+    base.mul_f64(f64::from(1_u32 << exp) * unit.clamp(0.0, 1.0))
+}
+
+/// Uniform value in [0, 1). `RandomState` is freshly keyed per call, which is plenty of
+/// entropy for spreading retries without pulling in a RNG crate.
+fn jitter_fraction() -> f64 {
+    let bits = std::collections::hash_map::RandomState::new()
+        .build_hasher()
+        .finish();
+    (bits >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+/// Low-cardinality endpoint label for retry metrics (URL path without query params).
+fn endpoint_label(url: &Url) -> &'static str {
+    match url.path().trim_end_matches('/').rsplit('/').next() {
+        Some("trades") => "trades",
+        Some("holders") => "holders",
+        Some("activity") => "activity",
+        Some("positions") => "positions",
+        Some("leaderboard") => "leaderboard",
+        Some("markets") => "gamma_markets",
+        Some("public-profile") => "public_profile",
+        _ => "other",
+    }
+}
+
 trait IntoUrlLike {
     fn into_url(self) -> Result<Url>;
 }
@@ -477,6 +701,20 @@ impl IntoUrlLike for Url {
     }
 }
 
+/// Display name from a Gamma `public-profile` response: `name`, else `pseudonym`, else None.
+pub fn profile_display_name(profile: &serde_json::Value) -> Option<String> {
+    let field = |key: &str| {
+        profile
+            .get(key)
+            .and_then(|v| v.as_str())
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+    field("name")
+        .or_else(|| field("pseudonym"))
+        .map(String::from)
+}
+
 #[derive(Debug, Clone, serde::Deserialize)]
 pub struct PolymarketPosition {
     #[serde(rename = "conditionId")]
@@ -622,6 +860,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_classify_404_as_not_found() {
+        let err = anyhow::Error::new(HttpStatusError {
+            status: StatusCode::NOT_FOUND,
+            url: Url::parse("https://data-api.polymarket.com/trades?user=0xabc").unwrap(),
+        });
+        assert_eq!(classify_anyhow_api_error(&err), ApiErrorKind::NotFound);
+    }
+
     #[test]
     fn test_classify_json_decode_as_decode() {
         let bad_json = b"{this is not json}";
@@ -630,6 +877,227 @@ mod tests {
         assert_eq!(classify_anyhow_api_error(&err), ApiErrorKind::Decode);
     }
 
+    /// Minimal HTTP server: answers every request with `status` and `body`, counting requests.
+    fn spawn_counting_server(
+        status: u16,
+        body: &'static str,
+    ) -> (String, Arc<std::sync::atomic::AtomicUsize>) {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let hits_server = hits.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut buf = [0_u8; 4096];
+                let _ = stream.read(&mut buf);
+                hits_server.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                let resp = format!(
+                    "HTTP/1.1 {status} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                let _ = stream.write_all(resp.as_bytes());
+            }
+        });
+        (format!("http://{addr}"), hits)
+    }
+
+    fn test_client(gamma_url: &str, ttl: Duration) -> PolymarketClient {
+        PolymarketClient::new_with_settings(
+            "http://127.0.0.1:9",
+            gamma_url,
+            Duration::from_secs(2),
+            Duration::ZERO,
+            0,
+            Duration::ZERO,
+        )
+        .with_cache_ttl(ttl)
+    }
+
+    #[tokio::test]
+    async fn test_gamma_markets_cache_serves_repeat_requests_from_memory() {
+        let (url, hits) = spawn_counting_server(200, "[]");
+        let client = test_client(&url, Duration::from_secs(60));
+        let filter = GammaFilter::default();
+
+        client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .unwrap();
+        client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .unwrap();
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        // Different params are a different cache key.
+        client
+            .fetch_gamma_markets_raw(10, 10, &filter)
+            .await
+            .unwrap();
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gamma_markets_cache_disabled_with_zero_ttl() {
+        let (url, hits) = spawn_counting_server(200, "[]");
+        let client = test_client(&url, Duration::ZERO);
+        let filter = GammaFilter::default();
+
+        client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .unwrap();
+        client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .unwrap();
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gamma_markets_cache_does_not_store_errors() {
+        let (url, hits) = spawn_counting_server(400, "{}");
+        let client = test_client(&url, Duration::from_secs(60));
+        let filter = GammaFilter::default();
+
+        assert!(client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .is_err());
+        assert!(client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .is_err());
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_short_circuits_after_repeated_5xx() {
+        let (url, hits) = spawn_counting_server(503, "{}");
+        let client =
+            test_client(&url, Duration::ZERO).with_circuit_breaker(2, Duration::from_secs(60));
+        let filter = GammaFilter::default();
+
+        for _ in 0..2 {
+            let err = client
+                .fetch_gamma_markets_raw(10, 0, &filter)
+                .await
+                .unwrap_err();
+            assert_eq!(classify_anyhow_api_error(&err), ApiErrorKind::Upstream5xx);
+        }
+        let err = client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .unwrap_err();
+        assert_eq!(classify_anyhow_api_error(&err), ApiErrorKind::CircuitOpen);
+        assert_eq!(
+            hits.load(std::sync::atomic::Ordering::SeqCst),
+            2,
+            "open circuit must not hit upstream"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_ignores_client_errors() {
+        let (url, hits) = spawn_counting_server(400, "{}");
+        let client =
+            test_client(&url, Duration::ZERO).with_circuit_breaker(1, Duration::from_secs(60));
+        let filter = GammaFilter::default();
+
+        for _ in 0..3 {
+            let err = client
+                .fetch_gamma_markets_raw(10, 0, &filter)
+                .await
+                .unwrap_err();
+            assert_ne!(classify_anyhow_api_error(&err), ApiErrorKind::CircuitOpen);
+        }
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_full_jitter_backoff_scales_with_attempt() {
+        let base = Duration::from_millis(100);
+        assert_eq!(full_jitter_backoff(base, 1, 0.0), Duration::ZERO);
+        assert_eq!(full_jitter_backoff(base, 1, 0.5), Duration::from_millis(50));
+        assert_eq!(
+            full_jitter_backoff(base, 3, 0.5),
+            Duration::from_millis(200)
+        );
+        // Exponent is capped so huge attempt counts can't overflow.
+        assert!(full_jitter_backoff(base, 100, 1.0) <= base * (1 << 16));
+    }
+
+    #[test]
+    fn test_jitter_fraction_in_unit_interval() {
+        for _ in 0..1000 {
+            let u = jitter_fraction();
+            assert!((0.0..1.0).contains(&u));
+        }
+    }
+
+    #[test]
+    fn test_endpoint_label_ignores_query_params() {
+        let url = Url::parse("https://data-api.polymarket.com/trades?user=0xabc").unwrap();
+        assert_eq!(endpoint_label(&url), "trades");
+        let url = Url::parse("https://data-api.polymarket.com/v1/leaderboard").unwrap();
+        assert_eq!(endpoint_label(&url), "leaderboard");
+        let url = Url::parse("https://gamma-api.polymarket.com/markets?limit=10").unwrap();
+        assert_eq!(endpoint_label(&url), "gamma_markets");
+        let url = Url::parse("https://example.com/unknown").unwrap();
+        assert_eq!(endpoint_label(&url), "other");
+    }
+
+    #[test]
+    fn test_profile_display_name_prefers_name_then_pseudonym() {
+        let profile = serde_json::json!({"name": " whale ", "pseudonym": "Brave-Otter"});
+        assert_eq!(profile_display_name(&profile), Some("whale".to_string()));
+        let profile = serde_json::json!({"name": "", "pseudonym": "Brave-Otter"});
+        assert_eq!(
+            profile_display_name(&profile),
+            Some("Brave-Otter".to_string())
+        );
+        assert_eq!(profile_display_name(&serde_json::json!({})), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_profile_name_treats_404_as_no_profile() {
+        let (url, _) = spawn_counting_server(404, "{}");
+        let client = test_client(&url, Duration::ZERO);
+        assert_eq!(client.fetch_profile_name("0xabc").await.unwrap(), None);
+
+        let (url, _) = spawn_counting_server(200, r#"{"name":"whale"}"#);
+        let client = test_client(&url, Duration::ZERO);
+        assert_eq!(
+            client.fetch_profile_name("0xabc").await.unwrap(),
+            Some("whale".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_retry_budget_stops_retries_early() {
+        let (url, hits) = spawn_counting_server(503, "{}");
+        let client = PolymarketClient::new_with_settings(
+            "http://127.0.0.1:9",
+            &url,
+            Duration::from_secs(2),
+            Duration::ZERO,
+            50,
+            Duration::from_millis(20),
+        )
+        .with_retry_budget(Duration::from_millis(150));
+        let filter = GammaFilter::default();
+
+        let started = Instant::now();
+        let err = client
+            .fetch_gamma_markets_raw(10, 0, &filter)
+            .await
+            .unwrap_err();
+        assert_eq!(classify_anyhow_api_error(&err), ApiErrorKind::Upstream5xx);
+        assert!(started.elapsed() < Duration::from_secs(2));
+        assert!(hits.load(std::sync::atomic::Ordering::SeqCst) < 51);
+    }
+
     #[tokio::test]
     async fn test_fetch_positions_parses_response() {
         // This will be an integration test that hits real API
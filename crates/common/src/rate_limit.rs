@@ -0,0 +1,117 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Token-bucket rate limiter shared by every caller of a `PolymarketClient`.
+///
+/// Tokens refill continuously at `rate_per_sec` up to `burst`. Each request takes one token;
+/// when the bucket is empty the caller sleeps until the next token is due. Because the bucket
+/// lives on the client (behind its `Arc`), parallel ingestion tasks share one request budget
+/// instead of each sleeping a fixed delay independently.
+pub struct TokenBucket {
+    rate_per_sec: f64,
+    burst: f64,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// `rate_per_sec <= 0` disables limiting. `burst` is clamped to at least 1.
+    pub fn new(rate_per_sec: f64, burst: u32) -> Self {
+        let burst = f64::from(burst.max(1));
+        Self {
+            rate_per_sec,
+            burst,
+            state: Mutex::new(BucketState {
+                tokens: burst,
+                last_refill: Instant::now(),
+            }),
+        }
+    }
+
+    /// Bucket equivalent to a fixed delay between requests (burst of 1).
+    pub fn from_delay(delay: Duration) -> Self {
+        if delay.is_zero() {
+            return Self::new(0.0, 1);
+        }
+        Self::new(1.0 / delay.as_secs_f64(), 1)
+    }
+
+    fn is_unlimited(&self) -> bool {
+        self.rate_per_sec <= 0.0
+    }
+
+    /// Take a token if one is available, otherwise return how long until one is.
+    fn try_acquire(&self) -> Result<(), Duration> {
+        if self.is_unlimited() {
+            return Ok(());
+        }
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * self.rate_per_sec).min(self.burst);
+        state.last_refill = now;
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            return Ok(());
+        }
+        let missing = 1.0 - state.tokens;
+        drop(state);
+        Err(Duration::from_secs_f64(missing / self.rate_per_sec))
+    }
+
+    /// Wait until a token is available and take it.
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_try_acquire_allows_burst_then_waits() {
+        let bucket = TokenBucket::new(10.0, 3);
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_ok());
+        let wait = bucket.try_acquire().unwrap_err();
+        assert!(wait <= Duration::from_millis(100), "wait={wait:?}");
+        assert!(wait > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_try_acquire_unlimited_never_waits() {
+        let bucket = TokenBucket::from_delay(Duration::ZERO);
+        for _ in 0..1000 {
+            assert!(bucket.try_acquire().is_ok());
+        }
+    }
+
+    #[test]
+    fn test_from_delay_matches_fixed_spacing() {
+        let bucket = TokenBucket::from_delay(Duration::from_millis(200));
+        assert!(bucket.try_acquire().is_ok());
+        let wait = bucket.try_acquire().unwrap_err();
+        assert!(wait <= Duration::from_millis(200), "wait={wait:?}");
+        assert!(wait > Duration::from_millis(150), "wait={wait:?}");
+    }
+
+    #[tokio::test]
+    async fn test_acquire_refills_over_time() {
+        let bucket = TokenBucket::new(100.0, 1);
+        bucket.acquire().await;
+        let start = Instant::now();
+        bucket.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(5));
+    }
+}
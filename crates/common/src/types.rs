@@ -167,6 +167,11 @@ pub struct GammaMarket {
     pub events: Option<Vec<GammaEventRef>>,
     #[serde(rename = "negRisk")]
     pub neg_risk: Option<bool>,
+    /// True once trading has stopped; resolution is final when one outcome price settles at 1.
+    pub closed: Option<bool>,
+    /// JSON-encoded array of outcome prices as strings, e.g. `"[\"1\", \"0\"]"`.
+    #[serde(rename = "outcomePrices")]
+    pub outcome_prices: Option<String>,
 }
 
 impl GammaMarket {
@@ -183,6 +188,31 @@ impl GammaMarket {
                     .filter(|s| !s.is_empty())
             })
     }
+
+    /// Index of the winning outcome for a closed market whose prices have settled
+    /// (exactly one outcome at ~1.0). None while open or when resolution is ambiguous.
+    pub fn resolved_outcome_index(&self) -> Option<usize> {
+        if self.closed != Some(true) {
+            return None;
+        }
+        let raw: Vec<String> = serde_json::from_str(self.outcome_prices.as_deref()?).ok()?;
+        let prices: Vec<f64> = raw.iter().filter_map(|p| p.parse().ok()).collect();
+        if prices.len() != raw.len() {
+            return None;
+        }
+        let mut winners = prices.iter().enumerate().filter(|(_, p)| **p >= 0.99);
+        let (idx, _) = winners.next()?;
+        if winners.next().is_some() {
+            return None;
+        }
+        Some(idx)
+    }
+
+    /// Current price (0-1) of outcome `idx` from `outcomePrices`. None when missing or unparsable.
+    pub fn outcome_price(&self, idx: usize) -> Option<f64> {
+        let raw: Vec<String> = serde_json::from_str(self.outcome_prices.as_deref()?).ok()?;
+        raw.get(idx)?.parse().ok()
+    }
 }
 
 /// Trade from Data API /trades.
@@ -305,6 +335,40 @@ mod tests {
         assert_eq!(DiscoverySource::Leaderboard.as_str(), "LEADERBOARD");
     }
 
+    #[test]
+    fn test_resolved_outcome_index() {
+        let mut m = GammaMarket {
+            closed: Some(true),
+            outcome_prices: Some(r#"["0", "1"]"#.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(m.resolved_outcome_index(), Some(1));
+
+        m.outcome_prices = Some(r#"["0.5", "0.5"]"#.to_string());
+        assert_eq!(m.resolved_outcome_index(), None, "ambiguous resolution");
+
+        m.outcome_prices = Some(r#"["1", "0"]"#.to_string());
+        m.closed = Some(false);
+        assert_eq!(
+            m.resolved_outcome_index(),
+            None,
+            "open market is not resolved"
+        );
+    }
+
+    #[test]
+    fn test_outcome_price() {
+        let mut m = GammaMarket {
+            outcome_prices: Some(r#"["0.37", "0.63"]"#.to_string()),
+            ..Default::default()
+        };
+        assert_eq!(m.outcome_price(1), Some(0.63));
+        assert_eq!(m.outcome_price(2), None);
+
+        m.outcome_prices = None;
+        assert_eq!(m.outcome_price(0), None);
+    }
+
     #[test]
     fn test_paper_trade_status() {
         assert_eq!(PaperTradeStatus::Open.as_str(), "open");
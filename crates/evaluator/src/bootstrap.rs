@@ -0,0 +1,61 @@
+/// Bootstrap jobs whose failure points at a broken API key / network / config rather than an
+/// empty pipeline (they are the first to call Polymarket).
+pub const CRITICAL_JOBS: &[&str] = &["event_scoring", "wallet_discovery", "leaderboard_discovery"];
+
+/// Outcome of the startup bootstrap run, built from each job's result.
+#[derive(Debug, Default)]
+pub struct BootstrapSummary {
+    /// `(job_name, error)` for every failed job.
+    pub failed: Vec<(&'static str, String)>,
+    pub total: usize,
+}
+
+impl BootstrapSummary {
+    pub fn record<T>(&mut self, job_name: &'static str, result: &anyhow::Result<T>) {
+        self.total += 1;
+        if let Err(e) = result {
+            self.failed.push((job_name, e.to_string()));
+        }
+    }
+
+    /// Failed jobs that are in `CRITICAL_JOBS`.
+    pub fn critical_failures(&self) -> Vec<&'static str> {
+        self.failed
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| CRITICAL_JOBS.contains(name))
+            .collect()
+    }
+
+    /// True when at least `threshold` critical jobs failed (`threshold` 0 disables the check).
+    pub fn is_broken(&self, threshold: usize) -> bool {
+        threshold > 0 && self.critical_failures().len() >= threshold
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bootstrap_summary_counts_critical_failures() {
+        let mut summary = BootstrapSummary::default();
+        summary.record("event_scoring", &Err::<u64, _>(anyhow::anyhow!("401")));
+        summary.record("wallet_discovery", &Err::<u64, _>(anyhow::anyhow!("401")));
+        summary.record(
+            "persona_classification",
+            &Err::<u64, _>(anyhow::anyhow!("x")),
+        );
+        summary.record("wallet_rules", &Ok::<u64, anyhow::Error>(3));
+
+        assert_eq!(summary.total, 4);
+        assert_eq!(summary.failed.len(), 3);
+        assert_eq!(
+            summary.critical_failures(),
+            vec!["event_scoring", "wallet_discovery"]
+        );
+        assert!(summary.is_broken(2));
+        assert!(!summary.is_broken(3));
+        assert!(!summary.is_broken(0));
+    }
+}
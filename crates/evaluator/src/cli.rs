@@ -1,3 +1,5 @@
+use crate::export::{self, ExportFormat};
+use crate::run_job;
 use anyhow::Result;
 use common::db::{AsyncDb, Database};
 use rusqlite::OptionalExtension;
@@ -23,6 +25,25 @@ pub enum Command {
     RetryFailedEvents {
         limit: usize,
     },
+    Export {
+        table: String,
+        format: ExportFormat,
+        out: String,
+    },
+    Backfill {
+        from_date: chrono::NaiveDate,
+        to_date: chrono::NaiveDate,
+    },
+    RunJob {
+        name: String,
+    },
+    Calibration {
+        buckets: usize,
+        min_trades: u32,
+    },
+    RescoreMarkets {
+        condition_ids: Vec<String>,
+    },
 }
 
 pub fn parse_args<I>(mut args: I) -> std::result::Result<Command, String>
@@ -51,6 +72,11 @@ where
         "pick-for-paper" => Ok(Command::PickForPaper),
         "replay-events" => parse_replay_events_args(args),
         "retry-failed-events" => parse_retry_failed_events_args(args),
+        "export" => parse_export_args(args),
+        "backfill" => parse_backfill_args(args),
+        "run-job" => parse_run_job_args(args),
+        "calibration" => parse_calibration_args(args),
+        "rescore-markets" => parse_rescore_markets_args(args),
         other => Err(format!("unknown command: {other}")),
     }
 }
@@ -135,6 +161,149 @@ where
     Ok(Command::RetryFailedEvents { limit })
 }
 
+const EXPORT_USAGE: &str =
+    "usage: evaluator export <wallets|wallet_scores_daily|paper_trades> --out=PATH [--format=ndjson|csv]";
+
+fn parse_export_args<I>(mut args: I) -> std::result::Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let table = args.next().ok_or_else(|| EXPORT_USAGE.to_string())?;
+    if !export::is_exportable_table(&table) {
+        return Err(format!("unknown export table: {table}\n{EXPORT_USAGE}"));
+    }
+    let mut format = ExportFormat::Ndjson;
+    let mut out: Option<String> = None;
+
+    for arg in args {
+        if let Some(val) = arg.strip_prefix("--format=") {
+            format = ExportFormat::parse(val)
+                .ok_or_else(|| format!("invalid --format value: {val}\n{EXPORT_USAGE}"))?;
+        } else if let Some(val) = arg.strip_prefix("--out=") {
+            out = Some(val.to_string());
+        } else {
+            return Err(format!("unknown flag for export: {arg}\n{EXPORT_USAGE}"));
+        }
+    }
+
+    let out = out.ok_or_else(|| format!("export requires --out=PATH\n{EXPORT_USAGE}"))?;
+    Ok(Command::Export { table, format, out })
+}
+
+const BACKFILL_USAGE: &str = "usage: evaluator backfill --from=YYYY-MM-DD --to=YYYY-MM-DD";
+
+fn parse_backfill_args<I>(args: I) -> std::result::Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let parse_date = |flag: &str, val: &str| {
+        chrono::NaiveDate::parse_from_str(val, "%Y-%m-%d")
+            .map_err(|e| format!("invalid {flag} value: {val} ({e})\n{BACKFILL_USAGE}"))
+    };
+    let mut from_date = None;
+    let mut to_date = None;
+
+    for arg in args {
+        if let Some(val) = arg.strip_prefix("--from=") {
+            from_date = Some(parse_date("--from", val)?);
+        } else if let Some(val) = arg.strip_prefix("--to=") {
+            to_date = Some(parse_date("--to", val)?);
+        } else {
+            return Err(format!(
+                "unknown flag for backfill: {arg}\n{BACKFILL_USAGE}"
+            ));
+        }
+    }
+
+    let (Some(from_date), Some(to_date)) = (from_date, to_date) else {
+        return Err(format!(
+            "backfill requires --from and --to\n{BACKFILL_USAGE}"
+        ));
+    };
+    if from_date > to_date {
+        return Err(format!(
+            "backfill --from ({from_date}) is after --to ({to_date})\n{BACKFILL_USAGE}"
+        ));
+    }
+    Ok(Command::Backfill { from_date, to_date })
+}
+
+fn parse_run_job_args<I>(mut args: I) -> std::result::Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let usage = format!(
+        "usage: evaluator run-job <name>\njobs: {}",
+        run_job::JOB_NAMES.join(", ")
+    );
+    let Some(name) = args.next() else {
+        return Err(usage);
+    };
+    if !run_job::is_known_job(&name) {
+        return Err(format!("unknown job: {name}\n{usage}"));
+    }
+    if let Some(extra) = args.next() {
+        return Err(format!("unexpected argument for run-job: {extra}\n{usage}"));
+    }
+    Ok(Command::RunJob { name })
+}
+
+const CALIBRATION_USAGE: &str = "usage: evaluator calibration [--buckets=N] [--min-trades=N]";
+
+fn parse_calibration_args<I>(args: I) -> std::result::Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut buckets = 5_usize;
+    let mut min_trades = 5_u32;
+
+    for arg in args {
+        if let Some(val) = arg.strip_prefix("--buckets=") {
+            buckets = val
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or_else(|| format!("invalid --buckets value: {val}\n{CALIBRATION_USAGE}"))?;
+        } else if let Some(val) = arg.strip_prefix("--min-trades=") {
+            min_trades = val
+                .parse::<u32>()
+                .map_err(|_e| format!("invalid --min-trades value: {val}\n{CALIBRATION_USAGE}"))?;
+        } else {
+            return Err(format!(
+                "unknown flag for calibration: {arg}\n{CALIBRATION_USAGE}"
+            ));
+        }
+    }
+
+    Ok(Command::Calibration {
+        buckets,
+        min_trades,
+    })
+}
+
+const RESCORE_MARKETS_USAGE: &str = "usage: evaluator rescore-markets <condition_id>...";
+
+fn parse_rescore_markets_args<I>(args: I) -> std::result::Result<Command, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut condition_ids = Vec::new();
+    for arg in args {
+        if arg.starts_with("--") {
+            return Err(format!(
+                "unknown flag for rescore-markets: {arg}\n{RESCORE_MARKETS_USAGE}"
+            ));
+        }
+        if !condition_ids.contains(&arg) {
+            condition_ids.push(arg);
+        }
+    }
+    if condition_ids.is_empty() {
+        return Err(RESCORE_MARKETS_USAGE.to_string());
+    }
+    Ok(Command::RescoreMarkets { condition_ids })
+}
+
 pub fn run_command(db: &Database, cmd: Command) -> Result<()> {
     match cmd {
         Command::Run => Ok(()),
@@ -150,9 +319,92 @@ pub fn run_command(db: &Database, cmd: Command) -> Result<()> {
             event_type,
         } => run_replay_events(db, &from, to.as_deref(), event_type.as_deref()),
         Command::RetryFailedEvents { limit } => run_retry_failed_events(db, limit),
+        Command::Export { table, format, out } => run_export(db, &table, format, &out),
+        Command::Backfill { from_date, to_date } => run_backfill(db, from_date, to_date),
+        Command::RunJob { name } => run_single_job(db, name),
+        Command::Calibration {
+            buckets,
+            min_trades,
+        } => show_calibration(db, buckets, min_trades),
+        Command::RescoreMarkets { condition_ids } => run_rescore_markets(db, condition_ids),
     }
 }
 
+fn run_backfill(_db: &Database, from: chrono::NaiveDate, to: chrono::NaiveDate) -> Result<()> {
+    let config = common::config::Config::load()?;
+
+    // Run in dedicated thread to avoid "runtime within runtime" when called from tokio::main
+    let handle = std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("runtime");
+        rt.block_on(async {
+            let async_db = AsyncDb::open(&config.database.path).await?;
+            let scores = crate::jobs::run_wallet_backfill(&async_db, &config, from, to).await?;
+            println!("Backfilled {from}..={to}: {scores} wallet score rows");
+            Ok::<_, anyhow::Error>(())
+        })
+    });
+    #[allow(clippy::map_err_ignore)] // JoinError is opaque
+    handle
+        .join()
+        .map_err(|_| anyhow::anyhow!("backfill thread panicked"))?
+}
+
+fn run_single_job(_db: &Database, name: String) -> Result<()> {
+    let config = common::config::Config::load()?;
+
+    // Run in dedicated thread to avoid "runtime within runtime" when called from tokio::main
+    let handle = std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("runtime");
+        rt.block_on(async {
+            let async_db = AsyncDb::open(&config.database.path).await?;
+            let started = std::time::Instant::now();
+            let summary = run_job::run_job_once(&async_db, &config, &name).await?;
+            println!(
+                "{name} done in {:.1}s: {summary}",
+                started.elapsed().as_secs_f64()
+            );
+            Ok::<_, anyhow::Error>(())
+        })
+    });
+    #[allow(clippy::map_err_ignore)] // JoinError is opaque
+    handle
+        .join()
+        .map_err(|_| anyhow::anyhow!("run-job thread panicked"))?
+}
+
+fn run_rescore_markets(_db: &Database, condition_ids: Vec<String>) -> Result<()> {
+    let config = common::config::Config::load()?;
+
+    // Run in dedicated thread to avoid "runtime within runtime" when called from tokio::main
+    let handle = std::thread::spawn(move || {
+        let rt = tokio::runtime::Runtime::new().expect("runtime");
+        rt.block_on(async {
+            let async_db = AsyncDb::open(&config.database.path).await?;
+            let api = common::polymarket::PolymarketClient::from_config(&config);
+            let (rescored, ranked) =
+                crate::jobs::run_market_rescore_once(&async_db, &api, &config, &condition_ids)
+                    .await?;
+            let skipped = (condition_ids.len() as u64).saturating_sub(rescored);
+            println!(
+                "Rescored {rescored} markets ({ranked} in top events, {skipped} not found or filtered out)"
+            );
+            Ok::<_, anyhow::Error>(())
+        })
+    });
+    #[allow(clippy::map_err_ignore)] // JoinError is opaque
+    handle
+        .join()
+        .map_err(|_| anyhow::anyhow!("rescore-markets thread panicked"))?
+}
+
+fn run_export(db: &Database, table: &str, format: ExportFormat, out: &str) -> Result<()> {
+    let file = std::fs::File::create(out)?;
+    let mut writer = std::io::BufWriter::new(file);
+    let rows = export::export_table(db, table, format, &mut writer)?;
+    println!("Exported {rows} rows from {table} to {out}");
+    Ok(())
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct MarketRow {
     pub condition_id: String,
@@ -316,7 +568,7 @@ fn show_wallet(db: &Database, address: &str) -> Result<()> {
     let persona: Option<String> = db
         .conn
         .query_row(
-            "SELECT persona FROM wallet_personas WHERE proxy_wallet = ?1
+            "SELECT persona FROM wallet_personas WHERE proxy_wallet = ?1 AND superseded_at IS NULL
              ORDER BY classified_at DESC LIMIT 1",
             rusqlite::params![address],
             |row| row.get(0),
@@ -339,6 +591,7 @@ fn show_pick_for_paper(db: &Database) -> Result<()> {
              SELECT proxy_wallet, persona,
                     ROW_NUMBER() OVER (PARTITION BY proxy_wallet ORDER BY classified_at DESC) AS rn
              FROM wallet_personas
+             WHERE superseded_at IS NULL
          ) p ON p.proxy_wallet = s.proxy_wallet AND p.rn = 1
          WHERE s.window_days = 30
            AND s.score_date = date('now')
@@ -368,6 +621,116 @@ fn show_pick_for_paper(db: &Database) -> Result<()> {
     Ok(())
 }
 
+/// One wallet's first persona prediction and the settled paper trades that followed it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationSample {
+    pub confidence: f64,
+    pub settled_trades: u32,
+    pub wins: u32,
+    pub pnl: f64,
+}
+
+/// Confidence bucket for a reliability diagram: predicted confidence vs realized outcomes.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CalibrationBucket {
+    pub lower: f64,
+    pub upper: f64,
+    pub wallets: usize,
+    pub mean_confidence: f64,
+    /// Share of wallets whose post-classification paper PnL is positive.
+    pub success_rate: f64,
+    /// Mean per-wallet paper win rate.
+    pub mean_hit_rate: f64,
+}
+
+/// Wallets with at least `min_trades` settled paper trades created after their first persona
+/// classification. The first classification is used so outcomes are strictly out-of-sample.
+pub fn query_calibration_samples(db: &Database, min_trades: u32) -> Result<Vec<CalibrationSample>> {
+    let mut stmt = db.conn.prepare(
+        "
+        WITH first_persona AS (
+            SELECT proxy_wallet, confidence, classified_at,
+                   ROW_NUMBER() OVER (PARTITION BY proxy_wallet ORDER BY classified_at ASC) AS rn
+            FROM wallet_personas
+        )
+        SELECT fp.confidence,
+               COUNT(*) AS settled,
+               SUM(CASE WHEN pt.status = 'settled_win' THEN 1 ELSE 0 END) AS wins,
+               COALESCE(SUM(pt.pnl), 0)
+        FROM first_persona fp
+        JOIN paper_trades pt
+          ON pt.proxy_wallet = fp.proxy_wallet
+         AND pt.status IN ('settled_win', 'settled_loss')
+         AND pt.created_at >= fp.classified_at
+        WHERE fp.rn = 1
+        GROUP BY fp.proxy_wallet
+        HAVING COUNT(*) >= ?1
+        ",
+    )?;
+    let rows = stmt.query_map([min_trades], |row| {
+        Ok(CalibrationSample {
+            confidence: row.get(0)?,
+            settled_trades: row.get(1)?,
+            wins: row.get(2)?,
+            pnl: row.get(3)?,
+        })
+    })?;
+    Ok(rows.collect::<std::result::Result<Vec<_>, _>>()?)
+}
+
+/// Group samples into `buckets` equal-width confidence bins over [0, 1]; empty bins are omitted.
+pub fn calibration_buckets(
+    samples: &[CalibrationSample],
+    buckets: usize,
+) -> Vec<CalibrationBucket> {
+    let buckets = buckets.max(1);
+    let width = 1.0 / buckets as f64;
+    let mut bins: Vec<Vec<&CalibrationSample>> = vec![Vec::new(); buckets];
+    for s in samples {
+        let idx = ((s.confidence.clamp(0.0, 1.0) / width) as usize).min(buckets - 1);
+        bins[idx].push(s);
+    }
+    bins.into_iter()
+        .enumerate()
+        .filter(|(_, b)| !b.is_empty())
+        .map(|(i, b)| {
+            let n = b.len() as f64;
+            CalibrationBucket {
+                lower: i as f64 * width,
+                upper: (i + 1) as f64 * width,
+                wallets: b.len(),
+                mean_confidence: b.iter().map(|s| s.confidence).sum::<f64>() / n,
+                success_rate: b.iter().filter(|s| s.pnl > 0.0).count() as f64 / n,
+                mean_hit_rate: b
+                    .iter()
+                    .map(|s| f64::from(s.wins) / f64::from(s.settled_trades.max(1)))
+                    .sum::<f64>()
+                    / n,
+            }
+        })
+        .collect()
+}
+
+fn show_calibration(db: &Database, buckets: usize, min_trades: u32) -> Result<()> {
+    let samples = query_calibration_samples(db, min_trades)?;
+    println!(
+        "Persona confidence calibration ({} wallets with >= {min_trades} settled paper trades after classification):",
+        samples.len()
+    );
+    if samples.is_empty() {
+        println!("  (no wallets with enough paper-trade outcomes yet)");
+        return Ok(());
+    }
+    println!("  confidence    wallets  mean_conf  success  hit_rate");
+    for b in calibration_buckets(&samples, buckets) {
+        println!(
+            "  {:.2}-{:.2}  {:>9}  {:>9.3}  {:>7.3}  {:>8.3}",
+            b.lower, b.upper, b.wallets, b.mean_confidence, b.success_rate, b.mean_hit_rate
+        );
+    }
+    Ok(())
+}
+
 fn run_classify(_db: &Database, limit: Option<usize>) -> Result<()> {
     let config = common::config::Config::load()?;
     let db_path = config.database.path.clone();
@@ -397,7 +760,7 @@ fn run_classify(_db: &Database, limit: Option<usize>) -> Result<()> {
     let db = Database::open(&db_path)?;
     let (followable, exclusions): (i64, i64) = db.conn.query_row(
         "SELECT
-            (SELECT COUNT(DISTINCT proxy_wallet) FROM wallet_personas),
+            (SELECT COUNT(DISTINCT proxy_wallet) FROM wallet_personas WHERE superseded_at IS NULL),
             (SELECT COUNT(DISTINCT proxy_wallet) FROM wallet_exclusions)",
         [],
         |row| Ok((row.get(0)?, row.get(1)?)),
@@ -581,6 +944,114 @@ mod tests {
         assert_eq!(rows[0].proxy_wallet, "0xw");
     }
 
+    #[test]
+    fn test_query_calibration_samples_uses_trades_after_first_classification() {
+        let db = common::db::Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+
+        db.conn.execute(
+            "INSERT INTO wallet_personas (proxy_wallet, persona, confidence, classified_at) VALUES
+                ('0xa', 'Informed Specialist', 0.9, '2026-02-01 00:00:00'),
+                ('0xa', 'Informed Specialist', 0.5, '2026-02-05 00:00:00'),
+                ('0xb', 'Consistent Generalist', 0.6, '2026-02-01 00:00:00')",
+            [],
+        ).unwrap();
+        db.conn.execute(
+            "INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, size_usdc, entry_price, status, pnl, created_at) VALUES
+                ('0xa', 'mirror', 'm1', 'BUY', 25.0, 0.5, 'settled_win', 10.0, '2026-01-31 00:00:00'),
+                ('0xa', 'mirror', 'm2', 'BUY', 25.0, 0.5, 'settled_win', 10.0, '2026-02-02 00:00:00'),
+                ('0xa', 'mirror', 'm3', 'BUY', 25.0, 0.5, 'settled_loss', -5.0, '2026-02-03 00:00:00'),
+                ('0xa', 'mirror', 'm4', 'BUY', 25.0, 0.5, 'open', NULL, '2026-02-04 00:00:00'),
+                ('0xb', 'mirror', 'm1', 'BUY', 25.0, 0.5, 'settled_loss', -25.0, '2026-02-02 00:00:00')",
+            [],
+        ).unwrap();
+
+        let samples = query_calibration_samples(&db, 2).unwrap();
+        assert_eq!(
+            samples,
+            vec![CalibrationSample {
+                confidence: 0.9,
+                settled_trades: 2,
+                wins: 1,
+                pnl: 5.0,
+            }]
+        );
+        assert_eq!(query_calibration_samples(&db, 1).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_calibration_buckets_groups_by_confidence() {
+        let sample = |confidence: f64, wins: u32, pnl: f64| CalibrationSample {
+            confidence,
+            settled_trades: 4,
+            wins,
+            pnl,
+        };
+        let samples = vec![
+            sample(0.85, 3, 10.0),
+            sample(0.95, 1, -5.0),
+            sample(1.0, 4, 20.0),
+            sample(0.1, 0, -10.0),
+        ];
+
+        let buckets = calibration_buckets(&samples, 5);
+        assert_eq!(buckets.len(), 2, "empty bins are omitted");
+
+        assert_eq!(buckets[0].wallets, 1);
+        assert!((buckets[0].lower - 0.0).abs() < 1e-9);
+        assert!((buckets[0].success_rate - 0.0).abs() < 1e-9);
+
+        // Confidence 1.0 lands in the top bin.
+        let top = &buckets[1];
+        assert_eq!(top.wallets, 3);
+        assert!((top.upper - 1.0).abs() < 1e-9);
+        assert!((top.success_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert!((top.mean_hit_rate - (0.75 + 0.25 + 1.0) / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_calibration_command() {
+        let args = |v: &[&str]| v.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+        let cmd = parse_args(args(&["evaluator", "calibration"]).into_iter()).unwrap();
+        assert_eq!(
+            cmd,
+            Command::Calibration {
+                buckets: 5,
+                min_trades: 5
+            }
+        );
+        let cmd = parse_args(
+            args(&["evaluator", "calibration", "--buckets=10", "--min-trades=3"]).into_iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::Calibration {
+                buckets: 10,
+                min_trades: 3
+            }
+        );
+        let err =
+            parse_args(args(&["evaluator", "calibration", "--buckets=0"]).into_iter()).unwrap_err();
+        assert!(err.contains("invalid --buckets"));
+    }
+
+    #[test]
+    fn test_parse_rescore_markets_command() {
+        let args = |v: &[&str]| v.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+        let cmd =
+            parse_args(args(&["evaluator", "rescore-markets", "0xa", "0xb", "0xa"]).into_iter())
+                .unwrap();
+        assert_eq!(
+            cmd,
+            Command::RescoreMarkets {
+                condition_ids: vec!["0xa".to_string(), "0xb".to_string()]
+            }
+        );
+        let err = parse_args(args(&["evaluator", "rescore-markets"]).into_iter()).unwrap_err();
+        assert!(err.contains("usage: evaluator rescore-markets"));
+    }
+
     #[test]
     fn test_parse_args_defaults_to_run() {
         let cmd = parse_args(vec!["evaluator".to_string()].into_iter()).unwrap();
@@ -673,6 +1144,128 @@ mod tests {
         assert!(result.unwrap_err().contains("unknown flag"));
     }
 
+    #[test]
+    fn test_parse_export_command() {
+        let cmd = parse_args(
+            vec![
+                "evaluator".to_string(),
+                "export".to_string(),
+                "paper_trades".to_string(),
+                "--format=csv".to_string(),
+                "--out=/tmp/paper.csv".to_string(),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::Export {
+                table: "paper_trades".to_string(),
+                format: ExportFormat::Csv,
+                out: "/tmp/paper.csv".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_export_rejects_unknown_table_and_missing_out() {
+        let args = |v: &[&str]| v.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+        let err = parse_args(args(&["evaluator", "export", "trades_raw", "--out=x"]).into_iter())
+            .unwrap_err();
+        assert!(err.contains("unknown export table"));
+        let err = parse_args(args(&["evaluator", "export", "wallets"]).into_iter()).unwrap_err();
+        assert!(err.contains("--out"));
+    }
+
+    #[test]
+    fn test_parse_run_job_command() {
+        let cmd = parse_args(
+            vec![
+                "evaluator".to_string(),
+                "run-job".to_string(),
+                "wallet_scoring".to_string(),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::RunJob {
+                name: "wallet_scoring".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_run_job_rejects_unknown_or_missing_name() {
+        let err = parse_args(
+            vec![
+                "evaluator".to_string(),
+                "run-job".to_string(),
+                "wallet_scorin".to_string(),
+            ]
+            .into_iter(),
+        )
+        .unwrap_err();
+        assert!(err.contains("unknown job: wallet_scorin"));
+        assert!(err.contains("wallet_scoring"), "lists known jobs: {err}");
+
+        let err = parse_args(vec!["evaluator".to_string(), "run-job".to_string()].into_iter())
+            .unwrap_err();
+        assert!(err.starts_with("usage: evaluator run-job"));
+    }
+
+    #[test]
+    fn test_parse_backfill_command() {
+        let cmd = parse_args(
+            vec![
+                "evaluator".to_string(),
+                "backfill".to_string(),
+                "--from=2026-01-01".to_string(),
+                "--to=2026-01-31".to_string(),
+            ]
+            .into_iter(),
+        )
+        .unwrap();
+        assert_eq!(
+            cmd,
+            Command::Backfill {
+                from_date: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap(),
+                to_date: chrono::NaiveDate::from_ymd_opt(2026, 1, 31).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_backfill_rejects_bad_or_inverted_dates() {
+        let args = |v: &[&str]| v.iter().map(|s| (*s).to_string()).collect::<Vec<_>>();
+        let err = parse_args(
+            args(&[
+                "evaluator",
+                "backfill",
+                "--from=2026-13-01",
+                "--to=2026-01-31",
+            ])
+            .into_iter(),
+        )
+        .unwrap_err();
+        assert!(err.contains("invalid --from"));
+        let err = parse_args(
+            args(&[
+                "evaluator",
+                "backfill",
+                "--from=2026-02-01",
+                "--to=2026-01-31",
+            ])
+            .into_iter(),
+        )
+        .unwrap_err();
+        assert!(err.contains("is after"));
+        let err = parse_args(args(&["evaluator", "backfill", "--from=2026-02-01"]).into_iter())
+            .unwrap_err();
+        assert!(err.contains("requires --from and --to"));
+    }
+
     #[test]
     fn test_parse_retry_failed_events_default_limit() {
         let cmd = parse_args(
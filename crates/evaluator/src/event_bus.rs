@@ -22,10 +22,18 @@
 //! - **Fast-path triggers:** Coalescing watch channel for latency-critical work
 //! - **Operational events:** Multi-subscriber broadcast for monitoring
 //!
+//! # Replay for late subscribers
+//!
+//! `broadcast` keeps no history, so a subscriber that attaches after an event fired never sees
+//! it. With `with_replay_buffer(n)` the bus also keeps the last `n` published pipeline events;
+//! `subscribe_with_replay()` hands them to the new subscriber together with a live receiver.
+//!
 //! See `docs/EVENT_ARCHITECTURE.md` for the full architecture reference.
 
 use crate::events::{FastPathTrigger, OperationalEvent, PipelineEvent};
 use chrono::Utc;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
 use tokio::sync::{broadcast, watch};
 
 /// Policy applied when a broadcast channel is at capacity.
@@ -52,14 +60,23 @@ pub struct EventBus {
     /// Multi-subscriber pub/sub for operational events (monitoring)
     operational_tx: broadcast::Sender<OperationalEvent>,
 
-    /// Channel capacity (stored for backpressure threshold calculations)
+    /// Pipeline channel capacity (stored for backpressure threshold calculations)
     capacity: usize,
 
+    /// Operational channel capacity, sized independently of the pipeline channel
+    operational_capacity: usize,
+
     /// Backpressure policy for pipeline events
     pipeline_backpressure: BackpressurePolicy,
 
     /// Threshold percentage [0, 100] at which to emit BackpressureWarning
     warn_threshold_pct: u8,
+
+    /// Last `replay_capacity` published pipeline events (None = replay disabled)
+    replay: Option<Arc<Mutex<VecDeque<PipelineEvent>>>>,
+
+    /// Maximum number of events kept in `replay`
+    replay_capacity: usize,
 }
 
 #[allow(dead_code)] // Phase 1: Infrastructure only, will be used in Phase 2+
@@ -69,20 +86,41 @@ impl EventBus {
     /// # Arguments
     /// * `capacity` - Buffer size for broadcast channels (pipeline and operational events)
     pub fn new(capacity: usize) -> Self {
-        let (pipeline_tx, _) = broadcast::channel(capacity);
+        Self::with_capacities(capacity, capacity)
+    }
+
+    /// Creates a new EventBus with separate buffers per event category, so a burst of
+    /// operational events can't make pipeline subscribers lag (and vice versa).
+    ///
+    /// # Arguments
+    /// * `pipeline_capacity` - Buffer size for the pipeline-event broadcast channel
+    /// * `operational_capacity` - Buffer size for the operational-event broadcast channel
+    pub fn with_capacities(pipeline_capacity: usize, operational_capacity: usize) -> Self {
+        let (pipeline_tx, _) = broadcast::channel(pipeline_capacity);
         let (fast_path_tx, _) = watch::channel(FastPathTrigger::default());
-        let (operational_tx, _) = broadcast::channel(capacity);
+        let (operational_tx, _) = broadcast::channel(operational_capacity);
 
         Self {
             pipeline_tx,
             fast_path_tx,
             operational_tx,
-            capacity,
+            capacity: pipeline_capacity,
+            operational_capacity,
             pipeline_backpressure: BackpressurePolicy::default(),
             warn_threshold_pct: 90,
+            replay: None,
+            replay_capacity: 0,
         }
     }
 
+    /// Keeps the last `capacity` pipeline events for `subscribe_with_replay` (0 = disabled).
+    pub fn with_replay_buffer(mut self, capacity: usize) -> Self {
+        self.replay_capacity = capacity;
+        self.replay =
+            (capacity > 0).then(|| Arc::new(Mutex::new(VecDeque::with_capacity(capacity))));
+        self
+    }
+
     /// Sets the backpressure policy for pipeline events.
     pub fn with_backpressure_policy(mut self, policy: BackpressurePolicy) -> Self {
         self.pipeline_backpressure = policy;
@@ -110,6 +148,11 @@ impl EventBus {
         self.capacity
     }
 
+    /// Returns the operational channel capacity.
+    pub fn operational_capacity(&self) -> usize {
+        self.operational_capacity
+    }
+
     /// Publishes a pipeline event, applying the configured backpressure policy.
     ///
     /// - **DropOldest**: Default broadcast behavior. Oldest events are overwritten when full.
@@ -137,25 +180,34 @@ impl EventBus {
                 });
         }
 
-        match self.pipeline_backpressure {
-            BackpressurePolicy::DropOldest => {
-                // Default tokio broadcast behavior: oldest messages are overwritten
-                self.pipeline_tx.send(event)
-            }
-            BackpressurePolicy::DropNewest => {
-                if current_len >= self.capacity {
-                    // Channel is full: drop the new event (return Ok(0) to indicate no receivers got it)
-                    tracing::warn!(
-                        current_len,
-                        capacity = self.capacity,
-                        "Backpressure: dropping newest pipeline event (channel full)"
-                    );
-                    Ok(0)
-                } else {
-                    self.pipeline_tx.send(event)
-                }
+        if current_len >= self.capacity
+            && self.pipeline_backpressure == BackpressurePolicy::DropNewest
+        {
+            // Channel is full: drop the new event (return Ok(0) to indicate no receivers got it)
+            tracing::warn!(
+                current_len,
+                capacity = self.capacity,
+                "Backpressure: dropping newest pipeline event (channel full)"
+            );
+            return Ok(0);
+        }
+
+        // DropOldest is the default tokio broadcast behavior: oldest messages are overwritten.
+        let Some(replay) = &self.replay else {
+            return self.pipeline_tx.send(event);
+        };
+        // Record before sending so a concurrent `subscribe_with_replay` never misses the event;
+        // the buffer lock is released before the send so publishers don't serialize on it.
+        {
+            let mut buffer = replay
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            if buffer.len() == self.replay_capacity {
+                buffer.pop_front();
             }
+            buffer.push_back(event.clone());
         }
+        self.pipeline_tx.send(event)
     }
 
     /// Subscribes to pipeline events.
@@ -165,6 +217,29 @@ impl EventBus {
         self.pipeline_tx.subscribe()
     }
 
+    /// Subscribes to pipeline events, also returning the buffered history (oldest first).
+    ///
+    /// An event published concurrently with this call can show up both in the history and on
+    /// the receiver, so consumers should tolerate a duplicate. With replay disabled
+    /// the history is empty and this behaves like `subscribe_pipeline`.
+    pub fn subscribe_with_replay(
+        &self,
+    ) -> (Vec<PipelineEvent>, broadcast::Receiver<PipelineEvent>) {
+        let Some(replay) = &self.replay else {
+            return (Vec::new(), self.pipeline_tx.subscribe());
+        };
+        // Subscribe before copying the history: anything recorded after the copy is still sent
+        // after this point and so reaches `rx`.
+        let rx = self.pipeline_tx.subscribe();
+        let history = replay
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect();
+        (history, rx)
+    }
+
     /// Triggers fast-path processing by incrementing the generation counter.
     ///
     /// Multiple triggers are coalesced - only the latest generation is tracked.
@@ -538,9 +613,72 @@ mod tests {
         assert_eq!(bus.pipeline_capacity(), 32);
     }
 
+    #[tokio::test]
+    async fn test_with_capacities_sizes_channels_independently() {
+        let bus = EventBus::with_capacities(32, 2);
+        assert_eq!(bus.pipeline_capacity(), 32);
+        assert_eq!(bus.operational_capacity(), 2);
+
+        let mut pipeline_rx = bus.subscribe_pipeline();
+        let mut operational_rx = bus.subscribe_operational();
+        for _ in 0..5 {
+            bus.publish_operational(OperationalEvent::JobStarted {
+                job_name: "noisy".to_string(),
+                started_at: Utc::now(),
+            })
+            .unwrap();
+        }
+        bus.publish_pipeline(markets_scored(1)).unwrap();
+
+        assert!(matches!(
+            operational_rx.recv().await,
+            Err(broadcast::error::RecvError::Lagged(3))
+        ));
+        assert!(matches!(
+            pipeline_rx.recv().await,
+            Ok(PipelineEvent::MarketsScored { .. })
+        ));
+    }
+
     #[test]
     fn test_with_warn_threshold_pct_sets_threshold() {
         let bus = EventBus::new(16).with_warn_threshold_pct(75);
         assert_eq!(bus.warn_threshold_pct, 75);
     }
+
+    fn markets_scored(n: u64) -> PipelineEvent {
+        PipelineEvent::MarketsScored {
+            markets_scored: n,
+            events_ranked: n,
+            completed_at: chrono::DateTime::UNIX_EPOCH,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_with_replay_returns_events_published_before_subscribing() {
+        let bus = EventBus::new(16).with_replay_buffer(2);
+
+        // Published with no subscribers at all: broadcast drops them, replay keeps the last 2.
+        let _ = bus.publish_pipeline(markets_scored(1));
+        let _ = bus.publish_pipeline(markets_scored(2));
+        let _ = bus.publish_pipeline(markets_scored(3));
+
+        let (history, mut rx) = bus.subscribe_with_replay();
+        assert_eq!(history, vec![markets_scored(2), markets_scored(3)]);
+        assert!(
+            rx.try_recv().is_err(),
+            "history must not be delivered twice"
+        );
+
+        bus.publish_pipeline(markets_scored(4)).unwrap();
+        assert_eq!(rx.recv().await.unwrap(), markets_scored(4));
+    }
+
+    #[test]
+    fn test_subscribe_with_replay_disabled_returns_no_history() {
+        let bus = EventBus::new(16);
+        let _ = bus.publish_pipeline(markets_scored(1));
+        let (history, _rx) = bus.subscribe_with_replay();
+        assert!(history.is_empty());
+    }
 }
@@ -137,7 +137,19 @@ pub async fn spawn_discovery_trigger_subscriber(
     event_bus: Arc<EventBus>,
     discovery_tx: mpsc::Sender<()>,
 ) {
-    let mut pipeline_rx = event_bus.subscribe_pipeline();
+    let (history, mut pipeline_rx) = event_bus.subscribe_with_replay();
+
+    // A (re)started subscriber may have missed a MarketsScored; one catch-up run covers it.
+    if history
+        .iter()
+        .any(|e| matches!(e, PipelineEvent::MarketsScored { .. }))
+    {
+        tracing::info!("replayed MarketsScored — triggering catch-up wallet discovery");
+        if discovery_tx.send(()).await.is_err() {
+            tracing::error!("failed to send discovery trigger");
+            return;
+        }
+    }
 
     loop {
         match pipeline_rx.recv().await {
@@ -236,8 +248,14 @@ pub async fn spawn_classification_trigger_subscriber(
     classification_tx: mpsc::Sender<()>,
     batch_window: Duration,
 ) {
-    let mut pipeline_rx = event_bus.subscribe_pipeline();
+    let (history, mut pipeline_rx) = event_bus.subscribe_with_replay();
     let mut accumulator = TradesIngestedAccumulator::new();
+    // Wallets ingested before this subscriber attached go into the first batch.
+    for event in history {
+        if let PipelineEvent::TradesIngested { wallet_address, .. } = event {
+            accumulator.add_wallet(wallet_address);
+        }
+    }
     let mut timer = tokio::time::interval(batch_window);
     // The first tick completes immediately; consume it so we don't trigger
     // classification with an empty accumulator at startup.
@@ -284,6 +302,64 @@ pub async fn spawn_classification_trigger_subscriber(
     }
 }
 
+/// Spawns a subscriber that batches `TradesIngested` events and enqueues a
+/// wallet-scoring run for just the wallets that received new trades.
+///
+/// Works like the classification trigger, except each batch carries the
+/// (sorted, deduplicated) wallet addresses so scoring can skip untouched wallets.
+///
+/// This subscriber only runs when `enable_scoring_event_trigger=true`.
+pub async fn spawn_scoring_trigger_subscriber(
+    event_bus: Arc<EventBus>,
+    scoring_tx: mpsc::Sender<Vec<String>>,
+    batch_window: Duration,
+) {
+    let (history, mut pipeline_rx) = event_bus.subscribe_with_replay();
+    let mut accumulator = TradesIngestedAccumulator::new();
+    for event in history {
+        if let PipelineEvent::TradesIngested { wallet_address, .. } = event {
+            accumulator.add_wallet(wallet_address);
+        }
+    }
+    let mut timer = tokio::time::interval(batch_window);
+    timer.tick().await;
+
+    loop {
+        tokio::select! {
+            Ok(event) = pipeline_rx.recv() => {
+                if let PipelineEvent::TradesIngested { wallet_address, .. } = event {
+                    accumulator.add_wallet(wallet_address);
+                }
+            }
+            _ = timer.tick() => {
+                if !accumulator.is_empty() {
+                    let mut batch: Vec<String> = accumulator.drain().into_iter().collect();
+                    batch.sort();
+                    let batch_size = batch.len();
+                    tracing::info!(
+                        wallets = batch_size,
+                        "scoring trigger: batched wallets, triggering wallet_scoring"
+                    );
+
+                    metrics::counter!("evaluator_event_triggers_fired_total", "trigger_type" => "wallet_scoring").increment(1);
+                    let start = std::time::Instant::now();
+
+                    if scoring_tx.send(batch).await.is_err() {
+                        tracing::warn!("scoring trigger: channel closed, shutting down");
+                        break;
+                    }
+                    let latency = start.elapsed().as_secs_f64();
+                    metrics::histogram!("evaluator_event_trigger_latency_seconds", "trigger_type" => "wallet_scoring").record(latency);
+                }
+            }
+            else => {
+                tracing::info!("scoring trigger subscriber shutting down");
+                break;
+            }
+        }
+    }
+}
+
 /// Spawns a fast-path subscriber that bridges pipeline TradesIngested events
 /// to the coalescing fast-path watch channel.
 ///
@@ -462,6 +538,34 @@ mod tests {
         handle.abort();
     }
 
+    #[tokio::test]
+    async fn test_discovery_trigger_replays_markets_scored_published_before_start() {
+        let bus = Arc::new(EventBus::new(16).with_replay_buffer(8));
+        let (discovery_tx, mut discovery_rx) = mpsc::channel::<()>(8);
+
+        // Fired while no discovery subscriber was running (e.g. during a restart).
+        let _ = bus.publish_pipeline(PipelineEvent::MarketsScored {
+            markets_scored: 100,
+            events_ranked: 50,
+            completed_at: Utc::now(),
+        });
+
+        let subscriber_bus = bus.clone();
+        let handle = tokio::spawn(async move {
+            spawn_discovery_trigger_subscriber(subscriber_bus, discovery_tx).await;
+        });
+
+        let result =
+            tokio::time::timeout(tokio::time::Duration::from_millis(100), discovery_rx.recv())
+                .await;
+        assert!(
+            matches!(result, Ok(Some(()))),
+            "late subscriber should trigger discovery from replay"
+        );
+
+        handle.abort();
+    }
+
     #[tokio::test]
     async fn test_discovery_trigger_ignores_non_markets_scored_events() {
         let bus = Arc::new(EventBus::new(16));
@@ -967,4 +1071,68 @@ mod tests {
 
         handle.abort();
     }
+
+    // ── Wallet-scoring trigger subscriber tests ──
+
+    #[tokio::test]
+    async fn test_scoring_trigger_sends_deduplicated_wallet_batch() {
+        let bus = Arc::new(EventBus::new(16));
+        let (scoring_tx, mut scoring_rx) = mpsc::channel::<Vec<String>>(8);
+
+        let subscriber_bus = bus.clone();
+        let handle = tokio::spawn(async move {
+            spawn_scoring_trigger_subscriber(
+                subscriber_bus,
+                scoring_tx,
+                Duration::from_millis(100),
+            )
+            .await;
+        });
+
+        tokio::time::sleep(Duration::from_millis(20)).await;
+
+        for wallet in ["0xwallet2", "0xwallet1", "0xwallet2"] {
+            let _ = bus.publish_pipeline(PipelineEvent::TradesIngested {
+                wallet_address: wallet.to_string(),
+                trades_count: 1,
+                ingested_at: Utc::now(),
+            });
+        }
+        let _ = bus.publish_pipeline(PipelineEvent::MarketsScored {
+            markets_scored: 1,
+            events_ranked: 1,
+            completed_at: Utc::now(),
+        });
+
+        let batch = tokio::time::timeout(Duration::from_millis(200), scoring_rx.recv())
+            .await
+            .expect("should receive scoring trigger")
+            .expect("channel should not be closed");
+        assert_eq!(
+            batch,
+            vec!["0xwallet1".to_string(), "0xwallet2".to_string()]
+        );
+
+        handle.abort();
+    }
+
+    #[tokio::test]
+    async fn test_scoring_trigger_does_not_fire_when_no_events() {
+        let bus = Arc::new(EventBus::new(16));
+        let (scoring_tx, mut scoring_rx) = mpsc::channel::<Vec<String>>(8);
+
+        let subscriber_bus = bus.clone();
+        let handle = tokio::spawn(async move {
+            spawn_scoring_trigger_subscriber(subscriber_bus, scoring_tx, Duration::from_millis(50))
+                .await;
+        });
+
+        let result = tokio::time::timeout(Duration::from_millis(150), scoring_rx.recv()).await;
+        assert!(
+            result.is_err(),
+            "Should not receive trigger when no events accumulated"
+        );
+
+        handle.abort();
+    }
 }
@@ -0,0 +1,171 @@
+use anyhow::Result;
+use common::db::Database;
+use rusqlite::types::ValueRef;
+use std::io::Write;
+
+/// Tables analysts may export. The table name is interpolated into SQL, so anything not
+/// listed here is rejected.
+pub const EXPORT_TABLES: &[&str] = &["wallets", "wallet_scores_daily", "paper_trades"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Ndjson,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "ndjson" | "jsonl" => Some(Self::Ndjson),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+pub fn is_exportable_table(table: &str) -> bool {
+    EXPORT_TABLES.contains(&table)
+}
+
+fn value_to_json(value: ValueRef<'_>) -> serde_json::Value {
+    match value {
+        ValueRef::Null => serde_json::Value::Null,
+        ValueRef::Integer(i) => serde_json::Value::from(i),
+        ValueRef::Real(f) => serde_json::Value::from(f),
+        ValueRef::Text(t) => serde_json::Value::from(String::from_utf8_lossy(t).into_owned()),
+        ValueRef::Blob(b) => serde_json::Value::from(format!("<blob {} bytes>", b.len())),
+    }
+}
+
+/// RFC 4180 field: quote when it contains a delimiter, quote or newline; NULL is empty.
+fn csv_field(value: ValueRef<'_>) -> String {
+    let raw = match value {
+        ValueRef::Null => return String::new(),
+        ValueRef::Integer(i) => return i.to_string(),
+        ValueRef::Real(f) => return f.to_string(),
+        ValueRef::Text(t) => String::from_utf8_lossy(t).into_owned(),
+        ValueRef::Blob(b) => format!("<blob {} bytes>", b.len()),
+    };
+    if raw.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", raw.replace('"', "\"\""))
+    } else {
+        raw
+    }
+}
+
+/// Stream every row of `table` to `out` as NDJSON (one object per line) or CSV (with header).
+///
+/// Rows are written as they are read, so large tables never sit in memory.
+/// Returns the number of rows written.
+pub fn export_table<W: Write>(
+    db: &Database,
+    table: &str,
+    format: ExportFormat,
+    out: &mut W,
+) -> Result<u64> {
+    if !is_exportable_table(table) {
+        anyhow::bail!(
+            "table {table:?} is not exportable (allowed: {})",
+            EXPORT_TABLES.join(", ")
+        );
+    }
+
+    let mut stmt = db.conn.prepare(&format!("SELECT * FROM {table}"))?;
+    let columns: Vec<String> = stmt
+        .column_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+
+    if format == ExportFormat::Csv {
+        writeln!(out, "{}", columns.join(","))?;
+    }
+
+    let mut rows = stmt.query([])?;
+    let mut written = 0_u64;
+    while let Some(row) = rows.next()? {
+        match format {
+            ExportFormat::Ndjson => {
+                let mut obj = serde_json::Map::with_capacity(columns.len());
+                for (i, name) in columns.iter().enumerate() {
+                    obj.insert(name.clone(), value_to_json(row.get_ref(i)?));
+                }
+                serde_json::to_writer(&mut *out, &obj)?;
+                writeln!(out)?;
+            }
+            ExportFormat::Csv => {
+                let fields = (0..columns.len())
+                    .map(|i| row.get_ref(i).map(csv_field))
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                writeln!(out, "{}", fields.join(","))?;
+            }
+        }
+        written += 1;
+    }
+    out.flush()?;
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seeded_db() -> Database {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        db.conn
+            .execute_batch(
+                "INSERT INTO wallets (proxy_wallet, name, discovered_from) VALUES ('0xa', 'Alice, \"A\"', 'HOLDER');
+                 INSERT INTO wallets (proxy_wallet, discovered_from) VALUES ('0xb', 'LEADERBOARD');",
+            )
+            .unwrap();
+        db
+    }
+
+    #[test]
+    fn test_export_table_ndjson_writes_one_object_per_row() {
+        let db = seeded_db();
+        let mut out = Vec::new();
+        let n = export_table(&db, "wallets", ExportFormat::Ndjson, &mut out).unwrap();
+        assert_eq!(n, 2);
+
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["proxy_wallet"], "0xa");
+        assert_eq!(lines[0]["name"], "Alice, \"A\"");
+        assert!(lines[1]["name"].is_null());
+    }
+
+    #[test]
+    fn test_export_table_csv_quotes_special_characters() {
+        let db = seeded_db();
+        let mut out = Vec::new();
+        export_table(&db, "wallets", ExportFormat::Csv, &mut out).unwrap();
+
+        let text = String::from_utf8(out).unwrap();
+        let mut lines = text.lines();
+        assert!(lines.next().unwrap().starts_with("proxy_wallet,"));
+        assert!(lines
+            .next()
+            .unwrap()
+            .starts_with("0xa,,\"Alice, \"\"A\"\"\","));
+    }
+
+    #[test]
+    fn test_export_table_rejects_unlisted_table() {
+        let db = seeded_db();
+        let mut out = Vec::new();
+        let err = export_table(
+            &db,
+            "wallets; DROP TABLE wallets",
+            ExportFormat::Ndjson,
+            &mut out,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("not exportable"));
+    }
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use rusqlite::Connection;
 
 use crate::persona_classification;
+use crate::persona_rules::{PersonaRules, RuleKind};
 
 /// Records current flow counts to Prometheus gauges (for Grafana flow panels).
 pub fn record_flow_counts(counts: &FlowCounts) {
@@ -15,6 +16,8 @@ pub fn record_flow_counts(counts: &FlowCounts) {
         .set(counts.funnel.wallets_tracked as f64);
     metrics::gauge!("evaluator_flow_funnel_wallets_ranked_today")
         .set(counts.funnel.wallets_ranked_today as f64);
+    metrics::gauge!("evaluator_flow_funnel_wallets_paper_traded")
+        .set(counts.funnel.wallets_paper_traded as f64);
     metrics::gauge!("evaluator_flow_classification_wallets_tracked")
         .set(counts.classification.wallets_tracked as f64);
     metrics::gauge!("evaluator_flow_classification_stage1_excluded")
@@ -27,6 +30,57 @@ pub fn record_flow_counts(counts: &FlowCounts) {
         .set(counts.classification.stage2_excluded as f64);
     metrics::gauge!("evaluator_flow_classification_stage2_unclassified")
         .set(counts.classification.stage2_unclassified as f64);
+    for (from, to, ratio) in conversion_ratios(counts) {
+        metrics::gauge!("evaluator_funnel_conversion_ratio", "from" => from, "to" => to).set(ratio);
+    }
+}
+
+/// `numerator / denominator`, or 0.0 when the denominator is 0 (empty stage, not a leak).
+fn ratio(numerator: i64, denominator: i64) -> f64 {
+    if denominator <= 0 {
+        return 0.0;
+    }
+    numerator as f64 / denominator as f64
+}
+
+/// Stage-to-stage conversion ratios derived from the flow counts, as `(from, to, ratio)`.
+/// "classified" = wallets with a Stage 2 outcome (followable or excluded).
+pub fn conversion_ratios(counts: &FlowCounts) -> Vec<(&'static str, &'static str, f64)> {
+    let f = &counts.funnel;
+    let c = &counts.classification;
+    let classified = c.stage2_followable + c.stage2_excluded;
+    vec![
+        (
+            "discovered",
+            "tracked",
+            ratio(f.wallets_tracked, f.wallets_discovered),
+        ),
+        (
+            "tracked",
+            "stage1_passed",
+            ratio(c.stage1_passed, c.wallets_tracked),
+        ),
+        (
+            "stage1_passed",
+            "classified",
+            ratio(classified, c.stage1_passed),
+        ),
+        (
+            "discovered",
+            "classified",
+            ratio(classified, f.wallets_discovered),
+        ),
+        (
+            "classified",
+            "followable",
+            ratio(c.stage2_followable, classified),
+        ),
+        (
+            "classified",
+            "paper_traded",
+            ratio(c.classified_paper_traded, classified),
+        ),
+    ]
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -36,6 +90,7 @@ pub struct FunnelFlowCounts {
     pub wallets_discovered: i64,
     pub wallets_tracked: i64,
     pub wallets_ranked_today: i64,
+    pub wallets_paper_traded: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -46,6 +101,8 @@ pub struct ClassificationFlowCounts {
     pub stage2_followable: i64,
     pub stage2_excluded: i64,
     pub stage2_unclassified: i64,
+    /// Paper-traded wallets with a Stage 2 outcome (followable or excluded).
+    pub classified_paper_traded: i64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -57,7 +114,9 @@ pub struct FlowCounts {
 /// Followable personas for Stage 2 counts come from
 /// `persona_classification::FOLLOWABLE_PERSONAS` (single source of truth).
 /// When adding a followable Persona, add it there.
-pub fn compute_flow_counts(conn: &Connection) -> Result<FlowCounts> {
+/// With a `personas.rules_file` loaded, its followable rule names count as followable and its
+/// exclusion rule names count as Stage 2 exclusions alongside `STAGE2_%` reasons.
+pub fn compute_flow_counts(conn: &Connection, rules: Option<&PersonaRules>) -> Result<FlowCounts> {
     let markets_fetched: i64 = conn.query_row("SELECT COUNT(*) FROM markets", [], |r| r.get(0))?;
     let markets_scored_today: i64 = conn.query_row(
         "SELECT COUNT(*) FROM market_scores WHERE score_date = date('now')",
@@ -76,6 +135,11 @@ pub fn compute_flow_counts(conn: &Connection) -> Result<FlowCounts> {
         [],
         |r| r.get(0),
     )?;
+    let wallets_paper_traded: i64 = conn.query_row(
+        "SELECT COUNT(DISTINCT proxy_wallet) FROM paper_trades",
+        [],
+        |r| r.get(0),
+    )?;
 
     // Stage 1: active wallets with any STAGE1_% exclusion
     let stage1_excluded: i64 = conn.query_row(
@@ -87,35 +151,55 @@ pub fn compute_flow_counts(conn: &Connection) -> Result<FlowCounts> {
     )?;
     let stage1_passed = wallets_tracked.saturating_sub(stage1_excluded);
 
-    // Stage 2 followable: active wallets with latest persona in followable list and no STAGE2_% exclusion
-    let followable_strs: Vec<&str> = persona_classification::FOLLOWABLE_PERSONAS
+    // Stage 2 followable: active wallets with latest persona in followable list and no Stage 2 exclusion
+    let mut followable_strs: Vec<&str> = persona_classification::FOLLOWABLE_PERSONAS
         .iter()
         .map(persona_classification::Persona::as_str)
         .collect();
-    let placeholders = followable_strs
-        .iter()
-        .map(|_| "?")
-        .collect::<Vec<_>>()
-        .join(",");
-    let mut followable_stmt = conn.prepare(&format!(
-        "SELECT COUNT(*) FROM (
-          SELECT p.proxy_wallet FROM wallet_personas p
+    let mut rule_exclusions: Vec<&str> = Vec::new();
+    if let Some(rules) = rules {
+        followable_strs.extend(rules.names(RuleKind::Followable));
+        rule_exclusions.extend(rules.names(RuleKind::Exclude));
+    }
+    let placeholders = |n: usize| vec!["?"; n].join(",");
+    let stage2_exclusion = format!(
+        "(e.reason LIKE 'STAGE2_%' OR e.reason IN ({}))",
+        placeholders(rule_exclusions.len())
+    );
+    let followable_wallets = format!(
+        "SELECT p.proxy_wallet FROM wallet_personas p
           INNER JOIN wallets w ON w.proxy_wallet = p.proxy_wallet AND w.is_active = 1
-          WHERE p.classified_at = (SELECT MAX(classified_at) FROM wallet_personas WHERE proxy_wallet = p.proxy_wallet)
-          AND p.persona IN ({placeholders})
-          AND NOT EXISTS (SELECT 1 FROM wallet_exclusions e WHERE e.proxy_wallet = p.proxy_wallet AND e.reason LIKE 'STAGE2_%')
-        )"
-    ))?;
-    let stage2_followable: i64 = followable_stmt.query_row(
-        rusqlite::params_from_iter(followable_strs.into_iter()),
+          WHERE p.superseded_at IS NULL
+          AND p.classified_at = (SELECT MAX(classified_at) FROM wallet_personas WHERE proxy_wallet = p.proxy_wallet)
+          AND p.persona IN ({})
+          AND NOT EXISTS (SELECT 1 FROM wallet_exclusions e WHERE e.proxy_wallet = p.proxy_wallet AND {stage2_exclusion})",
+        placeholders(followable_strs.len())
+    );
+    let followable_params = || followable_strs.iter().chain(&rule_exclusions);
+    let stage2_followable: i64 = conn.query_row(
+        &format!("SELECT COUNT(*) FROM ({followable_wallets})"),
+        rusqlite::params_from_iter(followable_params()),
         |r| r.get(0),
     )?;
 
-    let stage2_excluded: i64 = conn.query_row(
-        "SELECT COUNT(DISTINCT e.proxy_wallet) FROM wallet_exclusions e
+    let excluded_wallets = format!(
+        "SELECT e.proxy_wallet FROM wallet_exclusions e
          INNER JOIN wallets w ON w.proxy_wallet = e.proxy_wallet AND w.is_active = 1
-         WHERE e.reason LIKE 'STAGE2_%'",
-        [],
+         WHERE {stage2_exclusion}"
+    );
+    let stage2_excluded: i64 = conn.query_row(
+        &format!("SELECT COUNT(DISTINCT proxy_wallet) FROM ({excluded_wallets})"),
+        rusqlite::params_from_iter(&rule_exclusions),
+        |r| r.get(0),
+    )?;
+
+    // Paper-traded wallets that reached a Stage 2 outcome (numerator of classified -> paper_traded).
+    let classified_paper_traded: i64 = conn.query_row(
+        &format!(
+            "SELECT COUNT(DISTINCT proxy_wallet) FROM paper_trades
+             WHERE proxy_wallet IN ({followable_wallets}) OR proxy_wallet IN ({excluded_wallets})"
+        ),
+        rusqlite::params_from_iter(followable_params().chain(&rule_exclusions)),
         |r| r.get(0),
     )?;
 
@@ -130,6 +214,7 @@ pub fn compute_flow_counts(conn: &Connection) -> Result<FlowCounts> {
             wallets_discovered,
             wallets_tracked,
             wallets_ranked_today,
+            wallets_paper_traded,
         },
         classification: ClassificationFlowCounts {
             wallets_tracked,
@@ -138,6 +223,7 @@ pub fn compute_flow_counts(conn: &Connection) -> Result<FlowCounts> {
             stage2_followable,
             stage2_excluded,
             stage2_unclassified,
+            classified_paper_traded,
         },
     })
 }
@@ -190,8 +276,16 @@ mod tests {
                 [],
             )
             .unwrap();
+        // Paper trades on the classified w2 and the never-classified w1.
+        db.conn
+            .execute(
+                "INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, size_usdc, entry_price)
+                 VALUES ('w1','mirror','c1','BUY', 10.0, 0.5),('w2','mirror','c1','BUY', 10.0, 0.5)",
+                [],
+            )
+            .unwrap();
 
-        let got = compute_flow_counts(&db.conn).unwrap();
+        let got = compute_flow_counts(&db.conn, None).unwrap();
 
         assert_eq!(
             got.funnel,
@@ -201,6 +295,7 @@ mod tests {
                 wallets_discovered: 3,
                 wallets_tracked: 2,
                 wallets_ranked_today: 1,
+                wallets_paper_traded: 2,
             }
         );
 
@@ -213,10 +308,114 @@ mod tests {
                 stage2_followable: 1,
                 stage2_excluded: 0,
                 stage2_unclassified: 0,
+                classified_paper_traded: 1,
             }
         );
     }
 
+    #[test]
+    fn test_compute_flow_counts_counts_rule_personas_and_exclusions() {
+        let db = common::db::Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        db.conn
+            .execute_batch(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active)
+                 VALUES ('w1','HOLDER',1),('w2','HOLDER',1);
+                 INSERT INTO wallet_personas (proxy_wallet, persona, confidence)
+                 VALUES ('w1','STEADY_HAND', 0.8);
+                 INSERT INTO wallet_exclusions (proxy_wallet, reason) VALUES ('w2','HEAVY_TRADER');",
+            )
+            .unwrap();
+        let rules = PersonaRules::from_toml_str(
+            r#"
+            [[rule]]
+            name = "HEAVY_TRADER"
+            kind = "exclude"
+            when = [{ feature = "trades_per_week", op = ">", value = 9.0 }]
+
+            [[rule]]
+            name = "STEADY_HAND"
+            kind = "followable"
+            when = [{ feature = "concentration_ratio", op = ">=", value = 0.7 }]
+            "#,
+        )
+        .unwrap();
+
+        let builtin = compute_flow_counts(&db.conn, None).unwrap().classification;
+        assert_eq!((builtin.stage2_followable, builtin.stage2_excluded), (0, 0));
+
+        let got = compute_flow_counts(&db.conn, Some(&rules))
+            .unwrap()
+            .classification;
+        assert_eq!(got.stage2_followable, 1);
+        assert_eq!(got.stage2_excluded, 1);
+        assert_eq!(got.stage2_unclassified, 0);
+    }
+
+    fn ratio_of(ratios: &[(&str, &str, f64)], from: &str, to: &str) -> f64 {
+        ratios
+            .iter()
+            .find(|(f, t, _)| *f == from && *t == to)
+            .map(|(_, _, r)| *r)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_conversion_ratios_from_counts() {
+        let counts = FlowCounts {
+            funnel: FunnelFlowCounts {
+                markets_fetched: 10,
+                markets_scored_today: 5,
+                wallets_discovered: 100,
+                wallets_tracked: 80,
+                wallets_ranked_today: 3,
+                wallets_paper_traded: 5,
+            },
+            classification: ClassificationFlowCounts {
+                wallets_tracked: 80,
+                stage1_excluded: 40,
+                stage1_passed: 40,
+                stage2_followable: 10,
+                stage2_excluded: 10,
+                stage2_unclassified: 20,
+                classified_paper_traded: 5,
+            },
+        };
+        let ratios = conversion_ratios(&counts);
+        assert!((ratio_of(&ratios, "discovered", "tracked") - 0.8).abs() < 1e-9);
+        assert!((ratio_of(&ratios, "tracked", "stage1_passed") - 0.5).abs() < 1e-9);
+        assert!((ratio_of(&ratios, "stage1_passed", "classified") - 0.5).abs() < 1e-9);
+        assert!((ratio_of(&ratios, "discovered", "classified") - 0.2).abs() < 1e-9);
+        assert!((ratio_of(&ratios, "classified", "followable") - 0.5).abs() < 1e-9);
+        assert!((ratio_of(&ratios, "classified", "paper_traded") - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_conversion_ratios_zero_denominator_is_zero() {
+        let counts = FlowCounts {
+            funnel: FunnelFlowCounts {
+                markets_fetched: 0,
+                markets_scored_today: 0,
+                wallets_discovered: 0,
+                wallets_tracked: 0,
+                wallets_ranked_today: 0,
+                wallets_paper_traded: 3,
+            },
+            classification: ClassificationFlowCounts {
+                wallets_tracked: 0,
+                stage1_excluded: 0,
+                stage1_passed: 0,
+                stage2_followable: 0,
+                stage2_excluded: 0,
+                stage2_unclassified: 0,
+                classified_paper_traded: 0,
+            },
+        };
+        for (from, to, r) in conversion_ratios(&counts) {
+            assert!(r.abs() < f64::EPSILON, "{from}->{to} should be 0, got {r}");
+        }
+    }
+
     #[test]
     fn test_followable_personas_single_source_matches_expected() {
         // persona_classification::FOLLOWABLE_PERSONAS is the single source of truth.
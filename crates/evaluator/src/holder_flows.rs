@@ -0,0 +1,185 @@
+use anyhow::Result;
+use rusqlite::Connection;
+
+/// Direction of a holder's position change between two consecutive snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlowDirection {
+    Accumulating,
+    Distributing,
+}
+
+impl FlowDirection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Accumulating => "accumulating",
+            Self::Distributing => "distributing",
+        }
+    }
+}
+
+/// A wallet whose holding in one market outcome moved sharply between snapshots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HolderFlow {
+    pub condition_id: String,
+    pub proxy_wallet: String,
+    pub outcome_index: Option<i64>,
+    pub previous_amount: f64,
+    pub current_amount: f64,
+    pub direction: FlowDirection,
+}
+
+impl HolderFlow {
+    /// Relative change vs the previous snapshot, in percent.
+    pub fn change_pct(&self) -> f64 {
+        (self.current_amount - self.previous_amount) / self.previous_amount * 100.0
+    }
+}
+
+/// Compare the two most recent holder snapshots of `condition_id` and return wallets whose
+/// amount changed by at least `min_change_pct` percent.
+///
+/// Only wallets present in both snapshots are compared: the holders API returns the top N per
+/// outcome, so dropping out of (or entering) the list says nothing reliable about the size.
+pub fn detect_holder_flows(
+    conn: &Connection,
+    condition_id: &str,
+    min_change_pct: f64,
+) -> Result<Vec<HolderFlow>> {
+    let mut stmt = conn.prepare(
+        "
+        WITH snaps AS (
+            SELECT DISTINCT snapshot_at FROM holders_snapshots
+            WHERE condition_id = ?1
+            ORDER BY snapshot_at DESC
+            LIMIT 2
+        ),
+        cur AS (
+            SELECT proxy_wallet, outcome_index, SUM(amount) AS amount
+            FROM holders_snapshots
+            WHERE condition_id = ?1 AND snapshot_at = (SELECT MAX(snapshot_at) FROM snaps)
+            GROUP BY proxy_wallet, outcome_index
+        ),
+        prev AS (
+            SELECT proxy_wallet, outcome_index, SUM(amount) AS amount
+            FROM holders_snapshots
+            WHERE condition_id = ?1
+              AND snapshot_at = (SELECT MIN(snapshot_at) FROM snaps)
+              AND (SELECT COUNT(*) FROM snaps) = 2
+            GROUP BY proxy_wallet, outcome_index
+        )
+        SELECT cur.proxy_wallet, cur.outcome_index, prev.amount, cur.amount
+        FROM cur
+        JOIN prev
+          ON prev.proxy_wallet = cur.proxy_wallet
+         AND prev.outcome_index IS cur.outcome_index
+        WHERE prev.amount > 0
+        ORDER BY cur.proxy_wallet, cur.outcome_index
+        ",
+    )?;
+    let rows = stmt
+        .query_map([condition_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, Option<i64>>(1)?,
+                row.get::<_, f64>(2)?,
+                row.get::<_, f64>(3)?,
+            ))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let flows = rows
+        .into_iter()
+        .filter_map(|(proxy_wallet, outcome_index, previous, current)| {
+            let change_pct = (current - previous) / previous * 100.0;
+            if change_pct.abs() < min_change_pct {
+                return None;
+            }
+            let direction = if change_pct > 0.0 {
+                FlowDirection::Accumulating
+            } else {
+                FlowDirection::Distributing
+            };
+            Some(HolderFlow {
+                condition_id: condition_id.to_string(),
+                proxy_wallet,
+                outcome_index,
+                previous_amount: previous,
+                current_amount: current,
+                direction,
+            })
+        })
+        .collect();
+    Ok(flows)
+}
+
+/// Count and log detected flows (`evaluator_holder_flows_total{direction}`).
+pub fn record_holder_flows(flows: &[HolderFlow]) {
+    for flow in flows {
+        metrics::counter!(
+            "evaluator_holder_flows_total",
+            "direction" => flow.direction.as_str()
+        )
+        .increment(1);
+        tracing::info!(
+            condition_id = %flow.condition_id,
+            proxy_wallet = %flow.proxy_wallet,
+            outcome_index = ?flow.outcome_index,
+            previous_amount = flow.previous_amount,
+            current_amount = flow.current_amount,
+            change_pct = flow.change_pct(),
+            direction = flow.direction.as_str(),
+            "holder flow detected"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::db::Database;
+
+    fn insert(db: &Database, wallet: &str, outcome: i64, amount: f64, at: &str) {
+        db.conn
+            .execute(
+                "INSERT INTO holders_snapshots (condition_id, proxy_wallet, amount, outcome_index, snapshot_at)
+                 VALUES ('0xm1', ?1, ?2, ?3, ?4)",
+                rusqlite::params![wallet, amount, outcome, at],
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn test_detect_holder_flows_flags_large_changes_between_latest_snapshots() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        // Oldest snapshot is ignored: only the latest two are compared.
+        insert(&db, "0xacc", 0, 1.0, "2026-01-01 00:00:00");
+        insert(&db, "0xacc", 0, 100.0, "2026-01-02 00:00:00");
+        insert(&db, "0xacc", 0, 300.0, "2026-01-03 00:00:00");
+        insert(&db, "0xexit", 1, 400.0, "2026-01-02 00:00:00");
+        insert(&db, "0xexit", 1, 100.0, "2026-01-03 00:00:00");
+        insert(&db, "0xflat", 0, 100.0, "2026-01-02 00:00:00");
+        insert(&db, "0xflat", 0, 110.0, "2026-01-03 00:00:00");
+        // New entrant: no previous amount to compare against.
+        insert(&db, "0xnew", 0, 900.0, "2026-01-03 00:00:00");
+
+        let flows = detect_holder_flows(&db.conn, "0xm1", 50.0).unwrap();
+        assert_eq!(flows.len(), 2, "{flows:?}");
+        assert_eq!(flows[0].proxy_wallet, "0xacc");
+        assert_eq!(flows[0].direction, FlowDirection::Accumulating);
+        assert!((flows[0].change_pct() - 200.0).abs() < 1e-9);
+        assert_eq!(flows[1].proxy_wallet, "0xexit");
+        assert_eq!(flows[1].direction, FlowDirection::Distributing);
+        assert!((flows[1].change_pct() + 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_detect_holder_flows_needs_two_snapshots() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        insert(&db, "0xacc", 0, 100.0, "2026-01-03 00:00:00");
+        assert!(detect_holder_flows(&db.conn, "0xm1", 10.0)
+            .unwrap()
+            .is_empty());
+    }
+}
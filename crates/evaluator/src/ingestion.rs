@@ -45,6 +45,9 @@ pub async fn ingest_trades_for_wallet<P: TradesPager + Sync>(
         let fetch_result = pager.fetch_trades_page(user, limit, offset).await;
         let (trades, _raw_body) = match fetch_result {
             Ok(v) => v,
+            // Nothing fetched yet: the wallet itself failed (404, malformed response, ...).
+            // Surface it so the caller can count the failure streak.
+            Err(e) if pages == 0 => return Err(e),
             Err(e) => {
                 // Treat errors during pagination (e.g., HTTP 400 at high offsets)
                 // as "end of data" — return what we collected so far.
@@ -399,6 +402,15 @@ mod tests {
         assert_eq!(trades_count, 2);
     }
 
+    #[tokio::test]
+    async fn test_ingest_trades_for_wallet_returns_first_page_error() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let pager = FakeTradesPager::new(vec![Err(anyhow::anyhow!("HTTP 404 Not Found"))]);
+
+        let result = ingest_trades_for_wallet(&db, &pager, "0xw", 2).await;
+        assert!(result.is_err(), "Expected Err but got: {result:?}");
+    }
+
     #[tokio::test]
     async fn test_ingest_trades_stops_early_when_all_trades_already_known() {
         let db = AsyncDb::open(":memory:").await.unwrap();
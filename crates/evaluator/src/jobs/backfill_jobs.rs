@@ -0,0 +1,171 @@
+use anyhow::Result;
+use chrono::NaiveDate;
+use common::config::Config;
+use common::db::AsyncDb;
+
+use super::pipeline_jobs::score_wallets_as_of;
+use super::tracker::JobTracker;
+
+/// Last second of `date` (UTC) as a unix timestamp: the "as of" instant for that day's features.
+fn end_of_day_epoch(date: NaiveDate) -> i64 {
+    date.and_hms_opt(23, 59, 59)
+        .expect("23:59:59 is a valid time")
+        .and_utc()
+        .timestamp()
+}
+
+/// Recompute `wallet_features_daily` and `wallet_scores_daily` for every date in
+/// `from..=to` from the trades already in `trades_raw`.
+///
+/// Each date only sees trades up to the end of that day and is committed on its own, with
+/// REPLACE/upsert semantics on both daily tables. Re-running a range is therefore idempotent,
+/// and an interrupted backfill resumes by re-running from the last date logged.
+/// Returns the total number of score rows written.
+pub async fn run_wallet_backfill(
+    db: &AsyncDb,
+    cfg: &Config,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<u64> {
+    if from > to {
+        anyhow::bail!("backfill range is empty: {from} > {to}");
+    }
+    let tracker = JobTracker::start(db, "wallet_backfill").await?;
+
+    let total_days = (to - from).num_days() + 1;
+    let mut total_scores = 0_u64;
+    let mut total_features = 0_u64;
+    for (i, date) in from.iter_days().take_while(|d| *d <= to).enumerate() {
+        let (scores, features) = match score_wallets_as_of(
+            db,
+            cfg,
+            date.to_string(),
+            end_of_day_epoch(date),
+            None,
+        )
+        .await
+        {
+            Ok(counts) => counts,
+            Err(e) => {
+                tracing::error!(
+                    %date,
+                    error = %e,
+                    "backfill: date failed; re-run from this date to resume"
+                );
+                let _ = tracker.fail(&e).await;
+                return Err(e);
+            }
+        };
+        total_scores += scores;
+        total_features += features;
+        tracing::info!(
+            %date,
+            day = i + 1,
+            total_days,
+            scores,
+            features,
+            "backfill: date complete"
+        );
+    }
+
+    tracker
+        .success(Some(serde_json::json!({
+            "from": from.to_string(),
+            "to": to.to_string(),
+            "scores": total_scores,
+            "features": total_features,
+        })))
+        .await?;
+    Ok(total_scores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn seed(db: &AsyncDb, base: i64) {
+        db.call(move |conn| {
+            conn.execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, discovered_at, is_active)
+                 VALUES ('0xw', 'HOLDER', '2023-01-01 00:00:00', 1)",
+                [],
+            )?;
+            // One round-trip per day over 12 days on the same market.
+            for day in 0..12_i64 {
+                let ts = base + day * 86400;
+                conn.execute(
+                    "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp, transaction_hash)
+                     VALUES ('0xw', '0xm', 'BUY', 10.0, 0.40, ?1, ?2)",
+                    rusqlite::params![ts, format!("0xb{day}")],
+                )?;
+                conn.execute(
+                    "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp, transaction_hash)
+                     VALUES ('0xw', '0xm', 'SELL', 10.0, 0.60, ?1, ?2)",
+                    rusqlite::params![ts + 3600, format!("0xs{day}")],
+                )?;
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn test_end_of_day_epoch() {
+        let d = NaiveDate::from_ymd_opt(2023, 11, 14).unwrap();
+        assert_eq!(end_of_day_epoch(d), 1_700_006_399);
+    }
+
+    #[tokio::test]
+    async fn test_run_wallet_backfill_only_counts_trades_up_to_each_date_and_is_idempotent() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let mut cfg =
+            Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        cfg.wallet_scoring.windows_days = vec![30];
+        cfg.wallet_scoring.min_trades_for_score = 1;
+
+        // 2023-11-01 00:00:00 UTC
+        let base = 1_698_796_800;
+        seed(&db, base).await;
+
+        let from = NaiveDate::from_ymd_opt(2023, 11, 1).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 11, 3).unwrap();
+        let scores = run_wallet_backfill(&db, &cfg, from, to).await.unwrap();
+        assert_eq!(scores, 3, "one score row per date");
+
+        let counts = || {
+            db.call(|conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT feature_date, trade_count FROM wallet_features_daily
+                     WHERE proxy_wallet = '0xw' ORDER BY feature_date",
+                )?;
+                let rows = stmt
+                    .query_map([], |r| Ok((r.get::<_, String>(0)?, r.get::<_, i64>(1)?)))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+        };
+        let rows = counts().await.unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                ("2023-11-01".to_string(), 2),
+                ("2023-11-02".to_string(), 4),
+                ("2023-11-03".to_string(), 6),
+            ]
+        );
+
+        // Re-running the same range replaces rows instead of duplicating them.
+        run_wallet_backfill(&db, &cfg, from, to).await.unwrap();
+        assert_eq!(counts().await.unwrap(), rows);
+    }
+
+    #[tokio::test]
+    async fn test_run_wallet_backfill_rejects_inverted_range() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let cfg = Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        let from = NaiveDate::from_ymd_opt(2023, 11, 3).unwrap();
+        let to = NaiveDate::from_ymd_opt(2023, 11, 1).unwrap();
+        assert!(run_wallet_backfill(&db, &cfg, from, to).await.is_err());
+    }
+}
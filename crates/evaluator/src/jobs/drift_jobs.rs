@@ -0,0 +1,129 @@
+use anyhow::Result;
+use common::config::Config;
+use common::db::AsyncDb;
+
+use crate::event_bus::EventBus;
+use crate::events::PipelineEvent;
+use crate::persona_classification::PersonaConfig;
+use crate::persona_drift::reclassify_drifted_wallets;
+
+use super::tracker::JobTracker;
+
+/// Re-evaluate classified wallets whose recent features drifted from their classification snapshot.
+///
+/// Publishes `WalletsClassified` only when at least one wallet actually changed persona, with
+/// `wallets_classified` set to that count. Returns the number of wallets whose persona changed.
+pub async fn run_persona_drift_once(
+    db: &AsyncDb,
+    cfg: &Config,
+    event_bus: Option<&EventBus>,
+) -> Result<u64> {
+    let persona_config = PersonaConfig::load(&cfg.personas)?;
+    let tracker = JobTracker::start(db, "persona_drift").await?;
+    let threshold = cfg.personas.drift_threshold;
+    let classification_window_days = cfg.personas.classification_window_days;
+    let now_epoch = chrono::Utc::now().timestamp();
+
+    let stats = match db
+        .call_named("persona_drift.reclassify", move |conn| {
+            reclassify_drifted_wallets(
+                conn,
+                &persona_config,
+                threshold,
+                classification_window_days,
+                now_epoch,
+            )
+        })
+        .await
+    {
+        Ok(stats) => stats,
+        Err(e) => {
+            let _ = tracker.fail(&e).await;
+            return Err(e);
+        }
+    };
+
+    metrics::counter!("evaluator_persona_drift_reclassified_total").increment(stats.drifted);
+
+    if stats.changed > 0 {
+        if let Some(bus) = event_bus {
+            let _ = bus.publish_pipeline(PipelineEvent::WalletsClassified {
+                wallets_classified: stats.changed,
+                classified_at: chrono::Utc::now(),
+            });
+        }
+    }
+
+    tracker
+        .success(Some(serde_json::json!({
+            "checked": stats.checked,
+            "drifted": stats.drifted,
+            "changed": stats.changed,
+        })))
+        .await?;
+    Ok(stats.changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persona_drift::FeatureSnapshot;
+    use crate::wallet_features::compute_wallet_features;
+
+    fn test_config() -> Config {
+        Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap()
+    }
+
+    /// Classified wallet whose snapshot differs from its current (empty) features by
+    /// `extra_trades_per_week`.
+    async fn seed_persona(db: &AsyncDb, extra_trades_per_week: f64) {
+        db.call(move |conn| {
+            let now = chrono::Utc::now().timestamp();
+            let mut snapshot = FeatureSnapshot::from_features(&compute_wallet_features(
+                conn, "0xw", 180, now,
+            )?);
+            snapshot.trades_per_week += extra_trades_per_week;
+            conn.execute(
+                "INSERT INTO wallet_personas (proxy_wallet, persona, confidence, feature_values_json, classified_at)
+                 VALUES ('0xw', 'CONSISTENT_GENERALIST', 0.6, ?1, '2026-01-01 00:00:00.000')",
+                [serde_json::to_string(&snapshot)?],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_run_persona_drift_once_emits_event_only_when_persona_changes() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed_persona(&db, 40.0).await;
+        let bus = EventBus::new(16);
+        let mut rx = bus.subscribe_pipeline();
+
+        let changed = run_persona_drift_once(&db, &test_config(), Some(&bus))
+            .await
+            .unwrap();
+        assert_eq!(changed, 1);
+        match rx.try_recv().unwrap() {
+            PipelineEvent::WalletsClassified {
+                wallets_classified, ..
+            } => assert_eq!(wallets_classified, 1),
+            other => panic!("expected WalletsClassified, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_persona_drift_once_no_drift_no_event() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed_persona(&db, 0.0).await;
+        let bus = EventBus::new(16);
+        let mut rx = bus.subscribe_pipeline();
+
+        let changed = run_persona_drift_once(&db, &test_config(), Some(&bus))
+            .await
+            .unwrap();
+        assert_eq!(changed, 0);
+        assert!(rx.try_recv().is_err());
+    }
+}
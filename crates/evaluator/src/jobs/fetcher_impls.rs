@@ -257,3 +257,28 @@ impl LeaderboardFetcher for PolymarketClient {
         }
     }
 }
+
+impl ProfileFetcher for PolymarketClient {
+    async fn fetch_profile_name(&self, address: &str) -> Result<Option<String>> {
+        let start = Instant::now();
+        let res = PolymarketClient::fetch_profile_name(self, address).await;
+        let ms = start.elapsed().as_secs_f64() * 1000.0;
+        metrics::histogram!("evaluator_api_latency_ms", "endpoint" => "public_profile").record(ms);
+        match res {
+            Ok(v) => {
+                metrics::counter!("evaluator_api_requests_total", "endpoint" => "public_profile", "status" => "ok").increment(1);
+                Ok(v)
+            }
+            Err(e) => {
+                metrics::counter!("evaluator_api_requests_total", "endpoint" => "public_profile", "status" => "error").increment(1);
+                metrics::counter!(
+                    "evaluator_api_errors_total",
+                    "endpoint" => "public_profile",
+                    "kind" => classify_anyhow_api_error(&e).as_str()
+                )
+                .increment(1);
+                Err(e)
+            }
+        }
+    }
+}
@@ -67,3 +67,11 @@ pub trait LeaderboardFetcher {
         offset: u32,
     ) -> impl std::future::Future<Output = Result<Vec<ApiLeaderboardEntry>>> + Send;
 }
+
+pub trait ProfileFetcher {
+    /// Gamma display name for `address` (`Ok(None)` when no profile name is set).
+    fn fetch_profile_name(
+        &self,
+        address: &str,
+    ) -> impl std::future::Future<Output = Result<Option<String>>> + Send;
+}
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use common::polymarket::{classify_anyhow_api_error, ApiErrorKind};
+use rusqlite::Connection;
+
+/// `wallet_exclusions.reason` for wallets deactivated after repeated ingestion failures.
+pub const INGESTION_FAILED_REASON: &str = "INGESTION_FAILED";
+
+/// Whether a failed fetch says something about the wallet itself (400, 404, malformed data).
+/// Anything else — an unhealthy API, or a local SQLite error such as `database is locked` —
+/// neither counts nor resets the streak.
+pub fn is_wallet_specific_failure(err: &anyhow::Error) -> bool {
+    matches!(
+        classify_anyhow_api_error(err),
+        ApiErrorKind::BadRequest | ApiErrorKind::NotFound | ApiErrorKind::Decode
+    )
+}
+
+/// Clear a wallet's failure streak after a successful fetch.
+pub fn reset_ingestion_failures(conn: &Connection, proxy_wallet: &str) -> Result<()> {
+    conn.execute(
+        "DELETE FROM wallet_ingestion_failures WHERE proxy_wallet = ?1",
+        [proxy_wallet],
+    )?;
+    Ok(())
+}
+
+/// Count another consecutive failure for `proxy_wallet`.
+///
+/// Once the streak reaches `max_failures` (0 = never) the wallet is set `is_active = 0` and an
+/// `INGESTION_FAILED` exclusion is recorded so it shows on the excluded page.
+/// Returns true when this call deactivated the wallet.
+pub fn record_ingestion_failure(
+    conn: &Connection,
+    proxy_wallet: &str,
+    error: &str,
+    max_failures: u32,
+) -> Result<bool> {
+    let failures: u32 = conn.query_row(
+        "INSERT INTO wallet_ingestion_failures (proxy_wallet, consecutive_failures, last_error, last_failed_at)
+         VALUES (?1, 1, ?2, datetime('now'))
+         ON CONFLICT(proxy_wallet) DO UPDATE SET
+            consecutive_failures = consecutive_failures + 1,
+            last_error = excluded.last_error,
+            last_failed_at = excluded.last_failed_at
+         RETURNING consecutive_failures",
+        rusqlite::params![proxy_wallet, error],
+        |row| row.get(0),
+    )?;
+    if max_failures == 0 || failures < max_failures {
+        return Ok(false);
+    }
+
+    let deactivated = conn.execute(
+        "UPDATE wallets SET is_active = 0 WHERE proxy_wallet = ?1 AND is_active = 1",
+        [proxy_wallet],
+    )?;
+    conn.execute(
+        "INSERT OR REPLACE INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold)
+         VALUES (?1, ?2, ?3, ?4)",
+        rusqlite::params![
+            proxy_wallet,
+            INGESTION_FAILED_REASON,
+            f64::from(failures),
+            f64::from(max_failures)
+        ],
+    )?;
+    Ok(deactivated > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::db::Database;
+
+    fn setup() -> Database {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES ('0xw', 'HOLDER', 1)",
+                [],
+            )
+            .unwrap();
+        db
+    }
+
+    fn is_active(db: &Database) -> i64 {
+        db.conn
+            .query_row(
+                "SELECT is_active FROM wallets WHERE proxy_wallet = '0xw'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap()
+    }
+
+    #[test]
+    fn test_record_ingestion_failure_deactivates_after_threshold() {
+        let db = setup();
+        assert!(!record_ingestion_failure(&db.conn, "0xw", "404", 3).unwrap());
+        assert!(!record_ingestion_failure(&db.conn, "0xw", "404", 3).unwrap());
+        assert_eq!(is_active(&db), 1);
+        assert!(record_ingestion_failure(&db.conn, "0xw", "404", 3).unwrap());
+        assert_eq!(is_active(&db), 0);
+
+        let (reason, metric): (String, f64) = db
+            .conn
+            .query_row(
+                "SELECT reason, metric_value FROM wallet_exclusions WHERE proxy_wallet = '0xw'",
+                [],
+                |r| Ok((r.get(0)?, r.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(reason, INGESTION_FAILED_REASON);
+        assert!((metric - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reset_ingestion_failures_restarts_streak() {
+        let db = setup();
+        record_ingestion_failure(&db.conn, "0xw", "decode", 2).unwrap();
+        reset_ingestion_failures(&db.conn, "0xw").unwrap();
+        assert!(!record_ingestion_failure(&db.conn, "0xw", "decode", 2).unwrap());
+        assert_eq!(is_active(&db), 1);
+    }
+
+    #[test]
+    fn test_record_ingestion_failure_zero_threshold_never_deactivates() {
+        let db = setup();
+        for _ in 0..20 {
+            assert!(!record_ingestion_failure(&db.conn, "0xw", "404", 0).unwrap());
+        }
+        assert_eq!(is_active(&db), 1);
+    }
+
+    fn http_error(status: u16) -> anyhow::Error {
+        anyhow::Error::new(common::polymarket::HttpStatusError {
+            status: reqwest::StatusCode::from_u16(status).unwrap(),
+            url: "https://data-api.polymarket.com/trades?user=0xw"
+                .parse()
+                .unwrap(),
+        })
+    }
+
+    #[test]
+    fn test_is_wallet_specific_failure_counts_client_errors() {
+        assert!(is_wallet_specific_failure(&http_error(404)));
+        assert!(is_wallet_specific_failure(&http_error(400)));
+        let decode = serde_json::from_str::<Vec<u32>>("{not json").unwrap_err();
+        assert!(is_wallet_specific_failure(&anyhow::Error::from(decode)));
+    }
+
+    #[test]
+    fn test_is_wallet_specific_failure_ignores_circuit_open() {
+        let err = anyhow::Error::new(common::circuit_breaker::CircuitOpenError {
+            retry_in: std::time::Duration::from_secs(5),
+        });
+        assert!(!is_wallet_specific_failure(&err));
+        assert!(!is_wallet_specific_failure(&http_error(503)));
+    }
+
+    #[test]
+    fn test_is_wallet_specific_failure_ignores_non_http_errors() {
+        let busy = rusqlite::Error::SqliteFailure(
+            rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+            Some("database is locked".to_string()),
+        );
+        assert!(!is_wallet_specific_failure(&anyhow::Error::from(busy)));
+        assert!(!is_wallet_specific_failure(&anyhow::anyhow!(
+            "database is locked"
+        )));
+    }
+}
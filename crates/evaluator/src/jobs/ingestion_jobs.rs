@@ -2,17 +2,130 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use common::db::AsyncDb;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 
 use super::fetcher_traits::*;
+use super::ingestion_failures::{
+    is_wallet_specific_failure, record_ingestion_failure, reset_ingestion_failures,
+};
 use crate::event_bus::EventBus;
 use crate::events::PipelineEvent;
 
+/// Concurrency bounds for the wallet ingestion jobs: `per_job` wallets in flight within one job
+/// (`ingestion.parallel_tasks`) and a `global` semaphore shared by all ingestion jobs
+/// (`ingestion.max_in_flight`) so overlapping runs don't add up.
+#[derive(Clone)]
+pub struct IngestionConcurrency {
+    pub per_job: usize,
+    pub global: Arc<Semaphore>,
+}
+
+impl IngestionConcurrency {
+    pub fn new(per_job: usize, max_in_flight: usize) -> Self {
+        Self {
+            per_job,
+            global: Arc::new(Semaphore::new(max_in_flight)),
+        }
+    }
+}
+
+/// Slot in the cross-job ingestion limit (`ingestion.max_in_flight`), counted in
+/// `evaluator_ingestion_global_in_flight` until dropped.
+struct GlobalPermit {
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for GlobalPermit {
+    fn drop(&mut self) {
+        metrics::gauge!("evaluator_ingestion_global_in_flight").decrement(1.0);
+    }
+}
+
+/// Wait for a slot in the semaphore shared by all ingestion jobs. The semaphore is never
+/// closed; if it were, work would proceed unbounded rather than stall.
+async fn acquire_global_permit(global: &Arc<Semaphore>) -> GlobalPermit {
+    let permit = global.clone().acquire_owned().await.ok();
+    metrics::gauge!("evaluator_ingestion_global_in_flight").increment(1.0);
+    GlobalPermit { _permit: permit }
+}
+
+/// Fetch and store one wallet's trades, tracking its ingestion failure streak.
+/// Returns (pages, inserted); failures are logged and count as zero.
+async fn ingest_wallet_trades<P: crate::ingestion::TradesPager + Sync>(
+    db: &AsyncDb,
+    pager: &P,
+    w: &str,
+    limit: u32,
+    max_wallet_failures: u32,
+    event_bus: Option<&EventBus>,
+) -> (u64, u64) {
+    match crate::ingestion::ingest_trades_for_wallet(db, pager, w, limit).await {
+        Ok((p, ins)) => {
+            let wallet = w.to_string();
+            if let Err(e) = db
+                .call_named("run_trades_ingestion.reset_failures", move |conn| {
+                    reset_ingestion_failures(conn, &wallet)
+                })
+                .await
+            {
+                tracing::warn!(
+                    wallet = %w,
+                    error = %e,
+                    "failed to reset ingestion failures"
+                );
+            }
+            if let Some(bus) = event_bus {
+                let _ = bus.publish_pipeline(PipelineEvent::TradesIngested {
+                    wallet_address: w.to_string(),
+                    trades_count: ins,
+                    ingested_at: chrono::Utc::now(),
+                });
+            }
+            (p, ins)
+        }
+        Err(e) => {
+            tracing::warn!(
+                wallet = %w,
+                error = %e,
+                "trades ingestion failed for wallet; continuing to next"
+            );
+            if is_wallet_specific_failure(&e) {
+                let wallet = w.to_string();
+                let error = format!("{e:#}");
+                match db
+                    .call_named("run_trades_ingestion.record_failure", move |conn| {
+                        record_ingestion_failure(conn, &wallet, &error, max_wallet_failures)
+                    })
+                    .await
+                {
+                    Ok(true) => {
+                        metrics::counter!("evaluator_wallets_ingestion_failed_total").increment(1);
+                        tracing::warn!(
+                            wallet = %w,
+                            max_wallet_failures,
+                            "wallet deactivated after repeated ingestion failures"
+                        );
+                    }
+                    Ok(false) => {}
+                    Err(e) => tracing::warn!(
+                        wallet = %w,
+                        error = %e,
+                        "failed to record ingestion failure"
+                    ),
+                }
+            }
+            (0, 0)
+        }
+    }
+}
+
 pub async fn run_trades_ingestion_once<P: crate::ingestion::TradesPager + Send + Sync + 'static>(
     db: &AsyncDb,
     pager: Arc<P>,
     limit: u32,
     wallets_limit: u32,
-    parallel_tasks: usize,
+    concurrency: &IngestionConcurrency,
+    max_wallet_failures: u32,
     event_bus: Option<Arc<EventBus>>,
 ) -> Result<(u64, u64)> {
     // Backfill first: wallets with 0 trades (so persona can evaluate them), then wallets that
@@ -43,67 +156,60 @@ pub async fn run_trades_ingestion_once<P: crate::ingestion::TradesPager + Send +
     if total > 0 {
         tracing::info!(
             wallets = total,
-            parallel_tasks,
+            parallel_tasks = concurrency.per_job,
             first = %wallets[0],
             "trades_ingestion: processing wallets"
         );
     }
 
-    // Split wallets into N chunks, spawn N concurrent tasks
-    let num_tasks = parallel_tasks.max(1).min(total.max(1));
-    let chunk_size = total.div_ceil(num_tasks.max(1));
-    let mut handles = Vec::new();
+    // One task per wallet, with at most `concurrency.per_job` in flight.
+    // The shared global limit applies on top of that.
+    // API pacing stays with the client's rate limiter.
+    // DB writes all go through the single AsyncDb thread.
+    let semaphore = Arc::new(tokio::sync::Semaphore::new(concurrency.per_job.max(1)));
+    let in_flight = metrics::gauge!("evaluator_ingestion_tasks_in_flight");
+    let mut tasks = tokio::task::JoinSet::new();
+    let mut pages = 0_u64;
+    let mut inserted = 0_u64;
+    let mut collect = |res: std::result::Result<(u64, u64), tokio::task::JoinError>| match res {
+        Ok((p, ins)) => {
+            pages += p;
+            inserted += ins;
+        }
+        Err(e) => tracing::error!(error = %e, "trades_ingestion: spawned task panicked"),
+    };
 
-    for chunk in wallets.chunks(chunk_size) {
-        let chunk = chunk.to_vec();
+    for w in wallets {
+        let permit = semaphore.clone().acquire_owned().await?;
         let db = db.clone();
         let pager = pager.clone();
         let event_bus = event_bus.clone();
-
-        let handle = tokio::spawn(async move {
-            let mut pages = 0_u64;
-            let mut inserted = 0_u64;
-            for w in &chunk {
-                match crate::ingestion::ingest_trades_for_wallet(&db, &*pager, w, limit).await {
-                    Ok((p, ins)) => {
-                        pages += p;
-                        inserted += ins;
-                        if let Some(ref bus) = event_bus {
-                            let _ = bus.publish_pipeline(PipelineEvent::TradesIngested {
-                                wallet_address: w.clone(),
-                                trades_count: ins,
-                                ingested_at: chrono::Utc::now(),
-                            });
-                        }
-                    }
-                    Err(e) => {
-                        tracing::warn!(
-                            wallet = %w,
-                            error = %e,
-                            "trades ingestion failed for wallet; continuing to next"
-                        );
-                    }
-                }
-            }
-            (pages, inserted)
+        let in_flight = in_flight.clone();
+        let global = concurrency.global.clone();
+        in_flight.increment(1.0);
+        tasks.spawn(async move {
+            let global_permit = acquire_global_permit(&global).await;
+            let result = ingest_wallet_trades(
+                &db,
+                &*pager,
+                &w,
+                limit,
+                max_wallet_failures,
+                event_bus.as_deref(),
+            )
+            .await;
+            in_flight.decrement(1.0);
+            drop(global_permit);
+            drop(permit);
+            result
         });
-        handles.push(handle);
-    }
-
-    // Collect results from all tasks
-    let mut pages = 0_u64;
-    let mut inserted = 0_u64;
-    for handle in handles {
-        match handle.await {
-            Ok((p, ins)) => {
-                pages += p;
-                inserted += ins;
-            }
-            Err(e) => {
-                tracing::error!(error = %e, "trades_ingestion: spawned task panicked");
-            }
+        while let Some(res) = tasks.try_join_next() {
+            collect(res);
         }
     }
+    while let Some(res) = tasks.join_next().await {
+        collect(res);
+    }
 
     metrics::counter!("evaluator_trades_ingested_total").increment(inserted);
 
@@ -132,7 +238,7 @@ pub async fn run_activity_ingestion_once<P: ActivityPager + Send + Sync + 'stati
     pager: Arc<P>,
     limit: u32,
     wallets_limit: u32,
-    parallel_tasks: usize,
+    concurrency: &IngestionConcurrency,
 ) -> Result<u64> {
     // Same as trades: wallets with recent trades first; then no trades or too old (re)download.
     let wallets: Vec<String> = db
@@ -162,7 +268,7 @@ pub async fn run_activity_ingestion_once<P: ActivityPager + Send + Sync + 'stati
         .await?;
 
     let total = wallets.len();
-    let num_tasks = parallel_tasks.max(1).min(total.max(1));
+    let num_tasks = concurrency.per_job.max(1).min(total.max(1));
     let chunk_size = total.div_ceil(num_tasks.max(1));
     let mut handles = Vec::new();
 
@@ -170,10 +276,12 @@ pub async fn run_activity_ingestion_once<P: ActivityPager + Send + Sync + 'stati
         let chunk = chunk.to_vec();
         let db = db.clone();
         let pager = pager.clone();
+        let global = concurrency.global.clone();
 
         let handle = tokio::spawn(async move {
             let mut inserted = 0_u64;
             for w in chunk {
+                let _global_permit = acquire_global_permit(&global).await;
                 let fetch_result = pager.fetch_activity_page(&w, limit, 0).await;
                 let (events, _raw) = match fetch_result {
                     Ok(v) => v,
@@ -256,7 +364,7 @@ pub async fn run_positions_snapshot_once<P: PositionsPager + Send + Sync + 'stat
     pager: Arc<P>,
     limit: u32,
     wallets_limit: u32,
-    parallel_tasks: usize,
+    concurrency: &IngestionConcurrency,
 ) -> Result<u64> {
     let wallets: Vec<String> = db
         .call_named("run_positions_snapshot.wallets_select", move |conn| {
@@ -277,7 +385,7 @@ pub async fn run_positions_snapshot_once<P: PositionsPager + Send + Sync + 'stat
         .await?;
 
     let total = wallets.len();
-    let num_tasks = parallel_tasks.max(1).min(total.max(1));
+    let num_tasks = concurrency.per_job.max(1).min(total.max(1));
     let chunk_size = total.div_ceil(num_tasks.max(1));
     let mut handles = Vec::new();
 
@@ -285,10 +393,12 @@ pub async fn run_positions_snapshot_once<P: PositionsPager + Send + Sync + 'stat
         let chunk = chunk.to_vec();
         let db = db.clone();
         let pager = pager.clone();
+        let global = concurrency.global.clone();
 
         let handle = tokio::spawn(async move {
             let mut inserted = 0_u64;
             for w in chunk {
+                let _global_permit = acquire_global_permit(&global).await;
                 let fetch_result = pager.fetch_positions_page(&w, limit, 0).await;
                 let (positions, _raw) = match fetch_result {
                     Ok(v) => v,
@@ -369,31 +479,61 @@ pub async fn run_positions_snapshot_once<P: PositionsPager + Send + Sync + 'stat
     Ok(inserted)
 }
 
+/// Snapshot top holders of the `top_markets` best-ranked markets, then diff each market against
+/// its previous snapshot and record wallets whose holding moved by at least `flow_min_change_pct`
+/// percent. Markets without a score in the last `max_score_age_days` days are skipped, so a
+/// stalled market-scoring job doesn't keep spending API budget on stale picks.
 pub async fn run_holders_snapshot_once<H: HoldersFetcher + Sync>(
     db: &AsyncDb,
     holders: &H,
     per_market: u32,
+    top_markets: u32,
+    max_score_age_days: u32,
+    flow_min_change_pct: f64,
+    global: &Arc<Semaphore>,
 ) -> Result<u64> {
     let markets: Vec<String> = db
-        .call_named("run_holders_snapshot.markets_select", |conn| {
+        .call_named("run_holders_snapshot.markets_select", move |conn| {
             let mut stmt = conn.prepare(
                 "
                 SELECT condition_id
                 FROM market_scores
                 WHERE score_date = (SELECT MAX(score_date) FROM market_scores)
+                  AND score_date >= date('now', '-' || ?2 || ' days')
                 ORDER BY rank ASC
-                LIMIT 20
+                LIMIT ?1
                 ",
             )?;
             let rows = stmt
-                .query_map([], |row| row.get::<_, String>(0))?
+                .query_map(rusqlite::params![top_markets, max_score_age_days], |row| {
+                    row.get::<_, String>(0)
+                })?
                 .collect::<std::result::Result<Vec<_>, _>>()?;
             Ok(rows)
         })
         .await?;
+    if markets.is_empty() {
+        tracing::warn!(
+            max_score_age_days,
+            "holders snapshot: no market scored recently; skipping run"
+        );
+        return Ok(0);
+    }
+    tracing::info!(
+        markets = markets.len(),
+        top_markets,
+        "holders snapshot: snapshotting top-scored markets"
+    );
+
+    let per_market = crate::wallet_discovery::clamp_discovery_limit(
+        "holders_per_market",
+        per_market as usize,
+        crate::wallet_discovery::HOLDERS_PER_MARKET_MAX,
+    );
 
     let mut inserted = 0_u64;
     for condition_id in markets {
+        let _global_permit = acquire_global_permit(global).await;
         let fetch_result = holders.fetch_holders(&condition_id, per_market).await;
         let (holder_resp, _raw_h) = match fetch_result {
             Ok(v) => v,
@@ -411,6 +551,9 @@ pub async fn run_holders_snapshot_once<H: HoldersFetcher + Sync>(
         let page_inserted = db
             .call_named("run_holders_snapshot.insert_page", move |conn| {
                 let tx = conn.transaction()?;
+                // One timestamp per market page so the flow diff sees a single snapshot.
+                let snapshot_at: String =
+                    tx.query_row("SELECT datetime('now')", [], |row| row.get(0))?;
 
                 let mut ins = 0_u64;
                 for r in holder_resp {
@@ -425,9 +568,9 @@ pub async fn run_holders_snapshot_once<H: HoldersFetcher + Sync>(
                         let changed = tx.execute(
                             "
                             INSERT INTO holders_snapshots
-                                (condition_id, token, proxy_wallet, amount, outcome_index, pseudonym)
+                                (condition_id, token, proxy_wallet, amount, outcome_index, pseudonym, snapshot_at)
                             VALUES
-                                (?1, ?2, ?3, ?4, ?5, ?6)
+                                (?1, ?2, ?3, ?4, ?5, ?6, ?7)
                             ",
                             rusqlite::params![
                                 cid,
@@ -435,7 +578,8 @@ pub async fn run_holders_snapshot_once<H: HoldersFetcher + Sync>(
                                 proxy_wallet,
                                 amount,
                                 h.outcome_index,
-                                h.pseudonym
+                                h.pseudonym,
+                                snapshot_at
                             ],
                         )?;
                         ins += changed as u64;
@@ -447,6 +591,13 @@ pub async fn run_holders_snapshot_once<H: HoldersFetcher + Sync>(
             .await?;
 
         inserted += page_inserted;
+
+        let flows = db
+            .call_named("run_holders_snapshot.detect_flows", move |conn| {
+                crate::holder_flows::detect_holder_flows(conn, &condition_id, flow_min_change_pct)
+            })
+            .await?;
+        crate::holder_flows::record_holder_flows(&flows);
     }
 
     Ok(inserted)
@@ -459,6 +610,11 @@ mod tests {
     use crate::events::PipelineEvent;
     use common::types::ApiTrade;
 
+    /// `per_job` wallets in flight with no effective global cap.
+    fn limits(per_job: usize) -> IngestionConcurrency {
+        IngestionConcurrency::new(per_job, Semaphore::MAX_PERMITS)
+    }
+
     struct OnePagePager;
     impl crate::ingestion::TradesPager for OnePagePager {
         fn trades_url(&self, user: &str, limit: u32, offset: u32) -> String {
@@ -512,10 +668,71 @@ mod tests {
         .unwrap();
 
         let pager = Arc::new(OnePagePager);
-        let (_pages, inserted) = run_trades_ingestion_once(&db, pager, 100, 500, 4, None)
+        let (_pages, inserted) =
+            run_trades_ingestion_once(&db, pager, 100, 500, &limits(4), 10, None)
+                .await
+                .unwrap();
+        assert_eq!(inserted, 1);
+    }
+
+    /// Records which markets were asked for; returns no holders.
+    #[derive(Default)]
+    struct RecordingHoldersFetcher {
+        fetched: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl HoldersFetcher for RecordingHoldersFetcher {
+        fn holders_url(&self, condition_id: &str, limit: u32) -> String {
+            format!("https://data-api.polymarket.com/holders?market={condition_id}&limit={limit}")
+        }
+
+        async fn fetch_holders(
+            &self,
+            condition_id: &str,
+            _limit: u32,
+        ) -> Result<(Vec<common::types::ApiHolderResponse>, Vec<u8>)> {
+            self.fetched.lock().unwrap().push(condition_id.to_string());
+            Ok((vec![], b"[]".to_vec()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_holders_snapshot_only_top_n_recently_scored_markets() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            conn.execute_batch(
+                "INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES ('0xa', date('now'), 0.9, 1);
+                 INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES ('0xc', date('now'), 0.7, 3);
+                 INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES ('0xb', date('now'), 0.8, 2);
+                 INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES ('0xold', date('now', '-1 day'), 0.99, 1);",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        let global = Arc::new(Semaphore::new(Semaphore::MAX_PERMITS));
+
+        let fetcher = RecordingHoldersFetcher::default();
+        run_holders_snapshot_once(&db, &fetcher, 20, 2, 2, 50.0, &global)
             .await
             .unwrap();
-        assert_eq!(inserted, 1);
+        assert_eq!(*fetcher.fetched.lock().unwrap(), vec!["0xa", "0xb"]);
+
+        db.call(|conn| {
+            conn.execute(
+                "UPDATE market_scores SET score_date = date('now', '-5 days')",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        let fetcher = RecordingHoldersFetcher::default();
+        let inserted = run_holders_snapshot_once(&db, &fetcher, 20, 2, 2, 50.0, &global)
+            .await
+            .unwrap();
+        assert_eq!(inserted, 0);
+        assert!(fetcher.fetched.lock().unwrap().is_empty());
     }
 
     /// Pager that returns a unique trade per wallet (so inserts succeed for each wallet).
@@ -581,9 +798,10 @@ mod tests {
         let mut rx = bus.subscribe_pipeline();
 
         let pager = Arc::new(PerWalletPager);
-        let (_pages, inserted) = run_trades_ingestion_once(&db, pager, 100, 500, 4, Some(bus))
-            .await
-            .unwrap();
+        let (_pages, inserted) =
+            run_trades_ingestion_once(&db, pager, 100, 500, &limits(4), 10, Some(bus))
+                .await
+                .unwrap();
 
         // PerWalletPager returns 1 unique trade per wallet
         assert_eq!(inserted, 2);
@@ -627,9 +845,10 @@ mod tests {
 
         let pager = Arc::new(OnePagePager);
         // Should work fine without event_bus (backward compatible)
-        let (_pages, inserted) = run_trades_ingestion_once(&db, pager, 100, 500, 4, None)
-            .await
-            .unwrap();
+        let (_pages, inserted) =
+            run_trades_ingestion_once(&db, pager, 100, 500, &limits(4), 10, None)
+                .await
+                .unwrap();
         assert_eq!(inserted, 1);
     }
 
@@ -651,9 +870,10 @@ mod tests {
         .unwrap();
 
         let pager = Arc::new(PerWalletPager);
-        let (_pages, inserted) = run_trades_ingestion_once(&db, pager, 100, 500, 2, None)
-            .await
-            .unwrap();
+        let (_pages, inserted) =
+            run_trades_ingestion_once(&db, pager, 100, 500, &limits(2), 10, None)
+                .await
+                .unwrap();
 
         // All 5 wallets should have been processed (2 parallel tasks)
         assert_eq!(
@@ -661,4 +881,175 @@ mod tests {
             "all 5 wallets should be processed with parallel_tasks=2"
         );
     }
+
+    /// Records the peak number of concurrent page fetches.
+    #[derive(Default)]
+    struct ConcurrencyProbePager {
+        current: std::sync::atomic::AtomicUsize,
+        peak: std::sync::atomic::AtomicUsize,
+    }
+    impl crate::ingestion::TradesPager for ConcurrencyProbePager {
+        fn trades_url(&self, user: &str, limit: u32, offset: u32) -> String {
+            format!(
+                "https://data-api.polymarket.com/trades?user={user}&limit={limit}&offset={offset}"
+            )
+        }
+        async fn fetch_trades_page(
+            &self,
+            _user: &str,
+            _limit: u32,
+            _offset: u32,
+        ) -> Result<(Vec<ApiTrade>, Vec<u8>)> {
+            use std::sync::atomic::Ordering;
+            let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            Ok((vec![], b"[]".to_vec()))
+        }
+    }
+    impl ActivityPager for ConcurrencyProbePager {
+        fn activity_url(&self, user: &str, limit: u32, offset: u32) -> String {
+            format!(
+                "https://data-api.polymarket.com/activity?user={user}&limit={limit}&offset={offset}"
+            )
+        }
+        async fn fetch_activity_page(
+            &self,
+            _user: &str,
+            _limit: u32,
+            _offset: u32,
+        ) -> Result<(Vec<common::types::ApiActivity>, Vec<u8>)> {
+            use std::sync::atomic::Ordering;
+            let now = self.current.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak.fetch_max(now, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+            self.current.fetch_sub(1, Ordering::SeqCst);
+            Ok((vec![], b"[]".to_vec()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_trades_ingestion_bounds_concurrency_to_parallel_tasks() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            for i in 0..8 {
+                conn.execute(
+                    "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES (?1, 'HOLDER', 1)",
+                    rusqlite::params![format!("0xw{i}")],
+                )?;
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let pager = Arc::new(ConcurrencyProbePager::default());
+        run_trades_ingestion_once(&db, pager.clone(), 100, 500, &limits(3), 10, None)
+            .await
+            .unwrap();
+        let peak = pager.peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(peak <= 3, "peak concurrency {peak} exceeded parallel_tasks");
+        assert!(
+            peak >= 2,
+            "wallets should be fetched concurrently (peak {peak})"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_global_limit_bounds_concurrency_across_jobs() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            for i in 0..8 {
+                conn.execute(
+                    "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES (?1, 'HOLDER', 1)",
+                    rusqlite::params![format!("0xw{i}")],
+                )?;
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        // Each job alone may run 4 wallets at once; together they share 3 global slots.
+        let pager = Arc::new(ConcurrencyProbePager::default());
+        let concurrency = IngestionConcurrency::new(4, 3);
+        let (trades, activity) = tokio::join!(
+            run_trades_ingestion_once(&db, pager.clone(), 100, 500, &concurrency, 10, None),
+            run_activity_ingestion_once(&db, pager.clone(), 100, 500, &concurrency),
+        );
+        trades.unwrap();
+        activity.unwrap();
+        let peak = pager.peak.load(std::sync::atomic::Ordering::SeqCst);
+        assert!(
+            peak <= 3,
+            "peak concurrency {peak} exceeded the global limit"
+        );
+        assert_eq!(concurrency.global.available_permits(), 3);
+    }
+
+    struct MalformedPager;
+    impl crate::ingestion::TradesPager for MalformedPager {
+        fn trades_url(&self, user: &str, limit: u32, offset: u32) -> String {
+            format!(
+                "https://data-api.polymarket.com/trades?user={user}&limit={limit}&offset={offset}"
+            )
+        }
+        async fn fetch_trades_page(
+            &self,
+            _user: &str,
+            _limit: u32,
+            _offset: u32,
+        ) -> Result<(Vec<ApiTrade>, Vec<u8>)> {
+            let body = b"{malformed trades payload".to_vec();
+            let trades = serde_json::from_slice::<Vec<ApiTrade>>(&body)?;
+            Ok((trades, body))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_trades_ingestion_deactivates_wallet_after_repeated_failures() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            conn.execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES ('0xbad', 'HOLDER', 1)",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let is_active = || {
+            db.call(|conn| {
+                Ok(conn.query_row(
+                    "SELECT is_active FROM wallets WHERE proxy_wallet = '0xbad'",
+                    [],
+                    |r| r.get::<_, i64>(0),
+                )?)
+            })
+        };
+
+        let pager = Arc::new(MalformedPager);
+        run_trades_ingestion_once(&db, pager.clone(), 100, 500, &limits(1), 2, None)
+            .await
+            .unwrap();
+        assert_eq!(is_active().await.unwrap(), 1);
+        run_trades_ingestion_once(&db, pager, 100, 500, &limits(1), 2, None)
+            .await
+            .unwrap();
+        assert_eq!(is_active().await.unwrap(), 0);
+
+        let reason: String = db
+            .call(|conn| {
+                Ok(conn.query_row(
+                    "SELECT reason FROM wallet_exclusions WHERE proxy_wallet = '0xbad'",
+                    [],
+                    |r| r.get(0),
+                )?)
+            })
+            .await
+            .unwrap();
+        assert_eq!(reason, "INGESTION_FAILED");
+    }
 }
@@ -1,50 +1,96 @@
 use anyhow::Result;
 use common::db::AsyncDb;
+use std::sync::Arc;
 
 use crate::flow_metrics;
+use crate::persona_rules::PersonaRules;
 
 /// Compute flow counts from DB and record to Prometheus gauges (for Grafana flow panels).
-pub async fn run_flow_metrics_once(db: &AsyncDb) -> Result<()> {
+/// `rules` is the loaded `personas.rules_file`, so rule-defined personas count too.
+pub async fn run_flow_metrics_once(db: &AsyncDb, rules: Option<Arc<PersonaRules>>) -> Result<()> {
     let counts = db
-        .call_named("flow_metrics.compute", |conn| {
-            flow_metrics::compute_flow_counts(conn)
+        .call_named("flow_metrics.compute", move |conn| {
+            flow_metrics::compute_flow_counts(conn, rules.as_deref())
         })
         .await?;
     flow_metrics::record_flow_counts(&counts);
     Ok(())
 }
 
+/// Run `PRAGMA wal_checkpoint(<mode>)` and record the outcome. Returns (log, checkpointed) pages.
+fn checkpoint(conn: &rusqlite::Connection, mode: &'static str) -> Result<(i64, i64)> {
+    let mut stmt = conn.prepare(&format!("PRAGMA wal_checkpoint({mode})"))?;
+    let (busy, log, checkpointed) = stmt.query_row([], |row| {
+        Ok((
+            row.get::<_, i64>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, i64>(2)?,
+        ))
+    })?;
+    if busy != 0 {
+        tracing::warn!(
+            mode,
+            busy,
+            log,
+            checkpointed,
+            "WAL checkpoint: database was busy, partial checkpoint"
+        );
+        metrics::counter!("evaluator_wal_checkpoint_total", "status" => "busy", "mode" => mode)
+            .increment(1);
+    } else {
+        tracing::info!(mode, log, checkpointed, "WAL checkpoint complete");
+        metrics::counter!("evaluator_wal_checkpoint_total", "status" => "ok", "mode" => mode)
+            .increment(1);
+    }
+    metrics::gauge!("evaluator_wal_checkpoint_pages").set(checkpointed as f64);
+    Ok((log, checkpointed))
+}
+
 /// Run a WAL checkpoint to fold the WAL file back into the main database.
 ///
 /// Without periodic checkpointing, the WAL file grows unbounded (we observed
 /// 6.5 GB after 28 hours). TRUNCATE mode resets the WAL to zero bytes after
 /// checkpointing all pages.
 pub async fn run_wal_checkpoint_once(db: &AsyncDb) -> Result<(i64, i64)> {
-    db.call_named("wal_checkpoint.run", |conn| {
-        let mut stmt = conn.prepare("PRAGMA wal_checkpoint(TRUNCATE)")?;
-        let (busy, log, checkpointed) = stmt.query_row([], |row| {
-            Ok((
-                row.get::<_, i64>(0)?,
-                row.get::<_, i64>(1)?,
-                row.get::<_, i64>(2)?,
-            ))
-        })?;
-        if busy != 0 {
-            tracing::warn!(
-                busy,
-                log,
-                checkpointed,
-                "WAL checkpoint: database was busy, partial checkpoint"
-            );
-            metrics::counter!("evaluator_wal_checkpoint_total", "status" => "busy").increment(1);
-        } else {
-            tracing::info!(log, checkpointed, "WAL checkpoint complete");
-            metrics::counter!("evaluator_wal_checkpoint_total", "status" => "ok").increment(1);
-        }
-        metrics::gauge!("evaluator_wal_checkpoint_pages").set(checkpointed as f64);
-        Ok((log, checkpointed))
-    })
-    .await
+    db.call_named("wal_checkpoint.run", |conn| checkpoint(conn, "TRUNCATE"))
+        .await
+}
+
+fn wal_file_size(db_path: &str) -> u64 {
+    std::fs::metadata(format!("{db_path}-wal")).map_or(0, |m| m.len())
+}
+
+/// Checkpoint early when the `-wal` file has grown past `max_wal_bytes` (0 = disabled).
+///
+/// Heavy ingestion can grow the WAL a lot within one 5-minute timer period, and large WALs
+/// slow readers down. PASSIVE mode never waits on readers, so it cannot stall the single writer
+/// thread the way TRUNCATE can; shrinking to zero is left to the 5-minute TRUNCATE timer.
+/// `journal_size_limit` is set to `max_wal_bytes` so that once SQLite restarts the WAL after this
+/// checkpoint it truncates the file back under the limit, and the check stops firing.
+/// Always records the WAL size gauge. Returns the checkpoint result when one ran.
+pub async fn run_wal_size_check_once(
+    db: &AsyncDb,
+    db_path: &str,
+    max_wal_bytes: u64,
+) -> Result<Option<(i64, i64)>> {
+    let wal_size = wal_file_size(db_path);
+    metrics::gauge!("evaluator_db_wal_size_bytes").set(wal_size as f64);
+    if max_wal_bytes == 0 || wal_size <= max_wal_bytes {
+        return Ok(None);
+    }
+    tracing::info!(
+        wal_size,
+        max_wal_bytes,
+        "WAL over size limit, running PASSIVE checkpoint"
+    );
+    let size_limit = i64::try_from(max_wal_bytes).unwrap_or(i64::MAX);
+    let result = db
+        .call_named("wal_checkpoint.size_triggered", move |conn| {
+            conn.pragma_update(None, "journal_size_limit", size_limit)?;
+            checkpoint(conn, "PASSIVE")
+        })
+        .await?;
+    Ok(Some(result))
 }
 
 /// Collect SQLite file and page statistics and record them as Prometheus gauges.
@@ -54,9 +100,8 @@ pub async fn run_wal_checkpoint_once(db: &AsyncDb) -> Result<(i64, i64)> {
 /// the filesystem.
 pub async fn run_sqlite_stats_once(db: &AsyncDb, db_path: &str) -> Result<()> {
     // File sizes from the filesystem (cheap, no DB lock needed).
-    let db_file_size = std::fs::metadata(db_path).map(|m| m.len()).unwrap_or(0);
-    let wal_path = format!("{db_path}-wal");
-    let wal_file_size = std::fs::metadata(&wal_path).map(|m| m.len()).unwrap_or(0);
+    let db_file_size = std::fs::metadata(db_path).map_or(0, |m| m.len());
+    let wal_file_size = wal_file_size(db_path);
 
     metrics::gauge!("evaluator_db_file_size_bytes").set(db_file_size as f64);
     metrics::gauge!("evaluator_db_wal_size_bytes").set(wal_file_size as f64);
@@ -126,4 +171,46 @@ mod tests {
             "expected evaluator_db_freelist_count, got:\n{rendered}"
         );
     }
+
+    #[tokio::test]
+    async fn test_run_wal_size_check_once_checkpoints_only_above_limit() {
+        let tmp = tempfile::NamedTempFile::new().unwrap();
+        let path = tmp.path().to_str().unwrap().to_string();
+        let db = AsyncDb::open(&path).await.unwrap();
+        db.call(|conn| {
+            conn.execute_batch(
+                "INSERT INTO wallets (proxy_wallet, discovered_from) VALUES ('0xa', 'HOLDER');",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        assert!(wal_file_size(&path) > 0, "write should leave a WAL file");
+
+        assert_eq!(
+            run_wal_size_check_once(&db, &path, u64::MAX).await.unwrap(),
+            None
+        );
+        assert_eq!(run_wal_size_check_once(&db, &path, 0).await.unwrap(), None);
+        let (log, checkpointed) = run_wal_size_check_once(&db, &path, 1)
+            .await
+            .unwrap()
+            .expect("WAL above 1 byte should checkpoint");
+        assert_eq!(log, checkpointed);
+
+        // The next write restarts the checkpointed WAL and trims it to journal_size_limit.
+        let before = wal_file_size(&path);
+        db.call(|conn| {
+            conn.execute_batch(
+                "INSERT INTO wallets (proxy_wallet, discovered_from) VALUES ('0xb', 'HOLDER');",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+        assert!(
+            wal_file_size(&path) < before,
+            "WAL should shrink once restarted"
+        );
+    }
 }
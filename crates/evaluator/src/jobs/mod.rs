@@ -1,10 +1,21 @@
+mod backfill_jobs;
+mod drift_jobs;
 mod fetcher_impls;
 mod fetcher_traits;
+mod ingestion_failures;
 mod ingestion_jobs;
 mod maintenance;
 mod pipeline_jobs;
+mod profile_jobs;
+mod promotion_jobs;
+mod settlement_jobs;
 mod tracker;
 
+pub use backfill_jobs::*;
+pub use drift_jobs::*;
 pub use ingestion_jobs::*;
 pub use maintenance::*;
 pub use pipeline_jobs::*;
+pub use profile_jobs::*;
+pub use promotion_jobs::*;
+pub use settlement_jobs::*;
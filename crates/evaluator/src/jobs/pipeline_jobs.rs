@@ -2,23 +2,31 @@ use anyhow::Result;
 use common::config::Config;
 use common::db::AsyncDb;
 use common::polymarket::GammaFilter;
+use common::types::GammaMarket;
 #[cfg(test)]
-use common::types::{ApiHolderResponse, ApiLeaderboardEntry, ApiTrade, GammaMarket};
+use common::types::{ApiHolderResponse, ApiLeaderboardEntry, ApiTrade};
 
 use crate::market_scoring::{rank_events, rank_markets, MarketCandidate};
 use crate::persona_classification::{
-    classify_wallet, stage1_filter, stage1_known_bot_check, PersonaConfig, Stage1Config,
+    classify_wallet, stage1_filter, stage1_known_bot_check, stage1_wash_trading_check,
+    PersonaConfig, Stage1Config,
+};
+use crate::wallet_discovery::{
+    clamp_discovery_limit, discover_wallets_for_market, HolderWallet, TradeWallet,
+    HOLDERS_PER_MARKET_MAX,
 };
-use crate::wallet_discovery::{discover_wallets_for_market, HolderWallet, TradeWallet};
 use crate::wallet_features::{
-    compute_all_time_roi, compute_recent_pnl, compute_wallet_features, save_wallet_features,
-    WalletFeatures,
+    closed_trade_outcomes, compute_all_time_roi, compute_recent_pnl, compute_wallet_features,
+    save_wallet_features, WalletFeatures,
 };
 use crate::wallet_rules_engine::{
     evaluate_discovery, evaluate_live, evaluate_paper, read_state, record_event,
     style_snapshot_from_features, write_state, WalletRuleState,
 };
-use crate::wallet_scoring::{compute_wscore, score_input_from_features, WScoreWeights};
+use crate::wallet_scoring::{
+    compute_wscore, recency_edge_score, recency_weighted_roi_pct, score_input_from_features,
+    WScoreWeights,
+};
 
 use crate::event_bus::EventBus;
 use crate::events::PipelineEvent;
@@ -81,7 +89,7 @@ pub async fn run_wallet_rules_once(
                         WHEN (
                           SELECT MAX(classified_at)
                           FROM wallet_personas
-                          WHERE proxy_wallet = ?1
+                          WHERE proxy_wallet = ?1 AND superseded_at IS NULL
                         ) IS NULL THEN NULL
                         WHEN (
                           SELECT MAX(excluded_at)
@@ -95,7 +103,7 @@ pub async fn run_wallet_rules_once(
                         ) < (
                           SELECT MAX(classified_at)
                           FROM wallet_personas
-                          WHERE proxy_wallet = ?1
+                          WHERE proxy_wallet = ?1 AND superseded_at IS NULL
                         ) THEN 1
                         ELSE 0
                       END
@@ -200,16 +208,14 @@ pub async fn run_wallet_rules_once(
     Ok(changed)
 }
 
-pub async fn run_wallet_scoring_once(db: &AsyncDb, cfg: &Config) -> Result<u64> {
-    struct ScoreRow {
-        proxy_wallet: String,
-        window_days: i64,
-        wscore: f64,
-        edge_score: f64,
-        consistency_score: f64,
-        roi_pct: f64,
-    }
-
+/// Score active wallets for today. `wallets` restricts the run to that subset (the
+/// event-driven trigger passes the wallets whose trades were just ingested); `None`
+/// scores every active wallet.
+pub async fn run_wallet_scoring_once(
+    db: &AsyncDb,
+    cfg: &Config,
+    wallets: Option<&[String]>,
+) -> Result<u64> {
     let tracker = JobTracker::start(db, "wallet_scoring").await?;
 
     let min_trades = i64::from(cfg.wallet_scoring.min_trades_for_score);
@@ -245,6 +251,42 @@ pub async fn run_wallet_scoring_once(db: &AsyncDb, cfg: &Config) -> Result<u64>
 
     let today = chrono::Utc::now().date_naive().to_string();
     let now_epoch = chrono::Utc::now().timestamp();
+    let subset = wallets.map(<[String]>::to_vec);
+    let subset_size = subset.as_ref().map(Vec::len);
+    let (inserted, features_saved) = score_wallets_as_of(db, cfg, today, now_epoch, subset).await?;
+
+    metrics::gauge!("evaluator_wallet_scoring_features_saved").set(features_saved as f64);
+    tracker
+        .success(Some(serde_json::json!({
+            "inserted": inserted,
+            "features_saved": features_saved,
+            "subset_wallets": subset_size
+        })))
+        .await?;
+    Ok(inserted)
+}
+
+/// Compute features and WScores for active wallets as of `as_of_epoch`, writing them under
+/// `score_date`. Only trades at or before `as_of_epoch` count, and rows are upserted, so
+/// re-running a date (e.g. from the backfill CLI) replaces that date's rows.
+/// `wallets` limits scoring to those addresses (still only active ones).
+/// Returns `(scores_upserted, features_saved)`.
+pub(crate) async fn score_wallets_as_of(
+    db: &AsyncDb,
+    cfg: &Config,
+    score_date: String,
+    as_of_epoch: i64,
+    wallets: Option<Vec<String>>,
+) -> Result<(u64, u64)> {
+    struct ScoreRow {
+        proxy_wallet: String,
+        window_days: i64,
+        wscore: f64,
+        edge_score: f64,
+        consistency_score: f64,
+        recency_edge_score: f64,
+        roi_pct: f64,
+    }
 
     let w = WScoreWeights {
         edge_weight: cfg.wallet_scoring.edge_weight,
@@ -252,7 +294,9 @@ pub async fn run_wallet_scoring_once(db: &AsyncDb, cfg: &Config) -> Result<u64>
         market_skill_weight: cfg.wallet_scoring.market_skill_weight,
         timing_skill_weight: cfg.wallet_scoring.timing_skill_weight,
         behavior_quality_weight: cfg.wallet_scoring.behavior_quality_weight,
+        recency_edge_weight: cfg.wallet_scoring.recency_edge_weight,
     };
+    let recency_half_life_days = cfg.wallet_scoring.recency_half_life_days;
 
     let windows_days = cfg.wallet_scoring.windows_days.clone();
     let trust_30_90_multiplier = cfg.personas.trust_30_90_multiplier;
@@ -260,115 +304,320 @@ pub async fn run_wallet_scoring_once(db: &AsyncDb, cfg: &Config) -> Result<u64>
     let min_trades_u32 = cfg.wallet_scoring.min_trades_for_score;
 
     // Compute features, scores, and persist — all in one db.call() to avoid overhead.
-    let today_c = today.clone();
-    let (inserted, features_saved): (u64, u64) = db
-        .call_named("wallet_scoring.compute_and_upsert", move |conn| {
-            let wallets: Vec<(String, String, i64)> = conn
-                .prepare(
-                    "SELECT proxy_wallet,
-                            discovered_from,
-                            CAST((julianday('now') - julianday(discovered_at)) AS INTEGER) AS age_days
-                     FROM wallets
-                     WHERE is_active = 1
-                     ORDER BY discovered_at DESC
-                     LIMIT 500",
-                )?
-                .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
-                .collect::<std::result::Result<Vec<_>, _>>()?;
-
-            let mut score_rows = Vec::new();
-            let mut feat_count = 0_u64;
-
-            for (wallet, discovered_from, age_days) in &wallets {
-                for &wd in &windows_days {
-                    let features = match compute_wallet_features(conn, wallet, wd, now_epoch) {
-                        Ok(f) => f,
-                        Err(e) => {
-                            tracing::warn!(
-                                proxy_wallet = %wallet, window = wd,
-                                error = %e, "wallet_scoring: skipping feature computation"
-                            );
-                            continue;
-                        }
-                    };
-
-                    if features.trade_count < min_trades_u32 {
+    db.call_named("wallet_scoring.compute_and_upsert", move |conn| {
+        // A NULL subset means "all active wallets"; otherwise only the listed ones.
+        let subset_json = wallets.as_ref().map(serde_json::to_string).transpose()?;
+        let wallets: Vec<(String, String, i64)> = conn
+            .prepare(
+                "SELECT proxy_wallet,
+                        discovered_from,
+                        CAST((julianday(?1, 'unixepoch') - julianday(discovered_at)) AS INTEGER) AS age_days
+                 FROM wallets
+                 WHERE is_active = 1
+                   AND (?2 IS NULL OR proxy_wallet IN (SELECT value FROM json_each(?2)))
+                 ORDER BY discovered_at DESC
+                 LIMIT 500",
+            )?
+            .query_map(rusqlite::params![as_of_epoch, subset_json], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut score_rows = Vec::new();
+        let mut feat_count = 0_u64;
+
+        for (wallet, discovered_from, age_days) in &wallets {
+            for &wd in &windows_days {
+                let features = match compute_wallet_features(conn, wallet, wd, as_of_epoch) {
+                    Ok(f) => f,
+                    Err(e) => {
+                        tracing::warn!(
+                            proxy_wallet = %wallet, window = wd,
+                            error = %e, "wallet_scoring: skipping feature computation"
+                        );
                         continue;
                     }
+                };
 
-                    // Persist features
-                    if let Err(e) = save_wallet_features(conn, &features, &today_c) {
+                if features.trade_count < min_trades_u32 {
+                    continue;
+                }
+
+                // Persist features
+                if let Err(e) = save_wallet_features(conn, &features, &score_date) {
+                    tracing::warn!(
+                        proxy_wallet = %wallet, error = %e,
+                        "wallet_scoring: failed to save features"
+                    );
+                } else {
+                    feat_count += 1;
+                }
+
+                let recency_roi_pct = match closed_trade_outcomes(conn, wallet, wd, as_of_epoch) {
+                    Ok(outcomes) => {
+                        recency_weighted_roi_pct(&outcomes, as_of_epoch, recency_half_life_days)
+                    }
+                    Err(e) => {
                         tracing::warn!(
-                            proxy_wallet = %wallet, error = %e,
-                            "wallet_scoring: failed to save features"
+                            proxy_wallet = %wallet, window = wd,
+                            error = %e, "wallet_scoring: recency edge unavailable"
                         );
-                    } else {
-                        feat_count += 1;
+                        0.0
                     }
+                };
 
-                    let is_leaderboard = discovered_from == "LEADERBOARD";
-                    let input = score_input_from_features(
-                        &features,
-                        (*age_days).max(0) as u32,
-                        is_leaderboard,
-                    );
-                    let wscore = compute_wscore(
-                        &input, &w,
-                        trust_30_90_multiplier,
-                        obscurity_bonus_multiplier,
-                    );
-                    score_rows.push(ScoreRow {
-                        proxy_wallet: wallet.clone(),
-                        window_days: i64::from(wd),
-                        wscore,
-                        edge_score: crate::wallet_scoring::edge_score(input.roi_pct),
-                        consistency_score: crate::wallet_scoring::consistency_score(input.daily_return_stdev_pct),
-                        roi_pct: input.roi_pct,
-                    });
-                }
+                let is_leaderboard = discovered_from == "LEADERBOARD";
+                let input = score_input_from_features(
+                    &features,
+                    recency_roi_pct,
+                    (*age_days).max(0) as u32,
+                    is_leaderboard,
+                );
+                let wscore = compute_wscore(
+                    &input, &w,
+                    trust_30_90_multiplier,
+                    obscurity_bonus_multiplier,
+                );
+                score_rows.push(ScoreRow {
+                    proxy_wallet: wallet.clone(),
+                    window_days: i64::from(wd),
+                    wscore,
+                    edge_score: crate::wallet_scoring::edge_score(input.roi_pct),
+                    consistency_score: crate::wallet_scoring::consistency_score(input.daily_return_stdev_pct),
+                    recency_edge_score: recency_edge_score(input.recency_weighted_roi_pct),
+                    roi_pct: input.roi_pct,
+                });
             }
+        }
 
-            // Upsert scores in a transaction
-            let tx = conn.transaction()?;
-            let mut ins = 0_u64;
-            for r in &score_rows {
-                tx.execute(
-                    "INSERT INTO wallet_scores_daily
-                        (proxy_wallet, score_date, window_days, wscore, edge_score,
-                         consistency_score, paper_roi_pct, recommended_follow_mode)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)
-                     ON CONFLICT(proxy_wallet, score_date, window_days) DO UPDATE SET
-                        wscore = excluded.wscore,
-                        edge_score = excluded.edge_score,
-                        consistency_score = excluded.consistency_score,
-                        paper_roi_pct = excluded.paper_roi_pct,
-                        recommended_follow_mode = excluded.recommended_follow_mode",
-                    rusqlite::params![
-                        r.proxy_wallet,
-                        today_c,
-                        r.window_days,
-                        r.wscore,
-                        r.edge_score,
-                        r.consistency_score,
-                        r.roi_pct,
-                        "mirror"
-                    ],
-                )?;
-                ins += 1;
+        // Upsert scores in a transaction
+        let tx = conn.transaction()?;
+        let mut ins = 0_u64;
+        for r in &score_rows {
+            tx.execute(
+                "INSERT INTO wallet_scores_daily
+                    (proxy_wallet, score_date, window_days, wscore, edge_score,
+                     consistency_score, recency_edge_score, paper_roi_pct, recommended_follow_mode)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 ON CONFLICT(proxy_wallet, score_date, window_days) DO UPDATE SET
+                    wscore = excluded.wscore,
+                    edge_score = excluded.edge_score,
+                    consistency_score = excluded.consistency_score,
+                    recency_edge_score = excluded.recency_edge_score,
+                    paper_roi_pct = excluded.paper_roi_pct,
+                    recommended_follow_mode = excluded.recommended_follow_mode",
+                rusqlite::params![
+                    r.proxy_wallet,
+                    score_date,
+                    r.window_days,
+                    r.wscore,
+                    r.edge_score,
+                    r.consistency_score,
+                    r.recency_edge_score,
+                    r.roi_pct,
+                    "mirror"
+                ],
+            )?;
+            ins += 1;
+        }
+        tx.commit()?;
+        Ok((ins, feat_count))
+    })
+    .await
+}
+
+/// Market fields upserted into `markets` alongside scoring (not carried in `MarketCandidate`).
+#[derive(Clone)]
+struct MarketDbRow {
+    condition_id: String,
+    title: String,
+    slug: Option<String>,
+    description: Option<String>,
+    end_date: Option<String>,
+    liquidity: f64,
+    volume: f64,
+    category: Option<String>,
+    event_slug: Option<String>,
+}
+
+/// Markets dropped by the `[market_scoring]` filters before ranking, per reason.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct CandidateFilterCounts {
+    below_liquidity: u64,
+    below_volume: u64,
+    outside_expiry: u64,
+}
+
+impl CandidateFilterCounts {
+    fn add(&mut self, other: Self) {
+        self.below_liquidity += other.below_liquidity;
+        self.below_volume += other.below_volume;
+        self.outside_expiry += other.outside_expiry;
+    }
+}
+
+/// Turn one page of Gamma markets into scoring candidates: apply the config filters, upsert the
+/// markets table, and fill density / whale inputs from the local DB.
+async fn build_scoring_candidates(
+    db: &AsyncDb,
+    markets: Vec<GammaMarket>,
+    cfg: &Config,
+) -> Result<(Vec<MarketCandidate>, CandidateFilterCounts)> {
+    let mut page_candidates: Vec<MarketCandidate> = Vec::new();
+    let mut page_db_rows: Vec<MarketDbRow> = Vec::new();
+    let mut filtered = CandidateFilterCounts::default();
+
+    for m in markets {
+        let Some(condition_id) = m.condition_id.clone() else {
+            continue;
+        };
+        let title = m
+            .question
+            .clone()
+            .or_else(|| m.title.clone())
+            .unwrap_or_default();
+        if title.is_empty() {
+            continue;
+        }
+        let liquidity = m
+            .liquidity
+            .as_deref()
+            .and_then(|s| s.parse::<f64>().ok())
+            .unwrap_or(0.0);
+        let volume_24h = m
+            .volume_24hr
+            .as_deref()
+            .and_then(|s| s.parse::<f64>().ok())
+            .or_else(|| m.volume.as_deref().and_then(|s| s.parse::<f64>().ok()))
+            .unwrap_or(0.0);
+
+        // Filled from local DB (trades_raw + holders_snapshots) after we upsert markets.
+        let trades_24h = 0;
+        let unique_traders_24h = 0;
+        let top_holder_concentration = 0.5;
+
+        let days_to_expiry = compute_days_to_expiry(m.end_date.as_deref()).unwrap_or(0);
+
+        // The Gamma filter already asks for liquidity_num_min, but not every market reports
+        // liquidityNum, so enforce the floor here too: illiquid markets never reach market_scores.
+        if liquidity < cfg.market_scoring.min_liquidity_usdc {
+            filtered.below_liquidity += 1;
+            continue;
+        }
+        if volume_24h < cfg.market_scoring.min_daily_volume_usdc {
+            filtered.below_volume += 1;
+            continue;
+        }
+        if days_to_expiry > cfg.market_scoring.max_days_to_expiry
+            || days_to_expiry < cfg.market_scoring.min_days_to_expiry
+        {
+            filtered.outside_expiry += 1;
+            continue;
+        }
+
+        let event_slug = m.effective_event_slug();
+        page_db_rows.push(MarketDbRow {
+            condition_id: condition_id.clone(),
+            title: title.clone(),
+            slug: m.slug.clone(),
+            description: m.description.clone(),
+            end_date: m.end_date.clone(),
+            liquidity,
+            volume: volume_24h,
+            category: m.category.clone(),
+            event_slug: event_slug.clone(),
+        });
+
+        page_candidates.push(MarketCandidate {
+            condition_id,
+            title,
+            event_slug,
+            liquidity,
+            volume_24h,
+            trades_24h,
+            unique_traders_24h,
+            top_holder_concentration,
+            days_to_expiry,
+        });
+    }
+
+    // Upsert markets in one db.call().
+
+    db.call_named("market_scoring.upsert_markets_page", move |conn| {
+        let tx = conn.transaction()?;
+
+        for r in &page_db_rows {
+            let is_crypto_15m = is_crypto_15m_market(&r.title, r.slug.as_deref().unwrap_or(""));
+            let is_crypto_15m_i64 = i64::from(is_crypto_15m);
+            tx.execute(
+                "
+                INSERT INTO markets
+                    (condition_id, title, slug, description, end_date, liquidity, volume, category, event_slug, is_crypto_15m, last_updated_at)
+                VALUES
+                    (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, datetime('now'))
+                ON CONFLICT(condition_id) DO UPDATE SET
+                    title = excluded.title,
+                    slug = excluded.slug,
+                    description = excluded.description,
+                    end_date = excluded.end_date,
+                    liquidity = excluded.liquidity,
+                    volume = excluded.volume,
+                    category = excluded.category,
+                    event_slug = excluded.event_slug,
+                    is_crypto_15m = excluded.is_crypto_15m,
+                    last_updated_at = datetime('now')
+                ",
+                rusqlite::params![
+                    r.condition_id,
+                    r.title,
+                    r.slug,
+                    r.description,
+                    r.end_date,
+                    r.liquidity,
+                    r.volume,
+                    r.category,
+                    r.event_slug,
+                    is_crypto_15m_i64,
+                ],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    })
+    .await?;
+
+    // Populate density and whale inputs from local DB so MScore uses real signals.
+    let now_epoch = chrono::Utc::now().timestamp();
+    let condition_ids: Vec<String> = page_candidates
+        .iter()
+        .map(|c| c.condition_id.clone())
+        .collect();
+    let per_market: std::collections::HashMap<String, (u32, u32, f64)> = db
+        .call(move |conn| {
+            let mut out: std::collections::HashMap<String, (u32, u32, f64)> =
+                std::collections::HashMap::new();
+            for cid in condition_ids {
+                let trades_24h = count_trades_24h(conn, &cid, now_epoch)?;
+                let unique_traders_24h = count_unique_traders_24h(conn, &cid, now_epoch)?;
+                let top_holder_concentration = compute_whale_concentration(conn, &cid)?;
+                out.insert(
+                    cid,
+                    (trades_24h, unique_traders_24h, top_holder_concentration),
+                );
             }
-            tx.commit()?;
-            Ok((ins, feat_count))
+            Ok(out)
         })
         .await?;
 
-    metrics::gauge!("evaluator_wallet_scoring_features_saved").set(features_saved as f64);
-    tracker
-        .success(Some(serde_json::json!({
-            "inserted": inserted,
-            "features_saved": features_saved
-        })))
-        .await?;
-    Ok(inserted)
+    for c in &mut page_candidates {
+        if let Some((t, u, w)) = per_market.get(&c.condition_id) {
+            c.trades_24h = *t;
+            c.unique_traders_24h = *u;
+            c.top_holder_concentration = *w;
+        }
+    }
+
+    Ok((page_candidates, filtered))
 }
 
 pub async fn run_event_scoring_once<P: GammaMarketsPager + Sync>(
@@ -377,23 +626,10 @@ pub async fn run_event_scoring_once<P: GammaMarketsPager + Sync>(
     cfg: &Config,
     event_bus: Option<&EventBus>,
 ) -> Result<u64> {
-    // Extra fields from GammaMarket for the full DB upsert (not in MarketCandidate).
-    #[derive(Clone)]
-    struct MarketDbRow {
-        condition_id: String,
-        title: String,
-        slug: Option<String>,
-        description: Option<String>,
-        end_date: Option<String>,
-        liquidity: f64,
-        volume: f64,
-        category: Option<String>,
-        event_slug: Option<String>,
-    }
-
     let mut offset = 0_u32;
     let limit = 100_u32;
     let mut all: Vec<MarketCandidate> = Vec::new();
+    let mut filtered = CandidateFilterCounts::default();
 
     // Build server-side filter from config to avoid fetching thousands of dead markets.
     let tomorrow = (chrono::Utc::now() + chrono::Duration::days(1))
@@ -413,155 +649,9 @@ pub async fn run_event_scoring_once<P: GammaMarketsPager + Sync>(
             .await?;
         let page_len = markets.len();
 
-        let mut page_candidates: Vec<MarketCandidate> = Vec::new();
-        let mut page_db_rows: Vec<MarketDbRow> = Vec::new();
-
-        for m in markets {
-            let Some(condition_id) = m.condition_id.clone() else {
-                continue;
-            };
-            let title = m
-                .question
-                .clone()
-                .or_else(|| m.title.clone())
-                .unwrap_or_default();
-            if title.is_empty() {
-                continue;
-            }
-            let liquidity = m
-                .liquidity
-                .as_deref()
-                .and_then(|s| s.parse::<f64>().ok())
-                .unwrap_or(0.0);
-            let volume_24h = m
-                .volume_24hr
-                .as_deref()
-                .and_then(|s| s.parse::<f64>().ok())
-                .or_else(|| m.volume.as_deref().and_then(|s| s.parse::<f64>().ok()))
-                .unwrap_or(0.0);
-
-            // Filled from local DB (trades_raw + holders_snapshots) after we upsert markets.
-            let trades_24h = 0;
-            let unique_traders_24h = 0;
-            let top_holder_concentration = 0.5;
-
-            let days_to_expiry = compute_days_to_expiry(m.end_date.as_deref()).unwrap_or(0);
-
-            if liquidity < cfg.market_scoring.min_liquidity_usdc {
-                continue;
-            }
-            if volume_24h < cfg.market_scoring.min_daily_volume_usdc {
-                continue;
-            }
-            if days_to_expiry > cfg.market_scoring.max_days_to_expiry
-                || days_to_expiry < cfg.market_scoring.min_days_to_expiry
-            {
-                continue;
-            }
-
-            let event_slug = m.effective_event_slug();
-            page_db_rows.push(MarketDbRow {
-                condition_id: condition_id.clone(),
-                title: title.clone(),
-                slug: m.slug.clone(),
-                description: m.description.clone(),
-                end_date: m.end_date.clone(),
-                liquidity,
-                volume: volume_24h,
-                category: m.category.clone(),
-                event_slug: event_slug.clone(),
-            });
-
-            page_candidates.push(MarketCandidate {
-                condition_id,
-                title,
-                event_slug,
-                liquidity,
-                volume_24h,
-                trades_24h,
-                unique_traders_24h,
-                top_holder_concentration,
-                days_to_expiry,
-            });
-        }
-
-        // Upsert markets in one db.call().
-
-        db.call_named("market_scoring.upsert_markets_page", move |conn| {
-            let tx = conn.transaction()?;
-
-            for r in &page_db_rows {
-                let is_crypto_15m = is_crypto_15m_market(&r.title, r.slug.as_deref().unwrap_or(""));
-                let is_crypto_15m_i64 = i64::from(is_crypto_15m);
-                tx.execute(
-                    "
-                    INSERT INTO markets
-                        (condition_id, title, slug, description, end_date, liquidity, volume, category, event_slug, is_crypto_15m, last_updated_at)
-                    VALUES
-                        (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, datetime('now'))
-                    ON CONFLICT(condition_id) DO UPDATE SET
-                        title = excluded.title,
-                        slug = excluded.slug,
-                        description = excluded.description,
-                        end_date = excluded.end_date,
-                        liquidity = excluded.liquidity,
-                        volume = excluded.volume,
-                        category = excluded.category,
-                        event_slug = excluded.event_slug,
-                        is_crypto_15m = excluded.is_crypto_15m,
-                        last_updated_at = datetime('now')
-                    ",
-                    rusqlite::params![
-                        r.condition_id,
-                        r.title,
-                        r.slug,
-                        r.description,
-                        r.end_date,
-                        r.liquidity,
-                        r.volume,
-                        r.category,
-                        r.event_slug,
-                        is_crypto_15m_i64,
-                    ],
-                )?;
-            }
-            tx.commit()?;
-            Ok(())
-        })
-        .await?;
-
-        // Populate density and whale inputs from local DB so MScore uses real signals.
-        let now_epoch = chrono::Utc::now().timestamp();
-        let condition_ids: Vec<String> = page_candidates
-            .iter()
-            .map(|c| c.condition_id.clone())
-            .collect();
-        let per_market: std::collections::HashMap<String, (u32, u32, f64)> = db
-            .call(move |conn| {
-                let mut out: std::collections::HashMap<String, (u32, u32, f64)> =
-                    std::collections::HashMap::new();
-                for cid in condition_ids {
-                    let trades_24h = count_trades_24h(conn, &cid, now_epoch)?;
-                    let unique_traders_24h = count_unique_traders_24h(conn, &cid, now_epoch)?;
-                    let top_holder_concentration = compute_whale_concentration(conn, &cid)?;
-                    out.insert(
-                        cid,
-                        (trades_24h, unique_traders_24h, top_holder_concentration),
-                    );
-                }
-                Ok(out)
-            })
-            .await?;
-
-        for c in &mut page_candidates {
-            if let Some((t, u, w)) = per_market.get(&c.condition_id) {
-                c.trades_24h = *t;
-                c.unique_traders_24h = *u;
-                c.top_holder_concentration = *w;
-            }
-        }
-
+        let (page_candidates, page_filtered) = build_scoring_candidates(db, markets, cfg).await?;
         all.extend(page_candidates);
+        filtered.add(page_filtered);
 
         offset = offset.saturating_add(limit);
         if page_len < limit as usize {
@@ -569,6 +659,21 @@ pub async fn run_event_scoring_once<P: GammaMarketsPager + Sync>(
         }
     }
 
+    metrics::counter!("evaluator_markets_filtered_total", "reason" => "liquidity")
+        .increment(filtered.below_liquidity);
+    metrics::counter!("evaluator_markets_filtered_total", "reason" => "volume")
+        .increment(filtered.below_volume);
+    metrics::counter!("evaluator_markets_filtered_total", "reason" => "expiry")
+        .increment(filtered.outside_expiry);
+    tracing::info!(
+        candidates = all.len(),
+        below_min_liquidity = filtered.below_liquidity,
+        min_liquidity_usdc = cfg.market_scoring.min_liquidity_usdc,
+        below_min_volume = filtered.below_volume,
+        outside_expiry_window = filtered.outside_expiry,
+        "market_scoring: filtered markets before ranking"
+    );
+
     let scored = rank_markets(all);
     let (total_events_evaluated, ranked) = rank_events(scored, cfg.market_scoring.top_n_events);
 
@@ -643,6 +748,113 @@ pub async fn run_event_scoring_once<P: GammaMarketsPager + Sync>(
     Ok(inserted)
 }
 
+/// Rescore only `condition_ids` (e.g. an event whose liquidity shifted intraday) and re-rank the
+/// latest `market_scores` date so they enter the funnel without waiting for the full refresh.
+///
+/// Markets failing the `[market_scoring]` filters are skipped, and rows pushed out of the top N
+/// events are dropped, as a full run would. Returns `(rescored, still_ranked)`.
+pub async fn run_market_rescore_once<P: GammaMarketsPager + Sync>(
+    db: &AsyncDb,
+    pager: &P,
+    cfg: &Config,
+    condition_ids: &[String],
+) -> Result<(u64, u64)> {
+    let filter = GammaFilter {
+        closed: Some(false),
+        condition_ids: condition_ids.to_vec(),
+        ..Default::default()
+    };
+    let mut offset = 0_u32;
+    let limit = 100_u32;
+    let mut candidates: Vec<MarketCandidate> = Vec::new();
+    loop {
+        let (markets, _raw) = pager
+            .fetch_gamma_markets_page(limit, offset, &filter)
+            .await?;
+        let page_len = markets.len();
+        let (page_candidates, _filtered) = build_scoring_candidates(db, markets, cfg).await?;
+        candidates.extend(page_candidates);
+        offset = offset.saturating_add(limit);
+        if page_len < limit as usize {
+            break;
+        }
+    }
+
+    let scores: Vec<(String, f64)> = rank_markets(candidates)
+        .into_iter()
+        .map(|sm| (sm.market.condition_id, sm.mscore))
+        .collect();
+    let rescored_ids: Vec<String> = scores.iter().map(|(cid, _)| cid.clone()).collect();
+    let top_n_events = cfg.market_scoring.top_n_events as i64;
+
+    let (rescored, still_ranked) = db
+        .call_named("market_scoring.rescore_subset", move |conn| {
+            let tx = conn.transaction()?;
+            // Slot into the ranking wallet discovery reads (latest date), not a fresh partial day.
+            let score_date: String = tx.query_row(
+                "SELECT COALESCE(MAX(score_date), date('now')) FROM market_scores",
+                [],
+                |row| row.get(0),
+            )?;
+            for (condition_id, mscore) in &scores {
+                tx.execute(
+                    "
+                    INSERT INTO market_scores (condition_id, score_date, mscore)
+                    VALUES (?1, ?2, ?3)
+                    ON CONFLICT(condition_id, score_date) DO UPDATE SET mscore = excluded.mscore
+                    ",
+                    rusqlite::params![condition_id, score_date, mscore],
+                )?;
+            }
+            // Same event ranking as `rank_events`: EScore = best MScore per event.
+            tx.execute(
+                "
+                WITH keyed AS (
+                    SELECT ms.condition_id, ms.mscore,
+                           COALESCE(NULLIF(m.event_slug, ''), ms.condition_id) AS event_key
+                    FROM market_scores ms
+                    LEFT JOIN markets m ON m.condition_id = ms.condition_id
+                    WHERE ms.score_date = ?1
+                ),
+                events AS (
+                    SELECT event_key,
+                           ROW_NUMBER() OVER (ORDER BY MAX(mscore) DESC, event_key) AS event_rank
+                    FROM keyed
+                    GROUP BY event_key
+                )
+                UPDATE market_scores
+                SET rank = (
+                    SELECT e.event_rank
+                    FROM keyed k
+                    JOIN events e ON e.event_key = k.event_key
+                    WHERE k.condition_id = market_scores.condition_id
+                )
+                WHERE score_date = ?1
+                ",
+                [&score_date],
+            )?;
+            tx.execute(
+                "DELETE FROM market_scores WHERE score_date = ?1 AND rank > ?2",
+                rusqlite::params![score_date, top_n_events],
+            )?;
+            let mut still_ranked = 0_u64;
+            for condition_id in &rescored_ids {
+                let ranked: bool = tx.query_row(
+                    "SELECT EXISTS(SELECT 1 FROM market_scores WHERE score_date = ?1 AND condition_id = ?2)",
+                    rusqlite::params![score_date, condition_id],
+                    |row| row.get(0),
+                )?;
+                still_ranked += u64::from(ranked);
+            }
+            tx.commit()?;
+            Ok((rescored_ids.len() as u64, still_ranked))
+        })
+        .await?;
+
+    metrics::counter!("evaluator_markets_scored_total").increment(rescored);
+    Ok((rescored, still_ranked))
+}
+
 pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTradesFetcher + Sync>(
     db: &AsyncDb,
     holders: &H,
@@ -713,14 +925,33 @@ pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTrades
         tracing::info!(markets = total, "wallet_discovery: processing top events");
     }
 
-    let trades_pages = cfg
-        .wallet_discovery
-        .trades_pages_per_market
-        .min(TRADES_PAGES_CAP);
+    let trades_pages = clamp_discovery_limit(
+        "trades_pages_per_market",
+        cfg.wallet_discovery.trades_pages_per_market as usize,
+        TRADES_PAGES_CAP,
+    );
+    let holders_per_market = clamp_discovery_limit(
+        "holders_per_market",
+        cfg.wallet_discovery.holders_per_market,
+        HOLDERS_PER_MARKET_MAX,
+    );
+    // 0 = no cap on new wallets per run.
+    let max_new_wallets = cfg.wallet_discovery.max_new_wallets_per_run;
+    let exclusion_cooldown_days = cfg.wallet_discovery.exclusion_cooldown_days;
 
     let mut inserted = 0_u64;
+    let mut skipped_excluded = 0_u64;
     let mut all_new_wallets = Vec::new();
     for (idx, condition_id) in markets.iter().enumerate() {
+        if max_new_wallets > 0 && inserted >= max_new_wallets {
+            tracing::warn!(
+                inserted,
+                max_new_wallets,
+                markets_remaining = total - idx,
+                "wallet_discovery: max_new_wallets_per_run reached; stopping early"
+            );
+            break;
+        }
         if (idx + 1) % 10 == 0 || idx == 0 {
             tracing::info!(
                 progress = idx + 1,
@@ -739,7 +970,7 @@ pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTrades
                 .await;
         }
         let (holder_resp, _raw_h) = holders
-            .fetch_holders(condition_id, cfg.wallet_discovery.holders_per_market as u32)
+            .fetch_holders(condition_id, holders_per_market)
             .await?;
 
         let mut market_trades: Vec<common::types::ApiTrade> = Vec::new();
@@ -787,13 +1018,26 @@ pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTrades
             .collect();
 
         let cid = condition_id.clone();
-        let (page_inserted, new_wallets): (u64, Vec<String>) = db
+        let remaining = if max_new_wallets > 0 {
+            max_new_wallets - inserted
+        } else {
+            u64::MAX
+        };
+        let (page_inserted, new_wallets, page_skipped): (u64, Vec<String>, u64) = db
             .call_named("wallet_discovery.insert_wallets_page", move |conn| {
                 let tx = conn.transaction()?;
 
                 let mut ins = 0_u64;
+                let mut skipped = 0_u64;
                 let mut newly_inserted = Vec::new();
                 for (proxy_wallet, discovered_from) in wallets_to_insert {
+                    if ins >= remaining {
+                        break;
+                    }
+                    if recently_excluded(&tx, &proxy_wallet, exclusion_cooldown_days)? {
+                        skipped += 1;
+                        continue;
+                    }
                     let changed = tx.execute(
                         "
                         INSERT OR IGNORE INTO wallets
@@ -809,11 +1053,12 @@ pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTrades
                     }
                 }
                 tx.commit()?;
-                Ok((ins, newly_inserted))
+                Ok((ins, newly_inserted, skipped))
             })
             .await?;
 
         inserted += page_inserted;
+        skipped_excluded += page_skipped;
         all_new_wallets.extend(new_wallets);
 
         // Emit WalletsDiscovered event for this market
@@ -851,6 +1096,14 @@ pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTrades
     }
 
     metrics::counter!("evaluator_wallets_discovered_total").increment(inserted);
+    metrics::counter!("evaluator_discovery_skipped_excluded_total").increment(skipped_excluded);
+    if skipped_excluded > 0 {
+        tracing::info!(
+            skipped_excluded,
+            exclusion_cooldown_days,
+            "wallet_discovery: skipped recently excluded wallets"
+        );
+    }
     let watchlist: i64 = db
         .call_named("wallet_discovery.count_active_wallets", |conn| {
             Ok(conn.query_row(
@@ -864,6 +1117,7 @@ pub async fn run_wallet_discovery_once<H: HoldersFetcher + Sync, T: MarketTrades
     tracker
         .success(Some(serde_json::json!({
             "inserted": inserted,
+            "skipped_excluded": skipped_excluded,
             "total": total,
             "completed": true
         })))
@@ -878,6 +1132,32 @@ const TRADES_PAGE_SIZE: u32 = 200;
 const TRADES_PAGES_CAP: u32 = TRADES_API_OFFSET_CAP / TRADES_PAGE_SIZE;
 const LEADERBOARD_API_OFFSET_MAX: u32 = 1000;
 
+/// True when a wallet that is not on the watchlist has a terminal exclusion newer than
+/// `cooldown_days`. Stage 1 exclusions (too young, too few trades, ...) are re-evaluated on
+/// every run, so they don't block rediscovery. `cooldown_days = 0` disables the check.
+fn recently_excluded(
+    conn: &rusqlite::Connection,
+    proxy_wallet: &str,
+    cooldown_days: u32,
+) -> Result<bool> {
+    if cooldown_days == 0 {
+        return Ok(false);
+    }
+    Ok(conn.query_row(
+        "
+        SELECT NOT EXISTS(SELECT 1 FROM wallets WHERE proxy_wallet = ?1)
+           AND EXISTS(
+               SELECT 1 FROM wallet_exclusions
+               WHERE proxy_wallet = ?1
+                 AND reason NOT LIKE 'STAGE1_%'
+                 AND excluded_at >= strftime('%Y-%m-%d %H:%M:%f', 'now', '-' || ?2 || ' days')
+           )
+        ",
+        rusqlite::params![proxy_wallet, cooldown_days],
+        |row| row.get(0),
+    )?)
+}
+
 /// Discover wallets from Polymarket leaderboard API. Inserts with discovered_from=LEADERBOARD, discovered_market=NULL.
 pub async fn run_leaderboard_discovery_once<L: super::fetcher_traits::LeaderboardFetcher + Sync>(
     db: &AsyncDb,
@@ -1225,6 +1505,22 @@ fn process_wallet_chunk(
             }
         };
 
+        // Stage 1 wash-trading gate (needs features, so it runs after the cheap checks)
+        if let Some(reason) = stage1_wash_trading_check(
+            features.wash_trade_score,
+            stage1_config.stage1_max_wash_trade_score,
+        ) {
+            crate::persona_classification::record_exclusion(conn, proxy_wallet, &reason)?;
+            tracing::info!(
+                wallet = %proxy_wallet,
+                wash_trade_score = features.wash_trade_score,
+                "persona: excluded Stage 1 (wash trading)"
+            );
+            stage1_other += 1;
+            count += 1;
+            continue;
+        }
+
         // Stage 2 classification
         match classify_wallet(conn, &features, *wallet_age_days, persona_config) {
             Ok(result) => {
@@ -1237,6 +1533,14 @@ fn process_wallet_chunk(
                         stage2_excluded += 1;
                         tracing::info!(wallet = %proxy_wallet, reason = %r.reason_str(), "persona: excluded Stage 2");
                     }
+                    crate::persona_classification::ClassificationResult::RuleFollowable(p) => {
+                        suitable += 1;
+                        tracing::info!(wallet = %proxy_wallet, persona = %p, "persona: suitable (rule)");
+                    }
+                    crate::persona_classification::ClassificationResult::RuleExcluded(r) => {
+                        stage2_excluded += 1;
+                        tracing::info!(wallet = %proxy_wallet, reason = %r, "persona: excluded Stage 2 (rule)");
+                    }
                     crate::persona_classification::ClassificationResult::Unclassified => {}
                 }
                 if !matches!(
@@ -1273,10 +1577,10 @@ pub async fn run_persona_classification_once(
     event_bus: Option<&EventBus>,
     max_wallets: Option<usize>,
 ) -> Result<u64> {
+    let persona_config = PersonaConfig::load(&cfg.personas)?;
     let tracker = JobTracker::start(db, "persona_classification").await?;
     let now_epoch = chrono::Utc::now().timestamp();
-    let window_days = 180_u32;
-    let persona_config = PersonaConfig::from_personas(&cfg.personas);
+    let window_days = cfg.personas.classification_window_days;
     let stage1_config = Stage1Config {
         min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
         min_total_trades: cfg.personas.stage1_min_total_trades,
@@ -1285,6 +1589,7 @@ pub async fn run_persona_classification_once(
         stage1_min_all_time_roi: cfg.personas.stage1_min_all_time_roi,
         stage1_require_recent_profit: cfg.personas.stage1_require_recent_profit,
         stage1_recent_profit_window_days: cfg.personas.stage1_recent_profit_window_days,
+        stage1_max_wash_trade_score: cfg.personas.stage1_max_wash_trade_score,
     };
 
     // Get total wallet count for progress tracking
@@ -1310,8 +1615,9 @@ pub async fn run_persona_classification_once(
     }
 
     // Quality gate: Check for wallets with sufficient trade history
-    // Need at least min_total_trades and 7+ days of tracking
+    // Need at least min_total_trades and min_tracking_days of tracking
     let min_trades = i64::from(stage1_config.min_total_trades);
+    let min_tracking_secs = i64::from(cfg.personas.min_tracking_days) * 86400;
     let ready_wallets: i64 = db
         .call_named("persona_classification.count_ready_wallets", move |conn| {
             Ok(conn.query_row(
@@ -1320,8 +1626,8 @@ pub async fn run_persona_classification_once(
                  WHERE w.is_active = 1
                    AND (SELECT COUNT(*) FROM trades_raw tr WHERE tr.proxy_wallet = w.proxy_wallet) >= ?1
                    AND (SELECT MIN(timestamp) FROM trades_raw tr WHERE tr.proxy_wallet = w.proxy_wallet)
-                       <= unixepoch('now', '-7 days')",
-                [min_trades],
+                       <= unixepoch('now') - ?2",
+                [min_trades, min_tracking_secs],
                 |row| row.get(0),
             )?)
         })
@@ -1337,7 +1643,7 @@ pub async fn run_persona_classification_once(
                 "skipped": "insufficient trade history",
                 "total_wallets": total_wallets,
                 "min_trades_required": stage1_config.min_total_trades,
-                "min_tracking_days": 7
+                "min_tracking_days": cfg.personas.min_tracking_days
             })))
             .await?;
         return Ok(0);
@@ -1773,6 +2079,8 @@ mod tests {
                 event_slug: None,
                 events: None,
                 neg_risk: None,
+                closed: None,
+                outcome_prices: None,
             },
             GammaMarket {
                 condition_id: Some("0x2".to_string()),
@@ -1788,6 +2096,8 @@ mod tests {
                 event_slug: None,
                 events: None,
                 neg_risk: None,
+                closed: None,
+                outcome_prices: None,
             },
         ];
 
@@ -1840,6 +2150,8 @@ mod tests {
                 event_slug: None,
                 events: None,
                 neg_risk: None,
+                closed: None,
+                outcome_prices: None,
             },
             GammaMarket {
                 condition_id: Some("0x2".to_string()),
@@ -1855,6 +2167,8 @@ mod tests {
                 event_slug: None,
                 events: None,
                 neg_risk: None,
+                closed: None,
+                outcome_prices: None,
             },
         ];
 
@@ -1877,6 +2191,126 @@ mod tests {
         assert_eq!(cnt_markets, 2);
     }
 
+    #[tokio::test]
+    async fn test_build_scoring_candidates_drops_markets_below_min_liquidity() {
+        let mut cfg =
+            Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        cfg.market_scoring.min_liquidity_usdc = 10_000.0;
+        let db = AsyncDb::open(":memory:").await.unwrap();
+
+        let end_date = (chrono::Utc::now() + chrono::Duration::days(30)).to_rfc3339();
+        let market = |cid: &str, liquidity: &str| GammaMarket {
+            condition_id: Some(cid.to_string()),
+            question: Some(cid.to_string()),
+            title: None,
+            slug: None,
+            description: None,
+            end_date: Some(end_date.clone()),
+            liquidity: Some(liquidity.to_string()),
+            volume: Some("8000".to_string()),
+            volume_24hr: Some("8000".to_string()),
+            category: None,
+            event_slug: None,
+            events: None,
+            neg_risk: None,
+            closed: None,
+            outcome_prices: None,
+        };
+        let markets = vec![market("0xthin", "2500"), market("0xdeep", "50000")];
+
+        let (candidates, filtered) = build_scoring_candidates(&db, markets.clone(), &cfg)
+            .await
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+        assert_eq!(candidates[0].condition_id, "0xdeep");
+        assert_eq!(
+            filtered,
+            CandidateFilterCounts {
+                below_liquidity: 1,
+                ..Default::default()
+            }
+        );
+
+        let pager = FakeGammaPager::new(vec![(markets, br#"[{"page":1}]"#.to_vec())]);
+        run_event_scoring_once(&db, &pager, &cfg, None)
+            .await
+            .unwrap();
+        let scored: Vec<String> = db
+            .call(|conn| {
+                let mut stmt = conn.prepare("SELECT condition_id FROM market_scores")?;
+                let rows = stmt
+                    .query_map([], |row| row.get(0))?
+                    .collect::<rusqlite::Result<Vec<String>>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+        assert_eq!(scored, vec!["0xdeep".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_run_market_rescore_once_updates_subset_and_reranks() {
+        let mut cfg =
+            Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        cfg.market_scoring.top_n_events = 2;
+        cfg.market_scoring.min_liquidity_usdc = 0.0;
+        cfg.market_scoring.min_daily_volume_usdc = 0.0;
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            conn.execute_batch(
+                "INSERT INTO market_scores (condition_id, score_date, mscore, rank)
+                 VALUES ('0xa', '2026-01-01', 0.9, 1), ('0xb', '2026-01-01', 0.1, 2);",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let end_date = (chrono::Utc::now() + chrono::Duration::days(14)).to_rfc3339();
+        let market = GammaMarket {
+            condition_id: Some("0xc".to_string()),
+            question: Some("Newly liquid".to_string()),
+            title: None,
+            slug: None,
+            description: None,
+            end_date: Some(end_date),
+            liquidity: Some("900000".to_string()),
+            volume: Some("400000".to_string()),
+            volume_24hr: Some("400000".to_string()),
+            category: None,
+            event_slug: None,
+            events: None,
+            neg_risk: None,
+            closed: None,
+            outcome_prices: None,
+        };
+        let pager = FakeGammaPager::new(vec![(vec![market], b"[]".to_vec())]);
+
+        let (rescored, still_ranked) =
+            run_market_rescore_once(&db, &pager, &cfg, &["0xc".to_string()])
+                .await
+                .unwrap();
+        assert_eq!((rescored, still_ranked), (1, 1));
+
+        let rows: Vec<(String, i64)> = db
+            .call(|conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT condition_id, rank FROM market_scores
+                     WHERE score_date = '2026-01-01' ORDER BY rank",
+                )?;
+                let rows = stmt
+                    .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+        // 0xb is pushed out of the top 2 events; the rescored market joins the existing date.
+        assert_eq!(rows.len(), 2, "{rows:?}");
+        assert!(rows.iter().any(|(cid, _)| cid == "0xc"));
+        assert!(rows.iter().all(|(cid, _)| cid != "0xb"));
+    }
+
     struct FakeHoldersFetcher {
         resp: Vec<ApiHolderResponse>,
         raw: Vec<u8>,
@@ -2380,6 +2814,174 @@ mod tests {
         assert_eq!(inserted, 6);
     }
 
+    #[tokio::test]
+    async fn test_run_wallet_discovery_stops_at_max_new_wallets_per_run() {
+        let mut cfg =
+            Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        cfg.wallet_discovery.min_total_trades = 1;
+        cfg.wallet_discovery.max_new_wallets_per_run = 3;
+
+        let db = AsyncDb::open(":memory:").await.unwrap();
+
+        db.call(|conn| {
+            conn.execute(
+                "INSERT INTO markets (condition_id, title) VALUES ('0xcond1', 'M1'), ('0xcond2', 'M2'), ('0xcond3', 'M3')",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES (?1, date('now'), 0.9, 1)",
+                rusqlite::params!["0xcond1"],
+            )?;
+            conn.execute(
+                "INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES (?1, date('now'), 0.8, 2)",
+                rusqlite::params!["0xcond2"],
+            )?;
+            conn.execute(
+                "INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES (?1, date('now'), 0.7, 2)",
+                rusqlite::params!["0xcond3"],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let mut holders_by_market: std::collections::HashMap<String, Vec<ApiHolderResponse>> =
+            std::collections::HashMap::new();
+        for cid in ["0xcond1", "0xcond2", "0xcond3"] {
+            holders_by_market.insert(
+                cid.to_string(),
+                vec![ApiHolderResponse {
+                    token: Some("0xtok".to_string()),
+                    holders: vec![common::types::ApiHolder {
+                        proxy_wallet: Some(format!("0xholder_{cid}")),
+                        amount: Some(123.0),
+                        asset: None,
+                        pseudonym: None,
+                        name: None,
+                        outcome_index: Some(0),
+                    }],
+                }],
+            );
+        }
+
+        let mut trades_by_market: std::collections::HashMap<String, Vec<ApiTrade>> =
+            std::collections::HashMap::new();
+        for cid in ["0xcond1", "0xcond2", "0xcond3"] {
+            trades_by_market.insert(
+                cid.to_string(),
+                vec![ApiTrade {
+                    proxy_wallet: Some(format!("0xtrader_{cid}")),
+                    condition_id: Some(cid.to_string()),
+                    asset: None,
+                    size: Some("1".to_string()),
+                    price: Some("0.5".to_string()),
+                    timestamp: Some(1),
+                    title: None,
+                    slug: None,
+                    outcome: None,
+                    outcome_index: None,
+                    transaction_hash: Some(format!("0xtx_{cid}")),
+                    side: None,
+                    pseudonym: None,
+                    name: None,
+                }],
+            );
+        }
+
+        let holders = PerMarketHoldersFetcher {
+            by_market: holders_by_market,
+        };
+        let trades = PerMarketTradesFetcher {
+            by_market: trades_by_market,
+        };
+
+        let inserted = run_wallet_discovery_once(&db, &holders, &trades, &cfg, None)
+            .await
+            .unwrap();
+
+        let cnt_wallets: i64 = db
+            .call(|conn| Ok(conn.query_row("SELECT COUNT(*) FROM wallets", [], |row| row.get(0))?))
+            .await
+            .unwrap();
+        // Two wallets per market: market 1 fills 2, market 2 only gets 1, market 3 is skipped.
+        assert_eq!(cnt_wallets, 3);
+        assert_eq!(inserted, 3);
+    }
+
+    #[tokio::test]
+    async fn test_run_wallet_discovery_skips_recently_excluded_wallets() {
+        let mut cfg =
+            Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        cfg.wallet_discovery.min_total_trades = 1;
+        cfg.wallet_discovery.exclusion_cooldown_days = 30;
+
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            conn.execute(
+                "INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES ('0xcond1', date('now'), 0.9, 1)",
+                [],
+            )?;
+            // Terminal and recent: skipped. Terminal but stale, or Stage 1: rediscovered.
+            conn.execute_batch(
+                "INSERT INTO wallet_exclusions (proxy_wallet, reason, excluded_at) VALUES
+                    ('0xnoise', 'NOISE_TRADER', strftime('%Y-%m-%d %H:%M:%f', 'now', '-2 days')),
+                    ('0xstale', 'NOISE_TRADER', strftime('%Y-%m-%d %H:%M:%f', 'now', '-60 days')),
+                    ('0xyoung', 'STAGE1_TOO_YOUNG', strftime('%Y-%m-%d %H:%M:%f', 'now'));",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let holder = |w: &str| common::types::ApiHolder {
+            proxy_wallet: Some(w.to_string()),
+            amount: Some(1.0),
+            asset: None,
+            pseudonym: None,
+            name: None,
+            outcome_index: Some(0),
+        };
+        let mut holders_by_market = std::collections::HashMap::new();
+        holders_by_market.insert(
+            "0xcond1".to_string(),
+            vec![ApiHolderResponse {
+                token: Some("0xtok".to_string()),
+                holders: vec![holder("0xnoise"), holder("0xstale"), holder("0xyoung")],
+            }],
+        );
+        let holders = PerMarketHoldersFetcher {
+            by_market: holders_by_market,
+        };
+        let trades = PerMarketTradesFetcher {
+            by_market: std::collections::HashMap::new(),
+        };
+
+        let inserted = run_wallet_discovery_once(&db, &holders, &trades, &cfg, None)
+            .await
+            .unwrap();
+        assert_eq!(inserted, 2);
+
+        let wallets: Vec<String> = db
+            .call(|conn| {
+                let mut stmt =
+                    conn.prepare("SELECT proxy_wallet FROM wallets ORDER BY proxy_wallet")?;
+                let rows = stmt
+                    .query_map([], |row| row.get(0))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+        assert_eq!(wallets, vec!["0xstale", "0xyoung"]);
+
+        // Cooldown 0 turns the check off.
+        cfg.wallet_discovery.exclusion_cooldown_days = 0;
+        let inserted = run_wallet_discovery_once(&db, &holders, &trades, &cfg, None)
+            .await
+            .unwrap();
+        assert_eq!(inserted, 1);
+    }
+
     #[tokio::test]
     async fn test_run_wallet_scoring_inserts_wallet_scores() {
         let cfg = Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
@@ -2410,7 +3012,7 @@ mod tests {
         .await
         .unwrap();
 
-        let inserted = run_wallet_scoring_once(&db, &cfg).await.unwrap();
+        let inserted = run_wallet_scoring_once(&db, &cfg, None).await.unwrap();
         assert!(inserted > 0);
 
         let cnt: i64 = db
@@ -2425,6 +3027,19 @@ mod tests {
             .unwrap();
         assert!(cnt > 0);
 
+        // Every round trip gained 20%, so the decayed ROI saturates the recency edge.
+        let min_recency_edge: f64 = db
+            .call(|conn| {
+                Ok(conn.query_row(
+                    "SELECT MIN(recency_edge_score) FROM wallet_scores_daily",
+                    [],
+                    |row| row.get(0),
+                )?)
+            })
+            .await
+            .unwrap();
+        assert!(min_recency_edge > 0.99, "got {min_recency_edge}");
+
         // Verify features were also persisted
         let feat_cnt: i64 = db
             .call(|conn| {
@@ -2439,6 +3054,55 @@ mod tests {
         assert!(feat_cnt > 0);
     }
 
+    #[tokio::test]
+    async fn test_run_wallet_scoring_scores_only_requested_subset() {
+        let cfg = Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let now = chrono::Utc::now().timestamp();
+
+        db.call(move |conn| {
+            for wallet in ["0xa", "0xb"] {
+                conn.execute(
+                    "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES (?1, 'HOLDER', 1)",
+                    rusqlite::params![wallet],
+                )?;
+                for i in 0..6 {
+                    let cid = format!("cond{i}");
+                    conn.execute(
+                        "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
+                         VALUES (?1, ?2, 'BUY', 25.0, 0.50, ?3)",
+                        rusqlite::params![wallet, cid, now - 86400 * (i + 2)],
+                    )?;
+                    conn.execute(
+                        "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
+                         VALUES (?1, ?2, 'SELL', 25.0, 0.60, ?3)",
+                        rusqlite::params![wallet, cid, now - 86400 * (i + 1)],
+                    )?;
+                }
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let subset = vec!["0xb".to_string()];
+        let inserted = run_wallet_scoring_once(&db, &cfg, Some(&subset))
+            .await
+            .unwrap();
+        assert!(inserted > 0);
+
+        let scored: Vec<String> = db
+            .call(|conn| {
+                Ok(conn
+                    .prepare("SELECT DISTINCT proxy_wallet FROM wallet_scores_daily")?
+                    .query_map([], |row| row.get(0))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?)
+            })
+            .await
+            .unwrap();
+        assert_eq!(scored, vec!["0xb".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_wallet_scoring_skips_when_insufficient_trades() {
         let cfg = Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
@@ -2464,7 +3128,7 @@ mod tests {
         .unwrap();
 
         // Run wallet_scoring - should skip because insufficient trades in trades_raw
-        let inserted = run_wallet_scoring_once(&db, &cfg).await.unwrap();
+        let inserted = run_wallet_scoring_once(&db, &cfg, None).await.unwrap();
         assert_eq!(
             inserted, 0,
             "should score 0 wallets when insufficient settled trades"
@@ -3281,6 +3945,8 @@ mod tests {
                 event_slug: None,
                 events: None,
                 neg_risk: None,
+                closed: None,
+                outcome_prices: None,
             },
             GammaMarket {
                 condition_id: Some("0x2".to_string()),
@@ -3296,6 +3962,8 @@ mod tests {
                 event_slug: None,
                 events: None,
                 neg_risk: None,
+                closed: None,
+                outcome_prices: None,
             },
         ];
 
@@ -3344,6 +4012,8 @@ mod tests {
             event_slug: None,
             events: None,
             neg_risk: None,
+            closed: None,
+            outcome_prices: None,
         }];
 
         let pager = FakeGammaPager::new(vec![(markets, br#"[{"page":1}]"#.to_vec())]);
@@ -0,0 +1,219 @@
+use anyhow::Result;
+use common::config::{Config, Ranking};
+use common::db::AsyncDb;
+use common::funnel::follow_worthy_sql;
+use rusqlite::Connection;
+
+use super::fetcher_traits::ProfileFetcher;
+use super::tracker::JobTracker;
+
+/// Followed / ranked wallets whose `wallet_profiles` row is missing or older than
+/// `refresh_after_secs`: follow-worthy wallets, wallets promoted to the trader and wallets with
+/// paper trades. Missing profiles come first, then the stalest, capped at `limit`.
+pub fn wallets_needing_profile(
+    conn: &Connection,
+    ranking: &Ranking,
+    refresh_after_secs: u64,
+    limit: usize,
+) -> Result<Vec<String>> {
+    let ranked = follow_worthy_sql("ws7.proxy_wallet", "", "");
+    let sql = format!(
+        "WITH candidates AS (
+           {ranked}
+           UNION SELECT proxy_wallet FROM trader_promotions
+           UNION SELECT proxy_wallet FROM paper_trades
+         )
+         SELECT c.proxy_wallet
+         FROM candidates c
+         LEFT JOIN wallet_profiles wp ON wp.proxy_wallet = c.proxy_wallet
+         WHERE wp.fetched_at IS NULL OR wp.fetched_at <= datetime('now', :stale)
+         ORDER BY wp.fetched_at IS NOT NULL, wp.fetched_at, c.proxy_wallet
+         LIMIT :limit"
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt
+        .query_map(
+            rusqlite::named_params! {
+                ":min_roi_7d_pct": ranking.min_roi_7d_pct,
+                ":min_roi_30d_pct": ranking.min_roi_30d_pct,
+                ":stale": format!("-{refresh_after_secs} seconds"),
+                ":limit": limit as i64,
+            },
+            |row| row.get(0),
+        )?
+        .collect::<std::result::Result<Vec<String>, _>>()?;
+    Ok(rows)
+}
+
+/// Resolve Gamma profile names into `wallet_profiles` (see `[wallet_profiles]`).
+///
+/// A failed lookup leaves the existing row untouched, so the web keeps showing the last known
+/// name through Gamma outages and the wallet is retried next run. Returns the number of
+/// profiles stored.
+pub async fn run_wallet_profiles_once<F: ProfileFetcher + Sync>(
+    db: &AsyncDb,
+    fetcher: &F,
+    cfg: &Config,
+) -> Result<u64> {
+    let tracker = JobTracker::start(db, "wallet_profiles").await?;
+
+    let ranking = cfg.ranking.clone();
+    let refresh_after_secs = cfg.wallet_profiles.refresh_after_secs;
+    let batch_size = cfg.wallet_profiles.batch_size;
+    let wallets = match db
+        .call_named("wallet_profiles.pending", move |conn| {
+            wallets_needing_profile(conn, &ranking, refresh_after_secs, batch_size)
+        })
+        .await
+    {
+        Ok(w) => w,
+        Err(e) => {
+            let _ = tracker.fail(&e).await;
+            return Err(e);
+        }
+    };
+
+    let mut stored = 0_u64;
+    let mut failed = 0_u64;
+    for wallet in &wallets {
+        let name = match fetcher.fetch_profile_name(wallet).await {
+            Ok(name) => name,
+            Err(e) => {
+                failed += 1;
+                tracing::warn!(proxy_wallet = %wallet, error = %e, "profile lookup failed");
+                continue;
+            }
+        };
+        let wallet = wallet.clone();
+        db.call_named("wallet_profiles.upsert", move |conn| {
+            conn.execute(
+                "INSERT INTO wallet_profiles (proxy_wallet, display_name, fetched_at)
+                 VALUES (?1, ?2, datetime('now'))
+                 ON CONFLICT(proxy_wallet) DO UPDATE SET
+                   display_name = excluded.display_name,
+                   fetched_at = excluded.fetched_at",
+                rusqlite::params![wallet, name],
+            )?;
+            Ok(())
+        })
+        .await?;
+        stored += 1;
+    }
+
+    tracker
+        .success(Some(serde_json::json!({
+            "candidates": wallets.len(),
+            "stored": stored,
+            "failed": failed,
+        })))
+        .await?;
+    Ok(stored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Known wallets resolve to their entry; any other wallet is a failed lookup.
+    struct FakeProfiles {
+        names: HashMap<&'static str, Option<&'static str>>,
+    }
+
+    impl ProfileFetcher for FakeProfiles {
+        async fn fetch_profile_name(&self, address: &str) -> Result<Option<String>> {
+            match self.names.get(address) {
+                Some(name) => Ok(name.map(String::from)),
+                None => anyhow::bail!("gamma unavailable"),
+            }
+        }
+    }
+
+    fn test_config() -> Config {
+        Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap()
+    }
+
+    async fn seed(db: &AsyncDb) {
+        db.call(|conn| {
+            for window in [7, 30] {
+                conn.execute(
+                    "INSERT INTO wallet_scores_daily
+                        (proxy_wallet, score_date, window_days, wscore, paper_roi_pct)
+                     VALUES ('0xranked', '2026-03-01', ?1, 0.9, 50.0)",
+                    [window],
+                )?;
+            }
+            conn.execute(
+                "INSERT INTO trader_promotions (proxy_wallet, follow_mode) VALUES ('0xpromoted', 'mirror')",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO wallet_profiles (proxy_wallet, display_name, fetched_at) VALUES
+                    ('0xpromoted', 'old-name', datetime('now', '-2 days')),
+                    ('0xfresh', 'fresh', datetime('now'))",
+                [],
+            )?;
+            conn.execute(
+                "INSERT INTO trader_promotions (proxy_wallet, follow_mode) VALUES ('0xfresh', 'mirror')",
+                [],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_wallets_needing_profile_missing_first_then_stale() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed(&db).await;
+        let wallets = db
+            .call(|conn| wallets_needing_profile(conn, &Ranking::default(), 86400, 10))
+            .await
+            .unwrap();
+        assert_eq!(wallets, vec!["0xranked", "0xpromoted"]);
+
+        let wallets = db
+            .call(|conn| wallets_needing_profile(conn, &Ranking::default(), 86400, 1))
+            .await
+            .unwrap();
+        assert_eq!(wallets, vec!["0xranked"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_wallet_profiles_once_keeps_last_name_on_failure() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed(&db).await;
+        let fetcher = FakeProfiles {
+            names: HashMap::from([("0xranked", Some("whale"))]),
+        };
+
+        assert_eq!(
+            run_wallet_profiles_once(&db, &fetcher, &test_config())
+                .await
+                .unwrap(),
+            1
+        );
+
+        let rows: Vec<(String, Option<String>)> = db
+            .call(|conn| {
+                let mut stmt = conn.prepare(
+                    "SELECT proxy_wallet, display_name FROM wallet_profiles ORDER BY proxy_wallet",
+                )?;
+                let rows = stmt
+                    .query_map([], |r| Ok((r.get(0)?, r.get(1)?)))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            rows,
+            vec![
+                ("0xfresh".to_string(), Some("fresh".to_string())),
+                ("0xpromoted".to_string(), Some("old-name".to_string())),
+                ("0xranked".to_string(), Some("whale".to_string())),
+            ]
+        );
+    }
+}
@@ -0,0 +1,317 @@
+use anyhow::Result;
+use common::config::{Config, Ranking};
+use common::db::AsyncDb;
+use common::funnel::follow_worthy_sql;
+use rusqlite::Connection;
+use std::time::Duration;
+
+use super::tracker::JobTracker;
+
+/// A follow-worthy wallet that has not been registered with the trader yet.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromotionCandidate {
+    pub proxy_wallet: String,
+    pub follow_mode: String,
+    pub wscore: f64,
+}
+
+/// Wallets among the top `top_n` follow-worthy wallets (by WScore) with no `trader_promotions`
+/// row. Already-promoted wallets still take their slot, so a run never reaches past the top N.
+pub fn pending_promotions(
+    conn: &Connection,
+    ranking: &Ranking,
+    top_n: usize,
+) -> Result<Vec<PromotionCandidate>> {
+    let top = follow_worthy_sql(
+        "ws7.proxy_wallet, COALESCE(ws7.recommended_follow_mode, 'mirror') AS follow_mode,
+         ws7.wscore",
+        "",
+        "ORDER BY ws7.wscore DESC, ws7.proxy_wallet LIMIT :limit",
+    );
+    let sql = format!(
+        "SELECT proxy_wallet, follow_mode, wscore FROM ({top}) top
+         WHERE proxy_wallet NOT IN (SELECT proxy_wallet FROM trader_promotions)
+         ORDER BY wscore DESC, proxy_wallet"
+    );
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt
+        .query_map(
+            rusqlite::named_params! {
+                ":min_roi_7d_pct": ranking.min_roi_7d_pct,
+                ":min_roi_30d_pct": ranking.min_roi_30d_pct,
+                ":limit": top_n as i64,
+            },
+            |row| {
+                Ok(PromotionCandidate {
+                    proxy_wallet: row.get(0)?,
+                    follow_mode: row.get(1)?,
+                    wscore: row.get(2)?,
+                })
+            },
+        )?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
+/// Trader's answer to a follow request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromotionOutcome {
+    Followed,
+    /// 409: the wallet was already followed (e.g. by hand from the dashboard).
+    AlreadyFollowed,
+}
+
+/// `POST {base_url}/api/wallets` for one wallet. New follows always start in paper mode;
+/// going live stays an operator decision on the trader side.
+pub async fn promote_wallet(
+    client: &reqwest::Client,
+    base_url: &str,
+    api_key: Option<&str>,
+    candidate: &PromotionCandidate,
+) -> Result<PromotionOutcome> {
+    let url = format!("{}/api/wallets", base_url.trim_end_matches('/'));
+    let mut req = client.post(&url).json(&serde_json::json!({
+        "proxy_wallet": candidate.proxy_wallet,
+        "label": format!("evaluator:{}", candidate.follow_mode),
+        "follow_mode": candidate.follow_mode,
+        "trading_mode": "paper",
+    }));
+    if let Some(key) = api_key {
+        req = req.bearer_auth(key);
+    }
+    let resp = req.send().await?;
+    let status = resp.status();
+    if status == reqwest::StatusCode::CONFLICT {
+        return Ok(PromotionOutcome::AlreadyFollowed);
+    }
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        anyhow::bail!("trader POST /api/wallets returned {status}: {body}");
+    }
+    Ok(PromotionOutcome::Followed)
+}
+
+/// Register the top follow-worthy wallets with the trader microservice.
+///
+/// A wallet is recorded in `trader_promotions` only after the trader accepted it (or reported
+/// it as already followed), so failed requests are retried on the next run and successful ones
+/// are never sent twice. Returns the number of wallets recorded this run.
+pub async fn run_trader_promotion_once(db: &AsyncDb, cfg: &Config) -> Result<u64> {
+    let tp = &cfg.trader_promotion;
+    let Some(base_url) = tp.trader_base_url.clone() else {
+        anyhow::bail!("trader_promotion.trader_base_url is not set");
+    };
+    let tracker = JobTracker::start(db, "trader_promotion").await?;
+
+    let ranking = cfg.ranking.clone();
+    let top_n = tp.top_n;
+    let candidates = match db
+        .call_named("trader_promotion.pending", move |conn| {
+            pending_promotions(conn, &ranking, top_n)
+        })
+        .await
+    {
+        Ok(c) => c,
+        Err(e) => {
+            let _ = tracker.fail(&e).await;
+            return Err(e);
+        }
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()?;
+    let mut promoted = 0_u64;
+    let mut failed = 0_u64;
+    for candidate in candidates.iter().cloned() {
+        let outcome = match promote_wallet(
+            &client,
+            &base_url,
+            tp.trader_api_key.as_deref(),
+            &candidate,
+        )
+        .await
+        {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                failed += 1;
+                metrics::counter!("evaluator_trader_promotions_total", "outcome" => "failed")
+                    .increment(1);
+                tracing::warn!(proxy_wallet = %candidate.proxy_wallet, error = %e, "trader promotion failed");
+                continue;
+            }
+        };
+        let label = match outcome {
+            PromotionOutcome::Followed => "followed",
+            PromotionOutcome::AlreadyFollowed => "already_followed",
+        };
+        metrics::counter!("evaluator_trader_promotions_total", "outcome" => label).increment(1);
+
+        db.call_named("trader_promotion.record", move |conn| {
+            conn.execute(
+                "INSERT OR IGNORE INTO trader_promotions (proxy_wallet, follow_mode, wscore)
+                 VALUES (?1, ?2, ?3)",
+                rusqlite::params![
+                    candidate.proxy_wallet,
+                    candidate.follow_mode,
+                    candidate.wscore
+                ],
+            )?;
+            Ok(())
+        })
+        .await?;
+        promoted += 1;
+    }
+
+    tracker
+        .success(Some(serde_json::json!({
+            "candidates": candidates.len(),
+            "promoted": promoted,
+            "failed": failed,
+        })))
+        .await?;
+    Ok(promoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config(base_url: &str) -> Config {
+        let mut cfg =
+            Config::from_toml_str(include_str!("../../../../config/default.toml")).unwrap();
+        cfg.trader_promotion.enabled = true;
+        cfg.trader_promotion.trader_base_url = Some(base_url.to_string());
+        cfg.trader_promotion.top_n = 2;
+        cfg
+    }
+
+    /// Minimal HTTP server answering every request with `status`, recording each raw request.
+    fn spawn_trader(status: u16) -> (String, std::sync::Arc<std::sync::Mutex<Vec<String>>>) {
+        use std::io::{Read, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let requests = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let seen = requests.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                // Read until the whole JSON body (ends with '}') has arrived.
+                let mut request = Vec::new();
+                let mut buf = [0_u8; 4096];
+                while !request.ends_with(b"}") {
+                    match stream.read(&mut buf) {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buf[..n]),
+                    }
+                }
+                seen.lock()
+                    .unwrap()
+                    .push(String::from_utf8_lossy(&request).to_string());
+                let resp = format!(
+                    "HTTP/1.1 {status} X\r\nContent-Length: 2\r\nConnection: close\r\n\r\n{{}}"
+                );
+                let _ = stream.write_all(resp.as_bytes());
+            }
+        });
+        (format!("http://{addr}"), requests)
+    }
+
+    async fn seed_scores(db: &AsyncDb) {
+        db.call(|conn| {
+            for (wallet, wscore, mode) in [
+                ("0xtop", 0.9, "delay"),
+                ("0xsecond", 0.8, "mirror"),
+                ("0xthird", 0.7, "mirror"),
+            ] {
+                for window in [7, 30] {
+                    conn.execute(
+                        "INSERT INTO wallet_scores_daily
+                            (proxy_wallet, score_date, window_days, wscore, paper_roi_pct, recommended_follow_mode)
+                         VALUES (?1, '2026-03-01', ?2, ?3, 50.0, ?4)",
+                        rusqlite::params![wallet, window, wscore, mode],
+                    )?;
+                }
+            }
+            Ok(())
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_pending_promotions_skips_promoted_but_keeps_top_n() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed_scores(&db).await;
+        let pending = db
+            .call(|conn| {
+                conn.execute(
+                    "INSERT INTO trader_promotions (proxy_wallet, follow_mode) VALUES ('0xtop', 'delay')",
+                    [],
+                )?;
+                pending_promotions(conn, &Ranking::default(), 2)
+            })
+            .await
+            .unwrap();
+        assert_eq!(
+            pending,
+            vec![PromotionCandidate {
+                proxy_wallet: "0xsecond".to_string(),
+                follow_mode: "mirror".to_string(),
+                wscore: 0.8,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_run_trader_promotion_once_promotes_each_wallet_once() {
+        let (url, requests) = spawn_trader(201);
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed_scores(&db).await;
+        let mut cfg = test_config(&url);
+        cfg.trader_promotion.trader_api_key = Some("secret".to_string());
+
+        assert_eq!(run_trader_promotion_once(&db, &cfg).await.unwrap(), 2);
+        assert_eq!(run_trader_promotion_once(&db, &cfg).await.unwrap(), 0);
+
+        let requests = requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 2, "{requests:?}");
+        assert!(requests[0].starts_with("POST /api/wallets"));
+        assert!(requests[0].contains("authorization: Bearer secret"));
+        assert!(requests[0].contains("\"follow_mode\":\"delay\""));
+    }
+
+    #[tokio::test]
+    async fn test_run_trader_promotion_once_retries_after_trader_error() {
+        let (url, _) = spawn_trader(500);
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed_scores(&db).await;
+
+        assert_eq!(
+            run_trader_promotion_once(&db, &test_config(&url))
+                .await
+                .unwrap(),
+            0
+        );
+        let recorded: i64 = db
+            .call(|conn| {
+                Ok(conn.query_row("SELECT COUNT(*) FROM trader_promotions", [], |r| r.get(0))?)
+            })
+            .await
+            .unwrap();
+        assert_eq!(recorded, 0);
+    }
+
+    #[tokio::test]
+    async fn test_run_trader_promotion_once_records_already_followed() {
+        let (url, _) = spawn_trader(409);
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        seed_scores(&db).await;
+
+        assert_eq!(
+            run_trader_promotion_once(&db, &test_config(&url))
+                .await
+                .unwrap(),
+            2
+        );
+    }
+}
@@ -0,0 +1,275 @@
+use anyhow::Result;
+use common::db::AsyncDb;
+use common::polymarket::GammaFilter;
+use common::types::PaperTradeStatus;
+use std::collections::HashMap;
+
+use super::fetcher_traits::GammaMarketsPager;
+use super::tracker::JobTracker;
+
+/// Exit price and PnL for a paper trade whose market resolved.
+///
+/// `won` means the traded outcome is the winning one. BUY pays `entry_price` per share and
+/// redeems at 1 (win) or 0 (loss); SELL is the mirror image.
+fn settle_paper_trade(side: &str, size_usdc: f64, entry_price: f64, won: bool) -> (f64, f64) {
+    let exit_price = if won { 1.0 } else { 0.0 };
+    if entry_price <= 0.0 {
+        return (exit_price, 0.0);
+    }
+    let shares = size_usdc / entry_price;
+    let pnl = if side.eq_ignore_ascii_case("SELL") {
+        shares * (entry_price - exit_price)
+    } else {
+        shares * (exit_price - entry_price)
+    };
+    (exit_price, pnl)
+}
+
+/// Settle open paper trades whose markets have resolved on Polymarket.
+///
+/// Markets are looked up in batches of `batch_size` via the Gamma `/markets` endpoint
+/// (closed=true, condition_ids=...). Only rows still `open` are updated, so re-running is a
+/// no-op for already-settled trades. Trades without an `outcome_index` are left open.
+/// Returns the number of paper trades settled.
+pub async fn run_paper_settlement_once<P: GammaMarketsPager + Sync>(
+    db: &AsyncDb,
+    pager: &P,
+    batch_size: usize,
+) -> Result<u64> {
+    let tracker = JobTracker::start(db, "paper_settlement").await?;
+
+    let open_markets: Vec<String> = db
+        .call_named("paper_settlement.open_markets", |conn| {
+            let mut stmt = conn.prepare(
+                "SELECT DISTINCT condition_id FROM paper_trades WHERE status = 'open' ORDER BY condition_id",
+            )?;
+            let rows = stmt
+                .query_map([], |row| row.get(0))?
+                .collect::<std::result::Result<Vec<_>, _>>()?;
+            Ok(rows)
+        })
+        .await?;
+
+    let batch_size = batch_size.max(1);
+    let mut resolved: HashMap<String, i64> = HashMap::new();
+    for chunk in open_markets.chunks(batch_size) {
+        let filter = GammaFilter {
+            closed: Some(true),
+            condition_ids: chunk.to_vec(),
+            ..Default::default()
+        };
+        match pager
+            .fetch_gamma_markets_page(batch_size as u32, 0, &filter)
+            .await
+        {
+            Ok((markets, _raw)) => {
+                for m in markets {
+                    if let (Some(cid), Some(idx)) =
+                        (m.condition_id.clone(), m.resolved_outcome_index())
+                    {
+                        resolved.insert(cid, idx as i64);
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, markets = chunk.len(), "paper settlement: resolution lookup failed");
+            }
+        }
+    }
+
+    let markets_resolved = resolved.len() as u64;
+    let settled: u64 = db
+        .call_named("paper_settlement.settle", move |conn| {
+            let tx = conn.transaction()?;
+            let mut settled = 0_u64;
+            for (condition_id, winning_index) in &resolved {
+                let trades: Vec<(i64, String, Option<i64>, f64, f64)> = tx
+                    .prepare(
+                        "SELECT id, side, outcome_index, size_usdc, entry_price
+                         FROM paper_trades
+                         WHERE condition_id = ?1 AND status = 'open'",
+                    )?
+                    .query_map([condition_id], |row| {
+                        Ok((
+                            row.get(0)?,
+                            row.get(1)?,
+                            row.get(2)?,
+                            row.get(3)?,
+                            row.get(4)?,
+                        ))
+                    })?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+
+                for (id, side, outcome_index, size_usdc, entry_price) in trades {
+                    let Some(outcome_index) = outcome_index else {
+                        continue;
+                    };
+                    let won = outcome_index == *winning_index;
+                    let (exit_price, pnl) = settle_paper_trade(&side, size_usdc, entry_price, won);
+                    // Status follows the resolution, not the PnL sign: a BUY entered at 1.0
+                    // that wins settles at zero PnL but is still a win. A SELL wins when the
+                    // traded outcome loses.
+                    let sold = side.eq_ignore_ascii_case("SELL");
+                    let status = if won != sold {
+                        PaperTradeStatus::SettledWin
+                    } else {
+                        PaperTradeStatus::SettledLoss
+                    };
+                    settled += tx.execute(
+                        "UPDATE paper_trades
+                         SET status = ?2, exit_price = ?3, pnl = ?4, settled_at = datetime('now')
+                         WHERE id = ?1 AND status = 'open'",
+                        rusqlite::params![id, status.as_str(), exit_price, pnl],
+                    )? as u64;
+                }
+            }
+            tx.commit()?;
+            Ok(settled)
+        })
+        .await?;
+
+    metrics::counter!("evaluator_paper_trades_settled_total").increment(settled);
+    tracker
+        .success(Some(serde_json::json!({
+            "open_markets": open_markets.len(),
+            "markets_resolved": markets_resolved,
+            "settled": settled,
+        })))
+        .await?;
+    Ok(settled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::GammaMarket;
+
+    struct FakeResolutionPager {
+        markets: Vec<GammaMarket>,
+    }
+
+    impl GammaMarketsPager for FakeResolutionPager {
+        fn gamma_markets_url(&self, limit: u32, offset: u32) -> String {
+            format!("https://gamma-api.polymarket.com/markets?limit={limit}&offset={offset}")
+        }
+
+        async fn fetch_gamma_markets_page(
+            &self,
+            _limit: u32,
+            _offset: u32,
+            filter: &GammaFilter,
+        ) -> Result<(Vec<GammaMarket>, Vec<u8>)> {
+            let markets = self
+                .markets
+                .iter()
+                .filter(|m| {
+                    m.condition_id
+                        .as_ref()
+                        .is_some_and(|c| filter.condition_ids.contains(c))
+                })
+                .cloned()
+                .collect();
+            Ok((markets, Vec::new()))
+        }
+    }
+
+    fn resolved_market(condition_id: &str, prices: &str) -> GammaMarket {
+        GammaMarket {
+            condition_id: Some(condition_id.to_string()),
+            closed: Some(true),
+            outcome_prices: Some(prices.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_settle_paper_trade_buy_and_sell() {
+        let (exit, pnl) = settle_paper_trade("BUY", 25.0, 0.50, true);
+        assert_eq!(exit, 1.0);
+        assert!((pnl - 25.0).abs() < 1e-9);
+
+        let (exit, pnl) = settle_paper_trade("BUY", 25.0, 0.50, false);
+        assert_eq!(exit, 0.0);
+        assert!((pnl + 25.0).abs() < 1e-9);
+
+        let (_, pnl) = settle_paper_trade("SELL", 25.0, 0.50, false);
+        assert!((pnl - 25.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_run_paper_settlement_once_settles_resolved_and_is_idempotent() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            conn.execute_batch(
+                "INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, outcome_index, size_usdc, entry_price, status)
+                 VALUES ('0xw1', 'mirror', '0xwin', 'BUY', 0, 25.0, 0.50, 'open');
+                 INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, outcome_index, size_usdc, entry_price, status)
+                 VALUES ('0xw2', 'mirror', '0xwin', 'BUY', 1, 10.0, 0.40, 'open');
+                 INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, outcome_index, size_usdc, entry_price, status)
+                 VALUES ('0xw3', 'mirror', '0xopen', 'BUY', 0, 25.0, 0.50, 'open');",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let pager = FakeResolutionPager {
+            markets: vec![resolved_market("0xwin", r#"["1", "0"]"#)],
+        };
+
+        let settled = run_paper_settlement_once(&db, &pager, 50).await.unwrap();
+        assert_eq!(settled, 2);
+
+        let rows: Vec<(String, String, Option<f64>)> = db
+            .call(|conn| {
+                let mut stmt =
+                    conn.prepare("SELECT proxy_wallet, status, pnl FROM paper_trades ORDER BY id")?;
+                let rows = stmt
+                    .query_map([], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))?
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                Ok(rows)
+            })
+            .await
+            .unwrap();
+        assert_eq!(rows[0].1, "settled_win");
+        assert!((rows[0].2.unwrap() - 25.0).abs() < 1e-9);
+        assert_eq!(rows[1].1, "settled_loss");
+        assert!((rows[1].2.unwrap() + 10.0).abs() < 1e-9);
+        assert_eq!(rows[2].1, "open", "unresolved market stays open");
+
+        let settled_again = run_paper_settlement_once(&db, &pager, 50).await.unwrap();
+        assert_eq!(settled_again, 0, "already-settled rows are skipped");
+    }
+
+    #[tokio::test]
+    async fn test_run_paper_settlement_once_marks_win_entered_at_one_as_settled_win() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        db.call(|conn| {
+            conn.execute_batch(
+                "INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, outcome_index, size_usdc, entry_price, status)
+                 VALUES ('0xw1', 'mirror', '0xwin', 'BUY', 0, 25.0, 1.0, 'open');",
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let pager = FakeResolutionPager {
+            markets: vec![resolved_market("0xwin", r#"["1", "0"]"#)],
+        };
+        assert_eq!(run_paper_settlement_once(&db, &pager, 50).await.unwrap(), 1);
+
+        let (status, pnl): (String, f64) = db
+            .call(|conn| {
+                Ok(
+                    conn.query_row("SELECT status, pnl FROM paper_trades", [], |r| {
+                        Ok((r.get(0)?, r.get(1)?))
+                    })?,
+                )
+            })
+            .await
+            .unwrap();
+        assert_eq!(status, "settled_win");
+        assert!(pnl.abs() < 1e-9);
+    }
+}
@@ -1,15 +1,21 @@
 use anyhow::Result;
 use std::sync::Arc;
 
+mod bootstrap;
 mod cli;
 mod event_bus;
 mod events;
+mod export;
 mod flow_metrics;
+mod holder_flows;
 mod ingestion;
 mod jobs;
 mod market_scoring;
 mod metrics;
 mod persona_classification;
+mod persona_drift;
+mod persona_rules;
+mod run_job;
 mod scheduler;
 mod wallet_discovery;
 mod wallet_features;
@@ -40,6 +46,16 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Fail fast on a broken persona rules file instead of at the first classification run.
+    let persona_rules = match config.personas.rules_file.as_deref() {
+        Some(path) => {
+            let rules = persona_rules::PersonaRules::load(std::path::Path::new(path))?;
+            tracing::info!(path, rules = rules.rules.len(), "persona rules loaded");
+            Some(Arc::new(rules))
+        }
+        None => None,
+    };
+
     metrics::install_prometheus(config.observability.prometheus_port)?;
     metrics::describe();
 
@@ -47,19 +63,27 @@ async fn main() -> Result<()> {
     let db = common::db::AsyncDb::open(&config.database.path).await?;
 
     let cfg = Arc::new(config);
-    let api = Arc::new(common::polymarket::PolymarketClient::new_with_settings(
-        &cfg.polymarket.data_api_url,
-        &cfg.polymarket.gamma_api_url,
-        std::time::Duration::from_secs(15),
-        std::time::Duration::from_millis(cfg.ingestion.rate_limit_delay_ms),
-        cfg.ingestion.max_retries,
-        std::time::Duration::from_millis(cfg.ingestion.backoff_base_ms),
-    ));
+    let api = Arc::new(common::polymarket::PolymarketClient::from_config(&cfg));
+    // Shared by every ingestion job so overlapping runs (e.g. at bootstrap) stay within
+    // `ingestion.max_in_flight` wallets/markets in total.
+    let ingestion_concurrency =
+        jobs::IngestionConcurrency::new(cfg.ingestion.parallel_tasks, cfg.ingestion.max_in_flight);
 
     // ── Event Bus: Initialized when enabled, passed to all jobs (Phase 2) ──
     let event_bus = if cfg.events.enabled {
-        tracing::info!("event bus enabled (capacity={})", cfg.events.bus_capacity);
-        Some(Arc::new(event_bus::EventBus::new(cfg.events.bus_capacity)))
+        tracing::info!(
+            "event bus enabled (pipeline_capacity={}, operational_capacity={}, replay={})",
+            cfg.events.bus_capacity,
+            cfg.events.operational_bus_capacity,
+            cfg.events.replay_buffer_size
+        );
+        Some(Arc::new(
+            event_bus::EventBus::with_capacities(
+                cfg.events.bus_capacity,
+                cfg.events.operational_bus_capacity,
+            )
+            .with_replay_buffer(cfg.events.replay_buffer_size),
+        ))
     } else {
         None
     };
@@ -87,8 +111,13 @@ async fn main() -> Result<()> {
     let (persona_classification_tx, mut persona_classification_rx) =
         tokio::sync::mpsc::channel::<()>(8);
     let (wal_checkpoint_tx, mut wal_checkpoint_rx) = tokio::sync::mpsc::channel::<()>(8);
+    let (wal_size_check_tx, mut wal_size_check_rx) = tokio::sync::mpsc::channel::<()>(8);
     let (flow_metrics_tx, mut flow_metrics_rx) = tokio::sync::mpsc::channel::<()>(8);
     let (sqlite_stats_tx, mut sqlite_stats_rx) = tokio::sync::mpsc::channel::<()>(8);
+    let (paper_settlement_tx, mut paper_settlement_rx) = tokio::sync::mpsc::channel::<()>(8);
+    let (persona_drift_tx, mut persona_drift_rx) = tokio::sync::mpsc::channel::<()>(8);
+    let (trader_promotion_tx, mut trader_promotion_rx) = tokio::sync::mpsc::channel::<()>(8);
+    let (wallet_profiles_tx, mut wallet_profiles_rx) = tokio::sync::mpsc::channel::<()>(8);
 
     let discovery_continuous = cfg
         .wallet_discovery
@@ -146,6 +175,32 @@ async fn main() -> Result<()> {
         );
     }
 
+    // Event-driven wallet scoring: TradesIngested events are batched and each batch
+    // scores just those wallets. The daily full scoring run stays on the timer.
+    let (wallet_scoring_batch_tx, mut wallet_scoring_batch_rx) =
+        tokio::sync::mpsc::channel::<Vec<String>>(8);
+    let scoring_trigger_bus = event_bus
+        .as_ref()
+        .filter(|_| cfg.events.enable_scoring_event_trigger);
+    if let Some(bus) = scoring_trigger_bus {
+        let bus = bus.clone();
+        let batch_window = std::time::Duration::from_secs(cfg.events.scoring_batch_window_secs);
+        tokio::spawn(async move {
+            events::subscribers::spawn_scoring_trigger_subscriber(
+                bus,
+                wallet_scoring_batch_tx,
+                batch_window,
+            )
+            .await;
+        });
+        tracing::info!(
+            window_secs = cfg.events.scoring_batch_window_secs,
+            "event-driven wallet scoring trigger enabled (TradesIngested batched → wallet_scoring)"
+        );
+    } else {
+        drop(wallet_scoring_batch_tx);
+    }
+
     // Event-driven fast-path: when enabled, TradesIngested events trigger
     // fast-path coalescing for immediate paper trading reactions.
     let fast_path_enabled = cfg.events.enable_fast_path_trigger && event_bus.is_some();
@@ -222,6 +277,12 @@ async fn main() -> Result<()> {
             tick: wal_checkpoint_tx,
             run_immediately: false, // no need to checkpoint at startup
         },
+        scheduler::JobSpec {
+            name: "wal_size_check".to_string(),
+            interval: std::time::Duration::from_secs(cfg.database.wal_size_check_interval_secs),
+            tick: wal_size_check_tx,
+            run_immediately: false,
+        },
         scheduler::JobSpec {
             name: "flow_metrics".to_string(),
             interval: std::time::Duration::from_secs(60), // every minute for Grafana flow panels
@@ -234,6 +295,24 @@ async fn main() -> Result<()> {
             tick: sqlite_stats_tx,
             run_immediately: true,
         },
+        scheduler::JobSpec {
+            name: "paper_settlement".to_string(),
+            interval: std::time::Duration::from_secs(cfg.paper_trading.settlement_interval_secs),
+            tick: paper_settlement_tx,
+            run_immediately: true,
+        },
+        scheduler::JobSpec {
+            name: "persona_drift".to_string(),
+            interval: std::time::Duration::from_secs(cfg.personas.drift_check_interval_secs),
+            tick: persona_drift_tx,
+            run_immediately: false,
+        },
+        scheduler::JobSpec {
+            name: "wallet_profiles".to_string(),
+            interval: std::time::Duration::from_secs(cfg.wallet_profiles.interval_secs),
+            tick: wallet_profiles_tx,
+            run_immediately: true,
+        },
     ]);
 
     // Conditionally add persona_classification to scheduler (timer fallback when not event-driven)
@@ -246,17 +325,31 @@ async fn main() -> Result<()> {
         });
     }
 
+    // Opt-in: register follow-worthy wallets with the trader microservice.
+    if cfg.trader_promotion.enabled {
+        scheduler_jobs.push(scheduler::JobSpec {
+            name: "trader_promotion".to_string(),
+            interval: std::time::Duration::from_secs(cfg.trader_promotion.interval_secs),
+            tick: trader_promotion_tx,
+            run_immediately: false,
+        });
+    }
+
     // ── Spawn ALL worker loops BEFORE starting scheduler ──
     // This ensures workers are ready to receive messages when scheduler sends them immediately.
     tracing::info!("spawning worker loops (ready to receive scheduler ticks)");
+    // Cancelled on Ctrl-C: each worker finishes its current job and stops taking ticks.
+    let shutdown = tokio_util::sync::CancellationToken::new();
+    let mut workers = Vec::new();
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let api = api.clone();
         let cfg = cfg.clone();
         let db = db.clone();
         let event_bus = event_bus.clone();
         async move {
-            while event_scoring_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut event_scoring_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "event_scoring");
                 let _g = span.enter();
                 match jobs::run_event_scoring_once(
@@ -272,16 +365,17 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
 
     if discovery_continuous {
         // Continuous mode: run discovery in a loop (rate limit only, no scheduler interval).
-        tokio::spawn({
+        workers.push(tokio::spawn({
+            let shutdown = shutdown.clone();
             let api = api.clone();
             let cfg = cfg.clone();
             let db = db.clone();
             async move {
-                loop {
+                while !shutdown.is_cancelled() {
                     let span = tracing::info_span!("job_run", job = "wallet_discovery");
                     let _g = span.enter();
                     let mut had_error = false;
@@ -311,19 +405,23 @@ async fn main() -> Result<()> {
                     }
                     if had_error {
                         tracing::info!("discovery error backoff: sleeping 60s");
-                        tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+                        tokio::select! {
+                            () = shutdown.cancelled() => {}
+                            () = tokio::time::sleep(std::time::Duration::from_secs(60)) => {}
+                        }
                     }
                 }
             }
-        });
+        }));
     } else {
         // Scheduled mode: run on scheduler ticks.
-        tokio::spawn({
+        workers.push(tokio::spawn({
+            let shutdown = shutdown.clone();
             let api = api.clone();
             let cfg = cfg.clone();
             let db = db.clone();
             async move {
-                while wallet_discovery_rx.recv().await.is_some() {
+                while scheduler::next_tick(&mut wallet_discovery_rx, &shutdown).await {
                     let span = tracing::info_span!("job_run", job = "wallet_discovery");
                     let _g = span.enter();
                     match jobs::run_wallet_discovery_once(
@@ -346,26 +444,28 @@ async fn main() -> Result<()> {
                     }
                 }
             }
-        });
+        }));
     }
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let api = api.clone();
         let cfg = cfg.clone();
         let db = db.clone();
+        let ingestion_concurrency = ingestion_concurrency.clone();
         let event_bus = event_bus.clone();
         async move {
-            while trades_ingestion_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut trades_ingestion_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "trades_ingestion");
                 let _g = span.enter();
                 let w = cfg.ingestion.wallets_per_ingestion_run;
-                let pt = cfg.ingestion.parallel_tasks;
                 match jobs::run_trades_ingestion_once(
                     &db,
                     api.clone(),
                     200,
                     w,
-                    pt,
+                    &ingestion_concurrency,
+                    cfg.ingestion.max_wallet_ingestion_failures,
                     event_bus.clone(),
                 )
                 .await
@@ -377,56 +477,80 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let api = api.clone();
         let cfg = cfg.clone();
         let db = db.clone();
+        let ingestion_concurrency = ingestion_concurrency.clone();
         async move {
-            while activity_ingestion_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut activity_ingestion_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "activity_ingestion");
                 let _g = span.enter();
                 let w = cfg.ingestion.wallets_per_ingestion_run;
-                let pt = cfg.ingestion.parallel_tasks;
-                match jobs::run_activity_ingestion_once(&db, api.clone(), 200, w, pt).await {
+                match jobs::run_activity_ingestion_once(
+                    &db,
+                    api.clone(),
+                    200,
+                    w,
+                    &ingestion_concurrency,
+                )
+                .await
+                {
                     Ok(inserted) => tracing::info!(inserted, "activity_ingestion done"),
                     Err(e) => tracing::error!(error = %e, "activity_ingestion failed"),
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let api = api.clone();
         let cfg = cfg.clone();
         let db = db.clone();
+        let ingestion_concurrency = ingestion_concurrency.clone();
         async move {
-            while positions_snapshot_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut positions_snapshot_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "positions_snapshot");
                 let _g = span.enter();
                 let w = cfg.ingestion.wallets_per_ingestion_run;
-                let pt = cfg.ingestion.parallel_tasks;
-                match jobs::run_positions_snapshot_once(&db, api.clone(), 200, w, pt).await {
+                match jobs::run_positions_snapshot_once(
+                    &db,
+                    api.clone(),
+                    200,
+                    w,
+                    &ingestion_concurrency,
+                )
+                .await
+                {
                     Ok(inserted) => tracing::info!(inserted, "positions_snapshot done"),
                     Err(e) => tracing::error!(error = %e, "positions_snapshot failed"),
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let api = api.clone();
         let cfg = cfg.clone();
         let db = db.clone();
+        let ingestion_concurrency = ingestion_concurrency.clone();
         async move {
-            while holders_snapshot_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut holders_snapshot_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "holders_snapshot");
                 let _g = span.enter();
                 match jobs::run_holders_snapshot_once(
                     &db,
                     api.as_ref(),
                     cfg.wallet_discovery.holders_per_market as u32,
+                    cfg.wallet_discovery.holders_snapshot_top_markets,
+                    cfg.wallet_discovery.holders_snapshot_max_score_age_days,
+                    cfg.wallet_discovery.holder_flow_min_change_pct,
+                    &ingestion_concurrency.global,
                 )
                 .await
                 {
@@ -435,14 +559,15 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let cfg = cfg.clone();
         let db = db.clone();
         let event_bus = event_bus.clone();
         async move {
-            while wallet_rules_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut wallet_rules_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "wallet_rules");
                 let _g = span.enter();
                 match jobs::run_wallet_rules_once(&db, cfg.as_ref(), event_bus.as_deref()).await {
@@ -451,29 +576,41 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let cfg = cfg.clone();
         let db = db.clone();
         async move {
-            while wallet_scoring_rx.recv().await.is_some() {
+            loop {
+                // Timer ticks score every active wallet; event batches only their wallets.
+                let subset = tokio::select! {
+                    biased;
+                    () = shutdown.cancelled() => break,
+                    tick = wallet_scoring_rx.recv() => match tick {
+                        Some(()) => None,
+                        None => break,
+                    },
+                    Some(batch) = wallet_scoring_batch_rx.recv() => Some(batch),
+                };
                 let span = tracing::info_span!("job_run", job = "wallet_scoring");
                 let _g = span.enter();
-                match jobs::run_wallet_scoring_once(&db, cfg.as_ref()).await {
+                match jobs::run_wallet_scoring_once(&db, cfg.as_ref(), subset.as_deref()).await {
                     Ok(inserted) => tracing::info!(inserted, "wallet_scoring done"),
                     Err(e) => tracing::error!(error = %e, "wallet_scoring failed"),
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let cfg = cfg.clone();
         let db = db.clone();
         let event_bus = event_bus.clone();
         async move {
-            while persona_classification_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut persona_classification_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "persona_classification");
                 let _g = span.enter();
                 match jobs::run_persona_classification_once(
@@ -491,12 +628,13 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let db = db.clone();
         async move {
-            while wal_checkpoint_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut wal_checkpoint_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "wal_checkpoint");
                 let _g = span.enter();
                 match jobs::run_wal_checkpoint_once(&db).await {
@@ -507,26 +645,45 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
+
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
+        let db = db.clone();
+        let db_path = cfg.database.path.clone();
+        let max_wal_bytes = cfg.database.wal_checkpoint_max_bytes;
+        async move {
+            while scheduler::next_tick(&mut wal_size_check_rx, &shutdown).await {
+                let span = tracing::info_span!("job_run", job = "wal_size_check");
+                let _g = span.enter();
+                if let Err(e) = jobs::run_wal_size_check_once(&db, &db_path, max_wal_bytes).await {
+                    tracing::error!(error = %e, "wal_size_check failed");
+                }
+            }
+        }
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let db = db.clone();
+        let persona_rules = persona_rules.clone();
         async move {
-            while flow_metrics_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut flow_metrics_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "flow_metrics");
                 let _g = span.enter();
-                if let Err(e) = jobs::run_flow_metrics_once(&db).await {
+                if let Err(e) = jobs::run_flow_metrics_once(&db, persona_rules.clone()).await {
                     tracing::error!(error = %e, "flow_metrics failed");
                 }
             }
         }
-    });
+    }));
 
-    tokio::spawn({
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
         let db = db.clone();
         let db_path = cfg.database.path.clone();
         async move {
-            while sqlite_stats_rx.recv().await.is_some() {
+            while scheduler::next_tick(&mut sqlite_stats_rx, &shutdown).await {
                 let span = tracing::info_span!("job_run", job = "sqlite_stats");
                 let _g = span.enter();
                 if let Err(e) = jobs::run_sqlite_stats_once(&db, &db_path).await {
@@ -534,13 +691,88 @@ async fn main() -> Result<()> {
                 }
             }
         }
-    });
+    }));
+
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
+        let api = api.clone();
+        let cfg = cfg.clone();
+        let db = db.clone();
+        async move {
+            while scheduler::next_tick(&mut paper_settlement_rx, &shutdown).await {
+                let span = tracing::info_span!("job_run", job = "paper_settlement");
+                let _g = span.enter();
+                match jobs::run_paper_settlement_once(
+                    &db,
+                    api.as_ref(),
+                    cfg.paper_trading.settlement_batch_size,
+                )
+                .await
+                {
+                    Ok(settled) => tracing::info!(settled, "paper_settlement done"),
+                    Err(e) => tracing::error!(error = %e, "paper_settlement failed"),
+                }
+            }
+        }
+    }));
+
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
+        let cfg = cfg.clone();
+        let db = db.clone();
+        let event_bus = event_bus.clone();
+        async move {
+            while scheduler::next_tick(&mut persona_drift_rx, &shutdown).await {
+                let span = tracing::info_span!("job_run", job = "persona_drift");
+                let _g = span.enter();
+                match jobs::run_persona_drift_once(&db, cfg.as_ref(), event_bus.as_deref()).await {
+                    Ok(changed) => tracing::info!(changed, "persona_drift done"),
+                    Err(e) => tracing::error!(error = %e, "persona_drift failed"),
+                }
+            }
+        }
+    }));
+
+    workers.push(tokio::spawn({
+        let shutdown = shutdown.clone();
+        let api = api.clone();
+        let cfg = cfg.clone();
+        let db = db.clone();
+        async move {
+            while scheduler::next_tick(&mut wallet_profiles_rx, &shutdown).await {
+                let span = tracing::info_span!("job_run", job = "wallet_profiles");
+                let _g = span.enter();
+                match jobs::run_wallet_profiles_once(&db, api.as_ref(), cfg.as_ref()).await {
+                    Ok(stored) => tracing::info!(stored, "wallet_profiles done"),
+                    Err(e) => tracing::error!(error = %e, "wallet_profiles failed"),
+                }
+            }
+        }
+    }));
+
+    if cfg.trader_promotion.enabled {
+        workers.push(tokio::spawn({
+            let shutdown = shutdown.clone();
+            let cfg = cfg.clone();
+            let db = db.clone();
+            async move {
+                while scheduler::next_tick(&mut trader_promotion_rx, &shutdown).await {
+                    let span = tracing::info_span!("job_run", job = "trader_promotion");
+                    let _g = span.enter();
+                    match jobs::run_trader_promotion_once(&db, cfg.as_ref()).await {
+                        Ok(promoted) => tracing::info!(promoted, "trader_promotion done"),
+                        Err(e) => tracing::error!(error = %e, "trader_promotion failed"),
+                    }
+                }
+            }
+        }));
+    }
 
     tracing::info!("all worker loops spawned and ready");
 
     // ── Start scheduler AFTER worker loops are ready ──
     // Workers are now listening, so immediate messages will be received.
-    let _scheduler_handles = scheduler::start(scheduler_jobs);
+    let _scheduler_handles = scheduler::start(scheduler_jobs, Some(&db));
     tracing::info!("scheduler started (runs immediately on existing data)");
 
     // ── Bootstrap: Run all jobs concurrently for immediate startup ──
@@ -560,6 +792,13 @@ async fn main() -> Result<()> {
         jobs::run_wallet_rules_once(&db, cfg.as_ref(), event_bus.as_deref()),
     );
 
+    let mut summary = bootstrap::BootstrapSummary::default();
+    summary.record("event_scoring", &scoring_res);
+    summary.record("wallet_discovery", &wallet_res);
+    summary.record("leaderboard_discovery", &leaderboard_res);
+    summary.record("persona_classification", &classification_res);
+    summary.record("wallet_rules", &rules_res);
+
     match scoring_res {
         Ok(n) => tracing::info!(inserted = n, "bootstrap: event_scoring done"),
         Err(e) => tracing::error!(error = %e, "bootstrap: event_scoring failed"),
@@ -581,17 +820,62 @@ async fn main() -> Result<()> {
         Err(e) => tracing::error!(error = %e, "bootstrap: wallet_rules failed"),
     }
 
+    let threshold = cfg.general.bootstrap_critical_failure_threshold;
+    if summary.is_broken(threshold) {
+        let critical = summary.critical_failures().join(", ");
+        tracing::error!(
+            failed = summary.failed.len(),
+            total = summary.total,
+            critical = %critical,
+            threshold,
+            "bootstrap: critical jobs failed — check API access and config"
+        );
+        if let Some(bus) = event_bus.as_deref() {
+            let _ = bus.publish_operational(events::OperationalEvent::JobFailed {
+                job_name: "bootstrap".to_string(),
+                error: format!(
+                    "{} of {} bootstrap jobs failed (critical: {critical})",
+                    summary.failed.len(),
+                    summary.total
+                ),
+                failed_at: chrono::Utc::now(),
+            });
+        }
+        if cfg.general.bootstrap_exit_on_failure {
+            shutdown.cancel();
+            anyhow::bail!("bootstrap failed: critical jobs failed ({critical})");
+        }
+    } else if !summary.failed.is_empty() {
+        tracing::warn!(
+            failed = summary.failed.len(),
+            total = summary.total,
+            "bootstrap: some jobs failed"
+        );
+    }
+
     tracing::info!("bootstrap done — worker loops receiving scheduler ticks");
 
     tokio::signal::ctrl_c().await?;
-    tracing::info!("shutting down (force exit in 5s)");
+    tracing::info!("shutting down: draining in-flight jobs (force exit in 5s)");
+    shutdown.cancel();
 
-    // Give spawned tasks a moment to finish, then force exit.
+    // Hard fallback if a job doesn't finish in time.
     tokio::spawn(async {
         tokio::time::sleep(std::time::Duration::from_secs(5)).await;
         tracing::warn!("force exit after timeout");
         std::process::exit(0);
     });
 
+    for worker in workers {
+        if let Err(e) = worker.await {
+            tracing::error!(error = %e, "worker loop panicked during shutdown");
+        }
+    }
+    // Queued writes run before the close request, so this flushes the DB thread.
+    if let Err(e) = db.close().await {
+        tracing::error!(error = %e, "database close failed");
+    }
+    tracing::info!("shutdown complete");
+
     Ok(())
 }
@@ -59,6 +59,10 @@ pub fn describe() {
         "evaluator_markets_scored_total",
         "Number of markets scored by MScore."
     );
+    describe_counter!(
+        "evaluator_markets_filtered_total",
+        "Markets dropped by the market_scoring filters before ranking (label: reason)."
+    );
     describe_counter!(
         "evaluator_wallets_discovered_total",
         "Number of wallets discovered."
@@ -71,6 +75,18 @@ pub fn describe() {
         "evaluator_trades_ingested_total",
         "Number of trades ingested into trades_raw."
     );
+    describe_gauge!(
+        "evaluator_ingestion_tasks_in_flight",
+        "Per-wallet trades ingestion tasks currently running (bounded by ingestion.parallel_tasks)."
+    );
+    describe_gauge!(
+        "evaluator_ingestion_global_in_flight",
+        "Wallets/markets being ingested across all ingestion jobs (bounded by ingestion.max_in_flight)."
+    );
+    describe_counter!(
+        "evaluator_wallets_ingestion_failed_total",
+        "Wallets deactivated after repeated wallet-specific ingestion failures."
+    );
     describe_counter!(
         "evaluator_api_requests_total",
         "Number of API requests made."
@@ -83,6 +99,14 @@ pub fn describe() {
         "evaluator_api_latency_ms",
         "API request latency in milliseconds."
     );
+    describe_counter!(
+        "evaluator_polymarket_retries_total",
+        "Polymarket API retries (after 429/5xx/timeout/connect errors) by endpoint."
+    );
+    describe_gauge!(
+        "evaluator_polymarket_circuit_state",
+        "Polymarket API circuit breaker state (0=closed, 1=open, 2=half-open)."
+    );
     describe_gauge!(
         "evaluator_ingestion_lag_secs",
         "Ingestion lag (seconds) from newest observed trade."
@@ -134,6 +158,14 @@ pub fn describe() {
         "evaluator_flow_funnel_wallets_ranked_today",
         "Funnel: wallets with WScore today."
     );
+    describe_gauge!(
+        "evaluator_flow_funnel_wallets_paper_traded",
+        "Funnel: wallets with at least one paper trade."
+    );
+    describe_gauge!(
+        "evaluator_funnel_conversion_ratio",
+        "Share of wallets at stage `from` that reached stage `to` (0 when `from` is empty)."
+    );
     describe_gauge!(
         "evaluator_flow_classification_wallets_tracked",
         "Classification: active wallets (same as funnel)."
@@ -158,6 +190,18 @@ pub fn describe() {
         "evaluator_flow_classification_stage2_unclassified",
         "Classification: passed Stage 1, not yet classified at Stage 2."
     );
+    describe_counter!(
+        "evaluator_holder_flows_total",
+        "Wallets whose holding in a top market moved sharply between holder snapshots, by direction."
+    );
+    describe_counter!(
+        "evaluator_persona_drift_reclassified_total",
+        "Classified wallets re-evaluated because their features drifted from the classification snapshot."
+    );
+    describe_counter!(
+        "evaluator_trader_promotions_total",
+        "Follow-worthy wallets sent to the trader's POST /api/wallets, by outcome (followed, already_followed, failed)."
+    );
 }
 
 pub fn install_prometheus(port: u16) -> Result<()> {
@@ -274,6 +318,7 @@ mod tests {
                 wallets_discovered: 100,
                 wallets_tracked: 80,
                 wallets_ranked_today: 3,
+                wallets_paper_traded: 4,
             },
             classification: crate::flow_metrics::ClassificationFlowCounts {
                 wallets_tracked: 80,
@@ -282,6 +327,7 @@ mod tests {
                 stage2_followable: 20,
                 stage2_excluded: 2,
                 stage2_unclassified: 53,
+                classified_paper_traded: 4,
             },
         };
 
@@ -298,6 +344,12 @@ mod tests {
             rendered.contains("evaluator_flow_classification_stage2_followable"),
             "flow classification gauges should appear in Prometheus output"
         );
+        assert!(
+            rendered.contains(
+                "evaluator_funnel_conversion_ratio{from=\"discovered\",to=\"tracked\"} 0.8"
+            ),
+            "conversion ratio gauges should appear with from/to labels"
+        );
     }
 
     fn free_local_port() -> u16 {
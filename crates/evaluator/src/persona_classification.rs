@@ -1,6 +1,11 @@
 use anyhow::Result;
 use rusqlite::Connection;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
 
+use crate::persona_drift::FeatureSnapshot;
+use crate::persona_rules::{PersonaRules, RuleInputs, RuleKind};
 use crate::wallet_features::WalletFeatures;
 
 /// Result of running the full classification pipeline on a wallet.
@@ -8,6 +13,10 @@ use crate::wallet_features::WalletFeatures;
 pub enum ClassificationResult {
     Followable(Persona),
     Excluded(ExclusionReason),
+    /// Followable persona assigned by a `personas.rules_file` rule.
+    RuleFollowable(String),
+    /// Exclusion decided by a `personas.rules_file` rule (the rule name is the reason).
+    RuleExcluded(String),
     Unclassified,
 }
 
@@ -45,6 +54,10 @@ pub struct PersonaConfig {
     pub bonder_min_extreme_price_ratio: f64,
     pub whale_min_avg_trade_size_usdc: f64,
     pub stage2_min_roi: f64,
+    /// Per-persona minimum confidence (win rate); personas not listed have no cutoff.
+    pub min_confidence: HashMap<String, f64>,
+    /// Declarative rules from `personas.rules_file`; when set they replace the built-in detectors.
+    pub rules: Option<Arc<PersonaRules>>,
 }
 
 impl PersonaConfig {
@@ -81,6 +94,8 @@ impl PersonaConfig {
             bonder_min_extreme_price_ratio: 0.60,
             whale_min_avg_trade_size_usdc: 100.0,
             stage2_min_roi: 0.0,
+            min_confidence: HashMap::new(),
+            rules: None,
         }
     }
 
@@ -117,6 +132,33 @@ impl PersonaConfig {
             bonder_min_extreme_price_ratio: p.bonder_min_extreme_price_ratio,
             whale_min_avg_trade_size_usdc: p.whale_min_avg_trade_size_usdc,
             stage2_min_roi: p.stage2_min_roi,
+            min_confidence: p.min_confidence.clone(),
+            rules: None,
+        }
+    }
+
+    /// `from_personas` plus the rules file named by `personas.rules_file`, if any.
+    pub fn load(p: &common::config::Personas) -> Result<Self> {
+        let rules = p
+            .rules_file
+            .as_deref()
+            .map(|path| PersonaRules::load(Path::new(path)))
+            .transpose()?;
+        Ok(Self {
+            rules: rules.map(Arc::new),
+            ..Self::from_personas(p)
+        })
+    }
+
+    /// Whether `confidence` clears the configured cutoff for `persona` (no cutoff = always).
+    pub fn meets_min_confidence(&self, persona: &Persona, confidence: f64) -> bool {
+        self.meets_min_confidence_for(persona.as_str(), confidence)
+    }
+
+    fn meets_min_confidence_for(&self, persona: &str, confidence: f64) -> bool {
+        match self.min_confidence.get(persona) {
+            Some(min) => confidence >= *min,
+            None => true,
         }
     }
 }
@@ -178,6 +220,11 @@ pub enum ExclusionReason {
     },
     /// Wallet is in the configured known_bots list (Strategy Bible §4 Stage 1).
     KnownBot,
+    /// Volume is mostly quick BUY/SELL round trips on the same market.
+    WashTrading {
+        score: f64,
+        max_score: f64,
+    },
     /// Would pass persona checks but ROI < stage2_min_roi (win rate + PnL combo).
     InsufficientPnl {
         roi: f64,
@@ -201,6 +248,7 @@ impl ExclusionReason {
             Self::JackpotGambler { .. } => "JACKPOT_GAMBLER",
             Self::BotSwarmMicro { .. } => "BOT_SWARM_MICRO",
             Self::KnownBot => "KNOWN_BOT",
+            Self::WashTrading { .. } => "STAGE1_WASH_TRADING",
             Self::InsufficientPnl { .. } => "INSUFFICIENT_PNL",
         }
     }
@@ -226,6 +274,7 @@ impl ExclusionReason {
             Self::JackpotGambler { pnl_top1_share, .. } => *pnl_top1_share,
             Self::BotSwarmMicro { trades_per_day, .. } => *trades_per_day,
             Self::KnownBot => 1.0,
+            Self::WashTrading { score, .. } => *score,
             Self::InsufficientPnl { roi, .. } => *roi,
         }
     }
@@ -253,6 +302,7 @@ impl ExclusionReason {
             Self::JackpotGambler { .. } => 0.0,
             Self::BotSwarmMicro { .. } => 0.0,
             Self::KnownBot => 0.0,
+            Self::WashTrading { max_score, .. } => *max_score,
             Self::InsufficientPnl { min_roi, .. } => *min_roi,
         }
     }
@@ -273,6 +323,8 @@ pub struct Stage1Config {
     pub stage1_require_recent_profit: bool,
     /// Window (days) for the Stage 1.5 recent profitability check.
     pub stage1_recent_profit_window_days: u32,
+    /// Maximum `wash_trade_score` before the wallet is excluded as a wash trader.
+    pub stage1_max_wash_trade_score: f64,
 }
 
 /// Returns Some(KnownBot) if proxy_wallet is in the known_bots list, None otherwise.
@@ -317,6 +369,18 @@ pub fn stage1_filter(
     None
 }
 
+/// Returns Some(WashTrading) if the wallet's `wash_trade_score` exceeds `max_score`.
+pub fn stage1_wash_trading_check(wash_trade_score: f64, max_score: f64) -> Option<ExclusionReason> {
+    if wash_trade_score > max_score {
+        Some(ExclusionReason::WashTrading {
+            score: wash_trade_score,
+            max_score,
+        })
+    } else {
+        None
+    }
+}
+
 /// Remove any Stage 1 exclusion for this wallet (e.g. STAGE1_TOO_YOUNG). Call when the wallet
 /// passes Stage 1 on a re-run so we don't leave a stale "young" label.
 pub fn clear_stage1_exclusion(conn: &Connection, proxy_wallet: &str) -> Result<()> {
@@ -333,16 +397,27 @@ pub fn record_exclusion(
     conn: &Connection,
     proxy_wallet: &str,
     reason: &ExclusionReason,
+) -> Result<()> {
+    record_exclusion_values(
+        conn,
+        proxy_wallet,
+        reason.reason_str(),
+        reason.metric_value(),
+        reason.threshold(),
+    )
+}
+
+pub(crate) fn record_exclusion_values(
+    conn: &Connection,
+    proxy_wallet: &str,
+    reason: &str,
+    metric_value: f64,
+    threshold: f64,
 ) -> Result<()> {
     conn.execute(
         "INSERT OR REPLACE INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
          VALUES (?1, ?2, ?3, ?4, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
-        rusqlite::params![
-            proxy_wallet,
-            reason.reason_str(),
-            reason.metric_value(),
-            reason.threshold(),
-        ],
+        rusqlite::params![proxy_wallet, reason, metric_value, threshold],
     )?;
     Ok(())
 }
@@ -681,6 +756,77 @@ pub fn detect_consistent_generalist(
     Some(Persona::ConsistentGeneralist)
 }
 
+/// Worst-loss proxy (drawdown × average position) relative to the average win.
+fn tail_loss_ratio(features: &WalletFeatures) -> f64 {
+    let avg_win_pnl = if features.win_count > 0 {
+        features.total_pnl.max(1.0) / f64::from(features.win_count)
+    } else {
+        1.0
+    };
+    let max_loss_proxy = features.max_drawdown_pct * features.avg_position_size / 100.0;
+    if avg_win_pnl > 0.0 {
+        max_loss_proxy / avg_win_pnl
+    } else {
+        0.0
+    }
+}
+
+/// Stage 2 driven by `personas.rules_file`: the first matching rule decides. A followable rule
+/// below its `personas.min_confidence` cutoff is skipped (like the built-in personas), and the
+/// `stage2_min_roi` gate still applies.
+fn classify_with_rules(
+    conn: &Connection,
+    features: &WalletFeatures,
+    wallet_age_days: u32,
+    win_rate: f64,
+    roi: f64,
+    rules: &PersonaRules,
+    config: &PersonaConfig,
+) -> Result<ClassificationResult> {
+    let inputs = RuleInputs {
+        features,
+        wallet_age_days,
+        win_rate,
+        roi,
+        loss_ratio: tail_loss_ratio(features),
+    };
+    let Some(m) = rules.matches(&inputs).find(|m| {
+        m.kind == RuleKind::Exclude || config.meets_min_confidence_for(m.name, m.confidence)
+    }) else {
+        return Ok(ClassificationResult::Unclassified);
+    };
+    match m.kind {
+        RuleKind::Exclude => {
+            record_exclusion_values(
+                conn,
+                &features.proxy_wallet,
+                m.name,
+                m.metric_value,
+                m.threshold,
+            )?;
+            Ok(ClassificationResult::RuleExcluded(m.name.to_string()))
+        }
+        RuleKind::Followable => {
+            if let Some(reason) = record_exclusion_if_roi_fails(
+                conn,
+                &features.proxy_wallet,
+                roi,
+                config.stage2_min_roi,
+            )? {
+                return Ok(ClassificationResult::Excluded(reason));
+            }
+            record_persona_named(
+                conn,
+                &features.proxy_wallet,
+                m.name,
+                m.confidence,
+                &FeatureSnapshot::from_features(features),
+            )?;
+            Ok(ClassificationResult::RuleFollowable(m.name.to_string()))
+        }
+    }
+}
+
 /// Full classification pipeline for a wallet.
 /// Checks exclusions first (order matters — cheapest checks first), then followable personas.
 pub fn classify_wallet(
@@ -705,6 +851,18 @@ pub fn classify_wallet(
     // Traits are computed for all wallets (followable and excluded).
     record_persona_traits(conn, features, config)?;
 
+    if let Some(rules) = &config.rules {
+        return classify_with_rules(
+            conn,
+            features,
+            wallet_age_days,
+            win_rate,
+            roi,
+            rules,
+            config,
+        );
+    }
+
     // --- Exclusion checks (Stage 2) ---
 
     if let Some(reason) = detect_sniper_insider(
@@ -757,21 +915,9 @@ pub fn classify_wallet(
         return Ok(ClassificationResult::Excluded(reason));
     }
 
-    let avg_win_pnl = if features.win_count > 0 {
-        features.total_pnl.max(1.0) / f64::from(features.win_count)
-    } else {
-        1.0
-    };
-    let max_loss_proxy = features.max_drawdown_pct * features.avg_position_size / 100.0;
-    let loss_ratio = if avg_win_pnl > 0.0 {
-        max_loss_proxy / avg_win_pnl
-    } else {
-        0.0
-    };
-
     if let Some(reason) = detect_tail_risk_seller(
         win_rate,
-        loss_ratio,
+        tail_loss_ratio(features),
         config.tail_risk_min_win_rate,
         config.tail_risk_loss_multiplier,
     ) {
@@ -797,13 +943,21 @@ pub fn classify_wallet(
         config.specialist_max_active_positions,
         config.specialist_min_concentration,
         config.specialist_min_win_rate,
-    ) {
+    )
+    .filter(|p| config.meets_min_confidence(p, win_rate))
+    {
         if let Some(reason) =
             record_exclusion_if_roi_fails(conn, &features.proxy_wallet, roi, config.stage2_min_roi)?
         {
             return Ok(ClassificationResult::Excluded(reason));
         }
-        record_persona(conn, &features.proxy_wallet, &persona, win_rate)?;
+        record_persona(
+            conn,
+            &features.proxy_wallet,
+            &persona,
+            win_rate,
+            &FeatureSnapshot::from_features(features),
+        )?;
         return Ok(ClassificationResult::Followable(persona));
     }
 
@@ -814,13 +968,21 @@ pub fn classify_wallet(
         config.generalist_max_win_rate,
         config.generalist_max_drawdown,
         config.generalist_min_sharpe,
-    ) {
+    )
+    .filter(|p| config.meets_min_confidence(p, win_rate))
+    {
         if let Some(reason) =
             record_exclusion_if_roi_fails(conn, &features.proxy_wallet, roi, config.stage2_min_roi)?
         {
             return Ok(ClassificationResult::Excluded(reason));
         }
-        record_persona(conn, &features.proxy_wallet, &persona, win_rate)?;
+        record_persona(
+            conn,
+            &features.proxy_wallet,
+            &persona,
+            win_rate,
+            &FeatureSnapshot::from_features(features),
+        )?;
         return Ok(ClassificationResult::Followable(persona));
     }
 
@@ -830,13 +992,21 @@ pub fn classify_wallet(
         config.accumulator_max_trades_per_week,
         roi,
         config.accumulator_min_roi,
-    ) {
+    )
+    .filter(|p| config.meets_min_confidence(p, win_rate))
+    {
         if let Some(reason) =
             record_exclusion_if_roi_fails(conn, &features.proxy_wallet, roi, config.stage2_min_roi)?
         {
             return Ok(ClassificationResult::Excluded(reason));
         }
-        record_persona(conn, &features.proxy_wallet, &persona, win_rate)?;
+        record_persona(
+            conn,
+            &features.proxy_wallet,
+            &persona,
+            win_rate,
+            &FeatureSnapshot::from_features(features),
+        )?;
         return Ok(ClassificationResult::Followable(persona));
     }
 
@@ -845,16 +1015,33 @@ pub fn classify_wallet(
 
 /// Record a followable persona classification.
 /// Schema has UNIQUE(proxy_wallet, classified_at), so each run adds a row; use latest by classified_at for "current" persona.
+/// The feature snapshot is stored alongside so drift detection can tell when behaviour has changed.
 pub fn record_persona(
     conn: &Connection,
     proxy_wallet: &str,
     persona: &Persona,
     confidence: f64,
+    snapshot: &FeatureSnapshot,
+) -> Result<()> {
+    record_persona_named(conn, proxy_wallet, persona.as_str(), confidence, snapshot)
+}
+
+fn record_persona_named(
+    conn: &Connection,
+    proxy_wallet: &str,
+    persona: &str,
+    confidence: f64,
+    snapshot: &FeatureSnapshot,
 ) -> Result<()> {
     conn.execute(
-        "INSERT OR REPLACE INTO wallet_personas (proxy_wallet, persona, confidence, classified_at)
-         VALUES (?1, ?2, ?3, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
-        rusqlite::params![proxy_wallet, persona.as_str(), confidence],
+        "INSERT OR REPLACE INTO wallet_personas (proxy_wallet, persona, confidence, feature_values_json, classified_at)
+         VALUES (?1, ?2, ?3, ?4, strftime('%Y-%m-%d %H:%M:%f', 'now'))",
+        rusqlite::params![
+            proxy_wallet,
+            persona,
+            confidence,
+            serde_json::to_string(snapshot)?
+        ],
     )?;
     Ok(())
 }
@@ -912,6 +1099,7 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(
@@ -937,6 +1125,7 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(
@@ -962,6 +1151,7 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(
@@ -987,6 +1177,7 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(result, None);
@@ -1007,6 +1198,7 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(result, None);
@@ -1027,6 +1219,7 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(result, None);
@@ -1047,11 +1240,21 @@ mod tests {
                 stage1_min_all_time_roi: -0.10,
                 stage1_require_recent_profit: false,
                 stage1_recent_profit_window_days: 30,
+                stage1_max_wash_trade_score: 0.5,
             },
         );
         assert_eq!(result, None);
     }
 
+    #[test]
+    fn test_stage1_wash_trading_check() {
+        assert_eq!(stage1_wash_trading_check(0.5, 0.5), None);
+        let reason = stage1_wash_trading_check(0.9, 0.5).unwrap();
+        assert_eq!(reason.reason_str(), "STAGE1_WASH_TRADING");
+        assert_eq!(reason.metric_value(), 0.9);
+        assert_eq!(reason.threshold(), 0.5);
+    }
+
     #[test]
     fn test_record_exclusion_persists() {
         let db = Database::open(":memory:").unwrap();
@@ -1246,6 +1449,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1358,6 +1562,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1461,6 +1666,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1723,6 +1929,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1748,6 +1955,67 @@ mod tests {
         assert_eq!(persona, "INFORMED_SPECIALIST");
     }
 
+    #[test]
+    fn test_classify_wallet_respects_per_persona_min_confidence() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+
+        let features = WalletFeatures {
+            proxy_wallet: "0xabc".to_string(),
+            window_days: 30,
+            trade_count: 40,
+            win_count: 28,
+            loss_count: 12,
+            total_pnl: 500.0,
+            avg_position_size: 200.0,
+            unique_markets: 5,
+            avg_hold_time_hours: 24.0,
+            max_drawdown_pct: 8.0,
+            trades_per_week: 10.0,
+            trades_per_day: 10.0 / 7.0,
+            sharpe_ratio: 1.5,
+            active_positions: 3,
+            concentration_ratio: 0.75,
+            avg_trade_size_usdc: 200.0,
+            size_cv: 0.0,
+            buy_sell_balance: 0.0,
+            mid_fill_ratio: 0.0,
+            extreme_price_ratio: 0.0,
+            burstiness_top_1h_ratio: 0.0,
+            top_domain: None,
+            top_domain_ratio: 0.0,
+            profitable_markets: 3,
+            wash_trade_score: 0.0,
+            cashflow_pnl: 100.0,
+            fifo_realized_pnl: 0.0,
+            unrealized_pnl: 0.0,
+            open_positions_count: 0,
+        };
+
+        // win rate is 28/40 = 0.70: a 0.80 cutoff blocks the specialist persona.
+        let mut config = PersonaConfig::default_for_test();
+        config
+            .min_confidence
+            .insert("INFORMED_SPECIALIST".to_string(), 0.80);
+        assert!(!config.meets_min_confidence(&Persona::InformedSpecialist, 0.70));
+        assert!(config.meets_min_confidence(&Persona::ConsistentGeneralist, 0.10));
+
+        let result = classify_wallet(&db.conn, &features, 90, &config).unwrap();
+        assert_ne!(
+            result,
+            ClassificationResult::Followable(Persona::InformedSpecialist)
+        );
+        let specialists: i64 = db
+            .conn
+            .query_row(
+                "SELECT COUNT(*) FROM wallet_personas WHERE persona = 'INFORMED_SPECIALIST'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert_eq!(specialists, 0);
+    }
+
     #[test]
     fn test_classify_wallet_excluded_noise_trader() {
         let db = Database::open(":memory:").unwrap();
@@ -1778,6 +2046,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 5.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1836,6 +2105,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: -50.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1864,6 +2134,78 @@ mod tests {
         assert_eq!(reason, "INSUFFICIENT_PNL");
     }
 
+    #[test]
+    fn test_classify_wallet_uses_rules_file_when_configured() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+
+        let mut config = PersonaConfig::default_for_test();
+        config.rules = Some(Arc::new(
+            PersonaRules::from_toml_str(
+                r#"
+                [[rule]]
+                name = "HEAVY_TRADER"
+                kind = "exclude"
+                when = [{ feature = "trades_per_week", op = ">", value = 9.0 }]
+
+                [[rule]]
+                name = "STEADY_HAND"
+                kind = "followable"
+                when = [{ feature = "concentration_ratio", op = ">=", value = 0.7 }]
+                confidence = { base = 0.2, weights = { win_rate = 0.5 } }
+                "#,
+            )
+            .unwrap(),
+        ));
+
+        // Same features the built-in detectors classify as INFORMED_SPECIALIST.
+        let mut features = make_features(5, 28, 12);
+        features.proxy_wallet = "0xheavy".to_string();
+        features.trades_per_week = 10.0;
+        features.concentration_ratio = 0.75;
+        let result = classify_wallet(&db.conn, &features, 90, &config).unwrap();
+        assert_eq!(
+            result,
+            ClassificationResult::RuleExcluded("HEAVY_TRADER".to_string())
+        );
+        let (reason, metric, threshold): (String, f64, f64) = db
+            .conn
+            .query_row(
+                "SELECT reason, metric_value, threshold FROM wallet_exclusions WHERE proxy_wallet = '0xheavy'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(reason, "HEAVY_TRADER");
+        assert!((metric - 10.0).abs() < 1e-9);
+        assert!((threshold - 9.0).abs() < 1e-9);
+
+        features.proxy_wallet = "0xsteady".to_string();
+        features.trades_per_week = 2.0;
+        let result = classify_wallet(&db.conn, &features, 90, &config).unwrap();
+        assert_eq!(
+            result,
+            ClassificationResult::RuleFollowable("STEADY_HAND".to_string())
+        );
+        let (persona, confidence): (String, f64) = db
+            .conn
+            .query_row(
+                "SELECT persona, confidence FROM wallet_personas WHERE proxy_wallet = '0xsteady'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(persona, "STEADY_HAND");
+        // 0.2 + 0.5 × (28 / 40)
+        assert!((confidence - 0.55).abs() < 1e-9);
+
+        // Below its min_confidence cutoff the rule is skipped and nothing else matches.
+        config.min_confidence.insert("STEADY_HAND".to_string(), 0.9);
+        features.proxy_wallet = "0xskipped".to_string();
+        let result = classify_wallet(&db.conn, &features, 90, &config).unwrap();
+        assert_eq!(result, ClassificationResult::Unclassified);
+    }
+
     #[test]
     fn test_classify_wallet_unclassified() {
         let db = Database::open(":memory:").unwrap();
@@ -1894,6 +2236,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1938,6 +2281,7 @@ mod tests {
             top_domain: None,
             top_domain_ratio: 0.0,
             profitable_markets: 3,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -1995,6 +2339,7 @@ mod tests {
             top_domain: Some("sports".to_string()),
             top_domain_ratio: 0.9,
             profitable_markets: 4,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
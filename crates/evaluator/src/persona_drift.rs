@@ -0,0 +1,306 @@
+use anyhow::Result;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+
+use crate::persona_classification::{
+    classify_wallet, record_exclusion_values, ClassificationResult, PersonaConfig,
+};
+use crate::wallet_features::{compute_wallet_features, WalletFeatures};
+
+/// Behavioural features stored with each persona classification (`wallet_personas.feature_values_json`).
+///
+/// Taken over `personas.classification_window_days`; drift checks recompute the same window so
+/// snapshots are only ever compared like-for-like.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeatureSnapshot {
+    pub win_rate: f64,
+    pub trades_per_week: f64,
+    pub avg_trade_size_usdc: f64,
+    pub concentration_ratio: f64,
+    pub avg_hold_time_hours: f64,
+    pub buy_sell_balance: f64,
+    pub extreme_price_ratio: f64,
+}
+
+fn win_rate(win_count: u32, loss_count: u32) -> f64 {
+    let resolved = win_count + loss_count;
+    if resolved > 0 {
+        f64::from(win_count) / f64::from(resolved)
+    } else {
+        0.0
+    }
+}
+
+impl FeatureSnapshot {
+    pub fn from_features(features: &WalletFeatures) -> Self {
+        Self {
+            win_rate: win_rate(features.win_count, features.loss_count),
+            trades_per_week: features.trades_per_week,
+            avg_trade_size_usdc: features.avg_trade_size_usdc,
+            concentration_ratio: features.concentration_ratio,
+            avg_hold_time_hours: features.avg_hold_time_hours,
+            buy_sell_balance: features.buy_sell_balance,
+            extreme_price_ratio: features.extreme_price_ratio,
+        }
+    }
+
+    fn values(&self) -> [f64; 7] {
+        [
+            self.win_rate,
+            self.trades_per_week,
+            self.avg_trade_size_usdc,
+            self.concentration_ratio,
+            self.avg_hold_time_hours,
+            self.buy_sell_balance,
+            self.extreme_price_ratio,
+        ]
+    }
+}
+
+/// Largest per-feature change between two snapshots, in [0, 1].
+///
+/// Each feature's change is `|a - b| / max(|a|, |b|, 1)`: ratios in [0, 1] compare by absolute
+/// difference, while unbounded features (trade size, hold time, frequency) compare relatively.
+pub fn drift_score(before: &FeatureSnapshot, after: &FeatureSnapshot) -> f64 {
+    before
+        .values()
+        .iter()
+        .zip(after.values())
+        .map(|(a, b)| (a - b).abs() / a.abs().max(b.abs()).max(1.0))
+        .fold(0.0, f64::max)
+}
+
+/// Latest current (not superseded) persona per wallet with the snapshot stored at classification
+/// time. Rows classified before snapshots were recorded have no JSON and are skipped.
+pub fn latest_persona_snapshots(
+    conn: &Connection,
+) -> Result<Vec<(String, String, FeatureSnapshot)>> {
+    let mut stmt = conn.prepare(
+        "SELECT wp.proxy_wallet, wp.persona, wp.feature_values_json
+         FROM wallet_personas wp
+         JOIN (SELECT proxy_wallet, MAX(classified_at) AS classified_at
+               FROM wallet_personas WHERE superseded_at IS NULL GROUP BY proxy_wallet) latest
+           ON latest.proxy_wallet = wp.proxy_wallet AND latest.classified_at = wp.classified_at
+         WHERE wp.feature_values_json IS NOT NULL",
+    )?;
+    let rows = stmt
+        .query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+            ))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut out = Vec::with_capacity(rows.len());
+    for (wallet, persona, json) in rows {
+        match serde_json::from_str::<FeatureSnapshot>(&json) {
+            Ok(snapshot) => out.push((wallet, persona, snapshot)),
+            Err(e) => tracing::warn!(
+                proxy_wallet = %wallet,
+                error = %e,
+                "persona_drift: unreadable feature snapshot, skipping"
+            ),
+        }
+    }
+    Ok(out)
+}
+
+/// Exclusion reason for a drifted wallet that no longer classifies as any persona.
+pub const DRIFT_EXCLUSION_REASON: &str = "PERSONA_DRIFT";
+
+/// Mark a wallet's current persona rows superseded so it stops counting as suitable or ranked.
+/// The rows stay for history (e.g. the calibration report reads each wallet's first persona).
+fn supersede_personas(conn: &Connection, proxy_wallet: &str) -> Result<()> {
+    conn.execute(
+        "UPDATE wallet_personas SET superseded_at = strftime('%Y-%m-%d %H:%M:%f', 'now')
+         WHERE proxy_wallet = ?1 AND superseded_at IS NULL",
+        [proxy_wallet],
+    )?;
+    Ok(())
+}
+
+/// Outcome of one drift pass.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DriftStats {
+    pub checked: u64,
+    pub drifted: u64,
+    pub changed: u64,
+}
+
+/// Re-run `classify_wallet` for every classified wallet whose features over
+/// `classification_window_days` have drifted at least `threshold` from its classification snapshot.
+///
+/// A followable outcome inserts a new `wallet_personas` row whose snapshot (taken from the same
+/// features) becomes the new baseline. Any other outcome marks the wallet's persona rows
+/// superseded so it stops counting as suitable; exclusions are recorded by `classify_wallet`, and
+/// a wallet that matches nothing is excluded as `PERSONA_DRIFT`.
+pub fn reclassify_drifted_wallets(
+    conn: &Connection,
+    config: &PersonaConfig,
+    threshold: f64,
+    classification_window_days: u32,
+    now_epoch: i64,
+) -> Result<DriftStats> {
+    let mut stats = DriftStats::default();
+    for (wallet, persona, snapshot) in latest_persona_snapshots(conn)? {
+        stats.checked += 1;
+        let features =
+            compute_wallet_features(conn, &wallet, classification_window_days, now_epoch)?;
+        let score = drift_score(&snapshot, &FeatureSnapshot::from_features(&features));
+        if score < threshold {
+            continue;
+        }
+        stats.drifted += 1;
+
+        let age_days: i64 = conn.query_row(
+            "SELECT COALESCE((?2 - MIN(timestamp)) / 86400, 0) FROM trades_raw WHERE proxy_wallet = ?1",
+            rusqlite::params![wallet, now_epoch],
+            |row| row.get(0),
+        )?;
+        let result = classify_wallet(
+            conn,
+            &features,
+            age_days.clamp(0, i64::from(u32::MAX)) as u32,
+            config,
+        )?;
+        let new_persona = match &result {
+            ClassificationResult::Followable(p) => Some(p.as_str()),
+            ClassificationResult::RuleFollowable(p) => Some(p.as_str()),
+            _ => None,
+        };
+        if new_persona.is_none() {
+            if result == ClassificationResult::Unclassified {
+                record_exclusion_values(conn, &wallet, DRIFT_EXCLUSION_REASON, score, threshold)?;
+            }
+            supersede_personas(conn, &wallet)?;
+        }
+        if new_persona != Some(persona.as_str()) {
+            stats.changed += 1;
+            tracing::info!(
+                proxy_wallet = %wallet,
+                from = %persona,
+                to = new_persona.unwrap_or("none"),
+                drift = score,
+                "persona_drift: persona changed"
+            );
+        }
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::db::Database;
+
+    fn snapshot() -> FeatureSnapshot {
+        FeatureSnapshot {
+            win_rate: 0.6,
+            trades_per_week: 4.0,
+            avg_trade_size_usdc: 50.0,
+            concentration_ratio: 0.7,
+            avg_hold_time_hours: 72.0,
+            buy_sell_balance: 0.5,
+            extreme_price_ratio: 0.1,
+        }
+    }
+
+    #[test]
+    fn test_drift_score_identical_snapshots_is_zero() {
+        assert!(drift_score(&snapshot(), &snapshot()).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_drift_score_uses_largest_relative_change() {
+        let before = snapshot();
+        let mut after = before;
+        after.win_rate = 0.5; // 0.1 absolute
+        after.avg_hold_time_hours = 2.0; // 70/72 relative
+        let score = drift_score(&before, &after);
+        assert!((score - 70.0 / 72.0).abs() < 1e-9, "score={score}");
+    }
+
+    #[test]
+    fn test_drift_score_small_ratios_compare_absolutely() {
+        let before = snapshot();
+        let mut after = before;
+        after.extreme_price_ratio = 0.3; // tripled, but only +0.2 absolute
+        assert!((drift_score(&before, &after) - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_latest_persona_snapshots_reads_latest_row_with_snapshot() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        let json = serde_json::to_string(&snapshot()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallet_personas (proxy_wallet, persona, confidence, feature_values_json, classified_at)
+                 VALUES ('0xa', 'PATIENT_ACCUMULATOR', 0.6, NULL, '2026-01-01 00:00:00.000'),
+                        ('0xa', 'CONSISTENT_GENERALIST', 0.55, ?1, '2026-02-01 00:00:00.000'),
+                        ('0xb', 'INFORMED_SPECIALIST', 0.7, NULL, '2026-02-01 00:00:00.000')",
+                [&json],
+            )
+            .unwrap();
+
+        let rows = latest_persona_snapshots(&db.conn).unwrap();
+        assert_eq!(rows.len(), 1, "0xb has no snapshot");
+        assert_eq!(rows[0].0, "0xa");
+        assert_eq!(rows[0].1, "CONSISTENT_GENERALIST");
+        assert_eq!(rows[0].2, snapshot());
+    }
+
+    #[test]
+    fn test_reclassify_drifted_wallets_supersedes_persona_of_drifted_wallet() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        // 0xsteady's snapshot matches its current (empty) classification-window features;
+        // 0xdrift was classified while trading, and has no trades left in the window.
+        let now = 1_772_323_200; // 2026-03-01
+        let steady = FeatureSnapshot::from_features(
+            &compute_wallet_features(&db.conn, "0xsteady", 180, now).unwrap(),
+        );
+        db.conn
+            .execute(
+                "INSERT INTO wallet_personas (proxy_wallet, persona, confidence, feature_values_json, classified_at)
+                 VALUES ('0xdrift', 'CONSISTENT_GENERALIST', 0.6, ?1, '2026-01-01 00:00:00.000'),
+                        ('0xsteady', 'CONSISTENT_GENERALIST', 0.6, ?2, '2026-01-01 00:00:00.000')",
+                [
+                    serde_json::to_string(&snapshot()).unwrap(),
+                    serde_json::to_string(&steady).unwrap(),
+                ],
+            )
+            .unwrap();
+
+        let config = PersonaConfig::default_for_test();
+        let stats = reclassify_drifted_wallets(&db.conn, &config, 0.5, 180, now).unwrap();
+        assert_eq!(stats.checked, 2);
+        assert_eq!(stats.drifted, 1);
+        assert_eq!(
+            stats.changed, 1,
+            "no trades left to support the old persona"
+        );
+
+        let (personas, current, reason): (i64, i64, String) = db
+            .conn
+            .query_row(
+                "SELECT (SELECT COUNT(*) FROM wallet_personas WHERE proxy_wallet = '0xdrift'),
+                        (SELECT COUNT(*) FROM wallet_personas
+                         WHERE proxy_wallet = '0xdrift' AND superseded_at IS NULL),
+                        (SELECT reason FROM wallet_exclusions WHERE proxy_wallet = '0xdrift')",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .unwrap();
+        assert_eq!(personas, 1, "persona history is kept");
+        assert_eq!(current, 0, "drifted wallet no longer counts as suitable");
+        assert_eq!(reason, DRIFT_EXCLUSION_REASON);
+
+        // Only the steady wallet is left, and it has not drifted.
+        let stats = reclassify_drifted_wallets(&db.conn, &config, 0.5, 180, now).unwrap();
+        assert_eq!(stats.checked, 1);
+        assert_eq!(stats.drifted, 0);
+    }
+}
@@ -0,0 +1,434 @@
+//! Declarative Stage 2 persona rules loaded from `personas.rules_file`.
+//!
+//! Rules are evaluated top to bottom against a wallet's features; the first matching rule
+//! decides the wallet (exclusion or followable persona). When no rules file is configured,
+//! `persona_classification::classify_wallet` uses the built-in detectors.
+
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::wallet_features::WalletFeatures;
+
+/// Everything a rule can reference: the wallet's features plus values derived from them.
+pub struct RuleInputs<'a> {
+    pub features: &'a WalletFeatures,
+    pub wallet_age_days: u32,
+    pub win_rate: f64,
+    pub roi: f64,
+    /// Worst-loss proxy divided by average win (the tail-risk ratio).
+    pub loss_ratio: f64,
+}
+
+type FeatureFn = fn(&RuleInputs<'_>) -> f64;
+
+/// Feature names usable in `when` conditions and confidence weights.
+const FEATURES: &[(&str, FeatureFn)] = &[
+    ("win_rate", |i| i.win_rate),
+    ("roi", |i| i.roi),
+    ("abs_roi", |i| i.roi.abs()),
+    ("loss_ratio", |i| i.loss_ratio),
+    ("wallet_age_days", |i| f64::from(i.wallet_age_days)),
+    ("trade_count", |i| f64::from(i.features.trade_count)),
+    ("win_count", |i| f64::from(i.features.win_count)),
+    ("loss_count", |i| f64::from(i.features.loss_count)),
+    ("resolved_count", |i| {
+        f64::from(i.features.win_count + i.features.loss_count)
+    }),
+    ("total_pnl", |i| i.features.total_pnl),
+    ("cashflow_pnl", |i| i.features.cashflow_pnl),
+    ("avg_position_size", |i| i.features.avg_position_size),
+    ("unique_markets", |i| f64::from(i.features.unique_markets)),
+    ("profitable_markets", |i| {
+        f64::from(i.features.profitable_markets)
+    }),
+    ("avg_hold_time_hours", |i| i.features.avg_hold_time_hours),
+    ("max_drawdown_pct", |i| i.features.max_drawdown_pct),
+    ("trades_per_week", |i| i.features.trades_per_week),
+    ("trades_per_day", |i| i.features.trades_per_day),
+    ("sharpe_ratio", |i| i.features.sharpe_ratio),
+    ("active_positions", |i| {
+        f64::from(i.features.active_positions)
+    }),
+    ("concentration_ratio", |i| i.features.concentration_ratio),
+    ("avg_trade_size_usdc", |i| i.features.avg_trade_size_usdc),
+    ("size_cv", |i| i.features.size_cv),
+    ("buy_sell_balance", |i| i.features.buy_sell_balance),
+    ("mid_fill_ratio", |i| i.features.mid_fill_ratio),
+    ("extreme_price_ratio", |i| i.features.extreme_price_ratio),
+    ("burstiness_top_1h_ratio", |i| {
+        i.features.burstiness_top_1h_ratio
+    }),
+    ("top_domain_ratio", |i| i.features.top_domain_ratio),
+];
+
+fn feature(name: &str) -> Option<FeatureFn> {
+    FEATURES.iter().find(|(n, _)| *n == name).map(|(_, f)| *f)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RuleKind {
+    /// Matching wallets are excluded; `name` becomes the `wallet_exclusions.reason`.
+    Exclude,
+    /// Matching wallets get persona `name` in `wallet_personas`.
+    Followable,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum Op {
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Gte,
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Lte,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Condition {
+    pub feature: String,
+    pub op: Op,
+    pub value: f64,
+}
+
+impl Condition {
+    fn holds(&self, inputs: &RuleInputs<'_>) -> bool {
+        let actual = feature(&self.feature).map_or(f64::NAN, |f| f(inputs));
+        match self.op {
+            Op::Gt => actual > self.value,
+            Op::Gte => actual >= self.value,
+            Op::Lt => actual < self.value,
+            Op::Lte => actual <= self.value,
+        }
+    }
+}
+
+/// `base + Σ weight × feature`, clamped to 0..=1. Omitted on a followable rule = win rate.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfidenceFormula {
+    #[serde(default)]
+    pub base: f64,
+    #[serde(default)]
+    pub weights: BTreeMap<String, f64>,
+}
+
+impl ConfidenceFormula {
+    fn evaluate(&self, inputs: &RuleInputs<'_>) -> f64 {
+        let sum: f64 = self
+            .weights
+            .iter()
+            .map(|(name, w)| w * feature(name).map_or(0.0, |f| f(inputs)))
+            .sum();
+        (self.base + sum).clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PersonaRule {
+    pub name: String,
+    pub kind: RuleKind,
+    pub when: Vec<Condition>,
+    #[serde(default)]
+    pub confidence: Option<ConfidenceFormula>,
+}
+
+/// A rule whose conditions hold, with the values recorded alongside the classification.
+#[derive(Debug, Clone)]
+pub struct RuleMatch<'a> {
+    pub name: &'a str,
+    pub kind: RuleKind,
+    pub confidence: f64,
+    /// Actual value and threshold of the rule's first condition (for `wallet_exclusions`).
+    pub metric_value: f64,
+    pub threshold: f64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PersonaRules {
+    #[serde(rename = "rule", default)]
+    pub rules: Vec<PersonaRule>,
+}
+
+impl PersonaRules {
+    /// Load and validate a rules file.
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading persona rules file {}", path.display()))?;
+        Self::from_toml_str(&text)
+            .with_context(|| format!("invalid persona rules file {}", path.display()))
+    }
+
+    pub fn from_toml_str(text: &str) -> Result<Self> {
+        let rules: Self = toml::from_str(text)?;
+        rules.validate()?;
+        Ok(rules)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.rules.is_empty() {
+            bail!("no [[rule]] entries");
+        }
+        let mut seen = HashSet::new();
+        for rule in &self.rules {
+            let name = rule.name.as_str();
+            if name.is_empty()
+                || !name
+                    .chars()
+                    .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+            {
+                bail!("rule name {name:?} must be SCREAMING_SNAKE_CASE");
+            }
+            if name.starts_with("STAGE1_") {
+                bail!("rule {name}: the STAGE1_ prefix is reserved for Stage 1 exclusions");
+            }
+            if !seen.insert(name) {
+                bail!("duplicate rule name {name}");
+            }
+            if rule.when.is_empty() {
+                bail!("rule {name}: needs at least one condition in `when`");
+            }
+            for c in &rule.when {
+                if feature(&c.feature).is_none() {
+                    bail!("rule {name}: unknown feature {:?}", c.feature);
+                }
+                if !c.value.is_finite() {
+                    bail!("rule {name}: value for {} must be finite", c.feature);
+                }
+            }
+            match (&rule.confidence, rule.kind) {
+                (Some(_), RuleKind::Exclude) => {
+                    bail!("rule {name}: confidence only applies to followable rules");
+                }
+                (Some(formula), RuleKind::Followable) => {
+                    for (feature_name, weight) in &formula.weights {
+                        if feature(feature_name).is_none() {
+                            bail!("rule {name}: unknown confidence feature {feature_name:?}");
+                        }
+                        if !weight.is_finite() {
+                            bail!("rule {name}: weight for {feature_name} must be finite");
+                        }
+                    }
+                }
+                (None, _) => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Names of the rules of `kind`, in file order.
+    pub fn names(&self, kind: RuleKind) -> impl Iterator<Item = &str> {
+        self.rules
+            .iter()
+            .filter(move |r| r.kind == kind)
+            .map(|r| r.name.as_str())
+    }
+
+    /// Rules (in file order) whose conditions all hold; the caller takes the first it accepts.
+    pub fn matches<'a>(
+        &'a self,
+        inputs: &'a RuleInputs<'_>,
+    ) -> impl Iterator<Item = RuleMatch<'a>> + 'a {
+        self.rules
+            .iter()
+            .filter(|r| r.when.iter().all(|c| c.holds(inputs)))
+            .map(|rule| {
+                let first = &rule.when[0];
+                let confidence = rule
+                    .confidence
+                    .as_ref()
+                    .map_or(inputs.win_rate, |f| f.evaluate(inputs));
+                RuleMatch {
+                    name: &rule.name,
+                    kind: rule.kind,
+                    confidence,
+                    metric_value: feature(&first.feature).map_or(0.0, |f| f(inputs)),
+                    threshold: first.value,
+                }
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features() -> WalletFeatures {
+        WalletFeatures {
+            proxy_wallet: "0xabc".to_string(),
+            window_days: 30,
+            trade_count: 40,
+            win_count: 28,
+            loss_count: 12,
+            total_pnl: 500.0,
+            avg_position_size: 200.0,
+            unique_markets: 5,
+            avg_hold_time_hours: 24.0,
+            max_drawdown_pct: 8.0,
+            trades_per_week: 10.0,
+            trades_per_day: 10.0 / 7.0,
+            sharpe_ratio: 1.5,
+            active_positions: 3,
+            concentration_ratio: 0.75,
+            avg_trade_size_usdc: 200.0,
+            size_cv: 0.0,
+            buy_sell_balance: 0.0,
+            mid_fill_ratio: 0.0,
+            extreme_price_ratio: 0.0,
+            burstiness_top_1h_ratio: 0.0,
+            top_domain: None,
+            top_domain_ratio: 0.0,
+            profitable_markets: 3,
+            wash_trade_score: 0.0,
+            cashflow_pnl: 100.0,
+            fifo_realized_pnl: 0.0,
+            unrealized_pnl: 0.0,
+            open_positions_count: 0,
+        }
+    }
+
+    fn inputs(f: &WalletFeatures) -> RuleInputs<'_> {
+        RuleInputs {
+            features: f,
+            wallet_age_days: 90,
+            win_rate: 0.7,
+            roi: 0.0125,
+            loss_ratio: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_matches_yields_rules_in_file_order() {
+        let rules = PersonaRules::from_toml_str(
+            r#"
+            [[rule]]
+            name = "NOISE_TRADER"
+            kind = "exclude"
+            when = [{ feature = "trades_per_week", op = ">", value = 50.0 }]
+
+            [[rule]]
+            name = "FOCUSED_WINNER"
+            kind = "followable"
+            when = [
+              { feature = "concentration_ratio", op = ">=", value = 0.6 },
+              { feature = "win_rate", op = ">=", value = 0.6 },
+            ]
+            confidence = { base = 0.1, weights = { win_rate = 0.5 } }
+
+            [[rule]]
+            name = "ANYONE"
+            kind = "followable"
+            when = [{ feature = "trade_count", op = ">", value = 0.0 }]
+            "#,
+        )
+        .unwrap();
+
+        let f = features();
+        let i = inputs(&f);
+        let m = rules.matches(&i).next().unwrap();
+        assert_eq!(m.name, "FOCUSED_WINNER");
+        assert_eq!(m.kind, RuleKind::Followable);
+        assert!((m.confidence - 0.45).abs() < 1e-9);
+        assert!((m.metric_value - 0.75).abs() < 1e-9);
+
+        let mut noisy = features();
+        noisy.trades_per_week = 80.0;
+        let i = inputs(&noisy);
+        let m = rules.matches(&i).next().unwrap();
+        assert_eq!(m.name, "NOISE_TRADER");
+        assert!((m.threshold - 50.0).abs() < 1e-9);
+
+        let mut scattered = features();
+        scattered.concentration_ratio = 0.2;
+        let i = inputs(&scattered);
+        let m = rules.matches(&i).next().unwrap();
+        assert_eq!(m.name, "ANYONE");
+        // No formula: confidence is the win rate.
+        assert!((m.confidence - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_invalid_rules() {
+        let rule = |body: &str| format!("[[rule]]\n{body}\n");
+        let cases = [
+            (String::new(), "no [[rule]]"),
+            (
+                rule(
+                    r#"name = "X"
+kind = "exclude"
+when = [{ feature = "vibes", op = ">", value = 1.0 }]"#,
+                ),
+                "unknown feature",
+            ),
+            (
+                rule(
+                    r#"name = "STAGE1_X"
+kind = "exclude"
+when = [{ feature = "roi", op = "<", value = 0.0 }]"#,
+                ),
+                "reserved",
+            ),
+            (
+                rule(
+                    r#"name = "X"
+kind = "followable"
+when = []"#,
+                ),
+                "at least one condition",
+            ),
+            (
+                rule(
+                    r#"name = "X"
+kind = "exclude"
+when = [{ feature = "roi", op = "<", value = 0.0 }]
+confidence = { base = 1.0 }"#,
+                ),
+                "only applies to followable",
+            ),
+            (
+                format!(
+                    "{0}{0}",
+                    rule(
+                        r#"name = "X"
+kind = "exclude"
+when = [{ feature = "roi", op = "<", value = 0.0 }]"#
+                    )
+                ),
+                "duplicate",
+            ),
+            (
+                rule(
+                    r#"name = "X"
+kind = "exclude"
+when = [{ feature = "roi", op = "==", value = 0.0 }]"#,
+                ),
+                "unknown variant",
+            ),
+        ];
+        for (text, expected) in cases {
+            let err = PersonaRules::from_toml_str(&text).unwrap_err();
+            assert!(
+                format!("{err:#}").contains(expected),
+                "expected {expected:?} in {err:#} for:\n{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_example_rules_file_is_valid() {
+        let rules =
+            PersonaRules::from_toml_str(include_str!("../../../config/personas.example.toml"))
+                .unwrap();
+        assert!(rules
+            .rules
+            .iter()
+            .any(|r| r.kind == RuleKind::Followable && r.name == "INFORMED_SPECIALIST"));
+    }
+}
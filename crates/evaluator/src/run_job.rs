@@ -0,0 +1,185 @@
+use anyhow::Result;
+use common::config::Config;
+use common::db::AsyncDb;
+use common::polymarket::PolymarketClient;
+use std::sync::Arc;
+
+use crate::jobs;
+
+/// Jobs that `evaluator run-job <name>` can execute once (scheduler names).
+pub const JOB_NAMES: &[&str] = &[
+    "event_scoring",
+    "wallet_discovery",
+    "leaderboard_discovery",
+    "trades_ingestion",
+    "activity_ingestion",
+    "positions_snapshot",
+    "holders_snapshot",
+    "wallet_rules",
+    "wallet_scoring",
+    "persona_classification",
+    "persona_drift",
+    "paper_settlement",
+    "wal_checkpoint",
+    "wal_size_check",
+    "flow_metrics",
+    "sqlite_stats",
+    "trader_promotion",
+    "wallet_profiles",
+];
+
+pub fn is_known_job(name: &str) -> bool {
+    JOB_NAMES.contains(&name)
+}
+
+/// Run a single job once with the same arguments the scheduler uses, without the event bus.
+/// Returns a one-line summary of the job's result for printing.
+pub async fn run_job_once(db: &AsyncDb, cfg: &Config, name: &str) -> Result<String> {
+    let api = Arc::new(PolymarketClient::from_config(cfg));
+    let wallets = cfg.ingestion.wallets_per_ingestion_run;
+    let concurrency =
+        jobs::IngestionConcurrency::new(cfg.ingestion.parallel_tasks, cfg.ingestion.max_in_flight);
+
+    let summary = match name {
+        "event_scoring" => {
+            let n = jobs::run_event_scoring_once(db, api.as_ref(), cfg, None).await?;
+            format!("inserted={n}")
+        }
+        "wallet_discovery" => {
+            let n =
+                jobs::run_wallet_discovery_once(db, api.as_ref(), api.as_ref(), cfg, None).await?;
+            format!("inserted={n}")
+        }
+        "leaderboard_discovery" => {
+            let n = jobs::run_leaderboard_discovery_once(db, api.as_ref(), cfg).await?;
+            format!("inserted={n}")
+        }
+        "trades_ingestion" => {
+            let (pages, inserted) = jobs::run_trades_ingestion_once(
+                db,
+                api,
+                200,
+                wallets,
+                &concurrency,
+                cfg.ingestion.max_wallet_ingestion_failures,
+                None,
+            )
+            .await?;
+            format!("pages={pages} inserted={inserted}")
+        }
+        "activity_ingestion" => {
+            let n = jobs::run_activity_ingestion_once(db, api, 200, wallets, &concurrency).await?;
+            format!("inserted={n}")
+        }
+        "positions_snapshot" => {
+            let n = jobs::run_positions_snapshot_once(db, api, 200, wallets, &concurrency).await?;
+            format!("inserted={n}")
+        }
+        "holders_snapshot" => {
+            let n = jobs::run_holders_snapshot_once(
+                db,
+                api.as_ref(),
+                cfg.wallet_discovery.holders_per_market as u32,
+                cfg.wallet_discovery.holders_snapshot_top_markets,
+                cfg.wallet_discovery.holders_snapshot_max_score_age_days,
+                cfg.wallet_discovery.holder_flow_min_change_pct,
+                &concurrency.global,
+            )
+            .await?;
+            format!("inserted={n}")
+        }
+        "wallet_rules" => {
+            let n = jobs::run_wallet_rules_once(db, cfg, None).await?;
+            format!("changed={n}")
+        }
+        "wallet_scoring" => {
+            let n = jobs::run_wallet_scoring_once(db, cfg, None).await?;
+            format!("inserted={n}")
+        }
+        "persona_classification" => {
+            let n = jobs::run_persona_classification_once(db, cfg, None, None).await?;
+            format!("classified={n}")
+        }
+        "persona_drift" => {
+            let n = jobs::run_persona_drift_once(db, cfg, None).await?;
+            format!("changed={n}")
+        }
+        "paper_settlement" => {
+            let n = jobs::run_paper_settlement_once(
+                db,
+                api.as_ref(),
+                cfg.paper_trading.settlement_batch_size,
+            )
+            .await?;
+            format!("settled={n}")
+        }
+        "wal_checkpoint" => {
+            let (log, checkpointed) = jobs::run_wal_checkpoint_once(db).await?;
+            format!("log={log} checkpointed={checkpointed}")
+        }
+        "wal_size_check" => {
+            match jobs::run_wal_size_check_once(
+                db,
+                &cfg.database.path,
+                cfg.database.wal_checkpoint_max_bytes,
+            )
+            .await?
+            {
+                Some((log, checkpointed)) => format!("log={log} checkpointed={checkpointed}"),
+                None => "below limit".to_string(),
+            }
+        }
+        "flow_metrics" => {
+            let rules = crate::persona_classification::PersonaConfig::load(&cfg.personas)?.rules;
+            jobs::run_flow_metrics_once(db, rules).await?;
+            "ok".to_string()
+        }
+        "sqlite_stats" => {
+            jobs::run_sqlite_stats_once(db, &cfg.database.path).await?;
+            "ok".to_string()
+        }
+        "trader_promotion" => {
+            let n = jobs::run_trader_promotion_once(db, cfg).await?;
+            format!("promoted={n}")
+        }
+        "wallet_profiles" => {
+            let n = jobs::run_wallet_profiles_once(db, api.as_ref(), cfg).await?;
+            format!("stored={n}")
+        }
+        other => anyhow::bail!("unknown job: {other} (known: {})", JOB_NAMES.join(", ")),
+    };
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_run_job_once_runs_db_only_job() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let cfg = Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
+        let summary = run_job_once(&db, &cfg, "wallet_scoring").await.unwrap();
+        assert_eq!(summary, "inserted=0");
+    }
+
+    #[tokio::test]
+    async fn test_run_job_once_runs_wal_size_check() {
+        let tmp = tempfile::NamedTempFile::new().unwrap();
+        let db = AsyncDb::open(tmp.path().to_str().unwrap()).await.unwrap();
+        let mut cfg = Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
+        cfg.database.path = tmp.path().to_str().unwrap().to_string();
+        cfg.database.wal_checkpoint_max_bytes = u64::MAX;
+        assert!(is_known_job("wal_size_check"));
+        let summary = run_job_once(&db, &cfg, "wal_size_check").await.unwrap();
+        assert_eq!(summary, "below limit");
+    }
+
+    #[tokio::test]
+    async fn test_run_job_once_rejects_unknown_job() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let cfg = Config::from_toml_str(include_str!("../../../config/default.toml")).unwrap();
+        let err = run_job_once(&db, &cfg, "nope").await.unwrap_err();
+        assert!(err.to_string().contains("unknown job"));
+    }
+}
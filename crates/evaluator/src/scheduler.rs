@@ -1,7 +1,9 @@
+use common::db::AsyncDb;
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::{Instant, MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
 
 #[derive(Debug, Clone)]
 pub struct JobSpec {
@@ -11,10 +13,15 @@ pub struct JobSpec {
     pub run_immediately: bool,
 }
 
+/// Spawn one timer task per job. With `db`, each job's interval and next tick are written to
+/// `job_status` (at start and after every tick) so the dashboard can show a countdown.
+/// The write runs in the background after the tick is sent, so a busy DB thread never delays
+/// scheduling; a tick whose previous write is still queued skips its own.
 #[allow(dead_code)]
-pub fn start(jobs: Vec<JobSpec>) -> Vec<JoinHandle<()>> {
+pub fn start(jobs: Vec<JobSpec>, db: Option<&AsyncDb>) -> Vec<JoinHandle<()>> {
     jobs.into_iter()
         .map(|job| {
+            let db = db.cloned();
             tokio::spawn(async move {
                 let start_at = if job.run_immediately {
                     Instant::now()
@@ -23,33 +30,94 @@ pub fn start(jobs: Vec<JobSpec>) -> Vec<JoinHandle<()>> {
                 };
                 let mut interval = tokio::time::interval_at(start_at, job.interval);
                 interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                // A job that runs immediately records its countdown on that first tick.
+                let mut pending_write = db.as_ref().filter(|_| !job.run_immediately).map(|db| {
+                    tokio::spawn(record_next_run(
+                        db.clone(),
+                        job.clone(),
+                        next_run_at(job.interval),
+                    ))
+                });
 
                 loop {
                     interval.tick().await;
                     tracing::debug!(job = %job.name, "scheduler tick");
+                    let next = next_run_at(job.interval);
                     if job.tick.send(()).await.is_err() {
                         break;
                     }
+                    if let Some(db) = &db {
+                        if pending_write.as_ref().is_none_or(JoinHandle::is_finished) {
+                            pending_write =
+                                Some(tokio::spawn(record_next_run(db.clone(), job.clone(), next)));
+                        }
+                    }
                 }
             })
         })
         .collect()
 }
 
+/// Wall-clock time `until_next` from now, as stored in `job_status.next_run_at`.
+fn next_run_at(until_next: Duration) -> Option<String> {
+    chrono::Duration::from_std(until_next)
+        .ok()
+        .and_then(|d| chrono::Utc::now().checked_add_signed(d))
+        .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+}
+
+/// Upsert the job's interval and wall-clock next tick into `job_status`.
+/// Best-effort: a failed write only loses the countdown, never the tick.
+async fn record_next_run(db: AsyncDb, job: JobSpec, next_run_at: Option<String>) {
+    let name = job.name.clone();
+    let interval_secs = i64::try_from(job.interval.as_secs()).unwrap_or(i64::MAX);
+    let res = db
+        .call_named("scheduler.record_next_run", move |conn| {
+            conn.execute(
+                "INSERT INTO job_status (job_name, status, interval_secs, next_run_at, updated_at)
+                 VALUES (?1, 'idle', ?2, ?3, datetime('now'))
+                 ON CONFLICT(job_name) DO UPDATE SET
+                    interval_secs = excluded.interval_secs,
+                    next_run_at = excluded.next_run_at",
+                rusqlite::params![name, interval_secs, next_run_at],
+            )?;
+            Ok(())
+        })
+        .await;
+    if let Err(e) = res {
+        tracing::warn!(job = %job.name, error = %e, "failed to record scheduler next run");
+    }
+}
+
+/// Wait for the next tick on a worker's channel.
+/// Returns false once `shutdown` is cancelled or the channel closes, so the loop stops
+/// taking new work; a job already running is never interrupted.
+pub async fn next_tick(rx: &mut mpsc::Receiver<()>, shutdown: &CancellationToken) -> bool {
+    tokio::select! {
+        biased;
+        () = shutdown.cancelled() => false,
+        tick = rx.recv() => tick.is_some(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rusqlite::OptionalExtension;
     use tokio::sync::mpsc;
 
     #[tokio::test(start_paused = true)]
     async fn test_scheduler_fires_jobs_at_intervals() {
         let (tx, mut rx) = mpsc::channel(16);
-        let _handles = start(vec![JobSpec {
-            name: "job1".to_string(),
-            interval: Duration::from_secs(10),
-            tick: tx,
-            run_immediately: false,
-        }]);
+        let _handles = start(
+            vec![JobSpec {
+                name: "job1".to_string(),
+                interval: Duration::from_secs(10),
+                tick: tx,
+                run_immediately: false,
+            }],
+            None,
+        );
 
         // Ensure spawned task is polled at least once so it registers its timer.
         tokio::task::yield_now().await;
@@ -74,14 +142,80 @@ mod tests {
     #[tokio::test(start_paused = true)]
     async fn test_scheduler_can_fire_immediately() {
         let (tx, mut rx) = mpsc::channel(16);
-        let _handles = start(vec![JobSpec {
-            name: "job1".to_string(),
-            interval: Duration::from_secs(10),
-            tick: tx,
-            run_immediately: true,
-        }]);
+        let _handles = start(
+            vec![JobSpec {
+                name: "job1".to_string(),
+                interval: Duration::from_secs(10),
+                tick: tx,
+                run_immediately: true,
+            }],
+            None,
+        );
 
         tokio::task::yield_now().await;
         assert!(rx.try_recv().is_ok()); // t=0 initial tick
     }
+
+    #[tokio::test]
+    async fn test_scheduler_records_interval_and_next_run() {
+        let db = AsyncDb::open(":memory:").await.unwrap();
+        let (tx, mut rx) = mpsc::channel(16);
+        let _handles = start(
+            vec![JobSpec {
+                name: "job1".to_string(),
+                interval: Duration::from_secs(600),
+                tick: tx,
+                run_immediately: true,
+            }],
+            Some(&db),
+        );
+        rx.recv().await.unwrap();
+
+        // The countdown is written in the background after the tick.
+        let mut recorded = None;
+        for _ in 0..100 {
+            recorded = db
+                .call(|conn| {
+                    Ok(conn
+                        .query_row(
+                            "SELECT interval_secs, next_run_at FROM job_status WHERE job_name = 'job1'",
+                            [],
+                            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)),
+                        )
+                        .optional()?)
+                })
+                .await
+                .unwrap();
+            if recorded.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let (interval_secs, next_run_at) = recorded.expect("next run should be recorded");
+        assert_eq!(interval_secs, 600);
+        let next = chrono::NaiveDateTime::parse_from_str(&next_run_at, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_utc();
+        let until = (next - chrono::Utc::now()).num_seconds();
+        assert!((590..=600).contains(&until), "next run in {until}s");
+    }
+
+    #[tokio::test]
+    async fn test_next_tick_stops_after_shutdown_even_with_pending_ticks() {
+        let (tx, mut rx) = mpsc::channel(16);
+        let shutdown = CancellationToken::new();
+        tx.send(()).await.unwrap();
+        assert!(next_tick(&mut rx, &shutdown).await);
+
+        tx.send(()).await.unwrap();
+        shutdown.cancel();
+        assert!(!next_tick(&mut rx, &shutdown).await);
+    }
+
+    #[tokio::test]
+    async fn test_next_tick_false_when_channel_closed() {
+        let (tx, mut rx) = mpsc::channel::<()>(1);
+        drop(tx);
+        assert!(!next_tick(&mut rx, &CancellationToken::new()).await);
+    }
 }
@@ -67,10 +67,40 @@ pub fn discover_wallets_for_market(
     out
 }
 
+/// Data API `/holders` returns at most 20 holders per token; asking for more only burns budget.
+pub const HOLDERS_PER_MARKET_MAX: u32 = 20;
+
+/// Clamp a configured discovery limit to `max`, warning when the config asked for more.
+pub fn clamp_discovery_limit(name: &str, configured: usize, max: u32) -> u32 {
+    match u32::try_from(configured) {
+        Ok(v) if v <= max => v,
+        _ => {
+            tracing::warn!(
+                limit = name,
+                configured,
+                clamped_to = max,
+                "discovery limit exceeds safe maximum; clamping"
+            );
+            max
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_clamp_discovery_limit() {
+        assert_eq!(clamp_discovery_limit("holders_per_market", 10, 20), 10);
+        assert_eq!(clamp_discovery_limit("holders_per_market", 20, 20), 20);
+        assert_eq!(clamp_discovery_limit("holders_per_market", 5000, 20), 20);
+        assert_eq!(
+            clamp_discovery_limit("holders_per_market", usize::MAX, 20),
+            20
+        );
+    }
+
     #[test]
     fn test_discover_wallets_for_market_dedup_and_filter() {
         // Two holders; one of them also appears in trades.
@@ -30,6 +30,9 @@ pub struct WalletFeatures {
     pub top_domain_ratio: f64,
     /// Number of markets where total FIFO-paired PnL > 0.
     pub profitable_markets: u32,
+    /// Share of volume in quick BUY/SELL round trips on the same market (within
+    /// `WASH_TRADE_WINDOW_SECS`), scaled by `buy_sell_balance`. Near 1.0 = manufactured volume.
+    pub wash_trade_score: f64,
 
     // RENAMED (breaking change)
     /// Cashflow PnL: total sell proceeds minus total buy costs.
@@ -45,6 +48,66 @@ pub struct WalletFeatures {
     pub open_positions_count: u32,
 }
 
+/// One FIFO-paired round trip: when it closed, its PnL, and the capital it tied up (buy cost).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TradeOutcome {
+    pub closed_at: i64,
+    pub pnl: f64,
+    pub cost: f64,
+}
+
+/// Opposite-side trades on the same market this close together count as a wash round trip.
+const WASH_TRADE_WINDOW_SECS: i64 = 300;
+
+/// Fraction of traded size (0..=1) matched into BUY/SELL round trips on the same market within
+/// `WASH_TRADE_WINDOW_SECS`. Input rows are (condition_id, side, size, timestamp), sorted by
+/// condition_id then timestamp; each trade is matched FIFO against the opposite side's recent
+/// unmatched size.
+fn quick_round_trip_ratio(trades: &[(String, String, f64, i64)]) -> f64 {
+    use std::collections::VecDeque;
+
+    let total: f64 = trades.iter().map(|t| t.2).sum();
+    if total <= 0.0 {
+        return 0.0;
+    }
+    let mut matched = 0.0;
+    for market in trades.chunk_by(|a, b| a.0 == b.0) {
+        // Unmatched (remaining size, timestamp) per side.
+        let mut buys: VecDeque<(f64, i64)> = VecDeque::new();
+        let mut sells: VecDeque<(f64, i64)> = VecDeque::new();
+        for (_, side, size, ts) in market {
+            let (own, other) = if side == "BUY" {
+                (&mut buys, &mut sells)
+            } else {
+                (&mut sells, &mut buys)
+            };
+            while other
+                .front()
+                .is_some_and(|(_, t)| ts - t > WASH_TRADE_WINDOW_SECS)
+            {
+                other.pop_front();
+            }
+            let mut remaining = *size;
+            while remaining > 0.0 {
+                let Some(front) = other.front_mut() else {
+                    break;
+                };
+                let m = remaining.min(front.0);
+                matched += 2.0 * m;
+                remaining -= m;
+                front.0 -= m;
+                if front.0 <= 0.0 {
+                    other.pop_front();
+                }
+            }
+            if remaining > 0.0 {
+                own.push_back((remaining, *ts));
+            }
+        }
+    }
+    (matched / total).min(1.0)
+}
+
 /// Represents an open position (unmatched buys) in a single market
 #[derive(Debug, Clone)]
 struct OpenPosition {
@@ -62,6 +125,8 @@ struct PairedStats {
     hold_seconds: Vec<f64>,
     /// (timestamp of close, pnl) for daily series
     closed_pnls: Vec<(i64, f64)>,
+    /// Per round-trip outcomes including cost, for recency-weighted edge
+    outcomes: Vec<TradeOutcome>,
     /// Number of markets where total paired PnL > 0
     profitable_markets: u32,
 
@@ -76,7 +141,12 @@ struct PairedStats {
 /// hold time per position, and closed PnLs for drawdown/Sharpe.
 type MarketBuysSells = (Vec<(f64, f64, i64)>, Vec<(f64, f64, i64)>);
 
-fn paired_trade_stats(conn: &Connection, proxy_wallet: &str, cutoff: i64) -> Result<PairedStats> {
+fn paired_trade_stats(
+    conn: &Connection,
+    proxy_wallet: &str,
+    cutoff: i64,
+    until: i64,
+) -> Result<PairedStats> {
     #[derive(Debug)]
     struct Trade {
         condition_id: String,
@@ -90,10 +160,10 @@ fn paired_trade_stats(conn: &Connection, proxy_wallet: &str, cutoff: i64) -> Res
         .prepare(
             "SELECT condition_id, side, size, price, timestamp
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3
              ORDER BY condition_id, timestamp",
         )?
-        .query_map(rusqlite::params![proxy_wallet, cutoff], |row| {
+        .query_map(rusqlite::params![proxy_wallet, cutoff, until], |row| {
             Ok(Trade {
                 condition_id: row.get(0)?,
                 side: row.get(1)?,
@@ -108,6 +178,7 @@ fn paired_trade_stats(conn: &Connection, proxy_wallet: &str, cutoff: i64) -> Res
     let mut losses = 0u32;
     let mut hold_seconds: Vec<f64> = Vec::new();
     let mut closed_pnls: Vec<(i64, f64)> = Vec::new();
+    let mut outcomes: Vec<TradeOutcome> = Vec::new();
     let mut total_fifo_realized_pnl = 0.0;
     let mut open_positions_vec: Vec<OpenPosition> = Vec::new();
 
@@ -165,6 +236,11 @@ fn paired_trade_stats(conn: &Connection, proxy_wallet: &str, cutoff: i64) -> Res
                 }
                 hold_seconds.push((sell_ts - buy_ts) as f64);
                 closed_pnls.push((sell_ts, pnl));
+                outcomes.push(TradeOutcome {
+                    closed_at: sell_ts,
+                    pnl,
+                    cost: buy_price * matched_size,
+                });
 
                 remaining_buy_qty -= matched_size;
                 remaining_sell_qty -= matched_size;
@@ -233,6 +309,7 @@ fn paired_trade_stats(conn: &Connection, proxy_wallet: &str, cutoff: i64) -> Res
         losses,
         hold_seconds,
         closed_pnls,
+        outcomes,
         profitable_markets,
         total_fifo_realized_pnl,
         open_positions: open_positions_vec,
@@ -347,27 +424,27 @@ pub fn compute_wallet_features(
     let cutoff = now_epoch - i64::from(window_days) * 86400;
 
     let trade_count: u32 = conn.query_row(
-        "SELECT COUNT(*) FROM trades_raw WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-        rusqlite::params![proxy_wallet, cutoff],
+        "SELECT COUNT(*) FROM trades_raw WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+        rusqlite::params![proxy_wallet, cutoff, now_epoch],
         |row| row.get(0),
     )?;
 
     let unique_markets: u32 = conn.query_row(
-        "SELECT COUNT(DISTINCT condition_id) FROM trades_raw WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-        rusqlite::params![proxy_wallet, cutoff],
+        "SELECT COUNT(DISTINCT condition_id) FROM trades_raw WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+        rusqlite::params![proxy_wallet, cutoff, now_epoch],
         |row| row.get(0),
     )?;
 
     // Win/loss and hold times from actual per-position PnL (BUY-SELL pairing, FIFO per market).
-    let paired = paired_trade_stats(conn, proxy_wallet, cutoff)?;
+    let paired = paired_trade_stats(conn, proxy_wallet, cutoff, now_epoch)?;
     let win_count = paired.wins;
     let loss_count = paired.losses;
 
     let avg_position_size: f64 = conn
         .query_row(
             "SELECT COALESCE(AVG(size * price), 0.0) FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-            rusqlite::params![proxy_wallet, cutoff],
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| row.get(0),
         )
         .unwrap_or(0.0);
@@ -379,8 +456,8 @@ pub fn compute_wallet_features(
                 COALESCE(SUM(CASE WHEN side = 'BUY' THEN size * price ELSE 0.0 END), 0.0),
                 COALESCE(SUM(CASE WHEN side = 'SELL' THEN size * price ELSE 0.0 END), 0.0)
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-            rusqlite::params![proxy_wallet, cutoff],
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .unwrap_or((0.0, 0.0));
@@ -442,7 +519,7 @@ pub fn compute_wallet_features(
             "WITH market_volumes AS (
                 SELECT condition_id, SUM(size) as volume
                 FROM trades_raw
-                WHERE proxy_wallet = ?1 AND timestamp >= ?2
+                WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3
                 GROUP BY condition_id
             ),
             total AS (
@@ -455,7 +532,7 @@ pub fn compute_wallet_features(
             SELECT 
                 CASE WHEN t.total_volume > 0 THEN CAST(t3.top3_volume AS REAL) / t.total_volume ELSE 0.0 END
             FROM total t, top3 t3",
-            rusqlite::params![proxy_wallet, cutoff],
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| row.get(0),
         )
         .unwrap_or(0.0);
@@ -466,8 +543,8 @@ pub fn compute_wallet_features(
         .query_row(
             "SELECT COALESCE(AVG(size * price), 0.0), COALESCE(AVG((size * price) * (size * price)), 0.0)
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-            rusqlite::params![proxy_wallet, cutoff],
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .unwrap_or((0.0, 0.0));
@@ -484,8 +561,8 @@ pub fn compute_wallet_features(
                 COALESCE(SUM(CASE WHEN side = 'BUY' THEN 1 ELSE 0 END), 0),
                 COALESCE(SUM(CASE WHEN side = 'SELL' THEN 1 ELSE 0 END), 0)
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-            rusqlite::params![proxy_wallet, cutoff],
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .unwrap_or((0, 0));
@@ -505,8 +582,8 @@ pub fn compute_wallet_features(
                     ELSE 0.0
                 END
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-            rusqlite::params![proxy_wallet, cutoff],
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| row.get(0),
         )
         .unwrap_or(0.0);
@@ -519,8 +596,8 @@ pub fn compute_wallet_features(
                     ELSE 0.0
                 END
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2",
-            rusqlite::params![proxy_wallet, cutoff],
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3",
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| row.get(0),
         )
         .unwrap_or(0.0);
@@ -529,10 +606,12 @@ pub fn compute_wallet_features(
         .prepare(
             "SELECT timestamp
              FROM trades_raw
-             WHERE proxy_wallet = ?1 AND timestamp >= ?2
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3
              ORDER BY timestamp ASC",
         )?
-        .query_map(rusqlite::params![proxy_wallet, cutoff], |row| row.get(0))?
+        .query_map(rusqlite::params![proxy_wallet, cutoff, now_epoch], |row| {
+            row.get(0)
+        })?
         .collect::<std::result::Result<Vec<_>, _>>()?;
     let burstiness_top_1h_ratio = if ts_rows.is_empty() {
         0.0
@@ -551,6 +630,19 @@ pub fn compute_wallet_features(
         best as f64 / ts_rows.len() as f64
     };
 
+    let side_rows: Vec<(String, String, f64, i64)> = conn
+        .prepare(
+            "SELECT condition_id, side, size, timestamp
+             FROM trades_raw
+             WHERE proxy_wallet = ?1 AND timestamp >= ?2 AND timestamp <= ?3
+             ORDER BY condition_id, timestamp",
+        )?
+        .query_map(rusqlite::params![proxy_wallet, cutoff, now_epoch], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let wash_trade_score = quick_round_trip_ratio(&side_rows) * buy_sell_balance;
+
     // Domain = Polymarket category (Sports, Politics, Crypto). See STRATEGY_BIBLE §Domain hierarchy.
     let top_domain_row: Option<(String, f64)> = conn
         .query_row(
@@ -559,7 +651,7 @@ pub fn compute_wallet_features(
                 SELECT COALESCE(m.category, 'unknown') AS domain, SUM(tr.size) AS volume
                 FROM trades_raw tr
                 LEFT JOIN markets m ON m.condition_id = tr.condition_id
-                WHERE tr.proxy_wallet = ?1 AND tr.timestamp >= ?2
+                WHERE tr.proxy_wallet = ?1 AND tr.timestamp >= ?2 AND tr.timestamp <= ?3
                 GROUP BY COALESCE(m.category, 'unknown')
             ),
             total AS (
@@ -572,7 +664,7 @@ pub fn compute_wallet_features(
             ORDER BY dv.volume DESC
             LIMIT 1
             ",
-            rusqlite::params![proxy_wallet, cutoff],
+            rusqlite::params![proxy_wallet, cutoff, now_epoch],
             |row| Ok((row.get(0)?, row.get(1)?)),
         )
         .optional()?;
@@ -606,6 +698,7 @@ pub fn compute_wallet_features(
         top_domain,
         top_domain_ratio,
         profitable_markets: paired.profitable_markets,
+        wash_trade_score,
 
         // NEW AND RENAMED FIELDS
         cashflow_pnl,
@@ -615,6 +708,17 @@ pub fn compute_wallet_features(
     })
 }
 
+/// FIFO-paired round trips closed within `window_days` before `now_epoch`.
+pub fn closed_trade_outcomes(
+    conn: &Connection,
+    proxy_wallet: &str,
+    window_days: u32,
+    now_epoch: i64,
+) -> Result<Vec<TradeOutcome>> {
+    let cutoff = now_epoch - i64::from(window_days) * 86400;
+    Ok(paired_trade_stats(conn, proxy_wallet, cutoff, now_epoch)?.outcomes)
+}
+
 /// Compute all-time ROI using FIFO-paired realized PnL (closed positions only).
 /// Unrealized gains don't count - only proven profits from closed positions.
 ///
@@ -625,7 +729,7 @@ pub fn compute_wallet_features(
 /// Returns 0.0 if wallet has no trades or total_buy_cost is 0.
 pub fn compute_all_time_roi(conn: &Connection, proxy_wallet: &str) -> Result<f64> {
     // Get FIFO-paired realized PnL for ALL time (cutoff = 0)
-    let paired_stats = paired_trade_stats(conn, proxy_wallet, 0)?;
+    let paired_stats = paired_trade_stats(conn, proxy_wallet, 0, i64::MAX)?;
     let realized_pnl = paired_stats.total_fifo_realized_pnl;
 
     // Get total capital deployed (denominator for ROI)
@@ -660,7 +764,7 @@ pub fn compute_recent_pnl(
     let cutoff = now_epoch - (i64::from(window_days) * 86400);
 
     // Use FIFO-paired realized PnL in window
-    let paired_stats = paired_trade_stats(conn, proxy_wallet, cutoff)?;
+    let paired_stats = paired_trade_stats(conn, proxy_wallet, cutoff, now_epoch)?;
     Ok(paired_stats.total_fifo_realized_pnl)
 }
 
@@ -676,8 +780,9 @@ pub fn save_wallet_features(
           trades_per_week, trades_per_day, sharpe_ratio, active_positions, concentration_ratio,
           avg_trade_size_usdc, size_cv, buy_sell_balance, mid_fill_ratio, extreme_price_ratio,
           burstiness_top_1h_ratio, top_domain, top_domain_ratio, profitable_markets,
-          realized_pnl, cashflow_pnl, fifo_realized_pnl, unrealized_pnl, open_positions_count)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30)",
+          realized_pnl, cashflow_pnl, fifo_realized_pnl, unrealized_pnl, open_positions_count,
+          wash_trade_score)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25, ?26, ?27, ?28, ?29, ?30, ?31)",
         rusqlite::params![
             features.proxy_wallet,
             feature_date,
@@ -709,6 +814,7 @@ pub fn save_wallet_features(
             features.fifo_realized_pnl,
             features.unrealized_pnl,
             features.open_positions_count,
+            features.wash_trade_score,
         ],
     )?;
     Ok(())
@@ -811,7 +917,7 @@ pub async fn compute_wallet_features_with_unrealized(
         let wallet_clone2 = proxy_wallet.to_string();
         let open_positions = db
             .call_named("get_open_positions", move |conn| {
-                let stats = paired_trade_stats(conn, &wallet_clone2, cutoff)?;
+                let stats = paired_trade_stats(conn, &wallet_clone2, cutoff, now_epoch)?;
                 Ok(stats.open_positions)
             })
             .await?;
@@ -936,6 +1042,7 @@ mod tests {
             top_domain: Some("sports".to_string()),
             top_domain_ratio: 0.8,
             profitable_markets: 4,
+            wash_trade_score: 0.0,
             cashflow_pnl: 150.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -996,6 +1103,44 @@ mod tests {
         assert!(f.mid_fill_ratio >= 0.75);
     }
 
+    #[test]
+    fn test_wash_trade_score_flags_quick_round_trips() {
+        let now = 1_700_000_000i64;
+        let day = 86_400i64;
+        // Same-market BUY/SELL pairs seconds apart: the whole volume is churn.
+        let db = setup_db_with_trades(&[
+            ("0xwash", "m1", "BUY", 10.0, 0.50, now - 100),
+            ("0xwash", "m1", "SELL", 10.0, 0.50, now - 90),
+            ("0xwash", "m2", "SELL", 5.0, 0.50, now - 80),
+            ("0xwash", "m2", "BUY", 5.0, 0.50, now - 70),
+            // Directional trader: exits are days after entries.
+            ("0xreal", "m1", "BUY", 10.0, 0.40, now - 5 * day),
+            ("0xreal", "m1", "SELL", 10.0, 0.60, now - 2 * day),
+            ("0xreal", "m2", "BUY", 10.0, 0.40, now - 4 * day),
+            ("0xreal", "m2", "SELL", 10.0, 0.60, now - day),
+        ]);
+        let wash = compute_wallet_features(&db.conn, "0xwash", 30, now).unwrap();
+        assert!((wash.wash_trade_score - 1.0).abs() < 1e-9);
+        let real = compute_wallet_features(&db.conn, "0xreal", 30, now).unwrap();
+        assert_eq!(real.wash_trade_score, 0.0);
+    }
+
+    #[test]
+    fn test_quick_round_trip_ratio_partial_and_cross_market() {
+        let rows = |v: &[(&str, &str, f64, i64)]| -> Vec<(String, String, f64, i64)> {
+            v.iter()
+                .map(|(c, s, z, t)| ((*c).to_string(), (*s).to_string(), *z, *t))
+                .collect()
+        };
+        // 4 of the 10 bought are sold back within the window: 8 of 14 units matched.
+        let partial = rows(&[("m1", "BUY", 10.0, 0), ("m1", "SELL", 4.0, 60)]);
+        assert!((quick_round_trip_ratio(&partial) - 8.0 / 14.0).abs() < 1e-9);
+        // Opposite sides on different markets never match.
+        let cross = rows(&[("m1", "BUY", 10.0, 0), ("m2", "SELL", 10.0, 10)]);
+        assert_eq!(quick_round_trip_ratio(&cross), 0.0);
+        assert_eq!(quick_round_trip_ratio(&[]), 0.0);
+    }
+
     #[test]
     fn test_top_domain_ratio_computed() {
         let now = 1_700_000_000i64;
@@ -1552,12 +1697,28 @@ mod tests {
             ("0xtest", "mkt2", "SELL", 50.0, 0.55, 4000), // +2.50 realized
         ]);
 
-        let stats = paired_trade_stats(&db.conn, "0xtest", 0).unwrap();
+        let stats = paired_trade_stats(&db.conn, "0xtest", 0, i64::MAX).unwrap();
 
         // Total realized: 16.00 + 2.50 = 18.50
         assert!((stats.total_fifo_realized_pnl - 18.50).abs() < 0.01);
     }
 
+    #[test]
+    fn test_closed_trade_outcomes_records_cost_and_close_time() {
+        let db = setup_db_with_trades(&[
+            ("0xtest", "mkt1", "BUY", 100.0, 0.40, 1000),
+            ("0xtest", "mkt1", "SELL", 80.0, 0.60, 2000),
+        ]);
+
+        let outcomes = closed_trade_outcomes(&db.conn, "0xtest", 1, 2000).unwrap();
+
+        assert_eq!(outcomes.len(), 1);
+        assert_eq!(outcomes[0].closed_at, 2000);
+        assert!((outcomes[0].pnl - 16.0).abs() < 0.01);
+        // Matched 80 shares bought at 0.40
+        assert!((outcomes[0].cost - 32.0).abs() < 0.01);
+    }
+
     #[test]
     fn test_paired_stats_tracks_open_positions() {
         let db = setup_db_with_trades(&[
@@ -1567,7 +1728,7 @@ mod tests {
                                                           // Remaining: 20 @ $0.40 + 50 @ $0.50 = 70 shares, cost basis ~$0.457
         ]);
 
-        let stats = paired_trade_stats(&db.conn, "0xtest", 0).unwrap();
+        let stats = paired_trade_stats(&db.conn, "0xtest", 0, i64::MAX).unwrap();
 
         assert_eq!(stats.open_positions.len(), 1);
         let open = &stats.open_positions[0];
@@ -1589,7 +1750,7 @@ mod tests {
             ("0xtest", "mkt3", "SELL", 60.0, 0.40, 5000), // +6.00 realized, 40 open
         ]);
 
-        let stats = paired_trade_stats(&db.conn, "0xtest", 0).unwrap();
+        let stats = paired_trade_stats(&db.conn, "0xtest", 0, i64::MAX).unwrap();
 
         // Realized: 10.00 + 6.00 = 16.00
         assert!((stats.total_fifo_realized_pnl - 16.00).abs() < 0.01);
@@ -3,6 +3,9 @@ use anyhow::Result;
 use common::config::WalletRules;
 use rusqlite::{Connection, OptionalExtension};
 use serde::{Deserialize, Serialize};
+
+mod win_rate_gate;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum WalletRuleState {
     Candidate,
@@ -119,6 +122,12 @@ pub fn evaluate_paper(
         });
     }
 
+    // Last gate before real money: recent form, not just the paper-window averages.
+    let recent = compute_wallet_features(conn, proxy_wallet, 7, now)?;
+    if let Some(blocked) = win_rate_gate::evaluate_win_rate_7d(&recent, cfg) {
+        return Ok(blocked);
+    }
+
     Ok(WalletRuleDecision {
         allow: true,
         reason: "onchain_validation_passed".to_string(),
@@ -326,13 +335,13 @@ mod tests {
     use super::*;
     use common::db::Database;
 
-    fn default_rules() -> WalletRules {
-        common::config::Config::from_toml_str(include_str!("../../../config/default.toml"))
+    pub(super) fn default_rules() -> WalletRules {
+        common::config::Config::from_toml_str(include_str!("../../../../config/default.toml"))
             .unwrap()
             .wallet_rules
     }
 
-    fn base_features() -> WalletFeatures {
+    pub(super) fn base_features() -> WalletFeatures {
         WalletFeatures {
             proxy_wallet: "0xw".to_string(),
             window_days: 30,
@@ -358,6 +367,7 @@ mod tests {
             top_domain: Some("sports".to_string()),
             top_domain_ratio: 0.7,
             profitable_markets: 12,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
@@ -395,7 +405,7 @@ mod tests {
         db.run_migrations().unwrap();
         let now = chrono::Utc::now().timestamp();
         // Insert 35 BUY/SELL paired round-trips across markets (>= required_paper_trades=30)
-        // All within paper_window_days=14 days
+        // All within the last 7 days, so they also feed the rolling win-rate gate
         for i in 0..35 {
             let cid = format!("m{i}");
             let offset = i64::from(i * 1000);
@@ -403,14 +413,14 @@ mod tests {
                 .execute(
                     "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
                      VALUES ('0xw', ?1, 'BUY', 10.0, 0.50, ?2)",
-                    rusqlite::params![cid, now - 86400 * 10 + offset],
+                    rusqlite::params![cid, now - 86400 * 6 + offset],
                 )
                 .unwrap();
             db.conn
                 .execute(
                     "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
                      VALUES ('0xw', ?1, 'SELL', 10.0, 0.51, ?2)",
-                    rusqlite::params![cid, now - 86400 * 5 + offset],
+                    rusqlite::params![cid, now - 86400 * 3 + offset],
                 )
                 .unwrap();
         }
@@ -0,0 +1,89 @@
+use super::WalletRuleDecision;
+use crate::wallet_features::WalletFeatures;
+use common::config::WalletRules;
+
+/// Rolling 7-day win-rate gate for PAPER_TRADING → APPROVED. `recent` must be features over
+/// a 7-day window. Returns the blocking decision, or `None` when the wallet passes.
+pub fn evaluate_win_rate_7d(
+    recent: &WalletFeatures,
+    cfg: &WalletRules,
+) -> Option<WalletRuleDecision> {
+    let closed = recent.win_count + recent.loss_count;
+    if closed < cfg.paper_min_closed_trades_7d {
+        return Some(WalletRuleDecision {
+            allow: false,
+            reason: "not_enough_closed_trades_7d".to_string(),
+        });
+    }
+    if f64::from(recent.win_count) / f64::from(closed) < cfg.paper_min_win_rate_7d {
+        return Some(WalletRuleDecision {
+            allow: false,
+            reason: "win_rate_7d_too_low".to_string(),
+        });
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet_rules_engine::evaluate_paper;
+    use crate::wallet_rules_engine::tests::{base_features, default_rules};
+    use common::db::Database;
+
+    #[test]
+    fn test_win_rate_7d_gate_requires_sample_and_rate() {
+        let cfg = default_rules();
+        let mut recent = base_features();
+        recent.window_days = 7;
+
+        recent.win_count = 6;
+        recent.loss_count = 3;
+        let blocked = evaluate_win_rate_7d(&recent, &cfg).unwrap();
+        assert_eq!(blocked.reason, "not_enough_closed_trades_7d");
+
+        recent.win_count = 5;
+        recent.loss_count = 5;
+        let blocked = evaluate_win_rate_7d(&recent, &cfg).unwrap();
+        assert_eq!(blocked.reason, "win_rate_7d_too_low");
+
+        recent.win_count = 6;
+        recent.loss_count = 4;
+        assert!(evaluate_win_rate_7d(&recent, &cfg).is_none());
+    }
+    #[test]
+    fn test_paper_trading_blocked_when_recent_round_trips_lose() {
+        let db = Database::open(":memory:").unwrap();
+        db.run_migrations().unwrap();
+        let now = chrono::Utc::now().timestamp();
+        // 35 profitable round trips 10+ days ago, then 12 small losers this week.
+        for i in 0..47 {
+            let cid = format!("m{i}");
+            let offset = i64::from(i * 1000);
+            let (opened, closed, exit) = if i < 35 {
+                (now - 86400 * 13, now - 86400 * 10, 0.60)
+            } else {
+                (now - 86400 * 5, now - 86400 * 2, 0.499)
+            };
+            db.conn
+                .execute(
+                    "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
+                     VALUES ('0xw', ?1, 'BUY', 10.0, 0.50, ?2)",
+                    rusqlite::params![cid, opened + offset],
+                )
+                .unwrap();
+            db.conn
+                .execute(
+                    "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
+                     VALUES ('0xw', ?1, 'SELL', 10.0, ?2, ?3)",
+                    rusqlite::params![cid, exit, closed + offset],
+                )
+                .unwrap();
+        }
+        let mut cfg = default_rules();
+        cfg.max_paper_drawdown = 1.0;
+        let decision = evaluate_paper(&db.conn, "0xw", &cfg).unwrap();
+        assert!(!decision.allow);
+        assert_eq!(decision.reason, "win_rate_7d_too_low");
+    }
+}
@@ -1,5 +1,9 @@
 use crate::wallet_features::WalletFeatures;
 
+mod recency;
+
+pub use recency::{recency_edge_score, recency_weighted_roi_pct};
+
 #[derive(Debug, Clone, Copy)]
 pub struct WScoreWeights {
     pub edge_weight: f64,
@@ -7,6 +11,7 @@ pub struct WScoreWeights {
     pub market_skill_weight: f64,
     pub timing_skill_weight: f64,
     pub behavior_quality_weight: f64,
+    pub recency_edge_weight: f64,
 }
 
 impl Default for WScoreWeights {
@@ -17,6 +22,7 @@ impl Default for WScoreWeights {
             market_skill_weight: 0.20,
             timing_skill_weight: 0.15,
             behavior_quality_weight: 0.10,
+            recency_edge_weight: 0.15,
         }
     }
 }
@@ -25,6 +31,8 @@ impl Default for WScoreWeights {
 pub struct WalletScoreInput {
     /// Total ROI over the scoring window, percent (e.g. +12.3).
     pub roi_pct: f64,
+    /// ROI over the window with each closed trade exponentially decayed by age, percent.
+    pub recency_weighted_roi_pct: f64,
     /// Stddev of daily returns over the window, percent.
     pub daily_return_stdev_pct: f64,
     /// Win rate (hit rate) in range [0, 1].
@@ -92,12 +100,14 @@ pub fn compute_wscore(
     let ms = market_skill_score(input.profitable_markets, input.total_markets);
     let ts = timing_skill_score(input.avg_post_entry_drift_cents);
     let bq = behavior_quality_score(input.noise_trade_ratio);
+    let re = recency_edge_score(input.recency_weighted_roi_pct);
 
     let total_w = w.edge_weight
         + w.consistency_weight
         + w.market_skill_weight
         + w.timing_skill_weight
-        + w.behavior_quality_weight;
+        + w.behavior_quality_weight
+        + w.recency_edge_weight;
     if total_w <= 0.0 {
         return 0.0;
     }
@@ -106,7 +116,8 @@ pub fn compute_wscore(
         + w.consistency_weight * c
         + w.market_skill_weight * ms
         + w.timing_skill_weight * ts
-        + w.behavior_quality_weight * bq)
+        + w.behavior_quality_weight * bq
+        + w.recency_edge_weight * re)
         / total_w;
 
     // Win rate sensitivity analysis (Strategy Bible §3):
@@ -137,8 +148,10 @@ pub fn compute_wscore(
 }
 
 /// Build a WalletScoreInput from on-chain WalletFeatures (no paper_trades needed).
+/// `recency_weighted_roi_pct` comes from `recency_weighted_roi_pct` over the same window.
 pub fn score_input_from_features(
     features: &WalletFeatures,
+    recency_weighted_roi_pct: f64,
     wallet_age_days: u32,
     is_leaderboard: bool,
 ) -> WalletScoreInput {
@@ -167,6 +180,7 @@ pub fn score_input_from_features(
 
     WalletScoreInput {
         roi_pct,
+        recency_weighted_roi_pct,
         daily_return_stdev_pct,
         hit_rate,
         profitable_markets: features.profitable_markets,
@@ -215,6 +229,7 @@ mod tests {
     fn test_full_wscore_all_5_components() {
         let input = WalletScoreInput {
             roi_pct: 10.0,
+            recency_weighted_roi_pct: 10.0,
             daily_return_stdev_pct: 3.0,
             hit_rate: 0.55,
             profitable_markets: 5,
@@ -230,6 +245,7 @@ mod tests {
             market_skill_weight: 0.20,
             timing_skill_weight: 0.15,
             behavior_quality_weight: 0.10,
+            recency_edge_weight: 0.15,
         };
         let score = compute_wscore(&input, &weights, 0.8, 1.2);
         assert!(score > 0.0 && score <= 1.0);
@@ -241,6 +257,7 @@ mod tests {
         let s = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 12.0,
+                recency_weighted_roi_pct: 12.0,
                 daily_return_stdev_pct: 3.0,
                 hit_rate: 0.55,
                 profitable_markets: 0,
@@ -264,6 +281,7 @@ mod tests {
         let good = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 10.0,
+                recency_weighted_roi_pct: 10.0,
                 daily_return_stdev_pct: 2.0,
                 hit_rate: 0.60,
                 profitable_markets: 0,
@@ -280,6 +298,7 @@ mod tests {
         let bad = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 0.0,
+                recency_weighted_roi_pct: 0.0,
                 daily_return_stdev_pct: 2.0,
                 hit_rate: 0.60,
                 profitable_markets: 0,
@@ -302,6 +321,7 @@ mod tests {
         let stable = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 10.0,
+                recency_weighted_roi_pct: 10.0,
                 daily_return_stdev_pct: 1.0,
                 hit_rate: 0.60,
                 profitable_markets: 0,
@@ -318,6 +338,7 @@ mod tests {
         let unstable = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 10.0,
+                recency_weighted_roi_pct: 10.0,
                 daily_return_stdev_pct: 12.0,
                 hit_rate: 0.60,
                 profitable_markets: 0,
@@ -340,6 +361,7 @@ mod tests {
         let high_wr = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 10.0,
+                recency_weighted_roi_pct: 10.0,
                 daily_return_stdev_pct: 2.0,
                 hit_rate: 0.60,
                 profitable_markets: 0,
@@ -356,6 +378,7 @@ mod tests {
         let low_wr = compute_wscore(
             &WalletScoreInput {
                 roi_pct: 10.0,
+                recency_weighted_roi_pct: 10.0,
                 daily_return_stdev_pct: 2.0,
                 hit_rate: 0.40,
                 profitable_markets: 0,
@@ -399,16 +422,18 @@ mod tests {
             top_domain: Some("sports".to_string()),
             top_domain_ratio: 0.7,
             profitable_markets: 7,
+            wash_trade_score: 0.0,
             cashflow_pnl: 100.0,
             fifo_realized_pnl: 0.0,
             unrealized_pnl: 0.0,
             open_positions_count: 0,
         };
-        let input = score_input_from_features(&features, 120, false);
+        let input = score_input_from_features(&features, 4.0, 120, false);
         assert!((input.hit_rate - 0.6).abs() < 0.01);
         assert_eq!(input.profitable_markets, 7);
         assert_eq!(input.total_markets, 10);
         assert!(input.roi_pct > 0.0);
+        assert!((input.recency_weighted_roi_pct - 4.0).abs() < f64::EPSILON);
         assert_eq!(input.wallet_age_days, 120);
         assert!(!input.is_public_leaderboard_top_500);
         // daily_return_stdev_pct = max_drawdown_pct * 0.5 = 4.0
@@ -0,0 +1,72 @@
+use super::edge_score;
+use crate::wallet_features::TradeOutcome;
+
+/// ROI percent where each round trip's PnL and cost are weighted by `0.5^(age / half_life)`,
+/// so a trade closed one half-life ago counts half as much as one closed at `as_of_epoch`.
+/// Returns 0.0 with no outcomes, no weighted cost, or a non-positive half-life.
+pub fn recency_weighted_roi_pct(
+    outcomes: &[TradeOutcome],
+    as_of_epoch: i64,
+    half_life_days: f64,
+) -> f64 {
+    if half_life_days <= 0.0 {
+        return 0.0;
+    }
+    let half_life_secs = half_life_days * 86400.0;
+    let (mut weighted_pnl, mut weighted_cost) = (0.0, 0.0);
+    for o in outcomes {
+        let age_secs = (as_of_epoch - o.closed_at).max(0) as f64;
+        let weight = 0.5_f64.powf(age_secs / half_life_secs);
+        weighted_pnl += weight * o.pnl;
+        weighted_cost += weight * o.cost;
+    }
+    if weighted_cost <= 0.0 {
+        return 0.0;
+    }
+    100.0 * weighted_pnl / weighted_cost
+}
+
+/// Recency-weighted edge: same normalization as `edge_score`, applied to the decayed ROI.
+pub fn recency_edge_score(recency_weighted_roi_pct: f64) -> f64 {
+    edge_score(recency_weighted_roi_pct)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_700_000_000;
+
+    fn outcome(days_ago: i64, pnl: f64, cost: f64) -> TradeOutcome {
+        TradeOutcome {
+            closed_at: NOW - days_ago * 86400,
+            pnl,
+            cost,
+        }
+    }
+
+    #[test]
+    fn test_recency_weighted_roi_pct_favors_recent_trades() {
+        // Old big win, recent loss of the same size: decayed ROI should be negative.
+        let cooling = [outcome(60, 10.0, 100.0), outcome(1, -10.0, 100.0)];
+        let heating = [outcome(60, -10.0, 100.0), outcome(1, 10.0, 100.0)];
+        assert!(recency_weighted_roi_pct(&cooling, NOW, 14.0) < 0.0);
+        assert!(recency_weighted_roi_pct(&heating, NOW, 14.0) > 0.0);
+    }
+
+    #[test]
+    fn test_recency_weighted_roi_pct_half_life_weight() {
+        // A trade exactly one half-life old weighs half as much as one closed now.
+        let outcomes = [outcome(0, 30.0, 100.0), outcome(14, -30.0, 100.0)];
+        let roi = recency_weighted_roi_pct(&outcomes, NOW, 14.0);
+        // (30 - 15) / (100 + 50) = 10%
+        assert!((roi - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_recency_weighted_roi_pct_empty_or_invalid_half_life() {
+        assert!(recency_weighted_roi_pct(&[], NOW, 14.0).abs() < f64::EPSILON);
+        let outcomes = [outcome(1, 5.0, 100.0)];
+        assert!(recency_weighted_roi_pct(&outcomes, NOW, 0.0).abs() < f64::EPSILON);
+    }
+}
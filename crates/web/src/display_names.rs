@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How long a failed / unset profile lookup is remembered (capped at the positive TTL).
+/// Short so a wallet that sets a profile name shows up soon, long enough that repeated views
+/// of unknown wallets don't hammer Gamma.
+const NEGATIVE_TTL: Duration = Duration::from_secs(300);
+
+/// Entries beyond this trigger a sweep of expired lookups on insert.
+const MAX_ENTRIES: usize = 10_000;
+
+/// In-memory TTL cache of Polymarket display names (wallet -> name, or None if unset/failed).
+pub struct DisplayNameCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, Option<String>)>>,
+}
+
+impl DisplayNameCache {
+    /// `ttl` of zero disables caching (every lookup goes to Gamma).
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn ttl_for(&self, name: Option<&String>) -> Duration {
+        if name.is_some() {
+            self.ttl
+        } else {
+            NEGATIVE_TTL.min(self.ttl)
+        }
+    }
+
+    /// Cached lookup: `Some(name_or_none)` on a fresh hit, `None` on miss or expiry.
+    pub fn get(&self, wallet: &str) -> Option<Option<String>> {
+        self.get_at(wallet, Instant::now())
+    }
+
+    fn get_at(&self, wallet: &str, now: Instant) -> Option<Option<String>> {
+        let (fetched_at, name) = self.entries.lock().unwrap().get(wallet).cloned()?;
+        (now.duration_since(fetched_at) < self.ttl_for(name.as_ref())).then_some(name)
+    }
+
+    pub fn insert(&self, wallet: &str, name: Option<String>) {
+        self.insert_at(wallet, name, Instant::now());
+    }
+
+    fn insert_at(&self, wallet: &str, name: Option<String>, now: Instant) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        let mut entries = self.entries.lock().unwrap();
+        if entries.len() >= MAX_ENTRIES {
+            entries.retain(|_, (fetched_at, name)| {
+                now.duration_since(*fetched_at) < self.ttl_for(name.as_ref())
+            });
+            if entries.len() >= MAX_ENTRIES {
+                entries.clear();
+            }
+        }
+        entries.insert(wallet.to_string(), (now, name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_name_cache_hit_until_ttl() {
+        let cache = DisplayNameCache::new(Duration::from_secs(3600));
+        let t0 = Instant::now();
+        cache.insert_at("0xabc", Some("whale".to_string()), t0);
+        assert_eq!(
+            cache.get_at("0xabc", t0 + Duration::from_secs(10)),
+            Some(Some("whale".to_string()))
+        );
+        assert_eq!(cache.get_at("0xabc", t0 + Duration::from_secs(3600)), None);
+        assert_eq!(cache.get_at("0xother", t0), None);
+    }
+
+    #[test]
+    fn test_display_name_cache_negative_results_expire_sooner() {
+        let cache = DisplayNameCache::new(Duration::from_secs(3600));
+        let t0 = Instant::now();
+        cache.insert_at("0xabc", None, t0);
+        assert_eq!(
+            cache.get_at("0xabc", t0 + Duration::from_secs(60)),
+            Some(None)
+        );
+        assert_eq!(cache.get_at("0xabc", t0 + NEGATIVE_TTL), None);
+    }
+
+    #[test]
+    fn test_display_name_cache_zero_ttl_disables() {
+        let cache = DisplayNameCache::new(Duration::ZERO);
+        cache.insert("0xabc", Some("whale".to_string()));
+        assert_eq!(cache.get("0xabc"), None);
+    }
+}
@@ -0,0 +1,393 @@
+//! `GET /ws`: one WebSocket per open dashboard instead of a set of HTMX polls.
+//!
+//! A single publisher task re-reads status, funnel and rankings every
+//! `[web] dashboard_push_interval_secs` while at least one client is connected, diffs the result
+//! against the previous read and broadcasts only what changed. Clients get a full snapshot on
+//! connect, then versioned deltas. The HTMX partials are unchanged.
+
+use crate::models::{LastRunStats, RankingRow, SystemStatus, UnifiedFunnelStage};
+use crate::{queries, with_db, AppState};
+use anyhow::Result;
+use axum::extract::ws::{Message, WebSocket, WebSocketUpgrade};
+use axum::extract::State;
+use axum::response::Response;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+/// Deltas buffered per client; a client further behind is resynced with a snapshot.
+const UPDATE_BUFFER: usize = 16;
+
+/// Everything `/ws` pushes: the status strip, the unified funnel, last-run stats and the
+/// combined-window rankings.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DashboardState {
+    pub status: SystemStatus,
+    pub funnel: Vec<UnifiedFunnelStage>,
+    pub last_run: LastRunStats,
+    pub rankings: Vec<RankingRow>,
+}
+
+/// Ranking changes keyed by wallet; `order` is the full wallet order after applying them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RankingsDelta {
+    pub upserted: Vec<RankingRow>,
+    pub removed: Vec<String>,
+    pub order: Vec<String>,
+}
+
+/// Sections that changed between two versions; unchanged sections are omitted.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DashboardDelta {
+    pub version: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<SystemStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub funnel: Option<Vec<UnifiedFunnelStage>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_run: Option<LastRunStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rankings: Option<RankingsDelta>,
+}
+
+impl DashboardDelta {
+    fn is_empty(&self) -> bool {
+        self.status.is_none()
+            && self.funnel.is_none()
+            && self.last_run.is_none()
+            && self.rankings.is_none()
+    }
+}
+
+/// Wire format: `{"type":"snapshot","version":N,...}` or `{"type":"delta","version":N,...}`.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum WsMessage<'a> {
+    Snapshot {
+        version: u64,
+        #[serde(flatten)]
+        state: &'a DashboardState,
+    },
+    Delta(&'a DashboardDelta),
+    Error {
+        message: String,
+    },
+}
+
+fn option_if_changed<T: Clone + PartialEq>(old: &T, new: &T) -> Option<T> {
+    (old != new).then(|| new.clone())
+}
+
+fn diff_rankings(old: &[RankingRow], new: &[RankingRow]) -> Option<RankingsDelta> {
+    let previous: HashMap<&str, &RankingRow> =
+        old.iter().map(|r| (r.proxy_wallet.as_str(), r)).collect();
+    let upserted: Vec<RankingRow> = new
+        .iter()
+        .filter(|r| previous.get(r.proxy_wallet.as_str()) != Some(r))
+        .cloned()
+        .collect();
+    let current: HashSet<&str> = new.iter().map(|r| r.proxy_wallet.as_str()).collect();
+    let removed: Vec<String> = old
+        .iter()
+        .filter(|r| !current.contains(r.proxy_wallet.as_str()))
+        .map(|r| r.proxy_wallet.clone())
+        .collect();
+    let order: Vec<String> = new.iter().map(|r| r.proxy_wallet.clone()).collect();
+    let reordered = old.iter().map(|r| &r.proxy_wallet).ne(order.iter());
+    (!upserted.is_empty() || !removed.is_empty() || reordered).then_some(RankingsDelta {
+        upserted,
+        removed,
+        order,
+    })
+}
+
+/// What changed from `old` to `new`, stamped with `version`.
+fn diff(version: u64, old: &DashboardState, new: &DashboardState) -> DashboardDelta {
+    DashboardDelta {
+        version,
+        status: option_if_changed(&old.status, &new.status),
+        funnel: option_if_changed(&old.funnel, &new.funnel),
+        last_run: option_if_changed(&old.last_run, &new.last_run),
+        rankings: diff_rankings(&old.rankings, &new.rankings),
+    }
+}
+
+struct Snapshot {
+    version: u64,
+    state: Arc<DashboardState>,
+    loaded_at: Instant,
+}
+
+/// Latest dashboard state plus the broadcast of deltas to connected `/ws` clients.
+pub struct DashboardHub {
+    push_interval: Duration,
+    updates: broadcast::Sender<Arc<DashboardDelta>>,
+    latest: Mutex<Option<Snapshot>>,
+    // Serializes reloads so concurrent connects don't race on versions.
+    reload: tokio::sync::Mutex<()>,
+}
+
+impl DashboardHub {
+    pub fn new(push_interval: Duration) -> Self {
+        let (updates, _) = broadcast::channel(UPDATE_BUFFER);
+        Self {
+            push_interval,
+            updates,
+            latest: Mutex::new(None),
+            reload: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Latest snapshot if it was loaded less than `max_age` ago.
+    fn latest(&self, max_age: Duration) -> Option<(u64, Arc<DashboardState>)> {
+        self.latest
+            .lock()
+            .unwrap()
+            .as_ref()
+            .filter(|s| s.loaded_at.elapsed() < max_age)
+            .map(|s| (s.version, s.state.clone()))
+    }
+}
+
+async fn load_state(state: &Arc<AppState>) -> Result<DashboardState> {
+    let db_path_str = state.db_path.to_string_lossy().to_string();
+    let hidden_personas = state.funnel_hidden_personas.clone();
+    let min_age_days = state.persona_min_wallet_age_days;
+    let follow_worthy = state.follow_worthy;
+    with_db(state.clone(), move |conn| {
+        Ok(DashboardState {
+            status: queries::system_status(conn, &db_path_str)?,
+            funnel: queries::unified_funnel_counts(
+                conn,
+                &hidden_personas,
+                min_age_days,
+                follow_worthy,
+            )?
+            .to_stages(),
+            last_run: queries::last_run_stats(conn)?,
+            rankings: queries::follow_worthy_rankings(
+                conn,
+                None,
+                follow_worthy,
+                queries::RankingWindow::Combined,
+            )?,
+        })
+    })
+    .await
+}
+
+/// Re-read the dashboard state and broadcast whatever changed. A snapshot younger than
+/// `max_age` is reused, so a burst of connects costs one set of queries.
+async fn refresh(state: &Arc<AppState>, max_age: Duration) -> Result<(u64, Arc<DashboardState>)> {
+    let hub = &state.dashboard;
+    let _reload = hub.reload.lock().await;
+    if let Some(cached) = hub.latest(max_age) {
+        return Ok(cached);
+    }
+
+    let new = Arc::new(load_state(state).await?);
+    let mut latest = hub.latest.lock().unwrap();
+    let (version, delta) = match latest.as_ref() {
+        Some(prev) => {
+            let delta = diff(prev.version + 1, &prev.state, &new);
+            if delta.is_empty() {
+                (prev.version, None)
+            } else {
+                (delta.version, Some(delta))
+            }
+        }
+        None => (1, None),
+    };
+    *latest = Some(Snapshot {
+        version,
+        state: new.clone(),
+        loaded_at: Instant::now(),
+    });
+    drop(latest);
+    if let Some(delta) = delta {
+        // Err only means nobody is subscribed.
+        let _ = hub.updates.send(Arc::new(delta));
+    }
+    Ok((version, new))
+}
+
+/// Background publisher: reloads every push interval while anyone is listening.
+pub async fn spawn_dashboard_publisher(state: Arc<AppState>) {
+    let mut ticker = tokio::time::interval(state.dashboard.push_interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+    loop {
+        ticker.tick().await;
+        if state.dashboard.updates.receiver_count() == 0 {
+            continue;
+        }
+        if let Err(e) = refresh(&state, Duration::ZERO).await {
+            tracing::warn!(error = %e, "dashboard push: state reload failed");
+        }
+    }
+}
+
+/// `GET /ws`: snapshot on connect, then deltas until the client disconnects.
+pub async fn dashboard_ws(State(state): State<Arc<AppState>>, ws: WebSocketUpgrade) -> Response {
+    ws.on_upgrade(move |mut socket| async move {
+        ::metrics::gauge!("evaluator_web_ws_clients").increment(1.0);
+        if let Err(e) = run_client(&mut socket, &state).await {
+            tracing::debug!(error = %e, "dashboard websocket closed");
+        }
+        ::metrics::gauge!("evaluator_web_ws_clients").decrement(1.0);
+    })
+}
+
+async fn send(socket: &mut WebSocket, message: &WsMessage<'_>) -> Result<()> {
+    let text = serde_json::to_string(message)?;
+    socket.send(Message::Text(text.into())).await?;
+    Ok(())
+}
+
+async fn send_snapshot(
+    socket: &mut WebSocket,
+    version: u64,
+    state: &DashboardState,
+) -> Result<u64> {
+    send(socket, &WsMessage::Snapshot { version, state }).await?;
+    Ok(version)
+}
+
+async fn run_client(socket: &mut WebSocket, state: &Arc<AppState>) -> Result<()> {
+    // Subscribe before taking the snapshot so no delta newer than it can be missed.
+    let mut updates = state.dashboard.updates.subscribe();
+    let mut version = match refresh(state, state.dashboard.push_interval).await {
+        Ok((version, snapshot)) => send_snapshot(socket, version, &snapshot).await?,
+        Err(e) => {
+            let message = format!("DB unavailable: {e}");
+            send(socket, &WsMessage::Error { message }).await?;
+            return Ok(());
+        }
+    };
+
+    loop {
+        tokio::select! {
+            update = updates.recv() => match update {
+                Ok(delta) if delta.version > version => {
+                    version = delta.version;
+                    send(socket, &WsMessage::Delta(&delta)).await?;
+                }
+                Ok(_) => {}
+                Err(RecvError::Lagged(_)) => {
+                    if let Some((latest, snapshot)) = state.dashboard.latest(Duration::MAX) {
+                        version = send_snapshot(socket, latest, &snapshot).await?;
+                    }
+                }
+                Err(RecvError::Closed) => return Ok(()),
+            },
+            incoming = socket.recv() => match incoming {
+                None | Some(Err(_) | Ok(Message::Close(_))) => return Ok(()),
+                // Pings are answered by axum; client messages carry no meaning yet.
+                Some(Ok(_)) => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranking(wallet: &str, wscore: f64) -> RankingRow {
+        RankingRow {
+            rank: 1,
+            rank_display: "1".to_string(),
+            row_class: String::new(),
+            proxy_wallet: wallet.to_string(),
+            wallet_short: wallet.to_string(),
+            wscore,
+            wscore_display: format!("{wscore:.2}"),
+            wscore_pct: String::new(),
+            edge_score: 0.0,
+            edge_display: String::new(),
+            consistency_score: 0.0,
+            consistency_display: String::new(),
+            trade_count: 10,
+            paper_pnl: 0.0,
+            pnl_display: String::new(),
+            pnl_color: String::new(),
+            follow_mode: String::new(),
+            roi_display: String::new(),
+            roi_color: String::new(),
+        }
+    }
+
+    fn dashboard(rankings: Vec<RankingRow>, events_markets: i64) -> DashboardState {
+        DashboardState {
+            status: SystemStatus {
+                db_size_mb: "1.0".to_string(),
+                phase: "Scoring".to_string(),
+                jobs: vec![],
+                events_display: "50".to_string(),
+            },
+            funnel: vec![],
+            last_run: LastRunStats {
+                trades_wallets: 0,
+                trades_inserted: 0,
+                events_markets,
+                trades_run_at: None,
+                events_run_at: None,
+            },
+            rankings,
+        }
+    }
+
+    #[test]
+    fn test_diff_only_carries_changed_sections() {
+        let old = dashboard(vec![ranking("0xa", 0.9), ranking("0xb", 0.5)], 10);
+        let new = dashboard(vec![ranking("0xb", 0.95), ranking("0xc", 0.4)], 12);
+
+        let delta = diff(2, &old, &new);
+        assert!(delta.status.is_none());
+        assert!(delta.funnel.is_none());
+        assert_eq!(delta.last_run.as_ref().map(|r| r.events_markets), Some(12));
+        let rankings = delta.rankings.unwrap();
+        let upserted: Vec<&str> = rankings
+            .upserted
+            .iter()
+            .map(|r| r.proxy_wallet.as_str())
+            .collect();
+        assert_eq!(upserted, vec!["0xb", "0xc"]);
+        assert_eq!(rankings.removed, vec!["0xa".to_string()]);
+        assert_eq!(rankings.order, vec!["0xb".to_string(), "0xc".to_string()]);
+
+        assert!(diff(3, &new, &new).is_empty());
+    }
+
+    #[test]
+    fn test_diff_rankings_reports_pure_reorder() {
+        let old = vec![ranking("0xa", 0.9), ranking("0xb", 0.5)];
+        let new = vec![ranking("0xb", 0.5), ranking("0xa", 0.9)];
+
+        let delta = diff_rankings(&old, &new).unwrap();
+        assert!(delta.upserted.is_empty());
+        assert!(delta.removed.is_empty());
+        assert_eq!(delta.order, vec!["0xb".to_string(), "0xa".to_string()]);
+    }
+
+    #[test]
+    fn test_ws_message_wire_format() {
+        let state = dashboard(vec![], 3);
+        let snapshot = serde_json::to_value(WsMessage::Snapshot {
+            version: 1,
+            state: &state,
+        })
+        .unwrap();
+        assert_eq!(snapshot["type"], "snapshot");
+        assert_eq!(snapshot["version"], 1);
+        assert_eq!(snapshot["last_run"]["events_markets"], 3);
+
+        let delta = diff(2, &state, &dashboard(vec![], 4));
+        let delta = serde_json::to_value(WsMessage::Delta(&delta)).unwrap();
+        assert_eq!(delta["type"], "delta");
+        assert_eq!(delta["version"], 2);
+        assert!(delta.get("status").is_none());
+        assert_eq!(delta["last_run"]["events_markets"], 4);
+    }
+}
@@ -1,3 +1,5 @@
+mod display_names;
+mod live;
 mod metrics;
 mod models;
 mod queries;
@@ -5,12 +7,14 @@ mod queries;
 use anyhow::Result;
 use askama::Template;
 use axum::body::Body;
-use axum::extract::{Path, Query, Request, State};
+use axum::extract::rejection::FormRejection;
+use axum::extract::{MatchedPath, Path, Query, Request, State};
 use axum::http::{header, HeaderMap, Method, StatusCode};
 use axum::middleware::{self, Next};
 use axum::response::{Html, IntoResponse, Json, Redirect, Response};
 use axum::routing::get;
 use axum::{Form, Router};
+use common::db::{check_schema_version, SchemaVersionError};
 use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
 use models::{
     EventRow, ExcludedWalletRow, FunnelStage, LastRunStats, MarketRow, PaperSummary, PaperTradeRow,
@@ -25,11 +29,14 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Semaphore;
+use tower_http::limit::RequestBodyLimitLayer;
 use tower_http::trace::TraceLayer;
 
 pub struct AppState {
     pub db_path: PathBuf,
     pub auth_password: Option<String>,
+    /// Bearer token accepted instead of the login cookie (machine clients of the JSON API).
+    pub api_token: Option<String>,
     pub funnel_stage_infos: [String; 6],
     // Used to avoid async runtime starvation when DB reads are slow.
     pub db_semaphore: Arc<Semaphore>,
@@ -48,10 +55,90 @@ pub struct AppState {
     pub http_client: Option<reqwest::Client>,
     /// Base URL of the trader microservice (e.g. "http://aws-trader:8081").
     pub trader_api_url: Option<String>,
+    /// Personas excluded from the suitable/follow-worthy funnel counts (from [personas.funnel_visibility]).
+    pub funnel_hidden_personas: Vec<String>,
+    /// Stage-1 wallet age filter shared with the classification pipeline.
+    pub persona_min_wallet_age_days: u32,
+    /// Paper-ROI bar for "worth following" (from [ranking]); shared by rankings and funnel.
+    pub follow_worthy: queries::FollowWorthyThresholds,
+    /// WScore component weights (from [wallet_scoring]) for the score explanation.
+    pub wscore_weights: queries::WScoreWeights,
+    /// Polymarket display names already fetched from Gamma (see `[web] display_name_cache_ttl_secs`).
+    pub display_names: display_names::DisplayNameCache,
+    /// Page size for wallet trade/position/activity endpoints when `limit` is omitted.
+    pub default_page_size: u32,
+    /// Upper bound on `limit` for those endpoints.
+    pub max_page_size: u32,
+    /// Net shares above which a position counts as active (`[web] active_position_min_shares`).
+    pub active_position_min_shares: f64,
+    /// Send `Strict-Transport-Security` (off for plain-HTTP deployments).
+    pub hsts_enabled: bool,
+    /// Prebuilt Content-Security-Policy header (see `content_security_policy`).
+    pub content_security_policy: header::HeaderValue,
+    /// Set when the startup probe found the DB unusable; every request then gets a 503.
+    pub db_startup_error: Option<String>,
+    /// Shared state and change broadcast behind `GET /ws`.
+    pub dashboard: live::DashboardHub,
+}
+
+impl AppState {
+    /// Resolve a requested page size: configured default when omitted, clamped to the max.
+    fn page_limit(&self, requested: Option<u32>) -> u32 {
+        requested
+            .unwrap_or(self.default_page_size)
+            .min(self.max_page_size)
+    }
+}
+
+/// Outcome of the startup DB probe (`probe_db`).
+#[derive(Debug, PartialEq, Eq)]
+enum DbProbe {
+    Ready,
+    /// Missing file or no schema yet: the evaluator hasn't run; pages are just empty.
+    NotMigrated,
+    /// The file can't be read as a SQLite DB (corrupt, locked, permissions).
+    Unusable(String),
+    /// Tables exist but `user_version` isn't `SCHEMA_VERSION`: migrations are pending (or the
+    /// evaluator is newer than this build), so queries would fail on missing columns.
+    SchemaMismatch(SchemaVersionError),
+}
+
+/// Open the DB read-only and run a trivial query, so a corrupt file is reported at startup
+/// instead of as opaque per-request failures.
+fn probe_db(path: &std::path::Path) -> DbProbe {
+    if !path.exists() {
+        return DbProbe::NotMigrated;
+    }
+    let tables = Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .and_then(|conn| {
+        let count = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'job_status'",
+            [],
+            |r| r.get::<_, i64>(0),
+        )?;
+        Ok((count, conn))
+    });
+    let conn = match tables {
+        Ok((0, _)) => return DbProbe::NotMigrated,
+        Ok((_, conn)) => conn,
+        Err(e) => return DbProbe::Unusable(e.to_string()),
+    };
+    match check_schema_version(&conn) {
+        Ok(()) => DbProbe::Ready,
+        Err(e) => match e.downcast::<SchemaVersionError>() {
+            Ok(mismatch) => DbProbe::SchemaMismatch(mismatch),
+            Err(e) => DbProbe::Unusable(e.to_string()),
+        },
+    }
 }
 
 /// Open a read-only connection to the evaluator DB.
 /// Each request gets a fresh connection — SQLite WAL handles concurrent reads fine.
+/// Fails with a `SchemaVersionError` when the schema isn't the one this build reads, so a
+/// half-migrated DB surfaces as one clear message rather than "no such column" per query.
 pub fn open_readonly(state: &AppState) -> Result<Connection> {
     if !state.db_open_delay.is_zero() {
         std::thread::sleep(state.db_open_delay);
@@ -60,6 +147,29 @@ pub fn open_readonly(state: &AppState) -> Result<Connection> {
         &state.db_path,
         OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
     )?;
+    check_schema_version(&conn)?;
+    Ok(conn)
+}
+
+/// 503 for a failed DB call; schema problems get their own message so operators know to
+/// run the evaluator's migrations rather than chase a locked or missing file.
+fn db_error_response(e: &anyhow::Error) -> Response {
+    let body = match e.downcast_ref::<SchemaVersionError>() {
+        Some(schema) => format!("Dashboard waiting on migrations: {schema}"),
+        None => format!("DB unavailable: {e}"),
+    };
+    (StatusCode::SERVICE_UNAVAILABLE, body).into_response()
+}
+
+/// Open a writable connection for the few operator writes (wallet notes). Unlike the
+/// evaluator's `Database::open` this never creates the file or schema, and it waits out the
+/// evaluator's write lock for up to `db_timeout` instead of failing with SQLITE_BUSY.
+fn open_readwrite(state: &AppState) -> Result<Connection> {
+    let conn = Connection::open_with_flags(
+        &state.db_path,
+        OpenFlags::SQLITE_OPEN_READ_WRITE | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )?;
+    conn.busy_timeout(state.db_timeout)?;
     Ok(conn)
 }
 
@@ -68,6 +178,27 @@ pub fn open_readonly(state: &AppState) -> Result<Connection> {
 /// We limit concurrent DB work and apply a timeout to keep the dashboard responsive even under
 /// severe IO pressure.
 async fn with_db<R, F>(state: Arc<AppState>, f: F) -> Result<R>
+where
+    R: Send + 'static,
+    F: FnOnce(&Connection) -> Result<R> + Send + 'static,
+{
+    run_db(state, open_readonly, f).await
+}
+
+/// `with_db` on a writable connection. Only for operator-initiated writes behind auth + CSRF.
+async fn with_db_write<R, F>(state: Arc<AppState>, f: F) -> Result<R>
+where
+    R: Send + 'static,
+    F: FnOnce(&Connection) -> Result<R> + Send + 'static,
+{
+    run_db(state, open_readwrite, f).await
+}
+
+async fn run_db<R, F>(
+    state: Arc<AppState>,
+    open: fn(&AppState) -> Result<Connection>,
+    f: F,
+) -> Result<R>
 where
     R: Send + 'static,
     F: FnOnce(&Connection) -> Result<R> + Send + 'static,
@@ -77,7 +208,7 @@ where
 
     let handle = tokio::task::spawn_blocking(move || {
         let _permit = permit;
-        let conn = open_readonly(&state)?;
+        let conn = open(&state)?;
         f(&conn)
     });
 
@@ -100,6 +231,13 @@ use std::collections::HashMap;
 use std::sync::Mutex;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Sliding window for counting login attempts per IP.
+const LOGIN_WINDOW_SECS: u64 = 60;
+/// Attempts allowed per IP within the window.
+const LOGIN_MAX_ATTEMPTS: usize = 5;
+/// Upper bound on tracked IPs so rotating source addresses can't grow the map without limit.
+const LOGIN_MAX_TRACKED_IPS: usize = 10_000;
+
 #[derive(Clone)]
 pub struct LoginRateLimiter {
     attempts: Arc<Mutex<HashMap<String, Vec<u64>>>>,
@@ -111,6 +249,13 @@ impl Default for LoginRateLimiter {
     }
 }
 
+fn unix_now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
 impl LoginRateLimiter {
     pub fn new() -> Self {
         Self {
@@ -119,34 +264,64 @@ impl LoginRateLimiter {
     }
 
     /// Check if the client IP is rate limited (5 attempts per minute)
-    #[allow(clippy::significant_drop_tightening)] // lock needed for retain + len; Clippy's suggestion is invalid
     pub fn is_rate_limited(&self, client_ip: &str) -> bool {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        self.is_rate_limited_at(client_ip, unix_now_secs())
+    }
 
+    #[allow(clippy::significant_drop_tightening)] // lock needed for retain + len; Clippy's suggestion is invalid
+    fn is_rate_limited_at(&self, client_ip: &str, now: u64) -> bool {
         let count = {
             let mut attempts = self.attempts.lock().unwrap();
-            let client_attempts = attempts.entry(client_ip.to_string()).or_default();
-            client_attempts.retain(|&timestamp| now - timestamp < 60);
-            client_attempts.len()
+            attempts.get_mut(client_ip).map_or(0, |client_attempts| {
+                client_attempts.retain(|&timestamp| now - timestamp < LOGIN_WINDOW_SECS);
+                client_attempts.len()
+            })
         };
-        count >= 5
+        count >= LOGIN_MAX_ATTEMPTS
     }
 
     /// Record a login attempt
     pub fn record_attempt(&self, client_ip: &str) {
-        let now = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        self.attempts
-            .lock()
-            .unwrap()
-            .entry(client_ip.to_string())
-            .or_default()
-            .push(now);
+        self.record_attempt_at(client_ip, unix_now_secs());
+    }
+
+    fn record_attempt_at(&self, client_ip: &str, now: u64) {
+        let mut attempts = self.attempts.lock().unwrap();
+        if attempts.len() >= LOGIN_MAX_TRACKED_IPS && !attempts.contains_key(client_ip) {
+            Self::retain_recent(&mut attempts, now);
+            if attempts.len() >= LOGIN_MAX_TRACKED_IPS {
+                // Still full of live entries: forget the IP whose last attempt is oldest.
+                let oldest = attempts
+                    .iter()
+                    .min_by_key(|(_, ts)| ts.last().copied().unwrap_or(0))
+                    .map(|(ip, _)| ip.clone());
+                if let Some(ip) = oldest {
+                    attempts.remove(&ip);
+                }
+            }
+        }
+        attempts.entry(client_ip.to_string()).or_default().push(now);
+    }
+
+    /// Drop IPs with no attempts inside the window. Returns how many were removed.
+    pub fn sweep_stale(&self) -> usize {
+        self.sweep_stale_at(unix_now_secs())
+    }
+
+    fn sweep_stale_at(&self, now: u64) -> usize {
+        let mut attempts = self.attempts.lock().unwrap();
+        let before = attempts.len();
+        Self::retain_recent(&mut attempts, now);
+        before - attempts.len()
+    }
+
+    fn retain_recent(attempts: &mut HashMap<String, Vec<u64>>, now: u64) {
+        attempts.retain(|_, ts| ts.iter().any(|&t| now - t < LOGIN_WINDOW_SECS));
+    }
+
+    #[cfg(test)]
+    fn tracked_ips(&self) -> usize {
+        self.attempts.lock().unwrap().len()
     }
 
     /// Extract client IP from request
@@ -166,6 +341,20 @@ impl LoginRateLimiter {
     }
 }
 
+/// Periodically evict IPs that only made attempts outside the rate-limit window.
+/// `is_rate_limited` only prunes IPs that come back, so one-off addresses would otherwise stay
+/// in the map forever.
+async fn spawn_login_limiter_sweeper(limiter: Arc<LoginRateLimiter>) {
+    let mut interval = tokio::time::interval(Duration::from_secs(LOGIN_WINDOW_SECS));
+    loop {
+        interval.tick().await;
+        let removed = limiter.sweep_stale();
+        if removed > 0 {
+            tracing::debug!(removed, "swept stale login rate-limit entries");
+        }
+    }
+}
+
 /// Generate cryptographically secure auth token using SHA-256
 fn generate_auth_token(password: &str) -> String {
     use sha2::{Digest, Sha256};
@@ -211,16 +400,114 @@ async fn login_rate_limit_middleware(
     next.run(request).await
 }
 
+/// Record end-to-end handler latency (DB + template rendering), labelled by matched route path.
+async fn handler_latency_middleware(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "unmatched".to_string(), |p| p.as_str().to_string());
+    let start = std::time::Instant::now();
+    let response = next.run(request).await;
+    ::metrics::histogram!("evaluator_web_handler_latency_ms", "route" => route)
+        .record(start.elapsed().as_secs_f64() * 1000.0);
+    response
+}
+
+/// Credentials a request presented, for the access log. Computed before `auth_middleware`
+/// runs, so it records what was offered rather than whether the route required it.
+fn request_auth_state(state: &AppState, headers: &HeaderMap) -> &'static str {
+    let Some(password) = state.auth_password.as_deref() else {
+        return "auth_disabled";
+    };
+    if let Some(presented) = bearer_token(headers) {
+        let valid = state
+            .api_token
+            .as_deref()
+            .is_some_and(|expected| constant_time_eq(presented, expected));
+        return if valid { "bearer" } else { "bearer_invalid" };
+    }
+    if header_has_cookie(headers, AUTH_COOKIE_NAME, &generate_auth_token(password)) {
+        "session"
+    } else {
+        "anonymous"
+    }
+}
+
+/// Access-log span: method, path, client IP and auth state. Only the path is recorded (no
+/// query string, headers or body), so the `/login` password never reaches the logs.
+fn access_log_span(state: &AppState, request: &Request) -> tracing::Span {
+    tracing::info_span!(
+        target: "access_log",
+        "http_request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        client_ip = %LoginRateLimiter::extract_client_ip(request),
+        auth = request_auth_state(state, request.headers()),
+    )
+}
+
+/// One structured line per request; the JSON formatter attaches the `http_request` span fields.
+fn log_access(response: &Response, latency: Duration, _span: &tracing::Span) {
+    tracing::info!(
+        target: "access_log",
+        status = response.status().as_u16(),
+        latency_ms = latency.as_secs_f64() * 1000.0,
+        "request completed"
+    );
+}
+
+/// Degraded mode: when the startup probe found the DB unusable, answer every request with a
+/// 503 naming the cause instead of failing each query separately.
+async fn db_unavailable_middleware(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match &state.db_startup_error {
+        Some(reason) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!(
+                "Dashboard unavailable: the evaluator database could not be read at startup ({reason}). \
+                 Fix or restore database.path, then restart the web service."
+            ),
+        )
+            .into_response(),
+        None => next.run(request).await,
+    }
+}
+
+/// Content Security Policy - allow Tailwind and HTMX CDNs; partials fetched from same origin.
+/// Operators can append sources via `[web] csp_extra_script_src` / `csp_extra_connect_src`.
+fn content_security_policy(extra_script_src: &[String], extra_connect_src: &[String]) -> String {
+    let mut script_src = vec![
+        "'self'",
+        "'unsafe-inline'",
+        "https://cdn.tailwindcss.com",
+        "https://unpkg.com",
+    ];
+    script_src.extend(extra_script_src.iter().map(String::as_str));
+    let mut connect_src = vec!["'self'", "https://cdn.tailwindcss.com"];
+    connect_src.extend(extra_connect_src.iter().map(String::as_str));
+    format!(
+        "default-src 'self'; script-src {}; style-src 'self' 'unsafe-inline'; img-src 'self' data:; font-src 'self'; connect-src {}; frame-ancestors 'none';",
+        script_src.join(" "),
+        connect_src.join(" ")
+    )
+}
+
 /// Add security headers to all responses
-async fn security_headers_middleware(request: Request, next: Next) -> Response {
+async fn security_headers_middleware(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+    next: Next,
+) -> Response {
     let mut response = next.run(request).await;
 
     let headers = response.headers_mut();
 
-    // Content Security Policy - allow Tailwind and HTMX CDNs; partials fetched from same origin
     headers.insert(
         "Content-Security-Policy",
-        "default-src 'self'; script-src 'self' 'unsafe-inline' https://cdn.tailwindcss.com https://unpkg.com; style-src 'self' 'unsafe-inline'; img-src 'self' data:; font-src 'self'; connect-src 'self' https://cdn.tailwindcss.com; frame-ancestors 'none';".parse().unwrap(),
+        state.content_security_policy.clone(),
     );
 
     // Prevent clickjacking
@@ -238,11 +525,13 @@ async fn security_headers_middleware(request: Request, next: Next) -> Response {
         "strict-origin-when-cross-origin".parse().unwrap(),
     );
 
-    // Strict Transport Security (only in production)
-    headers.insert(
-        "Strict-Transport-Security",
-        "max-age=31536000; includeSubDomains".parse().unwrap(),
-    );
+    // Strict Transport Security (off for plain-HTTP deployments via `[web] hsts_enabled`)
+    if state.hsts_enabled {
+        headers.insert(
+            "Strict-Transport-Security",
+            "max-age=31536000; includeSubDomains".parse().unwrap(),
+        );
+    }
 
     response
 }
@@ -295,8 +584,19 @@ fn constant_time_eq(a: &str, b: &str) -> bool {
     result == 0
 }
 
+/// Token from an `Authorization: Bearer <token>` header, if present.
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::AUTHORIZATION)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim)
+}
+
 /// Redirects to /login if auth_password is configured and user is not authenticated.
 /// If auth_password is None, all requests pass through (no auth).
+/// With `[web] api_token` set, `Authorization: Bearer <token>` is accepted instead of the cookie;
+/// a wrong bearer token gets a plain 401 rather than a login redirect.
 async fn auth_middleware(
     State(state): State<Arc<AppState>>,
     request: Request,
@@ -307,6 +607,17 @@ async fn auth_middleware(
         return next.run(request).await;
     }
 
+    if let Some(presented) = bearer_token(request.headers()) {
+        let valid = state
+            .api_token
+            .as_deref()
+            .is_some_and(|expected| constant_time_eq(presented, expected));
+        if valid {
+            return next.run(request).await;
+        }
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
     // Check auth cookie
     let auth_token = generate_auth_token(state.auth_password.as_ref().unwrap());
     let is_authenticated = header_has_cookie(request.headers(), AUTH_COOKIE_NAME, &auth_token);
@@ -354,17 +665,51 @@ struct ExcludedTemplate {
     total_pages: i64,
 }
 
+#[derive(Template)]
+#[template(path = "market.html")]
+struct MarketTemplate {
+    market: models::MarketDetail,
+}
+
 #[derive(Template)]
 #[template(path = "journey.html")]
 struct JourneyTemplate {
     journey: WalletJourney,
 }
 
+/// Bare (escaped) display name for the HTMX swap on journey/scorecard pages.
+#[derive(Template)]
+#[template(path = "partials/display_name.html")]
+struct DisplayNameTemplate {
+    label: String,
+}
+
+/// Out-of-band unrealized PnL cells swapped into the scorecard after it renders.
+#[derive(Template)]
+#[template(path = "partials/position_prices.html")]
+struct PositionPricesTemplate {
+    positions: Vec<models::WalletPositionRow>,
+}
+
+/// Standalone journey snapshot for `/wallet/{wallet}/export`; inline CSS only, no CDN assets.
+#[derive(Template)]
+#[template(path = "journey_export.html")]
+struct JourneyExportTemplate {
+    journey: WalletJourney,
+    exported_at: String,
+}
+
 #[derive(Template)]
 #[template(path = "wallet_scorecard.html")]
 struct ScorecardTemplate {
     journey: WalletJourney,
     trader_connected: bool,
+    notes: Vec<models::WalletNoteRow>,
+    explanation: Option<models::WScoreExplanation>,
+    csrf_token: String,
+    max_note_chars: usize,
+    /// Active positions are priced by a follow-up `/wallet/{wallet}/position-prices` request.
+    position_prices_pending: bool,
 }
 
 #[derive(Template)]
@@ -474,10 +819,38 @@ struct PersonaBreakdownTemplate {
     ingestion: models::IngestionStats,
 }
 
+/// Small fragment returned when a template fails to render (see `render_html`).
+const TEMPLATE_ERROR_FRAGMENT: &str =
+    "<div class=\"text-red-400 text-sm\">Failed to render this section. Check logs.</div>";
+
+/// Render a template without panicking on render errors.
+///
+/// `Template::to_string()` panics if rendering fails. Partials are swapped in by HTMX, so a
+/// failure in one of them should not take down the request: log it with the template name,
+/// count it, and return a small error fragment instead.
+fn render_html<T: Template>(tmpl: &T) -> Html<String> {
+    match tmpl.render() {
+        Ok(html) => Html(html),
+        Err(e) => {
+            let template = std::any::type_name::<T>()
+                .rsplit("::")
+                .next()
+                .unwrap_or("unknown");
+            tracing::error!(template, error = %e, "template render failed");
+            ::metrics::counter!(
+                "evaluator_web_template_render_errors_total",
+                "template" => template
+            )
+            .increment(1);
+            Html(TEMPLATE_ERROR_FRAGMENT.to_string())
+        }
+    }
+}
+
 // --- Handlers ---
 
 async fn index() -> impl IntoResponse {
-    Html(DashboardTemplate.to_string())
+    render_html(&DashboardTemplate)
 }
 
 async fn login_form(State(state): State<Arc<AppState>>, headers: HeaderMap) -> impl IntoResponse {
@@ -494,13 +867,10 @@ async fn login_form(State(state): State<Arc<AppState>>, headers: HeaderMap) -> i
         "{CSRF_COOKIE_NAME}={csrf_token}; Path=/; HttpOnly; SameSite=Lax; Max-Age={SESSION_DURATION_SECS}"
     );
 
-    let response = Html(
-        LoginTemplate {
-            error: None,
-            csrf_token: Some(csrf_token.clone()),
-        }
-        .to_string(),
-    )
+    let response = render_html(&LoginTemplate {
+        error: None,
+        csrf_token: Some(csrf_token.clone()),
+    })
     .into_response();
 
     // Set CSRF cookie
@@ -518,11 +888,35 @@ struct LoginForm {
     csrf_token: String,
 }
 
+/// Largest body accepted on the public routes; a bigger POST /login gets 413 before rate limiting.
+const LOGIN_MAX_BODY_BYTES: usize = 4 * 1024;
+/// Longest password handed to the constant-time compare.
+const LOGIN_MAX_PASSWORD_BYTES: usize = 1024;
+/// Issued CSRF tokens are 64 hex chars; anything much longer is not one of ours.
+const LOGIN_MAX_CSRF_TOKEN_BYTES: usize = 128;
+
 async fn login_submit(
     State(state): State<Arc<AppState>>,
     headers: HeaderMap,
-    Form(form): Form<LoginForm>,
+    form: Result<Form<LoginForm>, FormRejection>,
 ) -> impl IntoResponse {
+    let form = match form {
+        Ok(Form(form)) => form,
+        // Keep axum's 413 for bodies over the limit; anything else that doesn't parse is a 400.
+        Err(rejection) if rejection.status() == StatusCode::PAYLOAD_TOO_LARGE => {
+            return rejection.into_response();
+        }
+        Err(rejection) => {
+            tracing::debug!(error = %rejection, "login rejected: malformed form");
+            return (StatusCode::BAD_REQUEST, "Malformed login form").into_response();
+        }
+    };
+    if form.password.len() > LOGIN_MAX_PASSWORD_BYTES
+        || form.csrf_token.len() > LOGIN_MAX_CSRF_TOKEN_BYTES
+    {
+        return (StatusCode::BAD_REQUEST, "Malformed login form").into_response();
+    }
+
     // If no auth configured, just redirect
     if state.auth_password.is_none() {
         return Redirect::to("/").into_response();
@@ -544,13 +938,10 @@ async fn login_submit(
             "{CSRF_COOKIE_NAME}={new_csrf_token}; Path=/; HttpOnly; SameSite=Lax; Max-Age={SESSION_DURATION_SECS}"
         );
 
-        let response = Html(
-            LoginTemplate {
-                error: Some("Invalid CSRF token".to_string()),
-                csrf_token: Some(new_csrf_token.clone()),
-            }
-            .to_string(),
-        )
+        let response = render_html(&LoginTemplate {
+            error: Some("Invalid CSRF token".to_string()),
+            csrf_token: Some(new_csrf_token.clone()),
+        })
         .into_response();
 
         let mut response = response;
@@ -582,13 +973,10 @@ async fn login_submit(
         let new_csrf_token = generate_csrf_token();
         let csrf_cookie = format!("{CSRF_COOKIE_NAME}={new_csrf_token}; Path=/; HttpOnly; SameSite=Lax; Max-Age={SESSION_DURATION_SECS}");
 
-        let response = Html(
-            LoginTemplate {
-                error: Some("Invalid password".to_string()),
-                csrf_token: Some(new_csrf_token.clone()),
-            }
-            .to_string(),
-        )
+        let response = render_html(&LoginTemplate {
+            error: Some("Invalid password".to_string()),
+            csrf_token: Some(new_csrf_token.clone()),
+        })
         .into_response();
 
         // Set new CSRF cookie
@@ -621,50 +1009,38 @@ async fn status_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse
     })
     .await
     {
-        Ok(status) => Html(StatusStripTemplate { status }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(status) => render_html(&StatusStripTemplate { status }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
 async fn unified_funnel_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let hidden_personas = state.funnel_hidden_personas.clone();
+    let min_age_days = state.persona_min_wallet_age_days;
+    let follow_worthy = state.follow_worthy;
     match with_db(state.clone(), move |conn| {
-        let counts = queries::unified_funnel_counts(conn)?;
+        let counts =
+            queries::unified_funnel_counts(conn, &hidden_personas, min_age_days, follow_worthy)?;
         Ok(counts.to_stages())
     })
     .await
     {
-        Ok(stages) => Html(UnifiedFunnelBarTemplate { stages }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(stages) => render_html(&UnifiedFunnelBarTemplate { stages }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
 async fn async_funnel_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     match with_db(state.clone(), queries::last_run_stats).await {
-        Ok(stats) => Html(AsyncFunnelBarTemplate { stats }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(stats) => render_html(&AsyncFunnelBarTemplate { stats }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
 async fn markets_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     match with_db(state.clone(), queries::top_markets_today).await {
-        Ok(markets) => Html(MarketsTemplate { markets }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(markets) => render_html(&MarketsTemplate { markets }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
@@ -680,35 +1056,49 @@ async fn events_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse
     })
     .await
     {
-        Ok(tmpl) => Html(tmpl.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(tmpl) => render_html(&tmpl).into_response(),
+        Err(e) => db_error_response(&e),
+    }
+}
+
+/// `?since_hours=N` on the wallet list partials: only rows newer than N hours (0 or absent = all time).
+#[derive(Deserialize)]
+struct SinceParams {
+    since_hours: Option<u32>,
+}
+
+impl SinceParams {
+    /// Cutoff as a SQLite `datetime()` string (UTC), or None for all time.
+    fn cutoff(&self) -> Option<String> {
+        let hours = self.since_hours.filter(|h| *h > 0)?;
+        let cutoff = chrono::Utc::now() - chrono::Duration::hours(i64::from(hours));
+        Some(cutoff.format("%Y-%m-%d %H:%M:%S").to_string())
     }
 }
 
-async fn wallets_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+async fn wallets_partial(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SinceParams>,
+) -> impl IntoResponse {
+    let since = params.cutoff();
     match with_db(state.clone(), move |conn| {
-        let wallets = queries::recent_wallets(conn, 10)?;
+        let wallets = queries::recent_wallets(conn, 10, since.as_deref())?;
         Ok(wallets)
     })
     .await
     {
-        Ok(wallets) => Html(WalletsTemplate { wallets }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(wallets) => render_html(&WalletsTemplate { wallets }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
 async fn suitable_personas_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let hidden_personas = state.funnel_hidden_personas.clone();
+    let min_age_days = state.persona_min_wallet_age_days;
     match with_db(state.clone(), move |conn| {
         let personas = queries::suitable_personas_wallets(conn, 20)?;
-        let (suitable_count, evaluated_count) = queries::suitable_personas_counts(conn)?;
+        let (suitable_count, evaluated_count) =
+            queries::suitable_personas_counts(conn, &hidden_personas, min_age_days)?;
         let excluded_count = queries::excluded_wallets_count(conn)?;
         let recent_exclusions = queries::excluded_wallets_latest(conn, 5, 0)?;
         Ok((
@@ -721,89 +1111,87 @@ async fn suitable_personas_partial(State(state): State<Arc<AppState>>) -> impl I
     })
     .await
     {
-        Ok((personas, suitable_count, evaluated_count, excluded_count, recent_exclusions)) => Html(
-            SuitablePersonasTemplate {
+        Ok((personas, suitable_count, evaluated_count, excluded_count, recent_exclusions)) => {
+            render_html(&SuitablePersonasTemplate {
                 personas,
                 suitable_count,
                 evaluated_count,
                 excluded_count,
                 recent_exclusions,
-            }
-            .to_string(),
-        )
-        .into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+            })
+            .into_response()
+        }
+        Err(e) => db_error_response(&e),
     }
 }
 
 async fn personas_summary_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    let hidden_personas = state.funnel_hidden_personas.clone();
+    let min_age_days = state.persona_min_wallet_age_days;
     match with_db(state.clone(), move |conn| {
-        let (suitable_count, evaluated_count) = queries::suitable_personas_counts(conn)?;
+        let (suitable_count, evaluated_count) =
+            queries::suitable_personas_counts(conn, &hidden_personas, min_age_days)?;
         let excluded_count = queries::excluded_wallets_count(conn)?;
         Ok((suitable_count, evaluated_count, excluded_count))
     })
     .await
     {
-        Ok((suitable_count, evaluated_count, excluded_count)) => Html(
-            PersonasSummaryBarTemplate {
+        Ok((suitable_count, evaluated_count, excluded_count)) => {
+            render_html(&PersonasSummaryBarTemplate {
                 suitable_count,
                 evaluated_count,
                 excluded_count,
-            }
-            .to_string(),
-        )
-        .into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+            })
+            .into_response()
+        }
+        Err(e) => db_error_response(&e),
     }
 }
 
-async fn paper_traded_wallets_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+async fn paper_traded_wallets_partial(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<SinceParams>,
+) -> impl IntoResponse {
+    let since = params.cutoff();
     match with_db(state.clone(), move |conn| {
-        let wallets = queries::paper_traded_wallets_list(conn, 20)?;
+        let wallets = queries::paper_traded_wallets_list(conn, 20, since.as_deref())?;
         Ok(wallets)
     })
     .await
     {
-        Ok(wallets) => Html(PaperTradedWalletsTemplate { wallets }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(wallets) => render_html(&PaperTradedWalletsTemplate { wallets }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
-async fn rankings_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+/// `?window=7|30` on the rankings partial: rank by that window's paper ROI (absent or 0 = combined).
+#[derive(Deserialize)]
+struct RankingsQuery {
+    window: Option<u32>,
+}
+
+async fn rankings_partial(
+    State(state): State<Arc<AppState>>,
+    Query(q): Query<RankingsQuery>,
+) -> impl IntoResponse {
+    let Some(window) = queries::RankingWindow::from_days(q.window) else {
+        return (StatusCode::BAD_REQUEST, "window must be 7 or 30").into_response();
+    };
+    let follow_worthy = state.follow_worthy;
     match with_db(state.clone(), move |conn| {
-        queries::follow_worthy_rankings(conn, None)
+        queries::follow_worthy_rankings(conn, None, follow_worthy, window)
     })
     .await
     {
-        Ok(rankings) => Html(RankingsTemplate { rankings }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(rankings) => render_html(&RankingsTemplate { rankings }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
 async fn jobs_partial(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     match with_db(state.clone(), queries::all_job_statuses).await {
-        Ok(jobs) => Html(JobsStatusTemplate { jobs }.to_string()).into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Ok(jobs) => render_html(&JobsStatusTemplate { jobs }).into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
@@ -815,19 +1203,12 @@ async fn persona_breakdown_partial(State(state): State<Arc<AppState>>) -> impl I
     })
     .await
     {
-        Ok((personas, ingestion)) => Html(
-            PersonaBreakdownTemplate {
-                personas,
-                ingestion,
-            }
-            .to_string(),
-        )
+        Ok((personas, ingestion)) => render_html(&PersonaBreakdownTemplate {
+            personas,
+            ingestion,
+        })
         .into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
@@ -853,23 +1234,16 @@ async fn excluded_page(
     {
         Ok((total, rows)) => {
             let total_pages = ((total + page_size - 1) / page_size).max(1);
-            Html(
-                ExcludedTemplate {
-                    rows,
-                    total,
-                    page,
-                    page_size,
-                    total_pages,
-                }
-                .to_string(),
-            )
+            render_html(&ExcludedTemplate {
+                rows,
+                total,
+                page,
+                page_size,
+                total_pages,
+            })
             .into_response()
         }
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
@@ -891,56 +1265,211 @@ async fn fetch_polymarket_display_name(
         return None;
     }
     let json: serde_json::Value = resp.json().await.ok()?;
-    let name = json.get("name").and_then(|v| v.as_str()).map(str::trim);
-    let pseudonym = json
-        .get("pseudonym")
-        .and_then(|v| v.as_str())
-        .map(str::trim);
-    name.filter(|s| !s.is_empty())
-        .or_else(|| pseudonym.filter(|s| !s.is_empty()))
-        .map(String::from)
+    common::polymarket::profile_display_name(&json)
 }
 
-async fn journey_page(
+/// Current Gamma markets for `condition_ids` (one request), keyed by condition id.
+/// Returns None on any HTTP or parse error so callers can fall back to no prices.
+async fn fetch_gamma_markets_by_id(
+    client: &reqwest::Client,
+    gamma_api_url: &str,
+    condition_ids: &[String],
+) -> Option<HashMap<String, common::types::GammaMarket>> {
+    let base = gamma_api_url.trim_end_matches('/');
+    let mut query = vec![("limit", condition_ids.len().to_string())];
+    query.extend(condition_ids.iter().map(|id| ("condition_ids", id.clone())));
+    let resp = client
+        .get(format!("{base}/markets"))
+        .query(&query)
+        .send()
+        .await
+        .ok()?;
+    if !resp.status().is_success() {
+        return None;
+    }
+    let markets: Vec<common::types::GammaMarket> = resp.json().await.ok()?;
+    Some(
+        markets
+            .into_iter()
+            .filter_map(|m| Some((m.condition_id.clone()?, m)))
+            .collect(),
+    )
+}
+
+/// Mark `positions` (one page of active positions, so one Gamma request) to current prices.
+/// Positions without a price, or every position when Gamma is unreachable, keep "—".
+async fn mark_positions_to_market(state: &AppState, positions: &mut [models::WalletPositionRow]) {
+    let (Some(client), Some(url)) = (state.http_client.as_ref(), state.gamma_api_url.as_deref())
+    else {
+        return;
+    };
+    if positions.is_empty() {
+        return;
+    }
+    let mut ids: Vec<String> = positions.iter().map(|p| p.condition_id.clone()).collect();
+    ids.sort();
+    ids.dedup();
+    let Some(markets) = fetch_gamma_markets_by_id(client, url, &ids).await else {
+        tracing::warn!(
+            markets = ids.len(),
+            "position prices unavailable from Gamma"
+        );
+        return;
+    };
+    for p in positions {
+        let price = p
+            .outcome_index
+            .and_then(|idx| usize::try_from(idx).ok())
+            .and_then(|idx| markets.get(&p.condition_id)?.outcome_price(idx));
+        if let Some(price) = price {
+            p.mark_to_market(price);
+        }
+    }
+}
+
+async fn market_page(
     State(state): State<Arc<AppState>>,
-    Path(wallet): Path<String>,
+    Path(condition_id): Path<String>,
 ) -> impl IntoResponse {
-    match with_db(state.clone(), move |conn| {
-        queries::wallet_journey(conn, &wallet)
+    match with_db(state, move |conn| {
+        queries::market_detail(conn, &condition_id)
     })
     .await
     {
-        Ok(Some(mut journey)) => {
-            if let (Some(client), Some(url)) =
-                (state.http_client.as_ref(), state.gamma_api_url.as_deref())
-            {
-                if let Some(name) =
-                    fetch_polymarket_display_name(client, url, &journey.proxy_wallet).await
-                {
-                    journey.wallet_display_label = name;
-                }
-            }
-            Html(JourneyTemplate { journey }.to_string()).into_response()
-        }
+        Ok(Some(market)) => render_html(&MarketTemplate { market }).into_response(),
         Ok(None) => StatusCode::NOT_FOUND.into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
-#[derive(Debug, Deserialize)]
-struct WalletTradesQuery {
-    #[serde(default)]
-    offset: u32,
-    #[serde(default = "default_trades_limit")]
-    limit: u32,
-}
-
-fn default_trades_limit() -> u32 {
-    20
+/// Polymarket display name for `wallet`, served from the TTL cache when fresh. Cold lookups are
+/// bounded by the HTTP client's timeout; misses and failures are cached briefly too.
+async fn cached_display_name(state: &AppState, wallet: &str) -> Option<String> {
+    let (Some(client), Some(url)) = (state.http_client.as_ref(), state.gamma_api_url.as_deref())
+    else {
+        return None;
+    };
+    if let Some(cached) = state.display_names.get(wallet) {
+        return cached;
+    }
+    let name = fetch_polymarket_display_name(client, url, wallet).await;
+    state.display_names.insert(wallet, name.clone());
+    name
+}
+
+/// How `load_wallet_journey` resolves a display name missing from `wallet_profiles`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum DisplayNameFetch {
+    /// Wait for Gamma (API and export responses carry the final name).
+    Live,
+    /// Use the cache only; on a miss render the short label and let the page fetch
+    /// `/wallet/{wallet}/display-name`, so a slow Gamma never delays the page.
+    Deferred,
+}
+
+/// Load a wallet's journey and replace the short wallet label with the Polymarket display
+/// name: from `wallet_profiles` when the evaluator has resolved it, else from Gamma.
+async fn load_wallet_journey(
+    state: Arc<AppState>,
+    wallet: String,
+    fetch: DisplayNameFetch,
+) -> Result<Option<models::WalletJourney>> {
+    let min_shares = state.active_position_min_shares;
+    let loaded = with_db(state.clone(), move |conn| {
+        let Some(journey) = queries::wallet_journey(conn, &wallet, min_shares)? else {
+            return Ok(None);
+        };
+        // A DB not yet migrated with wallet_profiles just falls back to the live fetch.
+        let stored_name = queries::wallet_profile_name(conn, &wallet).ok().flatten();
+        Ok(Some((journey, stored_name)))
+    })
+    .await?;
+    let Some((mut journey, stored_name)) = loaded else {
+        return Ok(None);
+    };
+    let name = match (stored_name, fetch) {
+        (Some(name), _) => name,
+        (None, DisplayNameFetch::Live) => cached_display_name(&state, &journey.proxy_wallet).await,
+        (None, DisplayNameFetch::Deferred) => {
+            match state.display_names.get(&journey.proxy_wallet) {
+                Some(cached) => cached,
+                None => {
+                    journey.display_name_pending =
+                        state.http_client.is_some() && state.gamma_api_url.is_some();
+                    None
+                }
+            }
+        }
+    };
+    if let Some(name) = name {
+        journey.wallet_display_label = name;
+    }
+    Ok(Some(journey))
+}
+
+/// Display name swapped into journey/scorecard pages after they render: the stored profile
+/// name, else Gamma (cached), else the short wallet label.
+async fn wallet_display_name(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+) -> Response {
+    let lookup = wallet.clone();
+    let stored_name = match with_db(state.clone(), move |conn| {
+        // A DB not yet migrated with wallet_profiles just falls back to the live fetch.
+        Ok(queries::wallet_profile_name(conn, &lookup).ok().flatten())
+    })
+    .await
+    {
+        Ok(stored_name) => stored_name,
+        Err(e) => return db_error_response(&e),
+    };
+    let name = match stored_name {
+        Some(name) => name,
+        None => cached_display_name(&state, &wallet).await,
+    };
+    let label = name.unwrap_or_else(|| models::shorten_wallet(&wallet));
+    render_html(&DisplayNameTemplate { label }).into_response()
+}
+
+/// Unrealized PnL for the first `limit` active positions, swapped into the scorecard after it
+/// renders so a slow Gamma never delays the page. Unpriced positions keep their "—".
+async fn wallet_position_prices(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+    Query(q): Query<WalletPositionsQuery>,
+) -> Response {
+    let limit = state.page_limit(q.limit);
+    let min_shares = state.active_position_min_shares;
+    let mut positions = match with_db(state.clone(), move |conn| {
+        queries::wallet_active_positions_page(conn, &wallet, q.offset, limit, min_shares)
+    })
+    .await
+    {
+        Ok((positions, _)) => positions,
+        Err(e) => return db_error_response(&e),
+    };
+    mark_positions_to_market(&state, &mut positions).await;
+    render_html(&PositionPricesTemplate { positions }).into_response()
+}
+
+async fn journey_page(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+) -> impl IntoResponse {
+    match load_wallet_journey(state, wallet, DisplayNameFetch::Deferred).await {
+        Ok(Some(journey)) => render_html(&JourneyTemplate { journey }).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => db_error_response(&e),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WalletTradesQuery {
+    #[serde(default)]
+    offset: u32,
+    limit: Option<u32>,
+    /// `ndjson` streams rows (no page-size cap; omit `limit` for every trade).
+    format: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -952,8 +1481,7 @@ struct WalletTradesResponse {
 #[derive(Debug, Deserialize)]
 struct WalletActivityQuery {
     offset: u32,
-    #[serde(default = "default_positions_limit")]
-    limit: u32,
+    limit: Option<u32>,
 }
 
 #[derive(Serialize)]
@@ -962,16 +1490,28 @@ struct WalletActivityResponse {
     total: u64,
 }
 
+#[derive(Debug, Deserialize)]
+struct WalletFeaturesHistoryQuery {
+    #[serde(default = "default_features_window_days")]
+    window_days: u32,
+    limit: Option<u32>,
+}
+
+fn default_features_window_days() -> u32 {
+    30
+}
+
+#[derive(Serialize)]
+struct WalletFeaturesHistoryResponse {
+    window_days: u32,
+    points: Vec<models::WalletFeaturePoint>,
+}
+
 #[derive(Debug, Deserialize)]
 struct WalletPositionsQuery {
     #[serde(default)]
     offset: u32,
-    #[serde(default = "default_positions_limit")]
-    limit: u32,
-}
-
-fn default_positions_limit() -> u32 {
-    20
+    limit: Option<u32>,
 }
 
 #[derive(Serialize)]
@@ -980,41 +1520,188 @@ struct WalletPositionsResponse {
     total: u64,
 }
 
+#[derive(Debug, Deserialize)]
+struct AllPositionsQuery {
+    #[serde(default)]
+    offset: u32,
+    limit: Option<u32>,
+    #[serde(default)]
+    sort: models::PositionSort,
+}
+
+#[derive(Serialize)]
+struct AllPositionsResponse {
+    positions: Vec<models::ActivePositionRow>,
+    total: u64,
+}
+
+async fn journey_api(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+) -> impl IntoResponse {
+    match load_wallet_journey(state, wallet, DisplayNameFetch::Live).await {
+        Ok(Some(journey)) => Json(journey).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => db_error_response(&e),
+    }
+}
+
+/// `?format=html|json` on the journey export (default html).
+#[derive(Deserialize)]
+struct JourneyExportQuery {
+    format: Option<String>,
+}
+
+/// Download a wallet's journey as one self-contained file for sharing in review docs.
+async fn journey_export(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+    Query(q): Query<JourneyExportQuery>,
+) -> Response {
+    let format = q.format.unwrap_or_else(|| "html".to_string());
+    if format != "html" && format != "json" {
+        return (StatusCode::BAD_REQUEST, "format must be html or json").into_response();
+    }
+    let journey = match load_wallet_journey(state, wallet, DisplayNameFetch::Live).await {
+        Ok(Some(journey)) => journey,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => return db_error_response(&e),
+    };
+    let disposition = format!(
+        "attachment; filename=\"journey-{}.{format}\"",
+        journey.proxy_wallet
+    );
+    let mut response = if format == "json" {
+        Json(journey).into_response()
+    } else {
+        let exported_at = chrono::Utc::now().format("%Y-%m-%d %H:%M UTC").to_string();
+        render_html(&JourneyExportTemplate {
+            journey,
+            exported_at,
+        })
+        .into_response()
+    };
+    if let Ok(value) = header::HeaderValue::from_str(&disposition) {
+        response
+            .headers_mut()
+            .insert(header::CONTENT_DISPOSITION, value);
+    }
+    response
+}
+
+/// Per-component breakdown of the wallet's latest 30d WScore (see `queries::wscore_explanation`).
+async fn wscore_explanation_api(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+) -> impl IntoResponse {
+    let weights = state.wscore_weights;
+    match with_db(state, move |conn| {
+        queries::wscore_explanation(conn, &wallet, weights)
+    })
+    .await
+    {
+        Ok(Some(explanation)) => Json(explanation).into_response(),
+        Ok(None) => StatusCode::NOT_FOUND.into_response(),
+        Err(e) => db_error_response(&e),
+    }
+}
+
 async fn scorecard_page(
     State(state): State<Arc<AppState>>,
     Path(wallet): Path<String>,
+    headers: HeaderMap,
 ) -> impl IntoResponse {
-    match with_db(state.clone(), move |conn| {
-        queries::wallet_journey(conn, &wallet)
+    let trader_connected = state.trader_api_url.is_some();
+    let journey = match load_wallet_journey(
+        state.clone(),
+        wallet.clone(),
+        DisplayNameFetch::Deferred,
+    )
+    .await
+    {
+        Ok(Some(journey)) => journey,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => return db_error_response(&e),
+    };
+    let position_prices_pending = !journey.active_positions.is_empty()
+        && state.http_client.is_some()
+        && state.gamma_api_url.is_some();
+    let weights = state.wscore_weights;
+    let (notes, explanation) = with_db(state, move |conn| {
+        // A DB not yet migrated with wallet_notes just shows no notes.
+        let notes = queries::wallet_notes(conn, &wallet).unwrap_or_default();
+        let explanation = queries::wscore_explanation(conn, &wallet, weights)?;
+        Ok((notes, explanation))
+    })
+    .await
+    .unwrap_or_default();
+
+    // Same CSRF cookie as the login form; reused so several open tabs keep valid forms.
+    let csrf_token =
+        header_get_cookie_value(&headers, CSRF_COOKIE_NAME).unwrap_or_else(generate_csrf_token);
+    let csrf_cookie = format!(
+        "{CSRF_COOKIE_NAME}={csrf_token}; Path=/; HttpOnly; SameSite=Lax; Max-Age={SESSION_DURATION_SECS}"
+    );
+    let mut response = render_html(&ScorecardTemplate {
+        journey,
+        trader_connected,
+        notes,
+        explanation,
+        csrf_token,
+        max_note_chars: MAX_WALLET_NOTE_CHARS,
+        position_prices_pending,
+    })
+    .into_response();
+    response
+        .headers_mut()
+        .insert(header::SET_COOKIE, csrf_cookie.parse().unwrap());
+    response
+}
+
+/// Longest note accepted by `POST /wallet/{wallet}/note`, in characters.
+const MAX_WALLET_NOTE_CHARS: usize = 500;
+
+#[derive(Deserialize)]
+struct WalletNoteForm {
+    note: String,
+    csrf_token: String,
+}
+
+/// Add an operator note to a known wallet (404 otherwise), then redirect back to its scorecard.
+async fn wallet_note_submit(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+    headers: HeaderMap,
+    Form(form): Form<WalletNoteForm>,
+) -> Response {
+    if !verify_csrf_token(&headers, &form.csrf_token) {
+        tracing::warn!(wallet = %wallet, "wallet note rejected: CSRF mismatch");
+        return (
+            StatusCode::FORBIDDEN,
+            "Invalid CSRF token. Reload the page.",
+        )
+            .into_response();
+    }
+    let note = form.note.trim().to_string();
+    if note.is_empty() || note.chars().count() > MAX_WALLET_NOTE_CHARS {
+        return (
+            StatusCode::BAD_REQUEST,
+            format!("Note must be 1-{MAX_WALLET_NOTE_CHARS} characters."),
+        )
+            .into_response();
+    }
+    match with_db_write(state, move |conn| {
+        queries::insert_wallet_note(conn, &wallet, &note)
     })
     .await
     {
-        Ok(Some(mut journey)) => {
-            if let (Some(client), Some(url)) =
-                (state.http_client.as_ref(), state.gamma_api_url.as_deref())
-            {
-                if let Some(name) =
-                    fetch_polymarket_display_name(client, url, &journey.proxy_wallet).await
-                {
-                    journey.wallet_display_label = name;
-                }
-            }
-            let trader_connected = state.trader_api_url.is_some();
-            Html(
-                ScorecardTemplate {
-                    journey,
-                    trader_connected,
-                }
-                .to_string(),
-            )
-            .into_response()
+        Ok(Some(stored)) => {
+            let wallet =
+                percent_encoding::utf8_percent_encode(&stored, percent_encoding::NON_ALPHANUMERIC);
+            Redirect::to(&format!("/wallet/{wallet}")).into_response()
         }
         Ok(None) => StatusCode::NOT_FOUND.into_response(),
-        Err(e) => (
-            StatusCode::SERVICE_UNAVAILABLE,
-            format!("DB unavailable: {e}"),
-        )
-            .into_response(),
+        Err(e) => db_error_response(&e),
     }
 }
 
@@ -1023,8 +1710,12 @@ async fn wallet_trades_json(
     Path(wallet): Path<String>,
     Query(q): Query<WalletTradesQuery>,
 ) -> impl IntoResponse {
+    if q.format.as_deref() == Some("ndjson") {
+        return wallet_trades_ndjson(state, wallet, q.offset, q.limit).await;
+    }
+    let limit = state.page_limit(q.limit);
     match with_db(state.clone(), move |conn| {
-        queries::wallet_trades_page(conn, &wallet, q.offset, q.limit)
+        queries::wallet_trades_page(conn, &wallet, q.offset, limit)
     })
     .await
     {
@@ -1040,12 +1731,49 @@ async fn wallet_trades_json(
     }
 }
 
+/// Stream a wallet's trades as NDJSON (one `WalletTradeRow` per line), fed from the blocking
+/// DB task through a bounded channel so large exports are never buffered in memory.
+///
+/// The status is already sent once rows flow, so a DB error is reported as a trailing
+/// `{"error": ...}` line. No `db_timeout` applies: a long export is the point.
+async fn wallet_trades_ndjson(
+    state: Arc<AppState>,
+    wallet: String,
+    offset: u32,
+    limit: Option<u32>,
+) -> Response {
+    let Ok(permit) = state.db_semaphore.clone().acquire_owned().await else {
+        return StatusCode::SERVICE_UNAVAILABLE.into_response();
+    };
+    let (tx, rx) = tokio::sync::mpsc::channel::<Result<String, std::convert::Infallible>>(64);
+    tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        let result = open_readonly(&state).and_then(|conn| {
+            queries::for_each_wallet_trade(&conn, &wallet, offset, limit, |trade| {
+                let line = serde_json::to_string(trade)? + "\n";
+                // A closed channel means the client went away: stop reading rows.
+                Ok(tx.blocking_send(Ok(line)).is_ok())
+            })
+        });
+        if let Err(e) = result {
+            tracing::warn!(error = %e, "wallet trades NDJSON stream failed");
+            let line = serde_json::json!({ "error": e.to_string() }).to_string() + "\n";
+            let _ = tx.blocking_send(Ok(line));
+        }
+    });
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        Body::from_stream(tokio_stream::wrappers::ReceiverStream::new(rx)),
+    )
+        .into_response()
+}
+
 async fn wallet_positions_json(
     State(state): State<Arc<AppState>>,
     Path(wallet): Path<String>,
     Query(q): Query<WalletPositionsQuery>,
 ) -> impl IntoResponse {
-    let limit = q.limit.min(100);
+    let limit = state.page_limit(q.limit);
     match with_db(state.clone(), move |conn| {
         queries::wallet_positions_page(conn, &wallet, q.offset, limit)
     })
@@ -1072,9 +1800,10 @@ async fn wallet_active_positions_json(
     Path(wallet): Path<String>,
     Query(q): Query<WalletPositionsQuery>,
 ) -> impl IntoResponse {
-    let limit = q.limit.min(100);
+    let limit = state.page_limit(q.limit);
+    let min_shares = state.active_position_min_shares;
     match with_db(state.clone(), move |conn| {
-        queries::wallet_active_positions_page(conn, &wallet, q.offset, limit)
+        queries::wallet_active_positions_page(conn, &wallet, q.offset, limit, min_shares)
     })
     .await
     {
@@ -1099,9 +1828,10 @@ async fn wallet_closed_positions_json(
     Path(wallet): Path<String>,
     Query(q): Query<WalletPositionsQuery>,
 ) -> impl IntoResponse {
-    let limit = q.limit.min(100);
+    let limit = state.page_limit(q.limit);
+    let min_shares = state.active_position_min_shares;
     match with_db(state.clone(), move |conn| {
-        queries::wallet_closed_positions_page(conn, &wallet, q.offset, limit)
+        queries::wallet_closed_positions_page(conn, &wallet, q.offset, limit, min_shares)
     })
     .await
     {
@@ -1126,7 +1856,7 @@ async fn wallet_activity_json(
     Path(wallet): Path<String>,
     Query(q): Query<WalletActivityQuery>,
 ) -> impl IntoResponse {
-    let limit = q.limit.min(100);
+    let limit = state.page_limit(q.limit);
     match with_db(state.clone(), move |conn| {
         queries::wallet_activity_page(conn, &wallet, q.offset, limit)
     })
@@ -1148,6 +1878,34 @@ async fn wallet_activity_json(
     }
 }
 
+async fn wallet_features_history_json(
+    State(state): State<Arc<AppState>>,
+    Path(wallet): Path<String>,
+    Query(q): Query<WalletFeaturesHistoryQuery>,
+) -> impl IntoResponse {
+    let limit = state.page_limit(q.limit);
+    let window_days = q.window_days;
+    match with_db(state.clone(), move |conn| {
+        queries::wallet_features_history(conn, &wallet, window_days, limit)
+    })
+    .await
+    {
+        Ok(points) => Json(WalletFeaturesHistoryResponse {
+            window_days,
+            points,
+        })
+        .into_response(),
+        Err(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(WalletFeaturesHistoryResponse {
+                window_days,
+                points: vec![],
+            }),
+        )
+            .into_response(),
+    }
+}
+
 async fn spawn_derived_gauges_updater(state: Arc<AppState>) {
     // Best-effort: these are derived metrics for UI/Grafana; failures should never take down web.
     let mut interval = tokio::time::interval(Duration::from_secs(60));
@@ -1187,7 +1945,24 @@ async fn spawn_derived_gauges_updater(state: Arc<AppState>) {
             .set(c.wallets_ranked as f64);
         }
 
-        if let Ok(c) = with_db(state.clone(), queries::persona_funnel_counts).await {
+        if let Ok(s) = with_db(state.clone(), queries::last_run_stats).await {
+            ::metrics::gauge!("evaluator_last_run_trades_inserted").set(s.trades_inserted as f64);
+            ::metrics::gauge!("evaluator_last_run_trades_wallets").set(s.trades_wallets as f64);
+            ::metrics::gauge!("evaluator_last_run_events_markets").set(s.events_markets as f64);
+            for (run, at) in [("trades", &s.trades_run_at), ("events", &s.events_run_at)] {
+                if let Some(ts) = at.as_deref().and_then(queries::parse_sqlite_datetime) {
+                    ::metrics::gauge!("evaluator_last_run_timestamp_seconds", "run" => run)
+                        .set(ts.timestamp() as f64);
+                }
+            }
+        }
+
+        let follow_worthy = state.follow_worthy;
+        if let Ok(c) = with_db(state.clone(), move |conn| {
+            queries::persona_funnel_counts(conn, follow_worthy)
+        })
+        .await
+        {
             ::metrics::gauge!(
                 "evaluator_persona_funnel_stage_count",
                 "stage" => "wallets_discovered"
@@ -1217,6 +1992,77 @@ async fn spawn_derived_gauges_updater(state: Arc<AppState>) {
     }
 }
 
+// --- Portfolio-wide active positions API ---
+
+async fn all_positions_api(
+    State(state): State<Arc<AppState>>,
+    Query(q): Query<AllPositionsQuery>,
+) -> impl IntoResponse {
+    let limit = state.page_limit(q.limit);
+    let min_shares = state.active_position_min_shares;
+    match with_db(state.clone(), move |conn| {
+        queries::all_active_positions(conn, q.offset, limit, q.sort, min_shares)
+    })
+    .await
+    {
+        Ok((positions, total)) => Json(AllPositionsResponse {
+            positions,
+            total: total as u64,
+        })
+        .into_response(),
+        Err(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(AllPositionsResponse {
+                positions: vec![],
+                total: 0,
+            }),
+        )
+            .into_response(),
+    }
+}
+
+// --- Last-run API (external monitoring) ---
+
+/// Last trades-ingestion / event-scoring run stats returned by GET /api/v1/last-run.
+/// Run-at timestamps are RFC3339 UTC, or null if the job has not run yet.
+#[derive(Serialize)]
+struct LastRunResponse {
+    trades_wallets: i64,
+    trades_inserted: i64,
+    events_markets: i64,
+    trades_run_at: Option<String>,
+    events_run_at: Option<String>,
+}
+
+impl From<LastRunStats> for LastRunResponse {
+    fn from(s: LastRunStats) -> Self {
+        Self {
+            trades_wallets: s.trades_wallets,
+            trades_inserted: s.trades_inserted,
+            events_markets: s.events_markets,
+            trades_run_at: s
+                .trades_run_at
+                .as_deref()
+                .and_then(queries::parse_sqlite_datetime)
+                .map(|dt| dt.to_rfc3339()),
+            events_run_at: s
+                .events_run_at
+                .as_deref()
+                .and_then(queries::parse_sqlite_datetime)
+                .map(|dt| dt.to_rfc3339()),
+        }
+    }
+}
+
+async fn last_run_api(
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<LastRunResponse>, StatusCode> {
+    let stats = with_db(state, queries::last_run_stats)
+        .await
+        .map_err(|_db_err| StatusCode::SERVICE_UNAVAILABLE)?;
+    Ok(Json(stats.into()))
+}
+
 // --- Recommended Wallets API (for trader microservice to poll) ---
 
 /// Wallet recommendation returned by GET /api/recommended-wallets.
@@ -1235,8 +2081,14 @@ struct RecommendedWallet {
 async fn recommended_wallets_api(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<Vec<RecommendedWallet>>, StatusCode> {
+    let follow_worthy = state.follow_worthy;
     let rankings = with_db(state, move |conn| {
-        queries::follow_worthy_rankings(conn, Some(50))
+        queries::follow_worthy_rankings(
+            conn,
+            Some(50),
+            follow_worthy,
+            queries::RankingWindow::Combined,
+        )
     })
     .await
     .map_err(|_db_err| StatusCode::SERVICE_UNAVAILABLE)?;
@@ -1333,7 +2185,7 @@ struct TraderOverviewTemplate {
 
 async fn trader_overview_page(State(state): State<Arc<AppState>>) -> impl IntoResponse {
     let trader_connected = state.trader_api_url.is_some();
-    Html(TraderOverviewTemplate { trader_connected }.to_string()).into_response()
+    render_html(&TraderOverviewTemplate { trader_connected }).into_response()
 }
 
 // --- Router ---
@@ -1350,14 +2202,21 @@ pub fn create_router_with_state(state: Arc<AppState>) -> Router {
         .layer(middleware::from_fn_with_state(
             state.login_rate_limiter.clone(),
             login_rate_limit_middleware,
-        )); // Apply rate limiting only to login
+        )) // Apply rate limiting only to login
+        // Outside the rate limiter so oversized bodies are refused before they count as attempts.
+        .layer(RequestBodyLimitLayer::new(LOGIN_MAX_BODY_BYTES));
 
     // Protected routes (auth required if password is set)
     let protected_routes = Router::new()
         .route("/", get(index))
         .route("/excluded", get(excluded_page))
+        .route("/market/{condition_id}", get(market_page))
         .route("/journey/{wallet}", get(journey_page))
         .route("/wallet/{wallet}", get(scorecard_page))
+        .route(
+            "/wallet/{wallet}/note",
+            axum::routing::post(wallet_note_submit),
+        )
         .route("/wallet/{wallet}/trades", get(wallet_trades_json))
         .route("/wallet/{wallet}/positions", get(wallet_positions_json))
         .route(
@@ -1369,6 +2228,16 @@ pub fn create_router_with_state(state: Arc<AppState>) -> Router {
             get(wallet_closed_positions_json),
         )
         .route("/wallet/{wallet}/activity", get(wallet_activity_json))
+        .route("/wallet/{wallet}/export", get(journey_export))
+        .route("/wallet/{wallet}/display-name", get(wallet_display_name))
+        .route(
+            "/wallet/{wallet}/position-prices",
+            get(wallet_position_prices),
+        )
+        .route(
+            "/wallet/{wallet}/features-history",
+            get(wallet_features_history_json),
+        )
         .route("/partials/status", get(status_partial))
         .route("/partials/async_funnel", get(async_funnel_partial))
         .route("/partials/unified_funnel", get(unified_funnel_partial))
@@ -1384,12 +2253,19 @@ pub fn create_router_with_state(state: Arc<AppState>) -> Router {
             "/partials/paper_traded_wallets",
             get(paper_traded_wallets_partial),
         )
+        // One WebSocket per dashboard carrying status, funnel and rankings (see live.rs)
+        .route("/ws", get(live::dashboard_ws))
         .route("/partials/rankings", get(rankings_partial))
         .route("/partials/jobs", get(jobs_partial))
         .route(
             "/partials/persona_breakdown",
             get(persona_breakdown_partial),
         )
+        // Last-run stats for external monitoring (staleness alerts)
+        .route("/api/v1/last-run", get(last_run_api))
+        .route("/api/v1/positions", get(all_positions_api))
+        .route("/api/v1/journey/{wallet}", get(journey_api))
+        .route("/api/v1/wscore/{wallet}", get(wscore_explanation_api))
         // Recommended wallets API (for trader microservice to poll)
         .route("/api/recommended-wallets", get(recommended_wallets_api))
         // Trader dashboard pages
@@ -1401,13 +2277,36 @@ pub fn create_router_with_state(state: Arc<AppState>) -> Router {
             auth_middleware,
         ));
 
+    let access_log_state = state.clone();
     public_routes
         .merge(protected_routes)
-        .layer(middleware::from_fn(security_headers_middleware)) // Security headers for all responses
-        .layer(TraceLayer::new_for_http())
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            db_unavailable_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            security_headers_middleware,
+        )) // Security headers for all responses
+        .layer(middleware::from_fn(handler_latency_middleware))
+        .layer(
+            TraceLayer::new_for_http()
+                .make_span_with(move |request: &Request| {
+                    access_log_span(&access_log_state, request)
+                })
+                .on_request(())
+                .on_response(log_access),
+        )
         .with_state(state)
 }
 
+/// Exporter with the configured latency buckets applied to every `evaluator_*` histogram.
+fn prometheus_builder(buckets_ms: &[f64]) -> Result<PrometheusBuilder> {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(Matcher::Prefix("evaluator_".to_string()), buckets_ms)
+        .map_err(anyhow::Error::from)
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Load config — use [web] section if present, otherwise defaults
@@ -1419,25 +2318,43 @@ async fn main() -> Result<()> {
 
     // Prometheus endpoint for web service health. Alloy scrapes this on localhost:3000.
     let metrics_addr: SocketAddr = ([127, 0, 0, 1], 3000).into();
-    PrometheusBuilder::new()
-        .set_buckets_for_metric(
-            Matcher::Prefix("evaluator_".to_string()),
-            &[
-                1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0, 200.0, 500.0, 1000.0, 2000.0, 5000.0,
-                10000.0,
-            ],
-        )
-        .map_err(anyhow::Error::from)?
+    let buckets = config.web.as_ref().map_or(
+        &common::config::DEFAULT_METRICS_HISTOGRAM_BUCKETS_MS[..],
+        |w| &w.metrics_histogram_buckets_ms,
+    );
+    prometheus_builder(buckets)?
         .with_http_listener(metrics_addr)
         .install()
         .map_err(anyhow::Error::msg)?;
     let db_path = PathBuf::from(&config.database.path);
+    let db_startup_error = match probe_db(&db_path) {
+        DbProbe::Ready => None,
+        DbProbe::NotMigrated => {
+            tracing::warn!(
+                path = %db_path.display(),
+                "evaluator DB not created or migrated yet; dashboard stays empty until the evaluator runs"
+            );
+            None
+        }
+        DbProbe::Unusable(e) => {
+            tracing::error!(
+                path = %db_path.display(),
+                error = %e,
+                "evaluator DB is unusable (corrupt or locked); serving 503 until restart"
+            );
+            Some(e)
+        }
+        DbProbe::SchemaMismatch(e) => {
+            anyhow::bail!("refusing to start against {}: {e}", db_path.display());
+        }
+    };
     let web_port = config.web.as_ref().map_or(8080, |w| w.port);
     let web_host = config
         .web
         .as_ref()
         .map_or("0.0.0.0".to_string(), |w| w.host.clone());
     let auth_password = config.web.as_ref().and_then(|w| w.auth_password.clone());
+    let api_token = config.web.as_ref().and_then(|w| w.api_token.clone());
     let funnel_stage_infos = common::funnel::funnel_stage_infos(&config);
     metrics::init()?;
 
@@ -1446,31 +2363,148 @@ async fn main() -> Result<()> {
         .build()
         .ok();
     let trader_api_url = config.web.as_ref().and_then(|w| w.trader_api_url.clone());
-    let state = Arc::new(AppState {
-        db_path,
-        auth_password,
-        funnel_stage_infos,
-        db_semaphore: Arc::new(Semaphore::new(8)),
-        login_rate_limiter: Arc::new(LoginRateLimiter::new()),
-        db_timeout: Duration::from_secs(5),
-        db_open_delay: Duration::ZERO,
-        paper_bankroll_usdc: config.risk.paper_bankroll_usdc,
+    let max_concurrent_db_queries = config
+        .web
+        .as_ref()
+        .map_or(8, |w| w.max_concurrent_db_queries);
+    let db_timeout_ms = config.web.as_ref().map_or(5000, |w| w.db_timeout_ms);
+    let (default_page_size, max_page_size) = config
+        .web
+        .as_ref()
+        .map_or((20, 100), |w| (w.default_page_size, w.max_page_size));
+    let hsts_enabled = config.web.as_ref().is_none_or(|w| w.hsts_enabled);
+    let active_position_min_shares = config
+        .web
+        .as_ref()
+        .map_or(0.5, |w| w.active_position_min_shares);
+    let content_security_policy = header::HeaderValue::from_str(&config.web.as_ref().map_or_else(
+        || content_security_policy(&[], &[]),
+        |w| content_security_policy(&w.csp_extra_script_src, &w.csp_extra_connect_src),
+    ))?;
+    let display_name_cache_ttl_secs = config
+        .web
+        .as_ref()
+        .map_or(3600, |w| w.display_name_cache_ttl_secs);
+    let dashboard_push_interval_secs = config
+        .web
+        .as_ref()
+        .map_or(10, |w| w.dashboard_push_interval_secs);
+    let state = Arc::new(AppState {
+        db_path,
+        auth_password,
+        api_token,
+        funnel_stage_infos,
+        db_semaphore: Arc::new(Semaphore::new(max_concurrent_db_queries)),
+        login_rate_limiter: Arc::new(LoginRateLimiter::new()),
+        db_timeout: Duration::from_millis(db_timeout_ms),
+        db_open_delay: Duration::ZERO,
+        paper_bankroll_usdc: config.risk.paper_bankroll_usdc,
         max_total_exposure_pct: config.paper_trading.max_total_exposure_pct,
         max_daily_loss_pct: config.paper_trading.max_daily_loss_pct,
         max_concurrent_positions: i64::from(config.risk.max_concurrent_positions),
         gamma_api_url: Some(config.polymarket.gamma_api_url.clone()),
         http_client,
         trader_api_url,
+        funnel_hidden_personas: config.personas.funnel_hidden_personas(),
+        persona_min_wallet_age_days: config.personas.stage1_min_wallet_age_days,
+        follow_worthy: queries::FollowWorthyThresholds::from(&config.ranking),
+        wscore_weights: queries::WScoreWeights::from(&config.wallet_scoring),
+        display_names: display_names::DisplayNameCache::new(Duration::from_secs(
+            display_name_cache_ttl_secs,
+        )),
+        default_page_size,
+        max_page_size,
+        active_position_min_shares,
+        hsts_enabled,
+        content_security_policy,
+        db_startup_error,
+        dashboard: live::DashboardHub::new(Duration::from_secs(dashboard_push_interval_secs)),
     });
 
     tokio::spawn(spawn_derived_gauges_updater(state.clone()));
+    tokio::spawn(live::spawn_dashboard_publisher(state.clone()));
+    tokio::spawn(spawn_login_limiter_sweeper(
+        state.login_rate_limiter.clone(),
+    ));
 
     let app = create_router_with_state(state);
     let addr: SocketAddr = format!("{web_host}:{web_port}").parse()?;
     tracing::info!("dashboard listening on {}", addr);
     let listener = tokio::net::TcpListener::bind(addr).await?;
-    axum::serve(listener, app).await?;
-    Ok(())
+    serve(
+        listener,
+        app,
+        ConnectionTuning::from_config(config.web.as_ref()),
+    )
+    .await
+}
+
+/// Connection-level HTTP settings from `[web]`; the defaults are hyper's own.
+#[derive(Debug, Clone, Copy)]
+struct ConnectionTuning {
+    http1_keep_alive: bool,
+    http2_max_concurrent_streams: u32,
+    http2_keep_alive_interval: Option<Duration>,
+    http2_keep_alive_timeout: Duration,
+}
+
+impl ConnectionTuning {
+    fn from_config(web: Option<&common::config::Web>) -> Self {
+        Self {
+            http1_keep_alive: web.is_none_or(|w| w.http1_keep_alive),
+            http2_max_concurrent_streams: web.map_or(200, |w| w.http2_max_concurrent_streams),
+            http2_keep_alive_interval: web
+                .map(|w| w.http2_keep_alive_interval_secs)
+                .filter(|&secs| secs > 0)
+                .map(Duration::from_secs),
+            http2_keep_alive_timeout: Duration::from_secs(
+                web.map_or(20, |w| w.http2_keep_alive_timeout_secs),
+            ),
+        }
+    }
+}
+
+/// Accept loop in place of `axum::serve`, which doesn't expose hyper's connection builder.
+/// Serves HTTP/1.1 and prior-knowledge HTTP/2 (h2c from a reverse proxy) on the same port.
+async fn serve(
+    listener: tokio::net::TcpListener,
+    app: Router,
+    tuning: ConnectionTuning,
+) -> Result<()> {
+    use hyper_util::rt::{TokioExecutor, TokioIo, TokioTimer};
+    use hyper_util::server::conn::auto::Builder;
+    use hyper_util::service::TowerToHyperService;
+
+    let mut builder = Builder::new(TokioExecutor::new());
+    builder.http1().keep_alive(tuning.http1_keep_alive);
+    builder
+        .http2()
+        .timer(TokioTimer::new())
+        .max_concurrent_streams(tuning.http2_max_concurrent_streams)
+        .keep_alive_interval(tuning.http2_keep_alive_interval)
+        .keep_alive_timeout(tuning.http2_keep_alive_timeout);
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                // Typically EMFILE; back off instead of spinning, as axum::serve does.
+                tracing::warn!(error = %e, "accept failed");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+                continue;
+            }
+        };
+        let builder = builder.clone();
+        let service = TowerToHyperService::new(app.clone());
+        tokio::spawn(async move {
+            if let Err(e) = builder
+                .serve_connection_with_upgrades(TokioIo::new(stream), service)
+                .await
+            {
+                tracing::debug!(%peer, error = %e, "connection closed with error");
+            }
+        });
+    }
 }
 
 #[cfg(test)]
@@ -1486,6 +2520,55 @@ mod tests {
     use std::time::Duration;
     use tower::ServiceExt;
 
+    #[test]
+    fn test_login_rate_limiter_sweep_evicts_stale_ips() {
+        let limiter = LoginRateLimiter::new();
+        limiter.record_attempt_at("1.1.1.1", 1_000);
+        limiter.record_attempt_at("2.2.2.2", 1_050);
+        assert_eq!(limiter.sweep_stale_at(1_059), 0);
+
+        // 1.1.1.1's only attempt is now outside the window; 2.2.2.2 is still live.
+        assert_eq!(limiter.sweep_stale_at(1_065), 1);
+        assert_eq!(limiter.tracked_ips(), 1);
+        assert_eq!(limiter.sweep_stale_at(1_200), 1);
+        assert_eq!(limiter.tracked_ips(), 0);
+    }
+
+    #[test]
+    fn test_login_rate_limiter_check_does_not_track_new_ips() {
+        let limiter = LoginRateLimiter::new();
+        assert!(!limiter.is_rate_limited_at("3.3.3.3", 1_000));
+        assert_eq!(limiter.tracked_ips(), 0);
+        for _ in 0..LOGIN_MAX_ATTEMPTS {
+            limiter.record_attempt_at("3.3.3.3", 1_000);
+        }
+        assert!(limiter.is_rate_limited_at("3.3.3.3", 1_030));
+        assert!(!limiter.is_rate_limited_at("3.3.3.3", 1_060));
+    }
+
+    #[test]
+    fn test_login_rate_limiter_caps_tracked_ips() {
+        let limiter = LoginRateLimiter::new();
+        limiter.record_attempt_at("10.0.0.0", 990);
+        for i in 1..LOGIN_MAX_TRACKED_IPS {
+            limiter.record_attempt_at(&format!("10.0.{}.{}", i / 256, i % 256), 1_000);
+        }
+        assert_eq!(limiter.tracked_ips(), LOGIN_MAX_TRACKED_IPS);
+
+        // Map is full of live entries: the IP with the oldest attempt makes room.
+        limiter.record_attempt_at("9.9.9.9", 1_010);
+        assert_eq!(limiter.tracked_ips(), LOGIN_MAX_TRACKED_IPS);
+        let (has_newest, has_oldest) = {
+            let attempts = limiter.attempts.lock().unwrap();
+            (
+                attempts.contains_key("9.9.9.9"),
+                attempts.contains_key("10.0.0.0"),
+            )
+        };
+        assert!(has_newest);
+        assert!(!has_oldest);
+    }
+
     #[test]
     fn test_funnel_info_icon_uses_css_tooltip_data_tip() {
         let info = "a>=b and \"quoted\"".to_string();
@@ -1525,6 +2608,54 @@ mod tests {
             !help_tip_tag.contains("title=\""),
             "help-tip span should not use native title tooltips"
         );
+
+        // Real stage infos carry the configured thresholds through escaping (`>` in `roi>N`).
+        let mut cfg =
+            common::config::Config::from_toml_str(include_str!("../../../config/default.toml"))
+                .unwrap();
+        cfg.ranking.min_roi_7d_pct = 7.5;
+        let infos = common::funnel::funnel_stage_infos(&cfg);
+        let counts = models::FunnelCounts {
+            markets_fetched: 0,
+            markets_scored: 0,
+            wallets_discovered: 0,
+            wallets_active: 0,
+            paper_trades_total: 0,
+            wallets_ranked: 0,
+        };
+        let html = FunnelBarTemplate {
+            stages: counts.to_stages(&infos),
+        }
+        .to_string();
+        assert!(
+            html.contains("min_roi_7d_pct&gt;7.5") || html.contains("min_roi_7d_pct&#62;7.5"),
+            "expected configured ROI cutoff in tooltip"
+        );
+    }
+
+    struct FailingDisplay;
+
+    impl std::fmt::Display for FailingDisplay {
+        fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            Err(std::fmt::Error)
+        }
+    }
+
+    #[derive(Template)]
+    #[template(source = "<p>{{ value }}</p>", ext = "html")]
+    struct FailingTemplate {
+        value: FailingDisplay,
+    }
+
+    #[test]
+    fn test_render_html_returns_fallback_fragment_on_render_error() {
+        let Html(html) = render_html(&FailingTemplate {
+            value: FailingDisplay,
+        });
+        assert_eq!(html, TEMPLATE_ERROR_FRAGMENT);
+
+        let Html(ok) = render_html(&DashboardTemplate);
+        assert_ne!(ok, TEMPLATE_ERROR_FRAGMENT);
     }
 
     fn create_test_app() -> Router {
@@ -1546,6 +2677,7 @@ mod tests {
         let state = Arc::new(AppState {
             db_path: path,
             auth_password: None,
+            api_token: None,
             funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
             db_semaphore: Arc::new(Semaphore::new(8)),
             login_rate_limiter: Arc::new(LoginRateLimiter::new()),
@@ -1558,6 +2690,22 @@ mod tests {
             gamma_api_url: None,
             http_client: None,
             trader_api_url: None,
+            funnel_hidden_personas: cfg.personas.funnel_hidden_personas(),
+            persona_min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
+            follow_worthy: queries::FollowWorthyThresholds::from(&cfg.ranking),
+            wscore_weights: queries::WScoreWeights::from(&cfg.wallet_scoring),
+            display_names: display_names::DisplayNameCache::new(Duration::ZERO),
+            default_page_size: 20,
+            max_page_size: 100,
+            active_position_min_shares: 0.5,
+            hsts_enabled: true,
+            content_security_policy: header::HeaderValue::from_str(&content_security_policy(
+                &[],
+                &[],
+            ))
+            .unwrap(),
+            db_startup_error: None,
+            dashboard: live::DashboardHub::new(Duration::from_secs(10)),
         });
         create_router_with_state(state)
     }
@@ -1577,6 +2725,7 @@ mod tests {
         let state = Arc::new(AppState {
             db_path: path,
             auth_password: Some(password.to_string()),
+            api_token: None,
             funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
             db_semaphore: Arc::new(Semaphore::new(8)),
             login_rate_limiter: Arc::new(LoginRateLimiter::new()),
@@ -1589,11 +2738,47 @@ mod tests {
             gamma_api_url: None,
             http_client: None,
             trader_api_url: None,
+            funnel_hidden_personas: cfg.personas.funnel_hidden_personas(),
+            persona_min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
+            follow_worthy: queries::FollowWorthyThresholds::from(&cfg.ranking),
+            wscore_weights: queries::WScoreWeights::from(&cfg.wallet_scoring),
+            display_names: display_names::DisplayNameCache::new(Duration::ZERO),
+            default_page_size: 20,
+            max_page_size: 100,
+            active_position_min_shares: 0.5,
+            hsts_enabled: true,
+            content_security_policy: header::HeaderValue::from_str(&content_security_policy(
+                &[],
+                &[],
+            ))
+            .unwrap(),
+            db_startup_error: None,
+            dashboard: live::DashboardHub::new(Duration::from_secs(10)),
         });
         create_router_with_state(state)
     }
 
     fn create_test_app_with_auth_and_db_delay(password: &str, db_open_delay: Duration) -> Router {
+        create_test_app_with_db_settings(Some(password), 8, db_open_delay)
+    }
+
+    fn create_test_app_with_db_settings(
+        password: Option<&str>,
+        db_permits: usize,
+        db_open_delay: Duration,
+    ) -> Router {
+        create_router_with_state(Arc::new(test_app_state(
+            password,
+            db_permits,
+            db_open_delay,
+        )))
+    }
+
+    fn test_app_state(
+        password: Option<&str>,
+        db_permits: usize,
+        db_open_delay: Duration,
+    ) -> AppState {
         let tmp = tempfile::NamedTempFile::new().unwrap();
         let path = tmp.path().to_path_buf();
         let db = Database::open(path.to_str().unwrap()).unwrap();
@@ -1605,11 +2790,12 @@ mod tests {
             common::config::Config::from_toml_str(include_str!("../../../config/default.toml"))
                 .unwrap();
         metrics::init().unwrap();
-        let state = Arc::new(AppState {
+        AppState {
             db_path: path,
-            auth_password: Some(password.to_string()),
+            auth_password: password.map(String::from),
+            api_token: None,
             funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
-            db_semaphore: Arc::new(Semaphore::new(8)),
+            db_semaphore: Arc::new(Semaphore::new(db_permits)),
             db_timeout: Duration::from_secs(5),
             db_open_delay,
             paper_bankroll_usdc: cfg.risk.paper_bankroll_usdc,
@@ -1620,8 +2806,23 @@ mod tests {
             gamma_api_url: None,
             http_client: None,
             trader_api_url: None,
-        });
-        create_router_with_state(state)
+            funnel_hidden_personas: cfg.personas.funnel_hidden_personas(),
+            persona_min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
+            follow_worthy: queries::FollowWorthyThresholds::from(&cfg.ranking),
+            wscore_weights: queries::WScoreWeights::from(&cfg.wallet_scoring),
+            display_names: display_names::DisplayNameCache::new(Duration::ZERO),
+            default_page_size: 20,
+            max_page_size: 100,
+            active_position_min_shares: 0.5,
+            hsts_enabled: true,
+            content_security_policy: header::HeaderValue::from_str(&content_security_policy(
+                &[],
+                &[],
+            ))
+            .unwrap(),
+            db_startup_error: None,
+            dashboard: live::DashboardHub::new(Duration::from_secs(10)),
+        }
     }
 
     fn auth_cookie(password: &str) -> String {
@@ -1773,6 +2974,111 @@ mod tests {
         assert_eq!(location, "/login");
     }
 
+    #[tokio::test]
+    async fn test_dashboard_ws_requires_auth() {
+        let app = create_test_app_with_auth("secret");
+        let response = app
+            .oneshot(Request::builder().uri("/ws").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers().get("location").unwrap(), "/login");
+    }
+
+    #[tokio::test]
+    async fn test_api_accepts_bearer_token_instead_of_cookie() {
+        let mut state = test_app_state(Some("secret"), 8, Duration::ZERO);
+        state.api_token = Some("machine-token".to_string());
+        let app = create_router_with_state(Arc::new(state));
+
+        let get = |auth: &str| {
+            Request::builder()
+                .uri("/api/v1/last-run")
+                .header("Authorization", auth)
+                .body(Body::empty())
+                .unwrap()
+        };
+        let response = app
+            .clone()
+            .oneshot(get("Bearer machine-token"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(get("Bearer wrong-token"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // Cookie auth still works for browsers.
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/last-run")
+                    .header("Cookie", auth_cookie("secret"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn test_request_auth_state_classifies_credentials() {
+        let headers = |name: &str, value: &str| {
+            let mut h = HeaderMap::new();
+            h.insert(
+                axum::http::HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                value.parse().unwrap(),
+            );
+            h
+        };
+        let open = test_app_state(None, 8, Duration::ZERO);
+        assert_eq!(
+            request_auth_state(&open, &HeaderMap::new()),
+            "auth_disabled"
+        );
+
+        let mut state = test_app_state(Some("secret"), 8, Duration::ZERO);
+        state.api_token = Some("machine-token".to_string());
+        assert_eq!(request_auth_state(&state, &HeaderMap::new()), "anonymous");
+        assert_eq!(
+            request_auth_state(&state, &headers("cookie", &auth_cookie("secret"))),
+            "session"
+        );
+        assert_eq!(
+            request_auth_state(&state, &headers("cookie", &auth_cookie("wrong"))),
+            "anonymous"
+        );
+        assert_eq!(
+            request_auth_state(&state, &headers("authorization", "Bearer machine-token")),
+            "bearer"
+        );
+        assert_eq!(
+            request_auth_state(&state, &headers("authorization", "Bearer nope")),
+            "bearer_invalid"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bearer_token_rejected_when_no_api_token_configured() {
+        let app = create_test_app_with_auth("secret");
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/last-run")
+                    .header("Authorization", "Bearer secret")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
     #[tokio::test]
     async fn test_login_page_shows_without_auth() {
         let app = create_test_app_with_auth("secret");
@@ -1826,6 +3132,73 @@ mod tests {
         assert!(set_cookie.contains(AUTH_COOKIE_NAME));
     }
 
+    #[tokio::test]
+    async fn test_login_oversized_body_gets_413_without_counting_as_attempt() {
+        let app = create_test_app_with_auth("secret");
+        let oversized = format!("password={}&csrf_token=x", "a".repeat(LOGIN_MAX_BODY_BYTES));
+        for _ in 0..LOGIN_MAX_ATTEMPTS + 1 {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri("/login")
+                        .method("POST")
+                        .header("Content-Type", "application/x-www-form-urlencoded")
+                        .header("Content-Length", oversized.len())
+                        .body(Body::from(oversized.clone()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        }
+
+        let csrf_token = get_csrf_token_from_login(&app).await;
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/login")
+                    .method("POST")
+                    .header("Content-Type", "application/x-www-form-urlencoded")
+                    .header("Cookie", format!("{CSRF_COOKIE_NAME}={csrf_token}"))
+                    .body(Body::from(format!(
+                        "password=secret&csrf_token={csrf_token}"
+                    )))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+    }
+
+    #[tokio::test]
+    async fn test_login_malformed_or_overlong_fields_get_400() {
+        let app = create_test_app_with_auth("secret");
+        let long_password = format!(
+            "password={}&csrf_token=abc",
+            "a".repeat(LOGIN_MAX_PASSWORD_BYTES + 1)
+        );
+        let long_token = format!(
+            "password=secret&csrf_token={}",
+            "a".repeat(LOGIN_MAX_CSRF_TOKEN_BYTES + 1)
+        );
+        for body in ["password=secret".to_string(), long_password, long_token] {
+            let response = app
+                .clone()
+                .oneshot(
+                    Request::builder()
+                        .uri("/login")
+                        .method("POST")
+                        .header("Content-Type", "application/x-www-form-urlencoded")
+                        .body(Body::from(body))
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+    }
+
     #[tokio::test]
     async fn test_login_with_csrf_cookie_in_second_cookie_header_succeeds() {
         let app = create_test_app_with_auth("secret");
@@ -2190,195 +3563,1007 @@ mod tests {
         assert!(html.contains("tailwindcss"));
     }
 
-    #[tokio::test]
-    async fn test_status_partial_returns_200() {
-        let app = create_test_app();
+    #[tokio::test]
+    async fn test_status_partial_returns_200() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/partials/status")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_last_run_api_returns_json() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/last-run")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["trades_inserted"], 0);
+        assert_eq!(json["events_markets"], 0);
+        assert!(json["trades_run_at"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_all_positions_api_rejects_unknown_sort() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/positions?sort=bogus")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_all_positions_api_returns_json() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/positions?sort=last_trade_at&limit=5")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["total"], 0);
+        assert!(json["positions"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_security_headers_default_to_hsts_and_base_csp() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let headers = response.headers();
+        assert!(headers.contains_key("Strict-Transport-Security"));
+        let csp = headers["Content-Security-Policy"].to_str().unwrap();
+        assert!(csp.contains("connect-src 'self' https://cdn.tailwindcss.com;"));
+    }
+
+    #[tokio::test]
+    async fn test_security_headers_follow_web_config() {
+        let mut state = test_app_state(None, 8, Duration::ZERO);
+        state.hsts_enabled = false;
+        state.content_security_policy = header::HeaderValue::from_str(&content_security_policy(
+            &["https://cdn.example.com".to_string()],
+            &["https://api.example.com".to_string()],
+        ))
+        .unwrap();
+        let app = create_router_with_state(Arc::new(state));
+        let response = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let headers = response.headers();
+        assert!(!headers.contains_key("Strict-Transport-Security"));
+        let csp = headers["Content-Security-Policy"].to_str().unwrap();
+        assert!(csp.contains("https://unpkg.com https://cdn.example.com;"));
+        assert!(
+            csp.contains("connect-src 'self' https://cdn.tailwindcss.com https://api.example.com;")
+        );
+        assert!(csp.ends_with("frame-ancestors 'none';"));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_trades_ndjson_streams_all_rows() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        for i in 0..150 {
+            db.conn
+                .execute(
+                    "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
+                     VALUES ('0xw', '0xm', 'BUY', 1.0, 0.5, ?1)",
+                    [i],
+                )
+                .unwrap();
+        }
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/wallet/0xw/trades?format=ndjson")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "application/x-ndjson"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        // Not capped at max_page_size (100) and newest first.
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 150);
+        let first: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+        let last: serde_json::Value = serde_json::from_str(lines[149]).unwrap();
+        assert!(first["id"].as_i64().unwrap() > last["id"].as_i64().unwrap());
+        assert!(first.get("error").is_none());
+    }
+
+    #[test]
+    fn test_probe_db_distinguishes_missing_unmigrated_and_corrupt() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("evaluator.db");
+        assert_eq!(probe_db(&path), DbProbe::NotMigrated);
+
+        Connection::open(&path)
+            .unwrap()
+            .execute_batch("CREATE TABLE t (x INTEGER)")
+            .unwrap();
+        assert_eq!(probe_db(&path), DbProbe::NotMigrated);
+
+        Database::open(path.to_str().unwrap())
+            .unwrap()
+            .run_migrations()
+            .unwrap();
+        assert_eq!(probe_db(&path), DbProbe::Ready);
+
+        Connection::open(&path)
+            .unwrap()
+            .pragma_update(None, "user_version", common::db::SCHEMA_VERSION + 1)
+            .unwrap();
+        assert_eq!(
+            probe_db(&path),
+            DbProbe::SchemaMismatch(SchemaVersionError::VersionMismatch {
+                found: common::db::SCHEMA_VERSION + 1,
+                expected: common::db::SCHEMA_VERSION,
+            })
+        );
+
+        let corrupt = dir.path().join("corrupt.db");
+        std::fs::write(&corrupt, b"this is definitely not a sqlite database file").unwrap();
+        assert!(matches!(probe_db(&corrupt), DbProbe::Unusable(_)));
+    }
+
+    #[tokio::test]
+    async fn test_unusable_db_at_startup_serves_503_with_reason() {
+        let mut state = test_app_state(None, 8, Duration::ZERO);
+        state.db_startup_error = Some("file is not a database".to_string());
+        let app = create_router_with_state(Arc::new(state));
+        let response = app
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("file is not a database"), "{body}");
+    }
+
+    #[tokio::test]
+    async fn test_pending_migrations_serve_schema_message_not_db_unavailable() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        Connection::open(&state.db_path)
+            .unwrap()
+            .pragma_update(None, "user_version", 0)
+            .unwrap();
+        let app = create_router_with_state(Arc::new(state));
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/partials/status")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let body = String::from_utf8(body.to_vec()).unwrap();
+        assert!(body.contains("schema not initialized"), "{body}");
+        assert!(!body.contains("DB unavailable"), "{body}");
+    }
+
+    #[test]
+    fn test_page_limit_uses_configured_default_and_max() {
+        let mut state = test_app_state(None, 8, Duration::ZERO);
+        assert_eq!(state.page_limit(None), 20);
+        assert_eq!(state.page_limit(Some(500)), 100);
+        state.default_page_size = 50;
+        state.max_page_size = 1000;
+        assert_eq!(state.page_limit(None), 50);
+        assert_eq!(state.page_limit(Some(500)), 500);
+    }
+
+    #[tokio::test]
+    async fn test_single_db_permit_serializes_partial_requests() {
+        let delay = Duration::from_millis(150);
+        let app = create_test_app_with_db_settings(None, 1, delay);
+        let request = |app: Router| async move {
+            app.oneshot(
+                Request::builder()
+                    .uri("/partials/status")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .status()
+        };
+
+        let started = std::time::Instant::now();
+        let (a, b, c) = tokio::join!(
+            request(app.clone()),
+            request(app.clone()),
+            request(app.clone())
+        );
+        assert_eq!((a, b, c), (StatusCode::OK, StatusCode::OK, StatusCode::OK));
+        // One permit: the three DB opens run back to back rather than overlapping.
+        assert!(started.elapsed() >= delay * 3);
+    }
+
+    #[tokio::test]
+    async fn test_status_partial_contains_phase() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/partials/status")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("Phase:"));
+        assert!(html.contains("Foundation"));
+    }
+
+    #[tokio::test]
+    async fn test_unified_funnel_partial_returns_200() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/partials/unified_funnel")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_unified_funnel_partial_contains_stages() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/partials/unified_funnel")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("Events"));
+        assert!(html.contains("All wallets"));
+        assert!(html.contains("Suitable personas wallets"));
+        assert!(html.contains("Actively paper traded"));
+        assert!(html.contains("Worth following"));
+    }
+
+    #[tokio::test]
+    async fn test_excluded_page_returns_200() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/excluded")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("Excluded Wallets"));
+    }
+
+    #[tokio::test]
+    async fn test_excluded_page_paginates_latest_per_wallet() {
+        let tmp = tempfile::NamedTempFile::new().unwrap();
+        let path = tmp.path().to_path_buf();
+        let db = Database::open(path.to_str().unwrap()).unwrap();
+        db.run_migrations().unwrap();
+
+        db.conn
+            .execute(
+                "INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
+                 VALUES ('0xaaaaaaaaaaaaaaaa', 'NOISE_TRADER', 60.0, 50.0, '2026-02-10 10:00:00')",
+                [],
+            )
+            .unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
+                 VALUES ('0xbbbbbbbbbbbbbbbb', 'TAIL_RISK_SELLER', 0.83, 0.80, '2026-02-10 11:00:00')",
+                [],
+            )
+            .unwrap();
+
+        drop(db);
+        std::mem::forget(tmp);
+
+        let cfg =
+            common::config::Config::from_toml_str(include_str!("../../../config/default.toml"))
+                .unwrap();
+        metrics::init().unwrap();
+        let state = Arc::new(AppState {
+            db_path: path,
+            auth_password: None,
+            api_token: None,
+            funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
+            db_semaphore: Arc::new(Semaphore::new(8)),
+            db_timeout: Duration::from_secs(5),
+            db_open_delay: Duration::ZERO,
+            paper_bankroll_usdc: cfg.risk.paper_bankroll_usdc,
+            max_total_exposure_pct: cfg.paper_trading.max_total_exposure_pct,
+            max_daily_loss_pct: cfg.paper_trading.max_daily_loss_pct,
+            max_concurrent_positions: i64::from(cfg.risk.max_concurrent_positions),
+            login_rate_limiter: Arc::new(LoginRateLimiter::new()),
+            gamma_api_url: None,
+            http_client: None,
+            trader_api_url: None,
+            funnel_hidden_personas: cfg.personas.funnel_hidden_personas(),
+            persona_min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
+            follow_worthy: queries::FollowWorthyThresholds::from(&cfg.ranking),
+            wscore_weights: queries::WScoreWeights::from(&cfg.wallet_scoring),
+            display_names: display_names::DisplayNameCache::new(Duration::ZERO),
+            default_page_size: 20,
+            max_page_size: 100,
+            active_position_min_shares: 0.5,
+            hsts_enabled: true,
+            content_security_policy: header::HeaderValue::from_str(&content_security_policy(
+                &[],
+                &[],
+            ))
+            .unwrap(),
+            db_startup_error: None,
+            dashboard: live::DashboardHub::new(Duration::from_secs(10)),
+        });
+        let app = create_router_with_state(state);
+
+        let resp1 = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/excluded?page=1&page_size=1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp1.status(), StatusCode::OK);
+        let body1 = axum::body::to_bytes(resp1.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html1 = String::from_utf8(body1.to_vec()).unwrap();
+
+        let resp2 = app
+            .oneshot(
+                Request::builder()
+                    .uri("/excluded?page=2&page_size=1")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp2.status(), StatusCode::OK);
+        let body2 = axum::body::to_bytes(resp2.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html2 = String::from_utf8(body2.to_vec()).unwrap();
+
+        assert_ne!(html1, html2);
+        assert!(html1.contains("0xbbbb") || html1.contains("0xaaaa"));
+        assert!(html2.contains("0xbbbb") || html2.contains("0xaaaa"));
+    }
+
+    #[tokio::test]
+    async fn test_journey_unknown_wallet_returns_404() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/journey/0xdoesnotexist")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_market_page_unknown_condition_id_returns_404() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/market/0xdoesnotexist")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_journey_api_unknown_wallet_returns_404() {
+        let app = create_test_app();
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/journey/0xdoesnotexist")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_journey_export_html_is_self_contained_and_json_matches_api() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES ('0xexp', 'HOLDER', 1)",
+                [],
+            )
+            .unwrap();
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+        let req = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(req("/wallet/0xexp/export"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"journey-0xexp.html\""
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("0xexp"));
+        assert!(html.contains("<style>"));
+        assert!(!html.contains("cdn."), "export must not load CDN assets");
+        assert!(!html.contains("<script"));
+
+        let response = app
+            .clone()
+            .oneshot(req("/wallet/0xexp/export?format=json"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["proxy_wallet"], "0xexp");
+
+        let response = app
+            .clone()
+            .oneshot(req("/wallet/0xexp/export?format=pdf"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let response = app.oneshot(req("/wallet/0xnobody/export")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_wscore_explanation_api_returns_components_or_404() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore, edge_score)
+                 VALUES ('0xw', '2026-02-10', 30, 0.61, 0.9)",
+                [],
+            )
+            .unwrap();
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/wscore/0xw")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["wscore_display"], "0.61");
+        assert_eq!(json["components"].as_array().unwrap().len(), 6);
+        assert_eq!(json["components"][0]["component"], "Edge");
+
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/partials/status")
+                    .uri("/api/v1/wscore/0xdoesnotexist")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
     }
 
     #[tokio::test]
-    async fn test_status_partial_contains_phase() {
+    async fn test_wallet_features_history_unknown_wallet_returns_empty_points() {
         let app = create_test_app();
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/partials/status")
+                    .uri("/wallet/0xdoesnotexist/features-history?window_days=7&limit=5")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
         let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let html = String::from_utf8(body.to_vec()).unwrap();
-        assert!(html.contains("Phase:"));
-        assert!(html.contains("Foundation"));
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["window_days"], 7);
+        assert_eq!(json["points"], serde_json::json!([]));
     }
 
     #[tokio::test]
-    async fn test_unified_funnel_partial_returns_200() {
-        let app = create_test_app();
+    async fn test_journey_prefers_stored_profile_name() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active)
+                 VALUES ('0xprofiled', 'HOLDER', 1)",
+                [],
+            )
+            .unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallet_profiles (proxy_wallet, display_name) VALUES ('0xprofiled', 'stored-whale')",
+                [],
+            )
+            .unwrap();
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/partials/unified_funnel")
+                    .uri("/journey/0xprofiled")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
         assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("stored-whale"));
     }
 
     #[tokio::test]
-    async fn test_unified_funnel_partial_contains_stages() {
-        let app = create_test_app();
+    async fn test_journey_defers_gamma_name_to_display_name_endpoint() {
+        // Fake Gamma counts profile lookups so the page render can be shown not to wait on it.
+        let hits = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let gamma_hits = hits.clone();
+        let gamma = Router::new().route(
+            "/public-profile",
+            get(move || {
+                gamma_hits.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                async { Json(serde_json::json!({"name": "<b>whale</b>"})) }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gamma_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, gamma).await });
+
+        let mut state = test_app_state(None, 8, Duration::ZERO);
+        state.gamma_api_url = Some(format!("http://{gamma_addr}"));
+        state.http_client = Some(reqwest::Client::new());
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active)
+                 VALUES ('0xdeferred', 'HOLDER', 1)",
+                [],
+            )
+            .unwrap();
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+        let body_of = |response: Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&bytes).into_owned()
+        };
+
         let response = app
+            .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/partials/unified_funnel")
+                    .uri("/journey/0xdeferred")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
-            .await
-            .unwrap();
-        let html = String::from_utf8(body.to_vec()).unwrap();
-        assert!(html.contains("Events"));
-        assert!(html.contains("All wallets"));
-        assert!(html.contains("Suitable personas wallets"));
-        assert!(html.contains("Actively paper traded"));
-        assert!(html.contains("Worth following"));
-    }
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = body_of(response).await;
+        assert!(page.contains(r#"hx-get="/wallet/0xdeferred/display-name""#));
+        assert!(!page.contains("whale"));
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 0);
 
-    #[tokio::test]
-    async fn test_excluded_page_returns_200() {
-        let app = create_test_app();
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/excluded")
+                    .uri("/wallet/0xdeferred/display-name")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
         assert_eq!(response.status(), StatusCode::OK);
-        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        let name = body_of(response).await;
+        // Gamma names are untrusted: escaped before HTMX swaps them into the page.
+        assert!(name.contains("whale") && !name.contains("<b>"), "{name}");
+        assert_eq!(hits.load(std::sync::atomic::Ordering::SeqCst), 1);
+    }
+
+    /// Start `serve` on an ephemeral port with the given tuning; abort the handle to stop it.
+    async fn spawn_tuned_server(
+        tuning: ConnectionTuning,
+    ) -> (SocketAddr, tokio::task::JoinHandle<Result<()>>) {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = tokio::spawn(serve(listener, create_test_app(), tuning));
+        (addr, handle)
+    }
+
+    #[test]
+    fn test_prometheus_builder_applies_configured_buckets() {
+        let recorder = prometheus_builder(&[3.0, 30.0]).unwrap().build_recorder();
+        let handle = recorder.handle();
+        ::metrics::with_local_recorder(&recorder, || {
+            ::metrics::histogram!("evaluator_web_handler_latency_ms", "route" => "/").record(12.0);
+        });
+
+        let rendered = handle.render();
+        assert!(rendered.contains(r#"le="3""#), "{rendered}");
+        assert!(rendered.contains(r#"le="30""#), "{rendered}");
+        assert!(!rendered.contains(r#"le="10""#), "{rendered}");
+
+        assert!(prometheus_builder(&[]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_serve_advertises_http2_max_concurrent_streams() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let tuning = ConnectionTuning {
+            http2_max_concurrent_streams: 7,
+            ..ConnectionTuning::from_config(None)
+        };
+        let (addr, server) = spawn_tuned_server(tuning).await;
+
+        // Prior-knowledge HTTP/2: preface plus an empty client SETTINGS frame.
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n")
             .await
             .unwrap();
-        let html = String::from_utf8(body.to_vec()).unwrap();
-        assert!(html.contains("Excluded Wallets"));
+        stream
+            .write_all(&[0, 0, 0, 4, 0, 0, 0, 0, 0])
+            .await
+            .unwrap();
+
+        let mut frame_header = [0u8; 9];
+        stream.read_exact(&mut frame_header).await.unwrap();
+        assert_eq!(frame_header[3], 4, "server must open with a SETTINGS frame");
+        let len = (usize::from(frame_header[0]) << 16)
+            | (usize::from(frame_header[1]) << 8)
+            | usize::from(frame_header[2]);
+        let mut payload = vec![0u8; len];
+        stream.read_exact(&mut payload).await.unwrap();
+        // Each setting is a u16 id + u32 value; SETTINGS_MAX_CONCURRENT_STREAMS is id 3.
+        let max_streams = payload
+            .chunks_exact(6)
+            .find(|s| s[..2] == [0, 3])
+            .map(|s| u32::from_be_bytes([s[2], s[3], s[4], s[5]]));
+        assert_eq!(max_streams, Some(7));
+        server.abort();
     }
 
     #[tokio::test]
-    async fn test_excluded_page_paginates_latest_per_wallet() {
-        let tmp = tempfile::NamedTempFile::new().unwrap();
-        let path = tmp.path().to_path_buf();
-        let db = Database::open(path.to_str().unwrap()).unwrap();
-        db.run_migrations().unwrap();
+    async fn test_serve_closes_http1_connection_when_keep_alive_disabled() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
-        db.conn
-            .execute(
-                "INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
-                 VALUES ('0xaaaaaaaaaaaaaaaa', 'NOISE_TRADER', 60.0, 50.0, '2026-02-10 10:00:00')",
-                [],
-            )
+        let tuning = ConnectionTuning {
+            http1_keep_alive: false,
+            ..ConnectionTuning::from_config(None)
+        };
+        let (addr, server) = spawn_tuned_server(tuning).await;
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /login HTTP/1.1\r\nHost: localhost\r\n\r\n")
+            .await
             .unwrap();
+        // The client asked for keep-alive; only the server setting can close the connection,
+        // so read_to_end finishing at all is the check.
+        let mut buf = Vec::new();
+        tokio::time::timeout(Duration::from_secs(5), stream.read_to_end(&mut buf))
+            .await
+            .expect("server should close the connection after one response")
+            .unwrap();
+        let response = String::from_utf8_lossy(&buf).to_lowercase();
+        assert!(response.starts_with("http/1.1 303"), "{response}");
+        assert!(response.contains("connection: close"), "{response}");
+        server.abort();
+    }
+
+    fn post_wallet_note(
+        wallet: &str,
+        cookie_token: &str,
+        form_token: &str,
+        note: &str,
+    ) -> Request<Body> {
+        Request::builder()
+            .uri(format!("/wallet/{wallet}/note"))
+            .method("POST")
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .header("Cookie", format!("{CSRF_COOKIE_NAME}={cookie_token}"))
+            .body(Body::from(format!("note={note}&csrf_token={form_token}")))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_scorecard_marks_active_positions_to_market() {
+        // Fake Gamma: prices only 0xpriced, so 0xunpriced must fall back to "—".
+        let gamma = Router::new().route(
+            "/markets",
+            get(|| async {
+                Json(serde_json::json!([
+                    {"conditionId": "0xpriced", "outcomePrices": "[\"0.25\", \"0.75\"]"}
+                ]))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let gamma_addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, gamma).await });
+
+        let mut state = test_app_state(None, 8, Duration::ZERO);
+        state.gamma_api_url = Some(format!("http://{gamma_addr}"));
+        state.http_client = Some(reqwest::Client::new());
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
         db.conn
-            .execute(
-                "INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
-                 VALUES ('0xbbbbbbbbbbbbbbbb', 'TAIL_RISK_SELLER', 0.83, 0.80, '2026-02-10 11:00:00')",
-                [],
+            .execute_batch(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active) VALUES ('0xmtm', 'HOLDER', 1);
+                 INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp, outcome, outcome_index)
+                   VALUES ('0xmtm', '0xpriced', 'BUY', 100.0, 0.50, 1700000000, 'No', 1);
+                 INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp, outcome, outcome_index)
+                   VALUES ('0xmtm', '0xunpriced', 'BUY', 10.0, 0.50, 1700000100, 'Yes', 0);",
             )
             .unwrap();
-
         drop(db);
-        std::mem::forget(tmp);
-
-        let cfg =
-            common::config::Config::from_toml_str(include_str!("../../../config/default.toml"))
-                .unwrap();
-        metrics::init().unwrap();
-        let state = Arc::new(AppState {
-            db_path: path,
-            auth_password: None,
-            funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
-            db_semaphore: Arc::new(Semaphore::new(8)),
-            db_timeout: Duration::from_secs(5),
-            db_open_delay: Duration::ZERO,
-            paper_bankroll_usdc: cfg.risk.paper_bankroll_usdc,
-            max_total_exposure_pct: cfg.paper_trading.max_total_exposure_pct,
-            max_daily_loss_pct: cfg.paper_trading.max_daily_loss_pct,
-            max_concurrent_positions: i64::from(cfg.risk.max_concurrent_positions),
-            login_rate_limiter: Arc::new(LoginRateLimiter::new()),
-            gamma_api_url: None,
-            http_client: None,
-            trader_api_url: None,
-        });
-        let app = create_router_with_state(state);
+        let app = create_router_with_state(Arc::new(state));
 
-        let resp1 = app
+        // The page renders "—" without waiting on Gamma and asks for prices afterwards.
+        let response = app
             .clone()
             .oneshot(
                 Request::builder()
-                    .uri("/excluded?page=1&page_size=1")
+                    .uri("/wallet/0xmtm")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(resp1.status(), StatusCode::OK);
-        let body1 = axum::body::to_bytes(resp1.into_body(), usize::MAX)
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let html1 = String::from_utf8(body1.to_vec()).unwrap();
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(html.contains("Unrealized PnL"));
+        assert!(
+            html.contains(r#"hx-get="/wallet/0xmtm/position-prices?limit=2""#),
+            "{html}"
+        );
+        assert!(!html.contains("+$25.00"));
 
-        let resp2 = app
+        let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/excluded?page=2&page_size=1")
+                    .uri("/wallet/0xmtm/position-prices?limit=2")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(resp2.status(), StatusCode::OK);
-        let body2 = axum::body::to_bytes(resp2.into_body(), usize::MAX)
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
             .await
             .unwrap();
-        let html2 = String::from_utf8(body2.to_vec()).unwrap();
-
-        assert_ne!(html1, html2);
-        assert!(html1.contains("0xbbbb") || html1.contains("0xaaaa"));
-        assert!(html2.contains("0xbbbb") || html2.contains("0xaaaa"));
+        let html = String::from_utf8(body.to_vec()).unwrap();
+        assert!(
+            html.contains(
+                r#"id="upnl-0xpriced-1" hx-swap-oob="true" class="text-green-400">+$25.00"#
+            ),
+            "{html}"
+        );
+        assert!(
+            html.contains(r#"id="upnl-0xunpriced-0" hx-swap-oob="true" class="text-gray-500">—"#)
+        );
     }
 
     #[tokio::test]
-    async fn test_journey_unknown_wallet_returns_404() {
-        let app = create_test_app();
+    async fn test_wallet_note_is_saved_and_shown_on_scorecard() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, is_active)
+                 VALUES ('0xnoted', 'HOLDER', 1)",
+                [],
+            )
+            .unwrap();
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+
+        let response = app
+            .clone()
+            .oneshot(post_wallet_note(
+                "0xnoted",
+                "tok",
+                "tok",
+                "manual+review+%3Cpending%3E",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(
+            response.headers().get("location").unwrap(),
+            "/wallet/0xnoted"
+        );
+
         let response = app
             .oneshot(
                 Request::builder()
-                    .uri("/journey/0xdoesnotexist")
+                    .uri("/wallet/0xnoted")
                     .body(Body::empty())
                     .unwrap(),
             )
             .await
             .unwrap();
-        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response
+            .headers()
+            .get("set-cookie")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with(CSRF_COOKIE_NAME));
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let html = String::from_utf8_lossy(&body);
+        assert!(
+            html.contains("manual review &lt;pending&gt;")
+                || html.contains("manual review &#60;pending&#62;"),
+            "note must be escaped"
+        );
+        assert!(html.contains("name=\"csrf_token\""));
+    }
+
+    #[tokio::test]
+    async fn test_wallet_note_rejects_bad_csrf_and_invalid_length() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db_path = state.db_path.clone();
+        let app = create_router_with_state(Arc::new(state));
+
+        let response = app
+            .clone()
+            .oneshot(post_wallet_note("0xw", "tok", "forged", "hi"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // Unknown wallets (typos, forged paths like a decoded newline) get a 404, not a note.
+        for wallet in ["0xw", "0xw%0a"] {
+            let response = app
+                .clone()
+                .oneshot(post_wallet_note(wallet, "tok", "tok", "hi"))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        }
+
+        let too_long = "a".repeat(MAX_WALLET_NOTE_CHARS + 1);
+        for note in ["+++", too_long.as_str()] {
+            let response = app
+                .clone()
+                .oneshot(post_wallet_note("0xw", "tok", "tok", note))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        }
+
+        let db = Database::open(db_path.to_str().unwrap()).unwrap();
+        let count: i64 = db
+            .conn
+            .query_row("SELECT COUNT(*) FROM wallet_notes", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
     }
 
     #[tokio::test]
@@ -2409,6 +4594,12 @@ mod tests {
                 [],
             )
             .unwrap();
+        db.conn
+            .execute_batch(
+                "INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xw2', '0xm1', 'COPIED');
+                 INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xw2', '0xm1', 'SKIPPED_DAILY_LOSS');",
+            )
+            .unwrap();
 
         drop(db);
         std::mem::forget(tmp);
@@ -2420,6 +4611,7 @@ mod tests {
         let state = Arc::new(AppState {
             db_path: path,
             auth_password: None,
+            api_token: None,
             funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
             db_semaphore: Arc::new(Semaphore::new(8)),
             db_timeout: Duration::from_secs(5),
@@ -2432,9 +4624,45 @@ mod tests {
             gamma_api_url: None,
             http_client: None,
             trader_api_url: None,
+            funnel_hidden_personas: cfg.personas.funnel_hidden_personas(),
+            persona_min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
+            follow_worthy: queries::FollowWorthyThresholds::from(&cfg.ranking),
+            wscore_weights: queries::WScoreWeights::from(&cfg.wallet_scoring),
+            display_names: display_names::DisplayNameCache::new(Duration::ZERO),
+            default_page_size: 20,
+            max_page_size: 100,
+            active_position_min_shares: 0.5,
+            hsts_enabled: true,
+            content_security_policy: header::HeaderValue::from_str(&content_security_policy(
+                &[],
+                &[],
+            ))
+            .unwrap(),
+            db_startup_error: None,
+            dashboard: live::DashboardHub::new(Duration::from_secs(10)),
         });
         let app = create_router_with_state(state);
 
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/api/v1/journey/0xw2")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["proxy_wallet"], "0xw2");
+        assert_eq!(json["persona"], "Informed Specialist");
+        assert_eq!(json["copy_fidelity_breakdown"][0]["outcome"], "COPIED");
+        assert_eq!(json["copy_fidelity_breakdown"][1]["share_display"], "50%");
+
         let response = app
             .oneshot(
                 Request::builder()
@@ -2451,6 +4679,8 @@ mod tests {
         let html = String::from_utf8(body.to_vec()).unwrap();
         assert!(html.contains("Journey"));
         assert!(html.contains("0xw2"));
+        assert!(html.contains("Copy Fidelity by Outcome"));
+        assert!(html.contains("SKIPPED_DAILY_LOSS"));
     }
 
     #[tokio::test]
@@ -2601,6 +4831,40 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_wallets_partial_since_hours_hides_older_wallets() {
+        let state = test_app_state(None, 8, Duration::ZERO);
+        let db = Database::open(state.db_path.to_str().unwrap()).unwrap();
+        db.conn
+            .execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, discovered_at) VALUES
+                    ('0xfresh00000000', 'HOLDER', datetime('now', '-1 hour')),
+                    ('0xstale00000000', 'HOLDER', datetime('now', '-3 days'))",
+                [],
+            )
+            .unwrap();
+        drop(db);
+        let app = create_router_with_state(Arc::new(state));
+
+        for (uri, expect_stale) in [
+            ("/partials/wallets?since_hours=24", false),
+            ("/partials/wallets?since_hours=0", true),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            let html = String::from_utf8_lossy(&body);
+            assert!(html.contains("0xfresh00000000"), "{uri}");
+            assert_eq!(html.contains("0xstale00000000"), expect_stale, "{uri}");
+        }
+    }
+
     #[tokio::test]
     async fn test_paper_traded_wallets_partial_returns_200() {
         let app = create_test_app();
@@ -2650,6 +4914,22 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_rankings_partial_window_param() {
+        for (uri, status) in [
+            ("/partials/rankings?window=7", StatusCode::OK),
+            ("/partials/rankings?window=30", StatusCode::OK),
+            ("/partials/rankings?window=0", StatusCode::OK),
+            ("/partials/rankings?window=14", StatusCode::BAD_REQUEST),
+        ] {
+            let response = create_test_app()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), status, "{uri}");
+        }
+    }
+
     #[tokio::test]
     async fn test_all_partials_return_200() {
         let routes = vec![
@@ -2737,6 +5017,7 @@ mod tests {
         let state = Arc::new(AppState {
             db_path: db_path.into(),
             auth_password: None,
+            api_token: None,
             funnel_stage_infos: common::funnel::funnel_stage_infos(&cfg),
             db_semaphore: Arc::new(Semaphore::new(8)),
             db_timeout: Duration::from_secs(5),
@@ -2749,6 +5030,22 @@ mod tests {
             gamma_api_url: None,
             http_client: None,
             trader_api_url: None,
+            funnel_hidden_personas: cfg.personas.funnel_hidden_personas(),
+            persona_min_wallet_age_days: cfg.personas.stage1_min_wallet_age_days,
+            follow_worthy: queries::FollowWorthyThresholds::from(&cfg.ranking),
+            wscore_weights: queries::WScoreWeights::from(&cfg.wallet_scoring),
+            display_names: display_names::DisplayNameCache::new(Duration::ZERO),
+            default_page_size: 20,
+            max_page_size: 100,
+            active_position_min_shares: 0.5,
+            hsts_enabled: true,
+            content_security_policy: header::HeaderValue::from_str(&content_security_policy(
+                &[],
+                &[],
+            ))
+            .unwrap(),
+            db_startup_error: None,
+            dashboard: live::DashboardHub::new(Duration::from_secs(10)),
         });
         let app = create_router_with_state(state);
 
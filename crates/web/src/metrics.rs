@@ -1,5 +1,5 @@
 use anyhow::Result;
-use metrics::describe_gauge;
+use metrics::{describe_counter, describe_gauge, describe_histogram};
 
 pub fn describe() {
     describe_gauge!(
@@ -14,6 +14,38 @@ pub fn describe() {
         "evaluator_persona_funnel_stage_count",
         "Persona funnel stage counts (derived from SQLite) for UI/Grafana."
     );
+    describe_gauge!(
+        "evaluator_last_run_trades_inserted",
+        "Trades inserted by the most recent trades ingestion run."
+    );
+    describe_gauge!(
+        "evaluator_last_run_trades_wallets",
+        "Wallets processed by the most recent trades ingestion run."
+    );
+    describe_gauge!(
+        "evaluator_last_run_events_markets",
+        "Markets scored by the most recent event scoring run."
+    );
+    describe_gauge!(
+        "evaluator_last_run_timestamp_seconds",
+        "Unix time of the most recent run, by run (trades, events); alert on time() - value."
+    );
+    describe_histogram!(
+        "evaluator_web_handler_latency_ms",
+        "End-to-end request handling time in ms (DB + template rendering), by matched route."
+    );
+    describe_gauge!(
+        "evaluator_web_ws_clients",
+        "Dashboard WebSocket (GET /ws) connections currently open."
+    );
+    describe_counter!(
+        "evaluator_web_template_render_errors_total",
+        "Template render failures served as a fallback fragment, by template."
+    );
+    describe_counter!(
+        "evaluator_future_timestamp_total",
+        "DB timestamps found in the future when computing ages (clock skew); clamped to age 0."
+    );
 }
 
 /// Describe metrics and set a stable build-info gauge.
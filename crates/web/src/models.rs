@@ -74,6 +74,7 @@ pub struct UnifiedFunnelCounts {
 }
 
 /// One stage in the unified funnel bar (counts only, no drop %).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub struct UnifiedFunnelStage {
     pub label: String,
     /// Display string (e.g. "50" or "50 / 127" for selected/evaluated)
@@ -116,6 +117,7 @@ pub struct FunnelStage {
 }
 
 /// A job heartbeat for the status strip
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub struct JobHeartbeat {
     pub name: String,
     pub short_name: String,
@@ -130,7 +132,12 @@ pub struct JobStatusRow {
     pub job_name: String,
     pub status: String,
     pub last_run_at: Option<String>,
+    /// Scheduler's next tick (UTC); None for event-driven / unscheduled jobs.
     pub next_run_at: Option<String>,
+    /// Scheduler interval in seconds.
+    pub interval_secs: Option<i64>,
+    /// Seconds until `next_run_at` (negative = overdue), computed at query time.
+    pub next_run_in_secs: Option<i64>,
     pub last_error: Option<String>,
     pub duration_ms: Option<i64>,
     pub metadata: Option<String>,
@@ -138,6 +145,20 @@ pub struct JobStatusRow {
 }
 
 impl JobStatusRow {
+    /// Countdown to the next scheduled run ("in 4m", "due"), or "-" when not scheduled.
+    pub fn next_run_display(&self) -> String {
+        let Some(secs) = self.next_run_in_secs else {
+            return "-".to_string();
+        };
+        match secs {
+            s if s <= 0 => "due".to_string(),
+            s if s < 60 => format!("in {s}s"),
+            s if s < 3600 => format!("in {}m", s / 60),
+            s if s < 86400 => format!("in {}h {}m", s / 3600, (s % 3600) / 60),
+            s => format!("in {}d {}h", s / 86400, (s % 86400) / 3600),
+        }
+    }
+
     /// Format metadata for display in the UI
     pub fn progress_display(&self) -> String {
         let Some(meta) = &self.metadata else {
@@ -219,6 +240,7 @@ impl JobStatusRow {
 }
 
 /// System status info
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub struct SystemStatus {
     pub db_size_mb: String,
     pub phase: String,
@@ -228,6 +250,7 @@ pub struct SystemStatus {
 }
 
 /// Last completed run stats for the "async funnel" (wallets/markets/trades processed).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
 pub struct LastRunStats {
     pub trades_wallets: i64,
     pub trades_inserted: i64,
@@ -260,6 +283,41 @@ pub struct MarketRow {
     pub polymarket_url: Option<String>,
 }
 
+/// One day's MScore for the market detail page (newest first).
+pub struct MarketScoreHistoryRow {
+    pub score_date: String,
+    pub mscore: f64,
+    pub rank: Option<i64>,
+    pub liquidity_score: f64,
+    pub volume_score: f64,
+    pub density_score: f64,
+}
+
+/// Wallet discovered from a market, with its latest persona and 30-day WScore.
+pub struct MarketWalletRow {
+    pub proxy_wallet: String,
+    pub wallet_short: String,
+    pub discovered_from: String,
+    pub discovered_at: String,
+    pub is_active: bool,
+    pub persona: Option<String>,
+    pub wscore: Option<f64>,
+}
+
+/// Market detail page: metadata, MScore history and wallets discovered from it.
+pub struct MarketDetail {
+    pub condition_id: String,
+    pub title: String,
+    pub category: Option<String>,
+    pub end_date: Option<String>,
+    pub liquidity: f64,
+    pub volume: f64,
+    /// Polymarket URL (event or market), None if no slug
+    pub polymarket_url: Option<String>,
+    pub score_history: Vec<MarketScoreHistoryRow>,
+    pub wallets: Vec<MarketWalletRow>,
+}
+
 /// Wallet discovery overview counts
 pub struct WalletOverview {
     pub total: i64,
@@ -329,6 +387,7 @@ pub struct PaperSummary {
 }
 
 /// Wallet ranking row
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
 pub struct RankingRow {
     pub rank: i64,
     pub rank_display: String,
@@ -347,6 +406,9 @@ pub struct RankingRow {
     pub pnl_display: String,
     pub pnl_color: String,
     pub follow_mode: String,
+    /// Paper ROI for the selected ranking window ("+6.0% / +11.0%" = 7d / 30d when combined).
+    pub roi_display: String,
+    pub roi_color: String,
 }
 
 /// Row in the excluded wallets list (latest exclusion per wallet).
@@ -359,7 +421,25 @@ pub struct ExcludedWalletRow {
     pub excluded_at: String,
 }
 
+/// One operator note on the wallet scorecard.
+#[derive(Debug)]
+pub struct WalletNoteRow {
+    pub note: String,
+    pub created_at: String,
+}
+
+/// One daily `wallet_features_daily` snapshot in a wallet's feature history.
+#[derive(Debug, serde::Serialize)]
+pub struct WalletFeaturePoint {
+    pub feature_date: String,
+    pub total_pnl: f64,
+    pub sharpe_ratio: f64,
+    pub max_drawdown_pct: f64,
+    pub trades_per_day: f64,
+}
+
 /// Latest 30-day on-chain features snapshot for the wallet scorecard.
+#[derive(serde::Serialize)]
 pub struct WalletFeaturesSnapshot {
     pub feature_date: String,
     pub total_pnl: f64,
@@ -398,6 +478,7 @@ pub struct WalletFeaturesSnapshot {
 }
 
 /// Latest 30-day WScore + component scores for the wallet scorecard.
+#[derive(serde::Serialize)]
 pub struct WalletScoreSnapshot {
     pub score_date: String,
     pub wscore: f64,
@@ -413,9 +494,12 @@ pub struct WalletScoreSnapshot {
     pub timing_skill_pct: String,
     pub behavior_quality_display: String,
     pub behavior_quality_pct: String,
+    pub recency_edge_display: String,
+    pub recency_edge_pct: String,
 }
 
 /// One row in the WScore history table (newest-first, 30-day window).
+#[derive(serde::Serialize)]
 pub struct ScoreHistoryRow {
     pub score_date: String,
     pub wscore_display: String,
@@ -426,11 +510,13 @@ pub struct ScoreHistoryRow {
 }
 
 /// Persona trait badge (e.g., BONDER, WHALE, TOPIC_LANE) with Tailwind color classes.
+#[derive(serde::Serialize)]
 pub struct WalletTrait {
     pub display: String,
     pub badge_color: String,
 }
 
+#[derive(serde::Serialize)]
 pub struct JourneyEvent {
     pub at: String,
     pub label: String,
@@ -449,6 +535,75 @@ pub struct WalletPositionRow {
     pub trade_count: u32,
     /// Polymarket URL for this market (event or market page)
     pub polymarket_url: Option<String>,
+    /// Unrealized PnL at the current market price; "—" until `mark_to_market` sets it.
+    pub unrealized_pnl_display: String,
+    pub unrealized_pnl_color: String,
+    // Inputs for `mark_to_market`; not part of the JSON API.
+    #[serde(skip)]
+    pub net_shares: f64,
+    #[serde(skip)]
+    pub avg_entry_price: f64,
+    #[serde(skip)]
+    pub outcome_index: Option<i64>,
+}
+
+impl WalletPositionRow {
+    /// DOM id of the scorecard's unrealized PnL cell, filled in by `/wallet/{wallet}/position-prices`.
+    pub fn unrealized_pnl_id(&self) -> String {
+        match self.outcome_index {
+            Some(idx) => format!("upnl-{}-{idx}", self.condition_id),
+            None => format!("upnl-{}", self.condition_id),
+        }
+    }
+
+    /// Value the open shares at `price` (0-1) against their average entry price.
+    pub fn mark_to_market(&mut self, price: f64) {
+        let pnl = self.net_shares * (price - self.avg_entry_price);
+        let sign = if pnl >= 0.0 { "+" } else { "-" };
+        self.unrealized_pnl_display = format!("{sign}${:.2}", pnl.abs());
+        self.unrealized_pnl_color = if pnl >= 0.0 {
+            "text-green-400".to_string()
+        } else {
+            "text-red-400".to_string()
+        };
+    }
+}
+
+/// One open position (net_shares > 0.5) across all wallets, for the portfolio risk view.
+#[derive(serde::Serialize)]
+pub struct ActivePositionRow {
+    pub proxy_wallet: String,
+    pub condition_id: String,
+    pub market_title: Option<String>,
+    pub outcome: Option<String>,
+    pub net_shares: f64,
+    pub avg_entry_price: f64,
+    pub total_bet: f64,
+    pub trade_count: u32,
+    /// Unix timestamp (seconds) of the most recent trade in this position
+    pub last_trade_at: i64,
+    /// Polymarket URL for this market (event or market page)
+    pub polymarket_url: Option<String>,
+}
+
+/// Sort order for the portfolio-wide active positions list (all descending).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionSort {
+    #[default]
+    TotalBet,
+    NetShares,
+    LastTradeAt,
+}
+
+impl PositionSort {
+    pub fn order_by(self) -> &'static str {
+        match self {
+            Self::TotalBet => "total_bet DESC",
+            Self::NetShares => "net_shares DESC",
+            Self::LastTradeAt => "last_trade_at DESC",
+        }
+    }
 }
 
 /// One row from trades_raw for the wallet scorecard.
@@ -466,6 +621,51 @@ pub struct WalletTradeRow {
     pub polygonscan_url: Option<String>,
 }
 
+/// Number of activity_raw rows of one type (TRADE, REDEEM, SPLIT, MERGE, ...) for a wallet.
+#[derive(Debug, serde::Serialize)]
+pub struct ActivityTypeCount {
+    pub activity_type: String,
+    pub count: usize,
+}
+
+/// Number of `copy_fidelity_events` with one outcome (COPIED, SKIPPED_PORTFOLIO_RISK, ...) for a wallet.
+#[derive(Debug, serde::Serialize)]
+pub struct CopyFidelityOutcomeCount {
+    pub outcome: String,
+    pub count: usize,
+    /// Share of all the wallet's copy-fidelity events, e.g. "20%".
+    pub share_display: String,
+}
+
+/// One WScore sub-score and how much it added to the weighted sum.
+#[derive(Debug, serde::Serialize)]
+pub struct WScoreContribution {
+    pub component: String,
+    pub sub_score: f64,
+    /// Configured weight (`[wallet_scoring]`), before normalizing by the weight total.
+    pub weight: f64,
+    /// `weight * sub_score / total_weight`; the contributions sum to `weighted_sum`.
+    pub contribution: f64,
+    pub sub_score_display: String,
+    pub weight_display: String,
+    pub contribution_display: String,
+}
+
+/// Why a wallet got its latest 30d WScore: per-component contributions plus the
+/// win-rate / trust / obscurity adjustment applied on top of the weighted sum.
+#[derive(Debug, serde::Serialize)]
+pub struct WScoreExplanation {
+    pub score_date: String,
+    pub wscore: f64,
+    pub weighted_sum: f64,
+    /// `wscore - weighted_sum` (negative when a penalty applied).
+    pub adjustment: f64,
+    pub components: Vec<WScoreContribution>,
+    pub wscore_display: String,
+    pub weighted_sum_display: String,
+    pub adjustment_display: String,
+}
+
 /// One activity row from activity_raw for the wallet scorecard Activity tab.
 #[derive(serde::Serialize)]
 pub struct WalletActivityRow {
@@ -482,11 +682,15 @@ pub struct WalletActivityRow {
     pub polymarket_url: Option<String>,
 }
 
+#[derive(serde::Serialize)]
 pub struct WalletJourney {
     pub proxy_wallet: String,
     pub wallet_short: String,
     /// Display label: Polymarket profile name if set, otherwise wallet_short.
     pub wallet_display_label: String,
+    /// Page rendered with the short label; the name is swapped in from `/wallet/{w}/display-name`.
+    #[serde(skip)]
+    pub display_name_pending: bool,
     pub discovered_at: String,
     /// When we last ingested trades for this wallet (MAX(ingested_at) in trades_raw). We never have "all" trades; this is when we last fetched.
     pub last_trades_ingestion_at: Option<String>,
@@ -498,6 +702,8 @@ pub struct WalletJourney {
     pub paper_pnl_display: String,
     pub exposure_display: String,
     pub copy_fidelity_display: String,
+    /// Copy-fidelity events per outcome, most frequent first (why fidelity is low).
+    pub copy_fidelity_breakdown: Vec<CopyFidelityOutcomeCount>,
     pub follower_slippage_display: String,
     pub score: Option<WalletScoreSnapshot>,
     pub features: Option<WalletFeaturesSnapshot>,
@@ -513,19 +719,48 @@ pub struct WalletJourney {
     /// Activity feed from activity_raw — initial page of 20.
     pub activities: Vec<WalletActivityRow>,
     pub total_activities_count: usize,
+    /// Activity counts per type (most frequent first) — trader vs mostly-redeem/merge wallet.
+    pub activity_type_counts: Vec<ActivityTypeCount>,
     /// Trades from trades_raw for this wallet (newest first; for collapsible "All trades" detail).
     pub trades: Vec<WalletTradeRow>,
     /// Total number of trades in trades_raw for this wallet (for "All trades (N)" and load-more).
     pub total_trades_count: usize,
 }
 
-// Helper to truncate wallet addresses
+// Helper to truncate wallet addresses (display only; links and lookups keep the lowercase key)
 pub fn shorten_wallet(addr: &str) -> String {
+    let addr = checksum_address(addr);
     if addr.len() > 10 {
         format!("{}..{}", &addr[..6], &addr[addr.len() - 4..])
     } else {
-        addr.to_string()
+        addr
+    }
+}
+
+/// EIP-55 mixed-case checksum form of a 20-byte hex address. Anything that is not
+/// `0x` + 40 hex chars is returned unchanged.
+pub fn checksum_address(addr: &str) -> String {
+    use sha3::{Digest, Keccak256};
+
+    let Some(hex) = addr
+        .strip_prefix("0x")
+        .filter(|h| h.len() == 40 && h.bytes().all(|b| b.is_ascii_hexdigit()))
+    else {
+        return addr.to_string();
+    };
+    let lower = hex.to_ascii_lowercase();
+    let hash = Keccak256::digest(lower.as_bytes());
+    let mut out = String::with_capacity(42);
+    out.push_str("0x");
+    for (i, c) in lower.chars().enumerate() {
+        let nibble = (hash[i / 2] >> (if i % 2 == 0 { 4 } else { 0 })) & 0x0f;
+        out.push(if nibble >= 8 {
+            c.to_ascii_uppercase()
+        } else {
+            c
+        });
     }
+    out
 }
 
 impl FunnelCounts {
@@ -693,6 +928,24 @@ mod tests {
     fn test_shorten_wallet() {
         assert_eq!(shorten_wallet("0xabcdef1234567890"), "0xabcd..7890");
         assert_eq!(shorten_wallet("0x123"), "0x123");
+        assert_eq!(
+            shorten_wallet("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed"),
+            "0x5aAe..eAed"
+        );
+    }
+
+    #[test]
+    fn test_checksum_address_eip55_vectors() {
+        // Test vectors from the EIP-55 spec.
+        for expected in [
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359",
+            "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB",
+            "0xD1220A0cf47c7B9Be7A2E6BA89F429762e7b9aDb",
+        ] {
+            assert_eq!(checksum_address(&expected.to_lowercase()), expected);
+        }
+        assert_eq!(checksum_address("0xnot-an-address"), "0xnot-an-address");
     }
 
     #[test]
@@ -777,6 +1030,8 @@ mod tests {
             status: "running".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(
@@ -795,6 +1050,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(r#"{"inserted":261,"total":392,"completed":true}"#.to_string()),
@@ -810,6 +1067,8 @@ mod tests {
             status: "running".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(
@@ -828,6 +1087,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(r#"{"classified":17489,"suitable":1234,"stage1_excluded":15000,"stage2_excluded":1255,"completed":true}"#.to_string()),
@@ -846,6 +1107,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(r#"{"some":"data"}"#.to_string()),
@@ -861,6 +1124,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: None,
@@ -869,6 +1134,34 @@ mod tests {
         assert_eq!(job.progress_display(), "");
     }
 
+    #[test]
+    fn test_job_status_row_next_run_display() {
+        let mut job = JobStatusRow {
+            job_name: "trades_ingestion".to_string(),
+            status: "idle".to_string(),
+            last_run_at: None,
+            next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
+            last_error: None,
+            duration_ms: None,
+            metadata: None,
+            updated_at: None,
+        };
+        assert_eq!(job.next_run_display(), "-");
+        let cases = [
+            (-5, "due"),
+            (42, "in 42s"),
+            (300, "in 5m"),
+            (3_900, "in 1h 5m"),
+            (90_000, "in 1d 1h"),
+        ];
+        for (secs, expected) in cases {
+            job.next_run_in_secs = Some(secs);
+            assert_eq!(job.next_run_display(), expected);
+        }
+    }
+
     #[test]
     fn test_job_status_row_progress_display_trades_ingestion() {
         let job = JobStatusRow {
@@ -876,6 +1169,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(r#"{"wallets":50,"inserted":15234}"#.to_string()),
@@ -891,6 +1186,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(r#"{"wallets":30,"inserted":500}"#.to_string()),
@@ -906,6 +1203,8 @@ mod tests {
             status: "idle".to_string(),
             last_run_at: None,
             next_run_at: None,
+            interval_secs: None,
+            next_run_in_secs: None,
             last_error: None,
             duration_ms: None,
             metadata: Some(r#"{"markets":20,"inserted":400}"#.to_string()),
@@ -913,4 +1212,30 @@ mod tests {
         };
         assert_eq!(job.progress_display(), "20 markets (400 inserted)");
     }
+
+    #[test]
+    fn test_wallet_position_mark_to_market() {
+        let mut p = WalletPositionRow {
+            condition_id: "0xc".to_string(),
+            market_title: None,
+            outcome: Some("Yes".to_string()),
+            shares_display: "100".to_string(),
+            avg_price_display: "40.0¢".to_string(),
+            total_bet_display: "$40.00".to_string(),
+            trade_count: 1,
+            polymarket_url: None,
+            unrealized_pnl_display: "—".to_string(),
+            unrealized_pnl_color: String::new(),
+            net_shares: 100.0,
+            avg_entry_price: 0.40,
+            outcome_index: Some(0),
+        };
+        p.mark_to_market(0.55);
+        assert_eq!(p.unrealized_pnl_display, "+$15.00");
+        assert_eq!(p.unrealized_pnl_color, "text-green-400");
+
+        p.mark_to_market(0.25);
+        assert_eq!(p.unrealized_pnl_display, "-$15.00");
+        assert_eq!(p.unrealized_pnl_color, "text-red-400");
+    }
 }
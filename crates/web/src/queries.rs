@@ -4,6 +4,7 @@ use rusqlite::Connection;
 use rusqlite::OptionalExtension;
 
 use crate::models::*;
+use common::funnel::follow_worthy_sql;
 
 fn timed_db_op<T>(op: &'static str, f: impl FnOnce() -> Result<T>) -> Result<T> {
     let start = std::time::Instant::now();
@@ -102,17 +103,26 @@ pub fn funnel_counts(conn: &Connection) -> Result<FunnelCounts> {
 pub fn all_job_statuses(conn: &Connection) -> Result<Vec<JobStatusRow>> {
     timed_db_op("web.all_job_statuses", || {
         let mut stmt = conn.prepare(
-            "SELECT job_name, status, last_run_at, metadata, duration_ms, last_error, updated_at
+            "SELECT job_name, status, last_run_at, metadata, duration_ms, last_error, updated_at,
+                    next_run_at, interval_secs
              FROM job_status
              ORDER BY job_name",
         )?;
+        let now = chrono::Utc::now();
         let rows = stmt
             .query_map([], |row| {
+                let next_run_at: Option<String> = row.get(7)?;
+                let next_run_in_secs = next_run_at
+                    .as_deref()
+                    .and_then(parse_sqlite_datetime)
+                    .map(|t| (t - now).num_seconds());
                 Ok(JobStatusRow {
                     job_name: row.get(0)?,
                     status: row.get(1)?,
                     last_run_at: row.get(2)?,
-                    next_run_at: None,
+                    next_run_at,
+                    interval_secs: row.get(8)?,
+                    next_run_in_secs,
                     last_error: row.get(5)?,
                     duration_ms: row.get(4)?,
                     metadata: row.get(3)?,
@@ -124,7 +134,69 @@ pub fn all_job_statuses(conn: &Connection) -> Result<Vec<JobStatusRow>> {
     })
 }
 
-pub fn persona_funnel_counts(conn: &Connection) -> Result<PersonaFunnelCounts> {
+/// Paper-ROI promotion bar for "worth following" (`[ranking]` in config).
+#[derive(Debug, Clone, Copy)]
+pub struct FollowWorthyThresholds {
+    pub min_roi_7d_pct: f64,
+    pub min_roi_30d_pct: f64,
+}
+
+impl Default for FollowWorthyThresholds {
+    fn default() -> Self {
+        Self::from(&common::config::Ranking::default())
+    }
+}
+
+impl From<&common::config::Ranking> for FollowWorthyThresholds {
+    fn from(r: &common::config::Ranking) -> Self {
+        Self {
+            min_roi_7d_pct: r.min_roi_7d_pct,
+            min_roi_30d_pct: r.min_roi_30d_pct,
+        }
+    }
+}
+
+/// WScore component weights (`[wallet_scoring]` in config), as used by the scoring job.
+#[derive(Debug, Clone, Copy)]
+pub struct WScoreWeights {
+    pub edge: f64,
+    pub consistency: f64,
+    pub market_skill: f64,
+    pub timing_skill: f64,
+    pub behavior_quality: f64,
+    pub recency_edge: f64,
+}
+
+impl From<&common::config::WalletScoring> for WScoreWeights {
+    fn from(w: &common::config::WalletScoring) -> Self {
+        Self {
+            edge: w.edge_weight,
+            consistency: w.consistency_weight,
+            market_skill: w.market_skill_weight,
+            timing_skill: w.timing_skill_weight,
+            behavior_quality: w.behavior_quality_weight,
+            recency_edge: w.recency_edge_weight,
+        }
+    }
+}
+
+/// Number of wallets currently clearing the follow-worthy thresholds.
+fn follow_worthy_count(conn: &Connection, t: FollowWorthyThresholds) -> Result<i64> {
+    let count = conn.query_row(
+        &follow_worthy_sql("COUNT(DISTINCT ws7.proxy_wallet)", "", ""),
+        rusqlite::named_params! {
+            ":min_roi_7d_pct": t.min_roi_7d_pct,
+            ":min_roi_30d_pct": t.min_roi_30d_pct,
+        },
+        |r| r.get(0),
+    )?;
+    Ok(count)
+}
+
+pub fn persona_funnel_counts(
+    conn: &Connection,
+    thresholds: FollowWorthyThresholds,
+) -> Result<PersonaFunnelCounts> {
     let wallets_discovered: i64 =
         conn.query_row("SELECT COUNT(*) FROM wallets", [], |r| r.get(0))?;
 
@@ -163,7 +235,7 @@ pub fn persona_funnel_counts(conn: &Connection) -> Result<PersonaFunnelCounts> {
             EXISTS (
               SELECT 1
               FROM wallet_personas p
-              WHERE p.proxy_wallet = w.proxy_wallet
+              WHERE p.proxy_wallet = w.proxy_wallet AND p.superseded_at IS NULL
             )
             OR EXISTS (
               SELECT 1
@@ -183,26 +255,7 @@ pub fn persona_funnel_counts(conn: &Connection) -> Result<PersonaFunnelCounts> {
         |r| r.get(0),
     )?;
 
-    // Follow-worthy is a best-effort approximation based on available data:
-    // Promotion rules in docs/EVALUATION_STRATEGY.md §3.3 use ROI + hit rate + drawdown, but
-    // hit rate/drawdown aren't fully computed yet. For visibility in UI/Grafana, we use ROI-only
-    // thresholds: >+5% (7d) and >+10% (30d), both for score_date=today.
-    let follow_worthy_wallets: i64 = conn.query_row(
-        "
-        SELECT COUNT(DISTINCT ws7.proxy_wallet)
-        FROM wallet_scores_daily ws7
-        JOIN wallet_scores_daily ws30
-          ON ws30.proxy_wallet = ws7.proxy_wallet
-         AND ws30.score_date = ws7.score_date
-         AND ws30.window_days = 30
-        WHERE ws7.score_date = (SELECT MAX(score_date) FROM wallet_scores_daily)
-          AND ws7.window_days = 7
-          AND COALESCE(ws7.paper_roi_pct, 0) > 5.0
-          AND COALESCE(ws30.paper_roi_pct, 0) > 10.0
-        ",
-        [],
-        |r| r.get(0),
-    )?;
+    let follow_worthy_wallets = follow_worthy_count(conn, thresholds)?;
 
     Ok(PersonaFunnelCounts {
         wallets_discovered,
@@ -239,36 +292,29 @@ pub fn events_counts(conn: &Connection) -> Result<(i64, i64)> {
     })
 }
 
-pub fn unified_funnel_counts(conn: &Connection) -> Result<UnifiedFunnelCounts> {
+/// `hidden_personas` lists personas that are classified but must not count toward the suitable
+/// funnel (see `Personas::funnel_hidden_personas`). `min_wallet_age_days` is the Stage-1 age
+/// filter (`personas.stage1_min_wallet_age_days`) so the funnel matches the pipeline.
+/// `thresholds` is the same follow-worthy bar the rankings table uses.
+pub fn unified_funnel_counts(
+    conn: &Connection,
+    hidden_personas: &[String],
+    min_wallet_age_days: u32,
+    thresholds: FollowWorthyThresholds,
+) -> Result<UnifiedFunnelCounts> {
     timed_db_op("web.unified_funnel_counts", || {
         let (events_selected, events_evaluated) = events_counts(conn)?;
         let all_wallets: i64 = conn.query_row("SELECT COUNT(*) FROM wallets", [], |r| r.get(0))?;
-        let suitable_personas: i64 =
-            conn.query_row("SELECT COUNT(*) FROM wallet_personas", [], |r| r.get(0))?;
-        // Evaluated = active, passed Stage 1, classified, and oldest trade >= 45 days ago.
+        let suitable_personas = suitable_personas_count(conn, hidden_personas)?;
+        // Evaluated = active, passed Stage 1, classified, and oldest trade >= min age.
         // Uses shared helper to avoid duplicate CTE scans.
-        let personas_evaluated = personas_evaluated_count(conn)?;
+        let personas_evaluated = personas_evaluated_count(conn, min_wallet_age_days)?;
         let actively_paper_traded: i64 = conn.query_row(
             "SELECT COUNT(DISTINCT proxy_wallet) FROM paper_trades",
             [],
             |r| r.get(0),
         )?;
-        let worth_following: i64 = conn.query_row(
-            "
-            SELECT COUNT(DISTINCT ws7.proxy_wallet)
-            FROM wallet_scores_daily ws7
-            JOIN wallet_scores_daily ws30
-              ON ws30.proxy_wallet = ws7.proxy_wallet
-             AND ws30.score_date = ws7.score_date
-             AND ws30.window_days = 30
-            WHERE ws7.score_date = (SELECT MAX(score_date) FROM wallet_scores_daily)
-              AND ws7.window_days = 7
-              AND COALESCE(ws7.paper_roi_pct, 0) > 5.0
-              AND COALESCE(ws30.paper_roi_pct, 0) > 10.0
-            ",
-            [],
-            |r| r.get(0),
-        )?;
+        let worth_following = follow_worthy_count(conn, thresholds)?;
         let personas_excluded: i64 = excluded_wallets_count(conn)?;
         Ok(UnifiedFunnelCounts {
             events_selected,
@@ -283,9 +329,9 @@ pub fn unified_funnel_counts(conn: &Connection) -> Result<UnifiedFunnelCounts> {
     })
 }
 
-/// Helper: Count personas evaluated (>= 45 days wallet age).
+/// Helper: Count personas evaluated (wallet age >= `min_wallet_age_days`).
 /// Shared by unified_funnel_counts and suitable_personas_counts to avoid duplicate CTE scans.
-fn personas_evaluated_count(conn: &Connection) -> Result<i64> {
+fn personas_evaluated_count(conn: &Connection, min_wallet_age_days: u32) -> Result<i64> {
     let count: i64 = conn.query_row(
         "
         WITH wallet_age_days AS (
@@ -303,23 +349,56 @@ fn personas_evaluated_count(conn: &Connection) -> Result<i64> {
             WHERE e.proxy_wallet = w.proxy_wallet AND e.reason LIKE 'STAGE1_%'
           )
           AND (
-            EXISTS (SELECT 1 FROM wallet_personas p WHERE p.proxy_wallet = w.proxy_wallet)
+            EXISTS (SELECT 1 FROM wallet_personas p
+                    WHERE p.proxy_wallet = w.proxy_wallet AND p.superseded_at IS NULL)
             OR EXISTS (SELECT 1 FROM wallet_exclusions e2
                        WHERE e2.proxy_wallet = w.proxy_wallet AND e2.reason NOT LIKE 'STAGE1_%')
           )
-          AND COALESCE(wad.age_days, 0) >= 45
+          AND COALESCE(wad.age_days, 0) >= ?1
         ",
-        [],
+        [min_wallet_age_days],
+        |r| r.get(0),
+    )?;
+    Ok(count)
+}
+
+/// Helper: Count wallet_personas rows whose persona counts toward the suitable funnel.
+fn suitable_personas_count(conn: &Connection, hidden_personas: &[String]) -> Result<i64> {
+    if hidden_personas.is_empty() {
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM wallet_personas WHERE superseded_at IS NULL",
+            [],
+            |r| r.get(0),
+        )?;
+        return Ok(count);
+    }
+    let placeholders = hidden_personas
+        .iter()
+        .map(|_| "?")
+        .collect::<Vec<_>>()
+        .join(",");
+    let count: i64 = conn.query_row(
+        &format!(
+            "SELECT COUNT(*) FROM wallet_personas
+             WHERE superseded_at IS NULL AND persona NOT IN ({placeholders})"
+        ),
+        rusqlite::params_from_iter(hidden_personas.iter()),
         |r| r.get(0),
     )?;
     Ok(count)
 }
 
 /// Returns (suitable_count, evaluated_count) for the suitable personas section.
-/// Evaluated = wallets whose oldest trade is at least 45 days ago (matches stage1_min_wallet_age_days).
-pub fn suitable_personas_counts(conn: &Connection) -> Result<(i64, i64)> {
-    let suitable: i64 = conn.query_row("SELECT COUNT(*) FROM wallet_personas", [], |r| r.get(0))?;
-    let evaluated = personas_evaluated_count(conn)?;
+/// Evaluated = wallets whose oldest trade is at least `min_wallet_age_days` ago
+/// (`personas.stage1_min_wallet_age_days`).
+/// Personas in `hidden_personas` are excluded from the suitable count only.
+pub fn suitable_personas_counts(
+    conn: &Connection,
+    hidden_personas: &[String],
+    min_wallet_age_days: u32,
+) -> Result<(i64, i64)> {
+    let suitable = suitable_personas_count(conn, hidden_personas)?;
+    let evaluated = personas_evaluated_count(conn, min_wallet_age_days)?;
     Ok((suitable, evaluated))
 }
 
@@ -332,7 +411,7 @@ pub fn persona_breakdown_counts(conn: &Connection) -> Result<Vec<PersonaBreakdow
             FROM wallet_personas p
             INNER JOIN (
                 SELECT proxy_wallet, MAX(classified_at) AS max_at
-                FROM wallet_personas GROUP BY proxy_wallet
+                FROM wallet_personas WHERE superseded_at IS NULL GROUP BY proxy_wallet
             ) latest ON latest.proxy_wallet = p.proxy_wallet AND latest.max_at = p.classified_at
             GROUP BY p.persona
             ORDER BY count DESC
@@ -386,6 +465,7 @@ pub fn suitable_personas_wallets(
             INNER JOIN (
                 SELECT proxy_wallet, MAX(classified_at) AS max_at
                 FROM wallet_personas
+                WHERE superseded_at IS NULL
                 GROUP BY proxy_wallet
             ) latest ON latest.proxy_wallet = p.proxy_wallet AND latest.max_at = p.classified_at
             ORDER BY p.classified_at DESC
@@ -407,7 +487,13 @@ pub fn suitable_personas_wallets(
     })
 }
 
-pub fn paper_traded_wallets_list(conn: &Connection, limit: usize) -> Result<Vec<WalletRow>> {
+/// Wallets by most recent paper trade. `since` (a `YYYY-MM-DD HH:MM:SS` UTC timestamp) keeps only
+/// wallets whose last paper trade is after it.
+pub fn paper_traded_wallets_list(
+    conn: &Connection,
+    limit: usize,
+    since: Option<&str>,
+) -> Result<Vec<WalletRow>> {
     timed_db_op("web.paper_traded_wallets_list", || {
         let mut stmt = conn.prepare(
             "
@@ -419,6 +505,7 @@ pub fn paper_traded_wallets_list(conn: &Connection, limit: usize) -> Result<Vec<
                 SELECT proxy_wallet, MAX(created_at) AS last_trade
                 FROM paper_trades
                 GROUP BY proxy_wallet
+                HAVING ?2 IS NULL OR last_trade > ?2
                 ORDER BY last_trade DESC
                 LIMIT ?1
             ) pt ON pt.proxy_wallet = w.proxy_wallet
@@ -427,7 +514,7 @@ pub fn paper_traded_wallets_list(conn: &Connection, limit: usize) -> Result<Vec<
             ",
         )?;
         let rows = stmt
-            .query_map([limit as i64], |row| {
+            .query_map(rusqlite::params![limit as i64, since], |row| {
                 let wallet: String = row.get(0)?;
                 Ok(WalletRow {
                     proxy_wallet: wallet.clone(),
@@ -444,76 +531,134 @@ pub fn paper_traded_wallets_list(conn: &Connection, limit: usize) -> Result<Vec<
     })
 }
 
-pub fn follow_worthy_rankings(conn: &Connection, limit: Option<usize>) -> Result<Vec<RankingRow>> {
+/// Which score window drives the rankings order and ROI column (`?window=7|30`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RankingWindow {
+    /// Ordered by 7d WScore, 7d and 30d ROI side by side.
+    #[default]
+    Combined,
+    /// Ordered by 7d paper ROI; 7d score columns.
+    Days7,
+    /// Ordered by 30d paper ROI; 30d score columns.
+    Days30,
+}
+
+impl RankingWindow {
+    /// Absent or 0 means combined; `None` for anything other than 7 or 30.
+    pub fn from_days(days: Option<u32>) -> Option<Self> {
+        match days {
+            None | Some(0) => Some(Self::Combined),
+            Some(7) => Some(Self::Days7),
+            Some(30) => Some(Self::Days30),
+            Some(_) => None,
+        }
+    }
+}
+
+/// Wallets clearing both follow-worthy ROI bars, ordered and displayed per `window`.
+/// Eligibility is the same dual 7d+30d threshold whatever the window.
+pub fn follow_worthy_rankings(
+    conn: &Connection,
+    limit: Option<usize>,
+    thresholds: FollowWorthyThresholds,
+    window: RankingWindow,
+) -> Result<Vec<RankingRow>> {
     let limit = limit.unwrap_or(500);
+    let (ws, order_by) = match window {
+        RankingWindow::Combined => ("ws7", "ws7.wscore DESC"),
+        RankingWindow::Days7 => (
+            "ws7",
+            "COALESCE(ws7.paper_roi_pct, 0) DESC, ws7.wscore DESC",
+        ),
+        RankingWindow::Days30 => (
+            "ws30",
+            "COALESCE(ws30.paper_roi_pct, 0) DESC, ws30.wscore DESC",
+        ),
+    };
     timed_db_op("web.follow_worthy_rankings", || {
-        let mut stmt = conn.prepare(
+        let sql = follow_worthy_sql(
+            &format!(
+                "ws7.proxy_wallet, {ws}.wscore,
+             COALESCE({ws}.edge_score, 0), COALESCE({ws}.consistency_score, 0),
+             COALESCE({ws}.recommended_follow_mode, 'mirror'),
+             COALESCE(tc.trade_count, 0),
+             COALESCE(pnl.total_pnl, 0),
+             COALESCE(ws7.paper_roi_pct, 0), COALESCE(ws30.paper_roi_pct, 0)"
+            ),
             "
-            SELECT ws.proxy_wallet, ws.wscore,
-                    COALESCE(ws.edge_score, 0), COALESCE(ws.consistency_score, 0),
-                    COALESCE(ws.recommended_follow_mode, 'mirror'),
-                    COALESCE(tc.trade_count, 0),
-                    COALESCE(pnl.total_pnl, 0)
-            FROM wallet_scores_daily ws
-            JOIN wallet_scores_daily ws30
-              ON ws30.proxy_wallet = ws.proxy_wallet
-             AND ws30.score_date = ws.score_date
-             AND ws30.window_days = 30
             LEFT JOIN (
               SELECT proxy_wallet, COUNT(*) as trade_count
               FROM trades_raw
               GROUP BY proxy_wallet
-            ) tc ON tc.proxy_wallet = ws.proxy_wallet
+            ) tc ON tc.proxy_wallet = ws7.proxy_wallet
             LEFT JOIN (
               SELECT proxy_wallet, SUM(pnl) as total_pnl
               FROM paper_trades
               WHERE status != 'open'
               GROUP BY proxy_wallet
-            ) pnl ON pnl.proxy_wallet = ws.proxy_wallet
-            WHERE ws.score_date = (SELECT MAX(score_date) FROM wallet_scores_daily)
-              AND ws.window_days = 7
-              AND COALESCE(ws.paper_roi_pct, 0) > 5.0
-              AND COALESCE(ws30.paper_roi_pct, 0) > 10.0
-            ORDER BY ws.wscore DESC
-            LIMIT ?1
-            ",
-        )?;
+            ) pnl ON pnl.proxy_wallet = ws7.proxy_wallet",
+            &format!("ORDER BY {order_by} LIMIT :limit"),
+        );
+        let mut stmt = conn.prepare(&sql)?;
         let rows = stmt
-            .query_map([limit as i64], |row| {
-                let wallet: String = row.get(0)?;
-                let wscore: f64 = row.get(1)?;
-                let edge_score: f64 = row.get(2)?;
-                let consistency_score: f64 = row.get(3)?;
-                let paper_pnl: f64 = row.get(6)?;
-
-                let pnl_color = if paper_pnl >= 0.0 {
-                    "text-green-400"
-                } else {
-                    "text-red-400"
-                }
-                .to_string();
-                let sign = if paper_pnl >= 0.0 { "+" } else { "" };
+            .query_map(
+                rusqlite::named_params! {
+                    ":min_roi_7d_pct": thresholds.min_roi_7d_pct,
+                    ":min_roi_30d_pct": thresholds.min_roi_30d_pct,
+                    ":limit": limit as i64,
+                },
+                |row| {
+                    let wallet: String = row.get(0)?;
+                    let wscore: f64 = row.get(1)?;
+                    let edge_score: f64 = row.get(2)?;
+                    let consistency_score: f64 = row.get(3)?;
+                    let paper_pnl: f64 = row.get(6)?;
+                    let roi_7d: f64 = row.get(7)?;
+                    let roi_30d: f64 = row.get(8)?;
+                    let (roi_display, driving_roi) = match window {
+                        RankingWindow::Combined => {
+                            (format!("{roi_7d:+.1}% / {roi_30d:+.1}%"), roi_7d)
+                        }
+                        RankingWindow::Days7 => (format!("{roi_7d:+.1}%"), roi_7d),
+                        RankingWindow::Days30 => (format!("{roi_30d:+.1}%"), roi_30d),
+                    };
+                    let roi_color = if driving_roi >= 0.0 {
+                        "text-green-400"
+                    } else {
+                        "text-red-400"
+                    };
 
-                Ok(RankingRow {
-                    rank: 0,
-                    rank_display: String::new(),
-                    row_class: String::new(),
-                    proxy_wallet: wallet.clone(),
-                    wallet_short: shorten_wallet(&wallet),
-                    wscore,
-                    wscore_display: format!("{wscore:.2}"),
-                    wscore_pct: format!("{:.0}", wscore * 100.0),
-                    edge_score,
-                    edge_display: format!("{edge_score:.2}"),
-                    consistency_score,
-                    consistency_display: format!("{consistency_score:.2}"),
-                    follow_mode: row.get(4)?,
-                    trade_count: row.get(5)?,
-                    paper_pnl,
-                    pnl_display: format!("{sign}${paper_pnl:.2}"),
-                    pnl_color,
-                })
-            })?
+                    let pnl_color = if paper_pnl >= 0.0 {
+                        "text-green-400"
+                    } else {
+                        "text-red-400"
+                    }
+                    .to_string();
+                    let sign = if paper_pnl >= 0.0 { "+" } else { "" };
+
+                    Ok(RankingRow {
+                        rank: 0,
+                        rank_display: String::new(),
+                        row_class: String::new(),
+                        proxy_wallet: wallet.clone(),
+                        wallet_short: shorten_wallet(&wallet),
+                        wscore,
+                        wscore_display: format!("{wscore:.2}"),
+                        wscore_pct: format!("{:.0}", wscore * 100.0),
+                        edge_score,
+                        edge_display: format!("{edge_score:.2}"),
+                        consistency_score,
+                        consistency_display: format!("{consistency_score:.2}"),
+                        follow_mode: row.get(4)?,
+                        trade_count: row.get(5)?,
+                        paper_pnl,
+                        pnl_display: format!("{sign}${paper_pnl:.2}"),
+                        pnl_color,
+                        roi_display,
+                        roi_color: roi_color.to_string(),
+                    })
+                },
+            )?
             .collect::<std::result::Result<Vec<_>, _>>()?;
 
         let rows: Vec<RankingRow> = rows
@@ -743,20 +888,41 @@ fn polygonscan_url(tx_hash: Option<&str>) -> Option<String> {
         .map(|h| format!("https://polygonscan.com/tx/{h}"))
 }
 
-/// Parse a SQLite datetime string and return age in seconds from now
+/// Parse a SQLite `datetime('now')` string ("YYYY-MM-DD HH:MM:SS", UTC).
+pub fn parse_sqlite_datetime(ts: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S")
+        .ok()
+        .map(|dt| dt.and_utc())
+}
+
+/// Parse a SQLite datetime string (UTC) and return age in seconds from now.
+///
+/// Timestamps in the future (clock skew between the writer and this host) are clamped to 0 and
+/// counted in `evaluator_future_timestamp_total`, so skew shows up instead of a negative age.
 fn age_seconds_from_timestamp(ts: &str) -> i64 {
     // SQLite returns either "YYYY-MM-DD" or "YYYY-MM-DD HH:MM:SS"
     use chrono::{NaiveDate, NaiveDateTime, Utc};
     let now = Utc::now().naive_utc();
 
-    if let Ok(dt) = NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S") {
-        (now - dt).num_seconds()
+    let dt = if let Ok(dt) = NaiveDateTime::parse_from_str(ts, "%Y-%m-%d %H:%M:%S") {
+        dt
     } else if let Ok(d) = NaiveDate::parse_from_str(ts, "%Y-%m-%d") {
-        let dt = d.and_hms_opt(0, 0, 0).unwrap();
-        (now - dt).num_seconds()
+        d.and_hms_opt(0, 0, 0).unwrap()
     } else {
-        i64::MAX // unknown format = treat as very old
+        return i64::MAX; // unknown format = treat as very old
+    };
+
+    let age = (now - dt).num_seconds();
+    if age < 0 {
+        metrics::counter!("evaluator_future_timestamp_total").increment(1);
+        tracing::warn!(
+            timestamp = ts,
+            ahead_secs = -age,
+            "timestamp is in the future (clock skew?)"
+        );
+        return 0;
     }
+    age
 }
 
 pub fn top_markets_today(conn: &Connection) -> Result<Vec<MarketRow>> {
@@ -799,6 +965,97 @@ pub fn top_markets_today(conn: &Connection) -> Result<Vec<MarketRow>> {
     })
 }
 
+/// Market detail: metadata, MScore history (newest first, up to 30 days) and wallets whose
+/// `discovered_market` is this market. Returns None for condition_ids not in `markets`.
+pub fn market_detail(conn: &Connection, condition_id: &str) -> Result<Option<MarketDetail>> {
+    timed_db_op("web.market_detail", || {
+        let market = conn
+            .query_row(
+                "SELECT title, category, end_date, COALESCE(liquidity, 0), COALESCE(volume, 0),
+                        event_slug, slug
+                FROM markets
+                WHERE condition_id = ?1",
+                [condition_id],
+                |r| {
+                    Ok((
+                        r.get::<_, String>(0)?,
+                        r.get::<_, Option<String>>(1)?,
+                        r.get::<_, Option<String>>(2)?,
+                        r.get::<_, f64>(3)?,
+                        r.get::<_, f64>(4)?,
+                        r.get::<_, Option<String>>(5)?,
+                        r.get::<_, Option<String>>(6)?,
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((title, category, end_date, liquidity, volume, event_slug, slug)) = market else {
+            return Ok(None);
+        };
+
+        let mut stmt = conn.prepare(
+            "SELECT score_date, mscore, rank, COALESCE(liquidity_score, 0),
+                    COALESCE(volume_score, 0), COALESCE(density_score, 0)
+            FROM market_scores
+            WHERE condition_id = ?1
+            ORDER BY score_date DESC
+            LIMIT 30",
+        )?;
+        let score_history = stmt
+            .query_map([condition_id], |r| {
+                Ok(MarketScoreHistoryRow {
+                    score_date: r.get(0)?,
+                    mscore: r.get(1)?,
+                    rank: r.get(2)?,
+                    liquidity_score: r.get(3)?,
+                    volume_score: r.get(4)?,
+                    density_score: r.get(5)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut stmt = conn.prepare(
+            "SELECT w.proxy_wallet, w.discovered_from, w.discovered_at, w.is_active,
+                    (SELECT p.persona FROM wallet_personas p
+                     WHERE p.proxy_wallet = w.proxy_wallet AND p.superseded_at IS NULL
+                     ORDER BY p.classified_at DESC LIMIT 1),
+                    (SELECT s.wscore FROM wallet_scores_daily s
+                     WHERE s.proxy_wallet = w.proxy_wallet AND s.window_days = 30
+                     ORDER BY s.score_date DESC LIMIT 1) AS wscore
+            FROM wallets w
+            WHERE w.discovered_market = ?1
+            ORDER BY wscore IS NULL, wscore DESC, w.discovered_at DESC
+            LIMIT 200",
+        )?;
+        let wallets = stmt
+            .query_map([condition_id], |r| {
+                let proxy_wallet: String = r.get(0)?;
+                Ok(MarketWalletRow {
+                    wallet_short: shorten_wallet(&proxy_wallet),
+                    proxy_wallet,
+                    discovered_from: r.get(1)?,
+                    discovered_at: r.get(2)?,
+                    is_active: r.get::<_, i64>(3)? != 0,
+                    persona: r.get(4)?,
+                    wscore: r.get(5)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        Ok(Some(MarketDetail {
+            condition_id: condition_id.to_string(),
+            title,
+            category,
+            end_date,
+            liquidity,
+            volume,
+            polymarket_url: polymarket_url(event_slug.as_deref(), slug.as_deref()),
+            score_history,
+            wallets,
+        }))
+    })
+}
+
 pub fn top_events(conn: &Connection, limit: usize) -> Result<Vec<EventRow>> {
     timed_db_op("web.top_events", || {
         let mut stmt = conn.prepare(
@@ -816,12 +1073,13 @@ pub fn top_events(conn: &Connection, limit: usize) -> Result<Vec<EventRow>> {
                 GROUP BY event_key
             )
             SELECT b.event_key, b.best_mscore, b.market_count,
-                   (SELECT s.title FROM scored s WHERE s.event_key = b.event_key ORDER BY s.mscore DESC LIMIT 1),
-                   (SELECT s.event_slug FROM scored s WHERE s.event_key = b.event_key ORDER BY s.mscore DESC LIMIT 1),
-                   (SELECT s.slug FROM scored s WHERE s.event_key = b.event_key ORDER BY s.mscore DESC LIMIT 1)
+                   (SELECT s.title FROM scored s WHERE s.event_key = b.event_key ORDER BY s.mscore DESC, s.condition_id ASC LIMIT 1),
+                   (SELECT s.event_slug FROM scored s WHERE s.event_key = b.event_key ORDER BY s.mscore DESC, s.condition_id ASC LIMIT 1),
+                   (SELECT s.slug FROM scored s WHERE s.event_key = b.event_key ORDER BY s.mscore DESC, s.condition_id ASC LIMIT 1)
             FROM best b
             WHERE b.market_count >= 1
-            ORDER BY b.best_mscore DESC
+            -- Tie-breaks keep equal-score events in a stable order across refreshes.
+            ORDER BY b.best_mscore DESC, b.market_count DESC, b.event_key ASC
             LIMIT ?1
             ",
         )?;
@@ -898,7 +1156,13 @@ pub fn wallet_overview(conn: &Connection) -> Result<WalletOverview> {
     })
 }
 
-pub fn recent_wallets(conn: &Connection, limit: usize) -> Result<Vec<WalletRow>> {
+/// Most recently discovered wallets. `since` (a `YYYY-MM-DD HH:MM:SS` UTC timestamp) keeps only
+/// wallets discovered after it.
+pub fn recent_wallets(
+    conn: &Connection,
+    limit: usize,
+    since: Option<&str>,
+) -> Result<Vec<WalletRow>> {
     timed_db_op("web.recent_wallets", || {
         let mut stmt = conn.prepare(
             "SELECT w.proxy_wallet, w.discovered_from,
@@ -906,11 +1170,12 @@ pub fn recent_wallets(conn: &Connection, limit: usize) -> Result<Vec<WalletRow>>
                     (SELECT COUNT(*) FROM trades_raw t WHERE t.proxy_wallet = w.proxy_wallet)
             FROM wallets w
             LEFT JOIN markets m ON m.condition_id = w.discovered_market
+            WHERE ?2 IS NULL OR w.discovered_at > ?2
             ORDER BY w.discovered_at DESC
             LIMIT ?1",
         )?;
         let rows = stmt
-            .query_map([limit as i64], |row| {
+            .query_map(rusqlite::params![limit as i64, since], |row| {
                 let wallet: String = row.get(0)?;
                 let wallet_short = shorten_wallet(&wallet);
                 Ok(WalletRow {
@@ -1024,22 +1289,26 @@ pub fn excluded_wallets_latest(
     offset: usize,
 ) -> Result<Vec<ExcludedWalletRow>> {
     timed_db_op("web.excluded_wallets_latest", || {
-        // NOTE: If multiple exclusion rows share the same `excluded_at` for a wallet, this query can
-        // return multiple rows for that wallet (tie on MAX(excluded_at)). Current semantics: show all
-        // "latest-timestamp" reasons. If we want strictly one row per wallet, add a deterministic
-        // tiebreak (e.g. MAX(id) among rows at MAX(excluded_at)) and join on that.
+        // One row per wallet: the latest exclusion, ties on excluded_at broken by the highest id,
+        // so the page matches COUNT(DISTINCT proxy_wallet) in `excluded_wallets_count`.
         let mut stmt = conn.prepare(
             "
             SELECT e.proxy_wallet, e.reason, e.metric_value, e.threshold, e.excluded_at
             FROM wallet_exclusions e
             JOIN (
-              SELECT proxy_wallet, MAX(excluded_at) AS max_excluded_at
-              FROM wallet_exclusions
-              GROUP BY proxy_wallet
+              SELECT w.proxy_wallet, MAX(w.id) AS latest_id
+              FROM wallet_exclusions w
+              JOIN (
+                SELECT proxy_wallet, MAX(excluded_at) AS max_excluded_at
+                FROM wallet_exclusions
+                GROUP BY proxy_wallet
+              ) m
+                ON m.proxy_wallet = w.proxy_wallet
+               AND m.max_excluded_at = w.excluded_at
+              GROUP BY w.proxy_wallet
             ) latest
-              ON latest.proxy_wallet = e.proxy_wallet
-             AND latest.max_excluded_at = e.excluded_at
-            ORDER BY e.excluded_at DESC
+              ON latest.latest_id = e.id
+            ORDER BY e.excluded_at DESC, e.id DESC
             LIMIT ?1 OFFSET ?2
             ",
         )?;
@@ -1086,8 +1355,12 @@ pub fn wallet_positions_count(conn: &Connection, proxy_wallet: &str) -> Result<u
     Ok(n as usize)
 }
 
-/// Count of active positions (net_shares > 0.5) for a wallet.
-fn wallet_active_positions_count(conn: &Connection, proxy_wallet: &str) -> Result<usize> {
+/// Count of active positions (net_shares > min_shares) for a wallet.
+fn wallet_active_positions_count(
+    conn: &Connection,
+    proxy_wallet: &str,
+    min_shares: f64,
+) -> Result<usize> {
     let n: i64 = conn.query_row(
         "
         SELECT COUNT(*) FROM (
@@ -1096,17 +1369,21 @@ fn wallet_active_positions_count(conn: &Connection, proxy_wallet: &str) -> Resul
                 - SUM(CASE WHEN side = 'SELL' THEN size ELSE 0 END) AS net_shares
             FROM trades_raw WHERE proxy_wallet = ?1
             GROUP BY condition_id, outcome
-            HAVING net_shares > 0.5
+            HAVING net_shares > ?2
         )
         ",
-        [proxy_wallet],
+        rusqlite::params![proxy_wallet, min_shares],
         |r| r.get(0),
     )?;
     Ok(n as usize)
 }
 
-/// Count of closed positions (net_shares <= 0.5) for a wallet.
-fn wallet_closed_positions_count(conn: &Connection, proxy_wallet: &str) -> Result<usize> {
+/// Count of closed positions (net_shares <= min_shares) for a wallet.
+fn wallet_closed_positions_count(
+    conn: &Connection,
+    proxy_wallet: &str,
+    min_shares: f64,
+) -> Result<usize> {
     let n: i64 = conn.query_row(
         "
         SELECT COUNT(*) FROM (
@@ -1115,10 +1392,10 @@ fn wallet_closed_positions_count(conn: &Connection, proxy_wallet: &str) -> Resul
                 - SUM(CASE WHEN side = 'SELL' THEN size ELSE 0 END) AS net_shares
             FROM trades_raw WHERE proxy_wallet = ?1
             GROUP BY condition_id, outcome
-            HAVING net_shares <= 0.5
+            HAVING net_shares <= ?2
         )
         ",
-        [proxy_wallet],
+        rusqlite::params![proxy_wallet, min_shares],
         |r| r.get(0),
     )?;
     Ok(n as usize)
@@ -1134,10 +1411,12 @@ struct PositionsSummary {
 
 /// Fetch both active and closed positions in a single query using CTE.
 /// Replaces 4 separate queries (2 counts + 2 data queries) with 1.
+/// A position is active when its net shares exceed `min_shares`.
 fn wallet_positions_summary(
     conn: &Connection,
     proxy_wallet: &str,
     limit: u32,
+    min_shares: f64,
 ) -> Result<PositionsSummary> {
     let limit = limit.min(100);
     let sql = "
@@ -1156,7 +1435,8 @@ fn wallet_positions_summary(
             COUNT(*) AS trade_count,
             m.event_slug,
             m.slug,
-            MAX(tr.timestamp) AS last_trade_at
+            MAX(tr.timestamp) AS last_trade_at,
+            MAX(tr.outcome_index) AS outcome_index
           FROM trades_raw tr
           LEFT JOIN markets m ON m.condition_id = tr.condition_id
           WHERE tr.proxy_wallet = ?1
@@ -1164,13 +1444,14 @@ fn wallet_positions_summary(
         )
         SELECT condition_id, title, outcome, net_shares, avg_entry_price, total_bet, trade_count,
                event_slug, slug,
-               CASE WHEN net_shares > 0.5 THEN 1 ELSE 0 END AS is_active
+               CASE WHEN net_shares > ?2 THEN 1 ELSE 0 END AS is_active,
+               outcome_index
         FROM position_base
         ORDER BY last_trade_at DESC
     ";
 
     let mut stmt = conn.prepare(sql)?;
-    let rows = stmt.query_map([proxy_wallet], |r| {
+    let rows = stmt.query_map(rusqlite::params![proxy_wallet, min_shares], |r| {
         Ok((
             r.get::<_, String>(0)?,         // condition_id
             r.get::<_, Option<String>>(1)?, // title
@@ -1182,6 +1463,7 @@ fn wallet_positions_summary(
             r.get::<_, Option<String>>(7)?, // event_slug
             r.get::<_, Option<String>>(8)?, // slug
             r.get::<_, i64>(9)?,            // is_active
+            r.get::<_, Option<i64>>(10)?,   // outcome_index
         ))
     })?;
 
@@ -1202,6 +1484,7 @@ fn wallet_positions_summary(
             event_slug,
             slug,
             is_active,
+            outcome_index,
         ) = row?;
 
         let pm_url = polymarket_url(event_slug.as_deref(), slug.as_deref());
@@ -1214,6 +1497,11 @@ fn wallet_positions_summary(
             total_bet_display: format!("${total_bet:.2}"),
             trade_count: trade_count as u32,
             polymarket_url: pm_url,
+            unrealized_pnl_display: "—".to_string(),
+            unrealized_pnl_color: "text-gray-500".to_string(),
+            net_shares,
+            avg_entry_price,
+            outcome_index,
         };
 
         if is_active == 1 {
@@ -1237,15 +1525,29 @@ fn wallet_positions_summary(
     })
 }
 
+/// Which positions `wallet_positions_filtered` returns.
+#[derive(Debug, Clone, Copy)]
+enum PositionBucket {
+    All,
+    /// net_shares > min_shares
+    Active(f64),
+    /// net_shares <= min_shares
+    Closed(f64),
+}
+
 /// Internal: query positions with a HAVING filter for active/closed split.
 fn wallet_positions_filtered(
     conn: &Connection,
     proxy_wallet: &str,
     offset: u32,
     limit: u32,
-    having_clause: &str,
+    bucket: PositionBucket,
 ) -> Result<Vec<WalletPositionRow>> {
-    let limit = limit.min(100);
+    let (having_clause, min_shares) = match bucket {
+        PositionBucket::All => ("", None),
+        PositionBucket::Active(min) => ("HAVING net_shares > ?4", Some(min)),
+        PositionBucket::Closed(min) => ("HAVING net_shares <= ?4", Some(min)),
+    };
     let sql = format!(
         "
         SELECT
@@ -1261,7 +1563,8 @@ fn wallet_positions_filtered(
           SUM(CASE WHEN tr.side = 'BUY' THEN tr.size * tr.price ELSE 0 END) AS total_bet,
           COUNT(*) AS trade_count,
           m.event_slug,
-          m.slug
+          m.slug,
+          MAX(tr.outcome_index) AS outcome_index
         FROM trades_raw tr
         LEFT JOIN markets m ON m.condition_id = tr.condition_id
         WHERE tr.proxy_wallet = ?1
@@ -1272,22 +1575,25 @@ fn wallet_positions_filtered(
         "
     );
     let mut stmt = conn.prepare(&sql)?;
-    let rows = stmt.query_map(
-        rusqlite::params![proxy_wallet, i64::from(limit), i64::from(offset)],
-        |r| {
-            Ok((
-                r.get::<_, String>(0)?,
-                r.get::<_, Option<String>>(1)?,
-                r.get::<_, Option<String>>(2)?,
-                r.get::<_, f64>(3)?,
-                r.get::<_, f64>(4)?,
-                r.get::<_, f64>(5)?,
-                r.get::<_, i64>(6)?,
-                r.get::<_, Option<String>>(7)?,
-                r.get::<_, Option<String>>(8)?,
-            ))
-        },
-    )?;
+    let (limit, offset) = (i64::from(limit), i64::from(offset));
+    let mut params: Vec<&dyn rusqlite::ToSql> = vec![&proxy_wallet, &limit, &offset];
+    if let Some(min) = &min_shares {
+        params.push(min);
+    }
+    let rows = stmt.query_map(params.as_slice(), |r| {
+        Ok((
+            r.get::<_, String>(0)?,
+            r.get::<_, Option<String>>(1)?,
+            r.get::<_, Option<String>>(2)?,
+            r.get::<_, f64>(3)?,
+            r.get::<_, f64>(4)?,
+            r.get::<_, f64>(5)?,
+            r.get::<_, i64>(6)?,
+            r.get::<_, Option<String>>(7)?,
+            r.get::<_, Option<String>>(8)?,
+            r.get::<_, Option<i64>>(9)?,
+        ))
+    })?;
     let positions: Vec<WalletPositionRow> = rows
         .map(|row| {
             let (
@@ -1300,6 +1606,7 @@ fn wallet_positions_filtered(
                 trade_count,
                 event_slug,
                 slug,
+                outcome_index,
             ) = row?;
             let pm_url = polymarket_url(event_slug.as_deref(), slug.as_deref());
             Ok(WalletPositionRow {
@@ -1311,47 +1618,132 @@ fn wallet_positions_filtered(
                 total_bet_display: format!("${total_bet:.2}"),
                 trade_count: trade_count as u32,
                 polymarket_url: pm_url,
+                unrealized_pnl_display: "—".to_string(),
+                unrealized_pnl_color: "text-gray-500".to_string(),
+                net_shares,
+                avg_entry_price,
+                outcome_index,
             })
         })
         .collect::<Result<Vec<_>>>()?;
     Ok(positions)
 }
 
-/// Paginated active positions (net_shares > 0.5) for a wallet.
+/// Per-(wallet, condition_id, outcome) position aggregates over all of trades_raw.
+/// Same shape as the `position_base` CTE in `wallet_positions_summary`, without the wallet filter.
+const ALL_POSITIONS_CTE: &str = "
+    WITH position_base AS (
+      SELECT
+        tr.proxy_wallet,
+        tr.condition_id,
+        m.title,
+        tr.outcome,
+        SUM(CASE WHEN tr.side = 'BUY' THEN tr.size ELSE 0 END)
+          - SUM(CASE WHEN tr.side = 'SELL' THEN tr.size ELSE 0 END) AS net_shares,
+        CASE WHEN SUM(CASE WHEN tr.side = 'BUY' THEN tr.size ELSE 0 END) > 0
+          THEN SUM(CASE WHEN tr.side = 'BUY' THEN tr.size * tr.price ELSE 0 END)
+               / SUM(CASE WHEN tr.side = 'BUY' THEN tr.size ELSE 0 END)
+          ELSE 0 END AS avg_entry_price,
+        SUM(CASE WHEN tr.side = 'BUY' THEN tr.size * tr.price ELSE 0 END) AS total_bet,
+        COUNT(*) AS trade_count,
+        m.event_slug,
+        m.slug,
+        MAX(tr.timestamp) AS last_trade_at
+      FROM trades_raw tr
+      LEFT JOIN markets m ON m.condition_id = tr.condition_id
+      GROUP BY tr.proxy_wallet, tr.condition_id, tr.outcome
+    )
+";
+
+/// Paginated open positions (net_shares > min_shares) across all wallets. Returns (positions, total_count).
+pub fn all_active_positions(
+    conn: &Connection,
+    offset: u32,
+    limit: u32,
+    sort: PositionSort,
+    min_shares: f64,
+) -> Result<(Vec<ActivePositionRow>, usize)> {
+    timed_db_op("web.all_active_positions", || {
+        let total: i64 = conn.query_row(
+            &format!(
+                "{ALL_POSITIONS_CTE} SELECT COUNT(*) FROM position_base WHERE net_shares > ?1"
+            ),
+            [min_shares],
+            |r| r.get(0),
+        )?;
+        let order_by = sort.order_by();
+        let sql = format!(
+            "{ALL_POSITIONS_CTE}
+            SELECT proxy_wallet, condition_id, title, outcome, net_shares, avg_entry_price,
+                   total_bet, trade_count, event_slug, slug, last_trade_at
+            FROM position_base
+            WHERE net_shares > ?3
+            ORDER BY {order_by}, proxy_wallet, condition_id
+            LIMIT ?1 OFFSET ?2
+            "
+        );
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt.query_map(
+            rusqlite::params![i64::from(limit), i64::from(offset), min_shares],
+            |r| {
+                let event_slug: Option<String> = r.get(8)?;
+                let slug: Option<String> = r.get(9)?;
+                Ok(ActivePositionRow {
+                    proxy_wallet: r.get(0)?,
+                    condition_id: r.get(1)?,
+                    market_title: r.get(2)?,
+                    outcome: r.get(3)?,
+                    net_shares: r.get(4)?,
+                    avg_entry_price: r.get(5)?,
+                    total_bet: r.get(6)?,
+                    trade_count: r.get::<_, i64>(7)? as u32,
+                    last_trade_at: r.get(10)?,
+                    polymarket_url: polymarket_url(event_slug.as_deref(), slug.as_deref()),
+                })
+            },
+        )?;
+        let positions = rows.collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok((positions, total as usize))
+    })
+}
+
+/// Paginated active positions (net_shares > min_shares) for a wallet.
 pub fn wallet_active_positions_page(
     conn: &Connection,
     proxy_wallet: &str,
     offset: u32,
     limit: u32,
+    min_shares: f64,
 ) -> Result<(Vec<WalletPositionRow>, usize)> {
     timed_db_op("web.wallet_active_positions_page", || {
-        let total = wallet_active_positions_count(conn, proxy_wallet)?;
+        let total = wallet_active_positions_count(conn, proxy_wallet, min_shares)?;
         let positions = wallet_positions_filtered(
             conn,
             proxy_wallet,
             offset,
             limit,
-            "HAVING net_shares > 0.5",
+            PositionBucket::Active(min_shares),
         )?;
         Ok((positions, total))
     })
 }
 
-/// Paginated closed positions (net_shares <= 0.5) for a wallet.
+/// Paginated closed positions (net_shares <= min_shares) for a wallet.
 pub fn wallet_closed_positions_page(
     conn: &Connection,
     proxy_wallet: &str,
     offset: u32,
     limit: u32,
+    min_shares: f64,
 ) -> Result<(Vec<WalletPositionRow>, usize)> {
     timed_db_op("web.wallet_closed_positions_page", || {
-        let total = wallet_closed_positions_count(conn, proxy_wallet)?;
+        let total = wallet_closed_positions_count(conn, proxy_wallet, min_shares)?;
         let positions = wallet_positions_filtered(
             conn,
             proxy_wallet,
             offset,
             limit,
-            "HAVING net_shares <= 0.5",
+            PositionBucket::Closed(min_shares),
         )?;
         Ok((positions, total))
     })
@@ -1367,7 +1759,8 @@ pub fn wallet_positions_page(
 ) -> Result<(Vec<WalletPositionRow>, usize)> {
     timed_db_op("web.wallet_positions_page", || {
         let total = wallet_positions_count(conn, proxy_wallet)?;
-        let positions = wallet_positions_filtered(conn, proxy_wallet, offset, limit, "")?;
+        let positions =
+            wallet_positions_filtered(conn, proxy_wallet, offset, limit, PositionBucket::All)?;
         Ok((positions, total))
     })
 }
@@ -1382,6 +1775,151 @@ pub fn wallet_activity_count(conn: &Connection, proxy_wallet: &str) -> Result<us
     Ok(n as usize)
 }
 
+/// Activity counts per `activity_type` for a wallet, most frequent first.
+pub fn wallet_activity_type_counts(
+    conn: &Connection,
+    proxy_wallet: &str,
+) -> Result<Vec<ActivityTypeCount>> {
+    timed_db_op("web.wallet_activity_type_counts", || {
+        let mut stmt = conn.prepare(
+            "
+            SELECT activity_type, COUNT(*) AS n
+            FROM activity_raw
+            WHERE proxy_wallet = ?1
+            GROUP BY activity_type
+            ORDER BY n DESC, activity_type ASC
+            ",
+        )?;
+        let rows = stmt
+            .query_map([proxy_wallet], |r| {
+                Ok(ActivityTypeCount {
+                    activity_type: r.get(0)?,
+                    count: r.get::<_, i64>(1)? as usize,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+}
+
+/// Copy-fidelity events per `outcome` for a wallet, most frequent first.
+pub fn copy_fidelity_breakdown(
+    conn: &Connection,
+    proxy_wallet: &str,
+) -> Result<Vec<CopyFidelityOutcomeCount>> {
+    timed_db_op("web.copy_fidelity_breakdown", || {
+        let mut stmt = conn.prepare(
+            "
+            SELECT outcome, COUNT(*) AS n
+            FROM copy_fidelity_events
+            WHERE proxy_wallet = ?1
+            GROUP BY outcome
+            ORDER BY n DESC, outcome ASC
+            ",
+        )?;
+        let counts = stmt
+            .query_map([proxy_wallet], |r| {
+                Ok((r.get::<_, String>(0)?, r.get::<_, i64>(1)? as usize))
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        let total: usize = counts.iter().map(|(_, n)| n).sum();
+        Ok(counts
+            .into_iter()
+            .map(|(outcome, count)| CopyFidelityOutcomeCount {
+                outcome,
+                count,
+                share_display: format!("{:.0}%", 100.0 * count as f64 / total as f64),
+            })
+            .collect())
+    })
+}
+
+/// Break the wallet's latest 30d WScore into weighted sub-score contributions.
+/// Mirrors the evaluator's `compute_wscore`: contributions are normalized by the weight total,
+/// and whatever the win-rate, trust and obscurity multipliers changed shows up as `adjustment`.
+pub fn wscore_explanation(
+    conn: &Connection,
+    proxy_wallet: &str,
+    weights: WScoreWeights,
+) -> Result<Option<WScoreExplanation>> {
+    timed_db_op("web.wscore_explanation", || {
+        let row = conn
+            .query_row(
+                "
+                SELECT score_date, wscore,
+                       COALESCE(edge_score, 0), COALESCE(consistency_score, 0),
+                       COALESCE(market_skill_score, 0), COALESCE(timing_skill_score, 0),
+                       COALESCE(behavior_quality_score, 0), COALESCE(recency_edge_score, 0)
+                FROM wallet_scores_daily
+                WHERE proxy_wallet = ?1 AND window_days = 30
+                ORDER BY score_date DESC
+                LIMIT 1
+                ",
+                [proxy_wallet],
+                |r| {
+                    Ok((
+                        r.get::<_, String>(0)?,
+                        r.get::<_, f64>(1)?,
+                        [
+                            r.get::<_, f64>(2)?,
+                            r.get::<_, f64>(3)?,
+                            r.get::<_, f64>(4)?,
+                            r.get::<_, f64>(5)?,
+                            r.get::<_, f64>(6)?,
+                            r.get::<_, f64>(7)?,
+                        ],
+                    ))
+                },
+            )
+            .optional()?;
+        let Some((score_date, wscore, sub_scores)) = row else {
+            return Ok(None);
+        };
+
+        let named_weights = [
+            ("Edge", weights.edge),
+            ("Consistency", weights.consistency),
+            ("Market Skill", weights.market_skill),
+            ("Timing", weights.timing_skill),
+            ("Behavior", weights.behavior_quality),
+            ("Recent Edge", weights.recency_edge),
+        ];
+        let total_weight: f64 = named_weights.iter().map(|(_, w)| w).sum();
+        let components: Vec<WScoreContribution> = named_weights
+            .iter()
+            .zip(sub_scores)
+            .map(|(&(component, weight), sub_score)| {
+                let contribution = if total_weight > 0.0 {
+                    weight * sub_score / total_weight
+                } else {
+                    0.0
+                };
+                WScoreContribution {
+                    component: component.to_string(),
+                    sub_score,
+                    weight,
+                    contribution,
+                    sub_score_display: format!("{sub_score:.2}"),
+                    weight_display: format!("{weight:.2}"),
+                    contribution_display: format!("{contribution:.2}"),
+                }
+            })
+            .collect();
+        let weighted_sum: f64 = components.iter().map(|c| c.contribution).sum();
+        let adjustment = wscore - weighted_sum;
+        Ok(Some(WScoreExplanation {
+            score_date,
+            wscore,
+            weighted_sum,
+            adjustment,
+            components,
+            wscore_display: format!("{wscore:.2}"),
+            weighted_sum_display: format!("{weighted_sum:.2}"),
+            adjustment_display: format!("{adjustment:+.2}"),
+        }))
+    })
+}
+
 /// Paginated activity feed for a wallet (from activity_raw). Returns (activities, total_count).
 pub fn wallet_activity_page(
     conn: &Connection,
@@ -1391,7 +1929,7 @@ pub fn wallet_activity_page(
 ) -> Result<(Vec<WalletActivityRow>, usize)> {
     timed_db_op("web.wallet_activity_page", || {
         let total = wallet_activity_count(conn, proxy_wallet)?;
-        let limit = i64::from(limit.min(100));
+        let limit = i64::from(limit);
         let offset = i64::from(offset);
 
         let mut stmt = conn.prepare(
@@ -1463,33 +2001,127 @@ pub fn wallet_activity_page(
     })
 }
 
-/// Latest 30-day features snapshot for a wallet.
-fn wallet_features_latest(
+/// Display name stored by the evaluator's wallet_profiles job: `None` when the wallet has not
+/// been resolved yet, `Some(None)` when it has no Polymarket profile name.
+pub fn wallet_profile_name(
     conn: &Connection,
     proxy_wallet: &str,
-) -> Result<Option<WalletFeaturesSnapshot>> {
-    let row = conn
-        .query_row(
-            "
-            SELECT feature_date, COALESCE(total_pnl, 0), COALESCE(win_count, 0), COALESCE(loss_count, 0),
-                   COALESCE(max_drawdown_pct, 0), COALESCE(sharpe_ratio, 0),
-                   COALESCE(trades_per_day, 0), COALESCE(trade_count, 0), COALESCE(unique_markets, 0),
-                   COALESCE(profitable_markets, 0), COALESCE(concentration_ratio, 0),
-                   COALESCE(avg_trade_size_usdc, 0), COALESCE(size_cv, 0),
-                   COALESCE(buy_sell_balance, 0), COALESCE(burstiness_top_1h_ratio, 0),
-                   COALESCE(top_domain, ''), COALESCE(top_domain_ratio, 0),
-                   COALESCE(mid_fill_ratio, 0), COALESCE(extreme_price_ratio, 0),
-                   COALESCE(active_positions, 0), COALESCE(avg_position_size, 0),
-                   COALESCE(cashflow_pnl, 0), COALESCE(fifo_realized_pnl, 0),
-                   COALESCE(unrealized_pnl, 0), COALESCE(open_positions_count, 0)
-            FROM wallet_features_daily
-            WHERE proxy_wallet = ?1 AND window_days = 30
-            ORDER BY feature_date DESC
-            LIMIT 1
-            ",
-            [proxy_wallet],
-            |r| {
-                Ok((
+) -> Result<Option<Option<String>>> {
+    timed_db_op("web.wallet_profile_name", || {
+        Ok(conn
+            .query_row(
+                "SELECT display_name FROM wallet_profiles WHERE proxy_wallet = ?1",
+                [proxy_wallet],
+                |r| r.get(0),
+            )
+            .optional()?)
+    })
+}
+
+/// Operator notes for a wallet, newest first.
+pub fn wallet_notes(conn: &Connection, proxy_wallet: &str) -> Result<Vec<WalletNoteRow>> {
+    timed_db_op("web.wallet_notes", || {
+        let mut stmt = conn.prepare(
+            "SELECT note, created_at FROM wallet_notes
+             WHERE proxy_wallet = ?1
+             ORDER BY created_at DESC, id DESC",
+        )?;
+        let rows = stmt
+            .query_map([proxy_wallet], |r| {
+                Ok(WalletNoteRow {
+                    note: r.get(0)?,
+                    created_at: r.get(1)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        Ok(rows)
+    })
+}
+
+/// Append an operator note to a known wallet. Returns the stored `proxy_wallet`, or None (and
+/// writes nothing) when the wallet is unknown. Needs a writable connection (see `with_db_write`
+/// in main).
+pub fn insert_wallet_note(
+    conn: &Connection,
+    proxy_wallet: &str,
+    note: &str,
+) -> Result<Option<String>> {
+    timed_db_op("web.insert_wallet_note", || {
+        let stored = conn
+            .query_row(
+                "INSERT INTO wallet_notes (proxy_wallet, note)
+                 SELECT proxy_wallet, ?2 FROM wallets WHERE proxy_wallet = ?1
+                 RETURNING proxy_wallet",
+                [proxy_wallet, note],
+                |r| r.get(0),
+            )
+            .optional()?;
+        Ok(stored)
+    })
+}
+
+/// Last `limit` daily feature snapshots for a wallet, oldest first (for charting).
+/// Wallets with fewer snapshots return what exists; unknown wallets return an empty list.
+pub fn wallet_features_history(
+    conn: &Connection,
+    proxy_wallet: &str,
+    window_days: u32,
+    limit: u32,
+) -> Result<Vec<WalletFeaturePoint>> {
+    timed_db_op("web.wallet_features_history", || {
+        let mut stmt = conn.prepare(
+            "
+            SELECT feature_date, COALESCE(total_pnl, 0), COALESCE(sharpe_ratio, 0),
+                   COALESCE(max_drawdown_pct, 0), COALESCE(trades_per_day, 0)
+            FROM wallet_features_daily
+            WHERE proxy_wallet = ?1 AND window_days = ?2
+            ORDER BY feature_date DESC
+            LIMIT ?3
+            ",
+        )?;
+        let mut points = stmt
+            .query_map(rusqlite::params![proxy_wallet, window_days, limit], |r| {
+                Ok(WalletFeaturePoint {
+                    feature_date: r.get(0)?,
+                    total_pnl: r.get(1)?,
+                    sharpe_ratio: r.get(2)?,
+                    max_drawdown_pct: r.get(3)?,
+                    trades_per_day: r.get(4)?,
+                })
+            })?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+        points.reverse();
+        Ok(points)
+    })
+}
+
+/// Latest 30-day features snapshot for a wallet.
+fn wallet_features_latest(
+    conn: &Connection,
+    proxy_wallet: &str,
+) -> Result<Option<WalletFeaturesSnapshot>> {
+    let row = conn
+        .query_row(
+            "
+            SELECT feature_date, COALESCE(total_pnl, 0), COALESCE(win_count, 0), COALESCE(loss_count, 0),
+                   COALESCE(max_drawdown_pct, 0), COALESCE(sharpe_ratio, 0),
+                   COALESCE(trades_per_day, 0), COALESCE(trade_count, 0), COALESCE(unique_markets, 0),
+                   COALESCE(profitable_markets, 0), COALESCE(concentration_ratio, 0),
+                   COALESCE(avg_trade_size_usdc, 0), COALESCE(size_cv, 0),
+                   COALESCE(buy_sell_balance, 0), COALESCE(burstiness_top_1h_ratio, 0),
+                   COALESCE(top_domain, ''), COALESCE(top_domain_ratio, 0),
+                   COALESCE(mid_fill_ratio, 0), COALESCE(extreme_price_ratio, 0),
+                   COALESCE(active_positions, 0), COALESCE(avg_position_size, 0),
+                   COALESCE(cashflow_pnl, 0), COALESCE(fifo_realized_pnl, 0),
+                   COALESCE(unrealized_pnl, 0), COALESCE(open_positions_count, 0)
+            FROM wallet_features_daily
+            WHERE proxy_wallet = ?1 AND window_days = 30
+            ORDER BY feature_date DESC
+            LIMIT 1
+            ",
+            [proxy_wallet],
+            |r| {
+                Ok((
                     r.get::<_, String>(0)?,
                     r.get::<_, f64>(1)?,
                     r.get::<_, i64>(2)?,
@@ -1629,7 +2261,7 @@ fn wallet_score_latest(
             SELECT score_date, wscore,
                    COALESCE(edge_score, 0), COALESCE(consistency_score, 0),
                    COALESCE(market_skill_score, 0), COALESCE(timing_skill_score, 0),
-                   COALESCE(behavior_quality_score, 0)
+                   COALESCE(behavior_quality_score, 0), COALESCE(recency_edge_score, 0)
             FROM wallet_scores_daily
             WHERE proxy_wallet = ?1 AND window_days = 30
             ORDER BY score_date DESC
@@ -1645,12 +2277,15 @@ fn wallet_score_latest(
                     r.get::<_, f64>(4)?,
                     r.get::<_, f64>(5)?,
                     r.get::<_, f64>(6)?,
+                    r.get::<_, f64>(7)?,
                 ))
             },
         )
         .optional()?;
 
-    let Some((score_date, wscore, edge, consistency, market_skill, timing, behavior)) = row else {
+    let Some((score_date, wscore, edge, consistency, market_skill, timing, behavior, recency_edge)) =
+        row
+    else {
         return Ok(None);
     };
 
@@ -1669,6 +2304,8 @@ fn wallet_score_latest(
         timing_skill_pct: format!("{:.0}", timing * 100.0),
         behavior_quality_display: format!("{behavior:.2}"),
         behavior_quality_pct: format!("{:.0}", behavior * 100.0),
+        recency_edge_display: format!("{recency_edge:.2}"),
+        recency_edge_pct: format!("{:.0}", recency_edge * 100.0),
     }))
 }
 
@@ -1786,7 +2423,11 @@ fn wallet_rules_events_timeline(
 }
 
 #[allow(clippy::too_many_lines)]
-pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<WalletJourney>> {
+pub fn wallet_journey(
+    conn: &Connection,
+    proxy_wallet: &str,
+    active_position_min_shares: f64,
+) -> Result<Option<WalletJourney>> {
     timed_db_op("web.wallet_journey", || {
         let discovered_at: Option<String> = conn
             .query_row(
@@ -1811,7 +2452,7 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
                 "
                 SELECT persona, confidence, classified_at
                 FROM wallet_personas
-                WHERE proxy_wallet = ?1
+                WHERE proxy_wallet = ?1 AND superseded_at IS NULL
                 ORDER BY classified_at DESC
                 LIMIT 1
                 ",
@@ -1902,6 +2543,8 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
             "N/A".to_string()
         };
 
+        let copy_fidelity_breakdown = copy_fidelity_breakdown(conn, proxy_wallet)?;
+
         let avg_slip: Option<f64> = conn.query_row(
             "SELECT AVG(slippage_cents) FROM follower_slippage WHERE proxy_wallet = ?1",
             [proxy_wallet],
@@ -1974,13 +2617,15 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
         events.sort_by(|a, b| a.at.cmp(&b.at));
 
         // Consolidate position queries: fetch both active and closed in single query
-        let positions_summary = wallet_positions_summary(conn, proxy_wallet, 20)?;
+        let positions_summary =
+            wallet_positions_summary(conn, proxy_wallet, 20, active_position_min_shares)?;
         let active_positions = positions_summary.active_positions;
         let total_active_positions_count = positions_summary.active_count;
         let closed_positions = positions_summary.closed_positions;
         let total_closed_positions_count = positions_summary.closed_count;
 
         let (activities, total_activities_count) = wallet_activity_page(conn, proxy_wallet, 0, 20)?;
+        let activity_type_counts = wallet_activity_type_counts(conn, proxy_wallet)?;
 
         let total_trades_count: usize = conn.query_row(
             "SELECT COUNT(*) FROM trades_raw WHERE proxy_wallet = ?1",
@@ -2050,6 +2695,7 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
             proxy_wallet: proxy_wallet.to_string(),
             wallet_short: wallet_short.clone(),
             wallet_display_label: wallet_short,
+            display_name_pending: false,
             discovered_at,
             last_trades_ingestion_at,
             persona,
@@ -2059,6 +2705,7 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
             paper_pnl_display,
             exposure_display,
             copy_fidelity_display,
+            copy_fidelity_breakdown,
             follower_slippage_display,
             score,
             features,
@@ -2071,6 +2718,7 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
             total_closed_positions_count,
             activities,
             total_activities_count,
+            activity_type_counts,
             trades,
             total_trades_count,
         }))
@@ -2078,6 +2726,33 @@ pub fn wallet_journey(conn: &Connection, proxy_wallet: &str) -> Result<Option<Wa
 }
 
 /// Paginated trades for a wallet (for load-more on scorecard). Returns (trades, total_count).
+/// Wallet trades newest first; binds `?1` wallet, `?2` limit (-1 = no limit), `?3` offset.
+const WALLET_TRADES_SQL: &str = "
+    SELECT tr.id, tr.condition_id, m.title, tr.side, tr.size, tr.price, tr.timestamp, tr.outcome, tr.transaction_hash
+    FROM trades_raw tr
+    LEFT JOIN markets m ON m.condition_id = tr.condition_id
+    WHERE tr.proxy_wallet = ?1
+    ORDER BY tr.timestamp DESC
+    LIMIT ?2 OFFSET ?3
+    ";
+
+fn wallet_trade_row(r: &rusqlite::Row<'_>) -> rusqlite::Result<WalletTradeRow> {
+    let size: f64 = r.get(4)?;
+    let price: f64 = r.get(5)?;
+    let tx_hash: Option<String> = r.get(8)?;
+    Ok(WalletTradeRow {
+        id: r.get(0)?,
+        condition_id: r.get(1)?,
+        market_title: r.get(2)?,
+        side: r.get(3)?,
+        size_display: format!("{size:.2}"),
+        price_display: format!("{price:.2}"),
+        timestamp_display: format_unix_timestamp(r.get(6)?),
+        outcome: r.get(7)?,
+        polygonscan_url: polygonscan_url(tx_hash.as_deref()),
+    })
+}
+
 pub fn wallet_trades_page(
     conn: &Connection,
     proxy_wallet: &str,
@@ -2091,64 +2766,42 @@ pub fn wallet_trades_page(
             |r| r.get(0),
         )?;
 
-        let limit = i64::from(limit.min(100));
+        let limit = i64::from(limit);
         let offset = i64::from(offset);
 
-        let mut stmt = conn.prepare(
-            "
-            SELECT tr.id, tr.condition_id, m.title, tr.side, tr.size, tr.price, tr.timestamp, tr.outcome, tr.transaction_hash
-            FROM trades_raw tr
-            LEFT JOIN markets m ON m.condition_id = tr.condition_id
-            WHERE tr.proxy_wallet = ?1
-            ORDER BY tr.timestamp DESC
-            LIMIT ?2 OFFSET ?3
-            ",
-        )?;
-        let rows = stmt.query_map(rusqlite::params![proxy_wallet, limit, offset], |r| {
-            Ok((
-                r.get::<_, i64>(0)?,
-                r.get::<_, String>(1)?,
-                r.get::<_, Option<String>>(2)?,
-                r.get::<_, String>(3)?,
-                r.get::<_, f64>(4)?,
-                r.get::<_, f64>(5)?,
-                r.get::<_, i64>(6)?,
-                r.get::<_, Option<String>>(7)?,
-                r.get::<_, Option<String>>(8)?,
-            ))
-        })?;
-        let trades: Vec<WalletTradeRow> = rows
-            .map(|row| {
-                let (
-                    id,
-                    condition_id,
-                    market_title,
-                    side,
-                    size,
-                    price,
-                    timestamp_sec,
-                    outcome,
-                    tx_hash,
-                ) = row?;
-                let timestamp_display = format_unix_timestamp(timestamp_sec);
-                let ps_url = polygonscan_url(tx_hash.as_deref());
-                Ok(WalletTradeRow {
-                    id,
-                    condition_id,
-                    market_title,
-                    side,
-                    size_display: format!("{size:.2}"),
-                    price_display: format!("{price:.2}"),
-                    timestamp_display,
-                    outcome,
-                    polygonscan_url: ps_url,
-                })
-            })
-            .collect::<Result<Vec<_>>>()?;
+        let mut stmt = conn.prepare(WALLET_TRADES_SQL)?;
+        let trades = stmt
+            .query_map(
+                rusqlite::params![proxy_wallet, limit, offset],
+                wallet_trade_row,
+            )?
+            .collect::<std::result::Result<Vec<_>, _>>()?;
         Ok((trades, total))
     })
 }
 
+/// Visit a wallet's trades newest first without buffering them (NDJSON export).
+/// `limit` None means every trade after `offset`; stops early when `f` returns `Ok(false)`.
+pub fn for_each_wallet_trade(
+    conn: &Connection,
+    proxy_wallet: &str,
+    offset: u32,
+    limit: Option<u32>,
+    mut f: impl FnMut(&WalletTradeRow) -> Result<bool>,
+) -> Result<()> {
+    timed_db_op("web.wallet_trades_stream", || {
+        let limit = limit.map_or(-1, i64::from);
+        let mut stmt = conn.prepare(WALLET_TRADES_SQL)?;
+        let mut rows = stmt.query(rusqlite::params![proxy_wallet, limit, i64::from(offset)])?;
+        while let Some(row) = rows.next()? {
+            if !f(&wallet_trade_row(row)?)? {
+                break;
+            }
+        }
+        Ok(())
+    })
+}
+
 #[allow(dead_code)] // Retained for potential future paper dashboard
 pub fn paper_summary(
     conn: &Connection,
@@ -2357,7 +3010,8 @@ pub fn top_rankings(conn: &Connection, window_days: i64, limit: usize) -> Result
                     COALESCE(ws.recommended_follow_mode, 'mirror'),
                     (SELECT COUNT(*) FROM trades_raw t WHERE t.proxy_wallet = ws.proxy_wallet),
                     COALESCE((SELECT SUM(pnl) FROM paper_trades pt
-                              WHERE pt.proxy_wallet = ws.proxy_wallet AND pt.status != 'open'), 0)
+                              WHERE pt.proxy_wallet = ws.proxy_wallet AND pt.status != 'open'), 0),
+                    COALESCE(ws.paper_roi_pct, 0)
             FROM wallet_scores_daily ws
             WHERE ws.score_date = (SELECT MAX(score_date) FROM wallet_scores_daily) AND ws.window_days = ?1
             ORDER BY ws.wscore DESC
@@ -2370,6 +3024,7 @@ pub fn top_rankings(conn: &Connection, window_days: i64, limit: usize) -> Result
                 let edge_score: f64 = row.get(2)?;
                 let consistency_score: f64 = row.get(3)?;
                 let paper_pnl: f64 = row.get(6)?;
+                let roi: f64 = row.get(7)?;
 
                 let pnl_color = if paper_pnl >= 0.0 {
                     "text-green-400"
@@ -2397,6 +3052,13 @@ pub fn top_rankings(conn: &Connection, window_days: i64, limit: usize) -> Result
                     paper_pnl,
                     pnl_display: format!("{sign}${paper_pnl:.2}"),
                     pnl_color,
+                    roi_display: format!("{roi:+.1}%"),
+                    roi_color: if roi >= 0.0 {
+                        "text-green-400"
+                    } else {
+                        "text-red-400"
+                    }
+                    .to_string(),
                 })
             })?
             .collect::<std::result::Result<Vec<_>, _>>()?;
@@ -2477,6 +3139,55 @@ mod tests {
         assert_eq!(counts.markets_fetched, 1);
     }
 
+    #[test]
+    fn test_top_events_orders_equal_scores_deterministically() {
+        let conn = test_db();
+        conn.execute_batch(
+            "INSERT INTO markets (condition_id, title, event_slug) VALUES
+                ('0xm1', 'Zeta single', 'zeta'),
+                ('0xm2', 'Alpha single', 'alpha'),
+                ('0xm3', 'Beta A', 'beta'),
+                ('0xm4', 'Beta B', 'beta');
+             INSERT INTO market_scores (condition_id, score_date, mscore, rank) VALUES
+                ('0xm1', '2026-01-01', 0.7, 1),
+                ('0xm2', '2026-01-01', 0.7, 2),
+                ('0xm3', '2026-01-01', 0.7, 3),
+                ('0xm4', '2026-01-01', 0.5, 3);",
+        )
+        .unwrap();
+
+        let keys = |rows: Vec<EventRow>| -> Vec<String> {
+            rows.into_iter().map(|r| r.event_key).collect()
+        };
+        let first = keys(top_events(&conn, 10).unwrap());
+        // Equal best MScore: more markets first, then event key ascending.
+        assert_eq!(first, vec!["beta", "alpha", "zeta"]);
+        for _ in 0..3 {
+            assert_eq!(keys(top_events(&conn, 10).unwrap()), first);
+        }
+    }
+
+    #[test]
+    fn test_excluded_wallets_latest_breaks_timestamp_ties() {
+        let conn = test_db();
+        conn.execute_batch(
+            "INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
+             VALUES ('0xw1', 'STAGE1_TOO_YOUNG', 5.0, 30.0, '2026-01-01 00:00:00.000');
+             INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
+             VALUES ('0xw1', 'NOISE_TRADER', 60.0, 50.0, '2026-01-01 00:00:00.000');
+             INSERT INTO wallet_exclusions (proxy_wallet, reason, metric_value, threshold, excluded_at)
+             VALUES ('0xw2', 'NOISE_TRADER', 70.0, 50.0, '2025-12-31 00:00:00.000');",
+        )
+        .unwrap();
+
+        let rows = excluded_wallets_latest(&conn, 10, 0).unwrap();
+        assert_eq!(rows.len() as i64, excluded_wallets_count(&conn).unwrap());
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].proxy_wallet, "0xw1");
+        assert_eq!(rows[0].reason, "NOISE_TRADER");
+        assert_eq!(rows[1].proxy_wallet, "0xw2");
+    }
+
     #[test]
     fn test_persona_funnel_counts_with_data() {
         let conn = test_db();
@@ -2562,7 +3273,7 @@ mod tests {
         )
         .unwrap();
 
-        let counts = persona_funnel_counts(&conn).unwrap();
+        let counts = persona_funnel_counts(&conn, FollowWorthyThresholds::default()).unwrap();
         assert_eq!(counts.wallets_discovered, 3);
         assert_eq!(counts.stage1_passed, 2);
         assert_eq!(counts.stage2_classified, 2);
@@ -2611,12 +3322,48 @@ mod tests {
             rusqlite::params![ts_5d],
         )
         .unwrap();
-        let (suitable, evaluated) = suitable_personas_counts(&conn).unwrap();
+        let (suitable, evaluated) = suitable_personas_counts(&conn, &[], 45).unwrap();
         assert_eq!(suitable, 2, "both wallets have persona");
         assert_eq!(
             evaluated, 1,
             "only wallet with oldest trade >= 45 days ago counts as evaluated"
         );
+
+        // The age filter follows config: a 60-day threshold excludes the 50-day-old wallet too.
+        let (_, evaluated_60) = suitable_personas_counts(&conn, &[], 60).unwrap();
+        assert_eq!(evaluated_60, 0);
+    }
+
+    #[test]
+    fn test_suitable_personas_counts_skips_hidden_personas_but_breakdown_keeps_them() {
+        let conn = test_db();
+        conn.execute(
+            "INSERT INTO wallet_personas (proxy_wallet, persona, confidence) VALUES ('0xw1', 'INFORMED_SPECIALIST', 0.9)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO wallet_personas (proxy_wallet, persona, confidence) VALUES ('0xw2', 'FADE_MATERIAL', 0.7)",
+            [],
+        )
+        .unwrap();
+        let hidden = vec!["FADE_MATERIAL".to_string()];
+
+        let (suitable, _) = suitable_personas_counts(&conn, &hidden, 45).unwrap();
+        assert_eq!(suitable, 1, "hidden persona must not count as suitable");
+        let counts =
+            unified_funnel_counts(&conn, &hidden, 45, FollowWorthyThresholds::default()).unwrap();
+        assert_eq!(counts.suitable_personas, 1);
+        let (suitable_all, _) = suitable_personas_counts(&conn, &[], 45).unwrap();
+        assert_eq!(suitable_all, 2);
+
+        let breakdown = persona_breakdown_counts(&conn).unwrap();
+        assert!(
+            breakdown
+                .iter()
+                .any(|r| r.persona == "FADE_MATERIAL" && r.count == 1),
+            "hidden persona should still appear in the per-persona breakdown"
+        );
     }
 
     #[test]
@@ -2768,12 +3515,44 @@ mod tests {
             [],
         )
         .unwrap();
-        let wallets = recent_wallets(&conn, 10).unwrap();
+        let wallets = recent_wallets(&conn, 10, None).unwrap();
         assert_eq!(wallets.len(), 1);
         assert_eq!(wallets[0].trade_count, 1);
         assert_eq!(wallets[0].wallet_short, "0xabcd..5678");
     }
 
+    #[test]
+    fn test_recent_wallets_and_paper_traded_since_filter() {
+        let conn = test_db();
+        for (wallet, at) in [
+            ("0xold", "2026-01-01 00:00:00"),
+            ("0xnew", "2026-03-01 00:00:00"),
+        ] {
+            conn.execute(
+                "INSERT INTO wallets (proxy_wallet, discovered_from, discovered_at) VALUES (?1, 'HOLDER', ?2)",
+                [wallet, at],
+            )
+            .unwrap();
+            conn.execute(
+                "INSERT INTO paper_trades (proxy_wallet, strategy, condition_id, side, size_usdc, entry_price, status, created_at)
+                 VALUES (?1, 'mirror', '0xm', 'BUY', 10.0, 0.5, 'open', ?2)",
+                [wallet, at],
+            )
+            .unwrap();
+        }
+
+        let all = recent_wallets(&conn, 10, None).unwrap();
+        assert_eq!(all.len(), 2);
+        let recent = recent_wallets(&conn, 10, Some("2026-02-01 00:00:00")).unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].proxy_wallet, "0xnew");
+
+        assert_eq!(paper_traded_wallets_list(&conn, 10, None).unwrap().len(), 2);
+        let recent = paper_traded_wallets_list(&conn, 10, Some("2026-02-01 00:00:00")).unwrap();
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].proxy_wallet, "0xnew");
+    }
+
     #[test]
     fn test_tracking_health_empty() {
         let conn = test_db();
@@ -2853,12 +3632,13 @@ mod tests {
         .unwrap();
         // Net shares = 0.0 (closed)
 
-        let (active, count) = wallet_active_positions_page(&conn, "0xw", 0, 10).unwrap();
+        let (active, count) = wallet_active_positions_page(&conn, "0xw", 0, 10, 0.5).unwrap();
         assert_eq!(count, 1); // Only 0xm is active
         assert_eq!(active.len(), 1);
         assert_eq!(active[0].condition_id, "0xm");
 
-        let (closed, count_closed) = wallet_closed_positions_page(&conn, "0xw", 0, 10).unwrap();
+        let (closed, count_closed) =
+            wallet_closed_positions_page(&conn, "0xw", 0, 10, 0.5).unwrap();
         assert_eq!(count_closed, 1); // Only 0xm2 is closed
         assert_eq!(closed.len(), 1);
         assert_eq!(closed[0].condition_id, "0xm2");
@@ -2886,6 +3666,179 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_wallet_activity_type_counts_groups_by_type() {
+        let conn = test_db();
+        conn.execute_batch(
+            "INSERT INTO activity_raw (proxy_wallet, activity_type, timestamp) VALUES ('0xw', 'REDEEM', 1);
+             INSERT INTO activity_raw (proxy_wallet, activity_type, timestamp) VALUES ('0xw', 'TRADE', 2);
+             INSERT INTO activity_raw (proxy_wallet, activity_type, timestamp) VALUES ('0xw', 'REDEEM', 3);
+             INSERT INTO activity_raw (proxy_wallet, activity_type, timestamp) VALUES ('0xother', 'SPLIT', 4);",
+        )
+        .unwrap();
+
+        let counts = wallet_activity_type_counts(&conn, "0xw").unwrap();
+        let pairs: Vec<(&str, usize)> = counts
+            .iter()
+            .map(|c| (c.activity_type.as_str(), c.count))
+            .collect();
+        assert_eq!(pairs, vec![("REDEEM", 2), ("TRADE", 1)]);
+        assert!(wallet_activity_type_counts(&conn, "0xnone")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_copy_fidelity_breakdown_groups_by_outcome() {
+        let conn = test_db();
+        conn.execute_batch(
+            "INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xw', '0xc', 'COPIED');
+             INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xw', '0xc', 'SKIPPED_PORTFOLIO_RISK');
+             INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xw', '0xc', 'COPIED');
+             INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xw', '0xc', 'COPIED');
+             INSERT INTO copy_fidelity_events (proxy_wallet, condition_id, outcome) VALUES ('0xother', '0xc', 'SKIPPED_NO_FILL');",
+        )
+        .unwrap();
+
+        let rows = copy_fidelity_breakdown(&conn, "0xw").unwrap();
+        let triples: Vec<(&str, usize, &str)> = rows
+            .iter()
+            .map(|r| (r.outcome.as_str(), r.count, r.share_display.as_str()))
+            .collect();
+        assert_eq!(
+            triples,
+            vec![("COPIED", 3, "75%"), ("SKIPPED_PORTFOLIO_RISK", 1, "25%")]
+        );
+        assert!(copy_fidelity_breakdown(&conn, "0xnone").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_wscore_explanation_weights_latest_30d_sub_scores() {
+        let conn = test_db();
+        conn.execute_batch(
+            "INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore, edge_score, consistency_score,
+                 market_skill_score, timing_skill_score, behavior_quality_score, recency_edge_score)
+               VALUES ('0xw', '2026-02-09', 30, 0.40, 0.1, 0.1, 0.1, 0.1, 0.1, 0.1);
+             INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore, edge_score, consistency_score,
+                 market_skill_score, timing_skill_score, behavior_quality_score, recency_edge_score)
+               VALUES ('0xw', '2026-02-10', 30, 0.30, 0.8, 0.4, 0.5, 0.2, 1.0, 0.6);
+             INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore, edge_score)
+               VALUES ('0xw', '2026-02-11', 7, 0.99, 0.99);",
+        )
+        .unwrap();
+        let weights = WScoreWeights {
+            edge: 0.5,
+            consistency: 0.5,
+            market_skill: 0.0,
+            timing_skill: 0.0,
+            behavior_quality: 0.0,
+            recency_edge: 0.0,
+        };
+
+        let e = wscore_explanation(&conn, "0xw", weights).unwrap().unwrap();
+        assert_eq!(e.score_date, "2026-02-10");
+        let edge = &e.components[0];
+        assert_eq!(edge.component, "Edge");
+        assert!((edge.contribution - 0.4).abs() < 1e-9);
+        assert!((e.components[1].contribution - 0.2).abs() < 1e-9);
+        assert!(e.components[2..]
+            .iter()
+            .all(|c| c.contribution.abs() < 1e-9));
+        assert!((e.weighted_sum - 0.6).abs() < 1e-9);
+        assert_eq!(e.adjustment_display, "-0.30");
+        assert!(wscore_explanation(&conn, "0xnone", weights)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_wallet_notes_newest_first_per_wallet() {
+        let conn = test_db();
+        conn.execute(
+            "INSERT INTO wallets (proxy_wallet, discovered_from) VALUES ('0xw1', 'HOLDER'), ('0xw2', 'HOLDER')",
+            [],
+        )
+        .unwrap();
+        insert_wallet_note(&conn, "0xw1", "manual review pending").unwrap();
+        insert_wallet_note(&conn, "0xw1", "suspected insider").unwrap();
+        insert_wallet_note(&conn, "0xw2", "other wallet").unwrap();
+        assert_eq!(
+            insert_wallet_note(&conn, "0xnone", "typo").unwrap(),
+            None,
+            "unknown wallets get no note"
+        );
+
+        let notes: Vec<String> = wallet_notes(&conn, "0xw1")
+            .unwrap()
+            .into_iter()
+            .map(|n| n.note)
+            .collect();
+        assert_eq!(notes, vec!["suspected insider", "manual review pending"]);
+        assert!(wallet_notes(&conn, "0xnone").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_wallet_features_history_returns_latest_n_oldest_first() {
+        let conn = test_db();
+        for (date, window, pnl) in [
+            ("2026-02-10", 30, 10.0),
+            ("2026-02-11", 30, 20.0),
+            ("2026-02-12", 30, 30.0),
+            ("2026-02-12", 7, 99.0),
+        ] {
+            conn.execute(
+                "INSERT INTO wallet_features_daily (proxy_wallet, feature_date, window_days, total_pnl, sharpe_ratio)
+                 VALUES ('0xw', ?1, ?2, ?3, 1.0)",
+                rusqlite::params![date, window, pnl],
+            )
+            .unwrap();
+        }
+
+        let points = wallet_features_history(&conn, "0xw", 30, 2).unwrap();
+        let dates: Vec<&str> = points.iter().map(|p| p.feature_date.as_str()).collect();
+        assert_eq!(dates, vec!["2026-02-11", "2026-02-12"]);
+        assert_eq!(points[1].total_pnl, 30.0);
+        assert_eq!(points[1].max_drawdown_pct, 0.0);
+
+        // Fewer snapshots than requested: return what exists.
+        assert_eq!(
+            wallet_features_history(&conn, "0xw", 7, 30).unwrap().len(),
+            1
+        );
+        assert!(wallet_features_history(&conn, "0xnone", 30, 30)
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_for_each_wallet_trade_honours_limit_and_early_stop() {
+        let conn = test_db();
+        for ts in 1..=5 {
+            conn.execute(
+                "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp)
+                 VALUES ('0xw', '0xm', 'BUY', 1.0, 0.5, ?1)",
+                [ts],
+            )
+            .unwrap();
+        }
+
+        let mut seen = Vec::new();
+        for_each_wallet_trade(&conn, "0xw", 1, None, |t| {
+            seen.push(t.id);
+            Ok(true)
+        })
+        .unwrap();
+        assert_eq!(seen, vec![4, 3, 2, 1]);
+
+        let mut seen = Vec::new();
+        for_each_wallet_trade(&conn, "0xw", 0, Some(3), |t| {
+            seen.push(t.id);
+            Ok(seen.len() < 2)
+        })
+        .unwrap();
+        assert_eq!(seen, vec![5, 4]);
+    }
+
     #[test]
     fn test_age_seconds_datetime_format() {
         // A date far in the past should have large age
@@ -2899,12 +3852,34 @@ mod tests {
         assert!(age > 86400);
     }
 
+    #[test]
+    fn test_age_seconds_future_timestamp_clamped_and_counted() {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        let future = (chrono::Utc::now() + chrono::Duration::hours(1))
+            .format("%Y-%m-%d %H:%M:%S")
+            .to_string();
+
+        let age = metrics::with_local_recorder(&recorder, || age_seconds_from_timestamp(&future));
+        assert_eq!(age, 0);
+        assert!(handle
+            .render()
+            .contains("evaluator_future_timestamp_total 1"));
+    }
+
     #[test]
     fn test_age_seconds_unknown_format() {
         let age = age_seconds_from_timestamp("garbage");
         assert_eq!(age, i64::MAX);
     }
 
+    #[test]
+    fn test_parse_sqlite_datetime() {
+        let dt = parse_sqlite_datetime("2026-02-10 11:00:00").unwrap();
+        assert_eq!(dt.to_rfc3339(), "2026-02-10T11:00:00+00:00");
+        assert!(parse_sqlite_datetime("2026-02-10").is_none());
+    }
+
     /// Helper: insert a wallet with features, scores, traits, and rules events for scorecard tests.
     fn insert_scored_wallet(conn: &Connection) {
         conn.execute(
@@ -2962,7 +3937,7 @@ mod tests {
     fn test_wallet_journey_includes_features() {
         let conn = test_db();
         insert_scored_wallet(&conn);
-        let journey = wallet_journey(&conn, "0xscored").unwrap().unwrap();
+        let journey = wallet_journey(&conn, "0xscored", 0.5).unwrap().unwrap();
         let f = journey.features.expect("features should be populated");
         assert_eq!(f.trade_count, 100);
         assert_eq!(f.win_count, 60);
@@ -2982,7 +3957,7 @@ mod tests {
     fn test_wallet_journey_includes_scores() {
         let conn = test_db();
         insert_scored_wallet(&conn);
-        let journey = wallet_journey(&conn, "0xscored").unwrap().unwrap();
+        let journey = wallet_journey(&conn, "0xscored", 0.5).unwrap().unwrap();
         let s = journey.score.expect("score should be populated");
         assert_eq!(s.wscore_display, "0.72");
         assert_eq!(s.wscore_pct, "72");
@@ -2991,13 +3966,15 @@ mod tests {
         assert_eq!(s.market_skill_display, "0.63");
         assert_eq!(s.timing_skill_display, "0.55");
         assert_eq!(s.behavior_quality_display, "0.88");
+        // Rows scored before recency_edge_score existed show 0
+        assert_eq!(s.recency_edge_display, "0.00");
     }
 
     #[test]
     fn test_wallet_journey_includes_traits() {
         let conn = test_db();
         insert_scored_wallet(&conn);
-        let journey = wallet_journey(&conn, "0xscored").unwrap().unwrap();
+        let journey = wallet_journey(&conn, "0xscored", 0.5).unwrap().unwrap();
         assert_eq!(journey.traits.len(), 2);
         let bonder = journey.traits.iter().find(|t| t.display == "BONDER");
         assert!(bonder.is_some(), "should have BONDER trait");
@@ -3020,7 +3997,7 @@ mod tests {
     fn test_wallet_journey_rules_events_in_timeline() {
         let conn = test_db();
         insert_scored_wallet(&conn);
-        let journey = wallet_journey(&conn, "0xscored").unwrap().unwrap();
+        let journey = wallet_journey(&conn, "0xscored", 0.5).unwrap().unwrap();
         let rules_event = journey
             .events
             .iter()
@@ -3040,7 +4017,7 @@ mod tests {
             [],
         )
         .unwrap();
-        let journey = wallet_journey(&conn, "0xbare").unwrap().unwrap();
+        let journey = wallet_journey(&conn, "0xbare", 0.5).unwrap().unwrap();
         assert!(journey.features.is_none());
         assert!(journey.score.is_none());
         assert!(journey.traits.is_empty());
@@ -3052,7 +4029,7 @@ mod tests {
     fn test_wallet_journey_score_history() {
         let conn = test_db();
         insert_scored_wallet(&conn);
-        let journey = wallet_journey(&conn, "0xscored").unwrap().unwrap();
+        let journey = wallet_journey(&conn, "0xscored", 0.5).unwrap().unwrap();
         assert_eq!(journey.score_history.len(), 2);
         // Newest first
         assert_eq!(journey.score_history[0].score_date, "2026-02-13");
@@ -3109,8 +4086,10 @@ mod tests {
         )
         .unwrap();
 
-        let (active, active_count) = wallet_active_positions_page(&conn, "0xpos", 0, 20).unwrap();
-        let (closed, closed_count) = wallet_closed_positions_page(&conn, "0xpos", 0, 20).unwrap();
+        let (active, active_count) =
+            wallet_active_positions_page(&conn, "0xpos", 0, 20, 0.5).unwrap();
+        let (closed, closed_count) =
+            wallet_closed_positions_page(&conn, "0xpos", 0, 20, 0.5).unwrap();
 
         assert_eq!(active_count, 1, "should have 1 active position");
         assert_eq!(closed_count, 1, "should have 1 closed position");
@@ -3120,6 +4099,45 @@ mod tests {
         assert_eq!(closed[0].condition_id, "0xm2");
     }
 
+    #[test]
+    fn test_wallet_positions_threshold_boundary_is_closed() {
+        let conn = test_db();
+        // net 2.0 on 0xm1 (exactly the threshold), net 2.5 on 0xm2 (just above).
+        for (cid, side, size, ts) in [
+            ("0xm1", "BUY", 5.0, 1000),
+            ("0xm1", "SELL", 3.0, 1001),
+            ("0xm2", "BUY", 2.5, 1002),
+        ] {
+            conn.execute(
+                "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp, outcome)
+                 VALUES ('0xedge', ?1, ?2, ?3, 0.5, ?4, 'Yes')",
+                rusqlite::params![cid, side, size, ts],
+            )
+            .unwrap();
+        }
+
+        let (active, active_count) =
+            wallet_active_positions_page(&conn, "0xedge", 0, 20, 2.0).unwrap();
+        let (closed, closed_count) =
+            wallet_closed_positions_page(&conn, "0xedge", 0, 20, 2.0).unwrap();
+        assert_eq!((active_count, closed_count), (1, 1));
+        assert_eq!(active[0].condition_id, "0xm2");
+        assert_eq!(closed[0].condition_id, "0xm1");
+
+        let summary = wallet_positions_summary(&conn, "0xedge", 20, 2.0).unwrap();
+        assert_eq!((summary.active_count, summary.closed_count), (1, 1));
+        assert_eq!(summary.closed_positions[0].condition_id, "0xm1");
+
+        let (rows, total) =
+            all_active_positions(&conn, 0, 20, PositionSort::TotalBet, 2.0).unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(rows[0].condition_id, "0xm2");
+
+        // Default threshold: both positions are active.
+        let (_, active_count) = wallet_active_positions_page(&conn, "0xedge", 0, 20, 0.5).unwrap();
+        assert_eq!(active_count, 2);
+    }
+
     /// Characterization test for follow_worthy_rankings before N+1 fix.
     /// Tests that trade counts and PnL are correctly retrieved.
     #[test]
@@ -3209,7 +4227,13 @@ mod tests {
         )
         .unwrap();
 
-        let rankings = follow_worthy_rankings(&conn, Some(10)).unwrap();
+        let rankings = follow_worthy_rankings(
+            &conn,
+            Some(10),
+            FollowWorthyThresholds::default(),
+            RankingWindow::Combined,
+        )
+        .unwrap();
         assert_eq!(rankings.len(), 2);
 
         // rank1 has higher wscore, should be first
@@ -3222,6 +4246,171 @@ mod tests {
         assert_eq!(rankings[1].pnl_display, "$-5.00");
     }
 
+    #[test]
+    fn test_follow_worthy_rankings_window_drives_order_and_roi() {
+        let conn = test_db();
+        // 0xshort: best 7d ROI and WScore; 0xlong: best 30d ROI. 0xcold fails the 30d bar.
+        for (wallet, wscore, roi_7d, roi_30d) in [
+            ("0xshort", 0.9, 20.0, 11.0),
+            ("0xlong", 0.6, 6.0, 40.0),
+            ("0xcold", 0.95, 50.0, 1.0),
+        ] {
+            conn.execute(
+                "INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore, paper_roi_pct)
+                 VALUES (?1, date('now'), 7, ?2, ?3), (?1, date('now'), 30, ?2, ?4)",
+                rusqlite::params![wallet, wscore, roi_7d, roi_30d],
+            )
+            .unwrap();
+        }
+        let t = FollowWorthyThresholds::default();
+        let order = |window| -> Vec<(String, String)> {
+            follow_worthy_rankings(&conn, None, t, window)
+                .unwrap()
+                .into_iter()
+                .map(|r| (r.proxy_wallet, r.roi_display))
+                .collect()
+        };
+
+        assert_eq!(
+            order(RankingWindow::Combined),
+            vec![
+                ("0xshort".to_string(), "+20.0% / +11.0%".to_string()),
+                ("0xlong".to_string(), "+6.0% / +40.0%".to_string()),
+            ]
+        );
+        assert_eq!(
+            order(RankingWindow::Days7),
+            vec![
+                ("0xshort".to_string(), "+20.0%".to_string()),
+                ("0xlong".to_string(), "+6.0%".to_string()),
+            ]
+        );
+        assert_eq!(
+            order(RankingWindow::Days30),
+            vec![
+                ("0xlong".to_string(), "+40.0%".to_string()),
+                ("0xshort".to_string(), "+11.0%".to_string()),
+            ]
+        );
+        assert_eq!(
+            RankingWindow::from_days(None),
+            Some(RankingWindow::Combined)
+        );
+        assert_eq!(
+            RankingWindow::from_days(Some(30)),
+            Some(RankingWindow::Days30)
+        );
+        assert_eq!(RankingWindow::from_days(Some(14)), None);
+    }
+
+    #[test]
+    fn test_follow_worthy_thresholds_shared_by_rankings_and_funnel() {
+        let conn = test_db();
+        for (wallet, roi_7d, roi_30d) in [("0xhot", 12.0, 25.0), ("0xwarm", 6.0, 11.0)] {
+            conn.execute(
+                "INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore, paper_roi_pct)
+                 VALUES (?1, date('now'), 7, 0.5, ?2), (?1, date('now'), 30, 0.5, ?3)",
+                rusqlite::params![wallet, roi_7d, roi_30d],
+            )
+            .unwrap();
+        }
+
+        let default = FollowWorthyThresholds::default();
+        assert_eq!(
+            follow_worthy_rankings(&conn, None, default, RankingWindow::Combined)
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            unified_funnel_counts(&conn, &[], 45, default)
+                .unwrap()
+                .worth_following,
+            2
+        );
+
+        let raised = FollowWorthyThresholds {
+            min_roi_7d_pct: 10.0,
+            min_roi_30d_pct: 20.0,
+        };
+        let rankings =
+            follow_worthy_rankings(&conn, None, raised, RankingWindow::Combined).unwrap();
+        assert_eq!(rankings.len(), 1);
+        assert_eq!(rankings[0].proxy_wallet, "0xhot");
+        assert_eq!(
+            unified_funnel_counts(&conn, &[], 45, raised)
+                .unwrap()
+                .worth_following,
+            1
+        );
+        assert_eq!(
+            persona_funnel_counts(&conn, raised)
+                .unwrap()
+                .follow_worthy_wallets,
+            1
+        );
+    }
+
+    #[test]
+    fn test_market_detail_lists_discovered_wallets_by_wscore() {
+        let conn = test_db();
+        conn.execute(
+            "INSERT INTO markets (condition_id, title, event_slug) VALUES ('0xm1', 'Market 1', 'ev1')",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO market_scores (condition_id, score_date, mscore, rank)
+             VALUES ('0xm1', '2026-02-09', 0.6, 3), ('0xm1', '2026-02-10', 0.8, 1)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO wallets (proxy_wallet, discovered_from, discovered_market, is_active)
+             VALUES ('0xlow', 'HOLDER', '0xm1', 1), ('0xhigh', 'HOLDER', '0xm1', 1),
+                    ('0xunscored', 'TRADER_RECENT', '0xm1', 0), ('0xelsewhere', 'HOLDER', '0xm2', 1)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO wallet_scores_daily (proxy_wallet, score_date, window_days, wscore)
+             VALUES ('0xlow', '2026-02-10', 30, 0.2), ('0xhigh', '2026-02-10', 30, 0.9)",
+            [],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO wallet_personas (proxy_wallet, persona, confidence, classified_at)
+             VALUES ('0xhigh', 'Informed Specialist', 0.9, '2026-02-10 10:00:00')",
+            [],
+        )
+        .unwrap();
+
+        let detail = market_detail(&conn, "0xm1").unwrap().unwrap();
+        assert_eq!(detail.title, "Market 1");
+        assert_eq!(
+            detail.polymarket_url.as_deref(),
+            Some("https://polymarket.com/event/ev1")
+        );
+        assert_eq!(detail.score_history.len(), 2);
+        assert_eq!(detail.score_history[0].score_date, "2026-02-10");
+        assert_eq!(detail.score_history[0].rank, Some(1));
+
+        let wallets: Vec<&str> = detail
+            .wallets
+            .iter()
+            .map(|w| w.proxy_wallet.as_str())
+            .collect();
+        assert_eq!(wallets, vec!["0xhigh", "0xlow", "0xunscored"]);
+        assert_eq!(
+            detail.wallets[0].persona.as_deref(),
+            Some("Informed Specialist")
+        );
+        assert_eq!(detail.wallets[2].wscore, None);
+        assert!(!detail.wallets[2].is_active);
+
+        assert!(market_detail(&conn, "0xunknown").unwrap().is_none());
+    }
+
     /// Direct test for wallet_positions_summary consolidated query.
     /// Verifies new function matches behavior of old separate queries.
     #[test]
@@ -3272,7 +4461,7 @@ mod tests {
         .unwrap();
 
         // Test new consolidated function
-        let summary = wallet_positions_summary(&conn, "0xtest", 20).unwrap();
+        let summary = wallet_positions_summary(&conn, "0xtest", 20, 0.5).unwrap();
 
         assert_eq!(summary.active_count, 1);
         assert_eq!(summary.closed_count, 1);
@@ -3282,6 +4471,43 @@ mod tests {
         assert_eq!(summary.closed_positions[0].condition_id, "0xm2");
     }
 
+    #[test]
+    fn test_all_active_positions_sorts_and_excludes_closed() {
+        let conn = test_db();
+        // 0xa: open 100 @ 0.50 (bet 50), last trade 1000
+        // 0xb: open 10 @ 0.90 (bet 9), last trade 3000
+        // 0xc: fully closed
+        for (wallet, cid, side, size, price, ts) in [
+            ("0xa", "0xm1", "BUY", 100.0, 0.50, 1000),
+            ("0xb", "0xm2", "BUY", 10.0, 0.90, 3000),
+            ("0xc", "0xm1", "BUY", 200.0, 0.50, 500),
+            ("0xc", "0xm1", "SELL", 200.0, 0.60, 600),
+        ] {
+            conn.execute(
+                "INSERT INTO trades_raw (proxy_wallet, condition_id, side, size, price, timestamp, outcome)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'Yes')",
+                rusqlite::params![wallet, cid, side, size, price, ts],
+            )
+            .unwrap();
+        }
+
+        let (rows, total) =
+            all_active_positions(&conn, 0, 20, PositionSort::TotalBet, 0.5).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(rows[0].proxy_wallet, "0xa");
+        assert!((rows[0].total_bet - 50.0).abs() < 1e-9);
+
+        let (rows, _) = all_active_positions(&conn, 0, 20, PositionSort::LastTradeAt, 0.5).unwrap();
+        assert_eq!(rows[0].proxy_wallet, "0xb");
+        assert_eq!(rows[0].last_trade_at, 3000);
+
+        let (rows, total) =
+            all_active_positions(&conn, 1, 1, PositionSort::NetShares, 0.5).unwrap();
+        assert_eq!(total, 2);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].proxy_wallet, "0xb");
+    }
+
     /// Test wallet_positions_summary respects limit with many positions.
     #[test]
     fn test_wallet_positions_summary_respects_limit() {
@@ -3333,7 +4559,7 @@ mod tests {
             .unwrap();
         }
 
-        let summary = wallet_positions_summary(&conn, "0xlimit", 20).unwrap();
+        let summary = wallet_positions_summary(&conn, "0xlimit", 20, 0.5).unwrap();
 
         // Counts should include ALL positions
         assert_eq!(
@@ -3405,7 +4631,8 @@ mod tests {
         )
         .unwrap();
 
-        let counts = unified_funnel_counts(&conn).unwrap();
+        let counts =
+            unified_funnel_counts(&conn, &[], 45, FollowWorthyThresholds::default()).unwrap();
         assert_eq!(counts.all_wallets, 2);
         assert_eq!(counts.suitable_personas, 2);
         assert_eq!(